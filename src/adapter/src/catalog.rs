@@ -20,7 +20,7 @@ use anyhow::bail;
 use chrono::{DateTime, Utc};
 use futures::Future;
 use itertools::Itertools;
-use mz_compute_client::protocol::command::ComputeParameters;
+use mz_compute_client::protocol::command::{CompactionSchedule, ComputeParameters};
 use mz_storage_client::controller::IntrospectionType;
 use mz_storage_client::types::parameters::{PersistParameters, StorageParameters};
 use once_cell::sync::Lazy;
@@ -46,9 +46,11 @@ use mz_ore::collections::CollectionExt;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::{to_datetime, EpochMillis, NowFn};
 use mz_pgrepr::oid::FIRST_USER_OID;
+use mz_repr::adt::interval::Interval;
 use mz_repr::{explain_new::ExprHumanizer, Diff, GlobalId, RelationDesc, ScalarType};
 use mz_secrets::InMemorySecretsController;
 use mz_sql::ast::display::AstDisplay;
+use mz_sql::ast::visit_mut::VisitMut;
 use mz_sql::ast::Expr;
 use mz_sql::catalog::{
     CatalogComputeInstance, CatalogDatabase, CatalogError as SqlCatalogError,
@@ -57,9 +59,9 @@ use mz_sql::catalog::{
     SessionCatalog, TypeReference,
 };
 use mz_sql::names::{
-    Aug, DatabaseId, FullObjectName, ObjectQualifiers, PartialObjectName, QualifiedObjectName,
-    QualifiedSchemaName, RawDatabaseSpecifier, ResolvedDatabaseSpecifier, RoleId, SchemaId,
-    SchemaSpecifier,
+    Aug, DatabaseId, FullObjectName, NameSimplifier, ObjectQualifiers, PartialObjectName,
+    QualifiedObjectName, QualifiedSchemaName, RawDatabaseSpecifier, ResolvedDatabaseSpecifier,
+    RoleId, SchemaId, SchemaSpecifier,
 };
 use mz_sql::plan::{
     CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
@@ -67,7 +69,12 @@ use mz_sql::plan::{
     Plan, PlanContext, StatementDesc, StorageHostConfig as PlanStorageHostConfig,
 };
 use mz_sql::{plan, DEFAULT_SCHEMA};
-use mz_sql_parser::ast::{CreateSinkOption, CreateSourceOption, Statement, WithOptionValue};
+use mz_sql_parser::ast::{
+    ClusterOption, ClusterOptionName, CreateClusterReplicaStatement, CreateClusterStatement,
+    CreateDatabaseStatement, CreateSchemaStatement, CreateSinkOption, CreateSourceOption, Ident,
+    Raw, ReplicaDefinition, ReplicaOption, ReplicaOptionName, Statement, UnresolvedDatabaseName,
+    UnresolvedSchemaName, Value, WithOptionValue,
+};
 use mz_ssh_util::keys::SshKeyPairSet;
 use mz_stash::{Stash, StashFactory};
 use mz_storage_client::types::hosts::{StorageHostConfig, StorageHostResourceAllocation};
@@ -730,6 +737,7 @@ impl CatalogState {
                     conn_id: None,
                     depends_on: vec![log_id],
                     compute_instance: id,
+                    lazy: false,
                 }),
             );
             log_indexes.insert(log.variant.clone(), index_id);
@@ -1364,6 +1372,84 @@ impl ConnCatalog<'_> {
     }
 }
 
+/// Returns the options of a `CREATE CLUSTER REPLICA` statement that creates a
+/// replica with the given configuration.
+fn replica_options(config: &ComputeReplicaConfig) -> Vec<ReplicaOption<Raw>> {
+    fn option(name: ReplicaOptionName, value: Value) -> ReplicaOption<Raw> {
+        ReplicaOption {
+            name,
+            value: Some(WithOptionValue::Value(value)),
+        }
+    }
+    fn addrs(addrs: &BTreeSet<String>) -> Option<WithOptionValue<Raw>> {
+        Some(WithOptionValue::Sequence(
+            addrs
+                .iter()
+                .map(|addr| WithOptionValue::Value(Value::String(addr.clone())))
+                .collect(),
+        ))
+    }
+
+    let mut options = vec![];
+    match &config.location {
+        ComputeReplicaLocation::Remote {
+            addrs: remote_addrs,
+            compute_addrs,
+            workers,
+        } => {
+            options.push(ReplicaOption {
+                name: ReplicaOptionName::Remote,
+                value: addrs(remote_addrs),
+            });
+            options.push(ReplicaOption {
+                name: ReplicaOptionName::Compute,
+                value: addrs(compute_addrs),
+            });
+            options.push(option(
+                ReplicaOptionName::Workers,
+                Value::Number(workers.to_string()),
+            ));
+        }
+        ComputeReplicaLocation::Managed {
+            size,
+            availability_zone,
+            az_user_specified,
+            ..
+        } => {
+            options.push(option(ReplicaOptionName::Size, Value::String(size.clone())));
+            if *az_user_specified {
+                options.push(option(
+                    ReplicaOptionName::AvailabilityZone,
+                    Value::String(availability_zone.clone()),
+                ));
+            }
+        }
+    }
+    match config.logging.interval {
+        Some(interval) => {
+            options.push(option(
+                ReplicaOptionName::IntrospectionInterval,
+                Value::String(format!("{} microseconds", interval.as_micros())),
+            ));
+            options.push(option(
+                ReplicaOptionName::IntrospectionDebugging,
+                Value::Boolean(config.logging.log_logging),
+            ));
+        }
+        None => options.push(option(
+            ReplicaOptionName::IntrospectionInterval,
+            Value::Null,
+        )),
+    }
+    if let Some(effort) = config.idle_arrangement_merge_effort {
+        options.push(option(
+            ReplicaOptionName::IdleArrangementMergeEffort,
+            Value::Number(effort.to_string()),
+        ));
+    }
+    options
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Database {
     pub name: String,
@@ -1382,6 +1468,19 @@ pub struct Schema {
     pub oid: u32,
     pub items: BTreeMap<String, GlobalId>,
     pub functions: BTreeMap<String, GlobalId>,
+    pub quotas: SchemaQuotas,
+}
+
+/// Limits on the number of objects of a schema, which apply in addition to the
+/// system-wide resource limits. Quotas that are `None` are not enforced.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SchemaQuotas {
+    /// The maximum number of sources that ingest data from an external system.
+    pub max_sources: Option<u32>,
+    /// The maximum number of indexes.
+    pub max_indexes: Option<u32>,
+    /// The maximum number of materialized views.
+    pub max_materialized_views: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -1504,6 +1603,9 @@ pub struct Source {
     /// Whether the source's logical compaction window is controlled by
     /// METRICS_RETENTION
     pub is_retained_metrics_relation: bool,
+    /// The temporal predicate that dataflows apply when they import the
+    /// source, if the source has a `RETENTION`.
+    pub retention_filter: Option<MirScalarExpr>,
 }
 
 impl Source {
@@ -1604,11 +1706,13 @@ pub struct Ingestion {
 pub struct Sink {
     pub create_sql: String,
     pub from: GlobalId,
+    pub from_projection: Option<Vec<usize>>,
     // TODO(benesch): this field duplicates information that could be derived
     // from the connection ID. Too hard to fix at the moment.
     pub connection: StorageSinkConnectionState,
     pub envelope: SinkEnvelope,
     pub with_snapshot: bool,
+    pub track_latency: bool,
     pub depends_on: Vec<GlobalId>,
     pub host_config: StorageHostConfig,
 }
@@ -1661,6 +1765,8 @@ pub struct Index {
     pub conn_id: Option<ConnectionId>,
     pub depends_on: Vec<GlobalId>,
     pub compute_instance: ComputeInstanceId,
+    /// Whether the dataflow of the index is only built once a query uses it.
+    pub lazy: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -2240,7 +2346,7 @@ impl Catalog {
         }
 
         let schemas = catalog.storage().await.load_schemas().await?;
-        for (schema_id, schema_name, database_id) in schemas {
+        for (schema_id, schema_name, database_id, quotas) in schemas {
             let oid = catalog.allocate_oid()?;
             let (schemas_by_id, schemas_by_name, database_spec) = match &database_id {
                 Some(database_id) => {
@@ -2272,6 +2378,7 @@ impl Catalog {
                     oid,
                     items: BTreeMap::new(),
                     functions: BTreeMap::new(),
+                    quotas,
                 },
             );
             schemas_by_name.insert(schema_name.clone(), schema_id);
@@ -2430,6 +2537,7 @@ impl Catalog {
                                 .is_retained_metrics_relation
                                 .then(|| catalog.state.system_config().metrics_retention()),
                             is_retained_metrics_relation: coll.is_retained_metrics_relation,
+                            retention_filter: None,
                         }),
                     );
                 }
@@ -2965,6 +3073,8 @@ impl Catalog {
             CatalogType::Uuid => CatalogType::Uuid,
             CatalogType::VarChar => CatalogType::VarChar,
             CatalogType::Int2Vector => CatalogType::Int2Vector,
+            CatalogType::Point => CatalogType::Point,
+            CatalogType::BoundingBox => CatalogType::BoundingBox,
         };
 
         BuiltinType {
@@ -3494,6 +3604,24 @@ impl Catalog {
             .await
     }
 
+    /// Returns the schemas stored in the built-in schema registry.
+    pub async fn registry_schemas(&mut self) -> Result<Vec<storage::RegistrySchema>, Error> {
+        self.storage().await.load_registry_schemas().await
+    }
+
+    /// Registers a schema under a subject in the built-in schema registry and
+    /// returns the ID of the schema.
+    pub async fn register_registry_schema(
+        &mut self,
+        subject: &str,
+        schema: &str,
+    ) -> Result<i32, Error> {
+        self.storage()
+            .await
+            .register_registry_schema(subject, schema)
+            .await
+    }
+
     pub fn resolve_database(&self, database_name: &str) -> Result<&Database, SqlCatalogError> {
         self.state.resolve_database(database_name)
     }
@@ -3665,6 +3793,7 @@ impl Catalog {
                 oid,
                 items: BTreeMap::new(),
                 functions: BTreeMap::new(),
+                quotas: SchemaQuotas::default(),
             },
         );
         Ok(())
@@ -4106,6 +4235,11 @@ impl Catalog {
                 database_id: DatabaseId,
                 schema_id: SchemaId,
             },
+            UpdateSchemaQuotas {
+                database_id: DatabaseId,
+                schema_id: SchemaId,
+                quotas: SchemaQuotas,
+            },
             DropRole {
                 name: String,
             },
@@ -4363,6 +4497,109 @@ impl Catalog {
                         },
                     )?;
                 }
+                Op::AlterSourceTimestampInterval {
+                    id,
+                    timestamp_interval,
+                } => {
+                    use mz_sql::ast::Value;
+                    use mz_sql_parser::ast::CreateSourceOptionName::TimestampInterval;
+
+                    let entry = state.get_entry(&id);
+                    let name = entry.name().clone();
+
+                    if entry.id().is_system() {
+                        let schema_name = state
+                            .resolve_full_name(&name, session.map(|session| session.conn_id()))
+                            .schema;
+                        return Err(AdapterError::Catalog(Error::new(
+                            ErrorKind::ReadOnlySystemSchema(schema_name),
+                        )));
+                    }
+
+                    let old_source = match entry.item() {
+                        CatalogItem::Source(source) => source.clone(),
+                        other => {
+                            coord_bail!("ALTER SOURCE entry was not a source: {}", other.typ())
+                        }
+                    };
+
+                    // As with `Op::AlterSource`, we need to rewrite the option in
+                    // the creation statement for the change to survive restarts.
+                    let mut stmt = mz_sql::parse::parse(&old_source.create_sql)
+                        .unwrap()
+                        .into_element();
+
+                    let create_stmt = match &mut stmt {
+                        Statement::CreateSource(s) => s,
+                        _ => coord_bail!(
+                            "source {id} was not created with a CREATE SOURCE statement"
+                        ),
+                    };
+
+                    create_stmt
+                        .with_options
+                        .retain(|x| x.name != TimestampInterval);
+
+                    let duration = match timestamp_interval {
+                        Some(interval) => {
+                            create_stmt.with_options.push(CreateSourceOption {
+                                name: TimestampInterval,
+                                value: Some(WithOptionValue::Value(Value::String(
+                                    interval.to_string(),
+                                ))),
+                            });
+                            interval.duration()?
+                        }
+                        None => state.system_config().default_timestamp_interval(),
+                    };
+
+                    let data_source = match old_source.data_source {
+                        DataSourceDesc::Ingestion(mut ingestion) => {
+                            ingestion.desc.timestamp_interval = duration;
+                            DataSourceDesc::Ingestion(ingestion)
+                        }
+                        _ => unreachable!("already guaranteed that we do not permit modifying the TIMESTAMP INTERVAL of subsource or introspection source"),
+                    };
+
+                    let create_sql = stmt.to_ast_string_stable();
+                    let source = CatalogItem::Source(Source {
+                        create_sql,
+                        data_source,
+                        ..old_source
+                    });
+
+                    let ser = Self::serialize_item(&source);
+                    tx.update_item(id, &name.item, &ser)?;
+
+                    // NB: this will be re-incremented by the action below.
+                    builtin_table_updates.extend(state.pack_item_update(id, -1));
+
+                    catalog_action(
+                        state,
+                        builtin_table_updates,
+                        Action::UpdateItem {
+                            id,
+                            to_name: name,
+                            to_item: source,
+                        },
+                    )?;
+                }
+                Op::AlterSchemaQuotas {
+                    database_id,
+                    schema_id,
+                    quotas,
+                } => {
+                    tx.update_schema_quotas(&schema_id, quotas.clone())?;
+                    catalog_action(
+                        state,
+                        builtin_table_updates,
+                        Action::UpdateSchemaQuotas {
+                            database_id,
+                            schema_id,
+                            quotas,
+                        },
+                    )?;
+                }
                 Op::CreateDatabase {
                     name,
                     oid,
@@ -5170,6 +5407,7 @@ impl Catalog {
                             oid,
                             items: BTreeMap::new(),
                             functions: BTreeMap::new(),
+                            quotas: SchemaQuotas::default(),
                         },
                     );
                     db.schemas_by_name.insert(schema_name, id.clone());
@@ -5279,6 +5517,17 @@ impl Catalog {
                     db.schemas_by_id.remove(&schema_id);
                 }
 
+                Action::UpdateSchemaQuotas {
+                    database_id,
+                    schema_id,
+                    quotas,
+                } => {
+                    let db = state.database_by_id.get_mut(&database_id).unwrap();
+                    let schema = db.schemas_by_id.get_mut(&schema_id).unwrap();
+                    info!("update quotas of schema {}.{}", db.name, schema.name.schema);
+                    schema.quotas = quotas;
+                }
+
                 Action::DropRole { name } => {
                     if state.roles.remove(&name).is_some() {
                         info!("drop role {}", name);
@@ -5515,6 +5764,7 @@ impl Catalog {
                 depends_on,
                 custom_logical_compaction_window: None,
                 is_retained_metrics_relation: false,
+                retention_filter: source.retention_filter,
             }),
             Plan::CreateView(CreateViewPlan { view, .. }) => {
                 let optimizer = Optimizer::logical_optimizer();
@@ -5549,18 +5799,22 @@ impl Catalog {
                 conn_id: None,
                 depends_on,
                 compute_instance: index.compute_instance,
+                lazy: index.lazy,
             }),
             Plan::CreateSink(CreateSinkPlan {
                 sink,
                 with_snapshot,
+                track_latency,
                 host_config,
                 ..
             }) => CatalogItem::Sink(Sink {
                 create_sql: sink.create_sql,
                 from: sink.from,
+                from_projection: sink.from_projection,
                 connection: StorageSinkConnectionState::Pending(sink.connection_builder),
                 envelope: sink.envelope,
                 with_snapshot,
+                track_latency,
                 depends_on,
                 host_config: self.resolve_storage_host_config(&host_config)?,
             }),
@@ -5604,6 +5858,123 @@ impl Catalog {
         self.state.dump()
     }
 
+    /// Returns the statements that recreate all user objects in the catalog,
+    /// as pairs of the object name and the statement.
+    ///
+    /// Databases and schemas come first, then clusters and their replicas, and
+    /// then items, each of which follows all items it depends on. Replaying the
+    /// statements in an empty environment recreates the objects, which allows
+    /// migrating between versions with incompatible catalogs. Secret values,
+    /// roles and the clusters linked to sources and sinks are not included.
+    pub fn dump_sql(&self) -> Vec<(String, String)> {
+        let mut statements = vec![];
+
+        for database in self.databases() {
+            let stmt = CreateDatabaseStatement {
+                name: UnresolvedDatabaseName(Ident::new(&database.name)),
+                if_not_exists: true,
+            };
+            statements.push((database.name.clone(), stmt.to_ast_string_stable()));
+            for schema in database.schemas_by_id.values() {
+                let stmt = CreateSchemaStatement {
+                    name: UnresolvedSchemaName(vec![
+                        Ident::new(&database.name),
+                        Ident::new(&schema.name.schema),
+                    ]),
+                    if_not_exists: true,
+                };
+                let name = format!("{}.{}", database.name, schema.name.schema);
+                statements.push((name, stmt.to_ast_string_stable()));
+            }
+        }
+
+        let mut instances: Vec<_> = self
+            .user_compute_instances()
+            .filter(|instance| instance.linked_object_id.is_none())
+            .collect();
+        instances.sort_by_key(|instance| instance.id);
+        for instance in instances {
+            let stmt = CreateClusterStatement::<Raw> {
+                name: Ident::new(&instance.name),
+                options: vec![ClusterOption {
+                    name: ClusterOptionName::Replicas,
+                    value: Some(WithOptionValue::Sequence(vec![])),
+                }],
+            };
+            statements.push((instance.name.clone(), stmt.to_ast_string_stable()));
+            let mut replicas: Vec<_> = instance.replicas_by_id.iter().collect();
+            replicas.sort_by_key(|(id, _)| **id);
+            for (_, replica) in replicas {
+                let stmt = CreateClusterReplicaStatement::<Raw> {
+                    of_cluster: Ident::new(&instance.name),
+                    definition: ReplicaDefinition {
+                        name: Ident::new(&replica.name),
+                        options: replica_options(&replica.config),
+                    },
+                };
+                let name = format!("{}.{}", instance.name, replica.name);
+                statements.push((name, stmt.to_ast_string_stable()));
+            }
+        }
+
+        // Order the items topologically, using Kahn's algorithm. Of the items
+        // whose dependencies have been emitted, the one with the lowest ID is
+        // emitted first, which keeps the order stable.
+        let session_catalog = self.for_system_session();
+        let items: BTreeMap<_, _> = self
+            .entries()
+            .filter(|entry| entry.id().is_user() && !entry.item().is_temporary())
+            .map(|entry| (entry.id(), entry))
+            .collect();
+        let mut dependencies: BTreeMap<GlobalId, BTreeSet<GlobalId>> = BTreeMap::new();
+        let mut dependents: BTreeMap<GlobalId, BTreeSet<GlobalId>> = BTreeMap::new();
+        for (id, entry) in &items {
+            let uses: BTreeSet<_> = entry
+                .uses()
+                .iter()
+                .filter(|dep| *dep != id && items.contains_key(dep))
+                .copied()
+                .collect();
+            for dep in &uses {
+                dependents.entry(*dep).or_default().insert(*id);
+            }
+            dependencies.insert(*id, uses);
+        }
+        let mut ready: BTreeSet<GlobalId> = dependencies
+            .iter()
+            .filter(|(_, uses)| uses.is_empty())
+            .map(|(id, _)| *id)
+            .collect();
+        while let Some(id) = ready.iter().next().copied() {
+            ready.remove(&id);
+            let entry = items[&id];
+            let name = self.resolve_full_name(entry.name(), None).to_string();
+            // Refer to clusters by name rather than by ID, as the IDs are not
+            // preserved when the statements are replayed.
+            let stmt = mz_sql::parse::parse(entry.create_sql())
+                .expect("create_sql cannot be invalid")
+                .into_element();
+            let (mut stmt, _) = mz_sql::names::resolve(&session_catalog, stmt)
+                .expect("create_sql cannot be invalid");
+            NameSimplifier {
+                catalog: &session_catalog,
+            }
+            .visit_statement_mut(&mut stmt);
+            statements.push((name, stmt.to_ast_string_stable()));
+            for dependent in dependents.get(&id).into_iter().flatten() {
+                let uses = dependencies
+                    .get_mut(dependent)
+                    .expect("dependent is an item");
+                uses.remove(&id);
+                if uses.is_empty() {
+                    ready.insert(*dependent);
+                }
+            }
+        }
+
+        statements
+    }
+
     pub fn config(&self) -> &mz_sql::catalog::CatalogConfig {
         self.state.config()
     }
@@ -5761,6 +6132,24 @@ impl Catalog {
         ComputeParameters {
             max_result_size: Some(config.max_result_size()),
             persist: self.persist_config(),
+            arrangement_maintenance_budget: Some(config.arrangement_maintenance_budget()),
+            arrangement_compaction_schedule: Some(self.compaction_schedule()),
+            disabled_log_families: Some(config.introspection_disabled_logs()),
+            schedule_sample_rate: Some(config.introspection_scheduling_sample_rate()),
+        }
+    }
+
+    /// Return the current schedule for the merging of arrangement batches, which defers the
+    /// merging of large arrangements outside of the `arrangement_compaction_window`.
+    pub fn compaction_schedule(&self) -> CompactionSchedule {
+        let config = self.system_config();
+        match config.arrangement_compaction_window() {
+            Some(window) if !window.contains_time((self.config().now)()) => {
+                CompactionSchedule::Deferred {
+                    min_records: u64::cast_from(config.arrangement_compaction_min_records()),
+                }
+            }
+            _ => CompactionSchedule::Always,
         }
     }
 
@@ -5796,6 +6185,16 @@ pub enum Op {
         id: GlobalId,
         host_config: plan::StorageHostConfig,
     },
+    AlterSourceTimestampInterval {
+        id: GlobalId,
+        /// The new timestamp interval, or `None` to reset it to the default.
+        timestamp_interval: Option<Interval>,
+    },
+    AlterSchemaQuotas {
+        database_id: DatabaseId,
+        schema_id: SchemaId,
+        quotas: SchemaQuotas,
+    },
     CreateDatabase {
         name: String,
         oid: u32,
@@ -6269,6 +6668,14 @@ impl SessionCatalog for ConnCatalog<'_> {
         self.conn_id == SYSTEM_CONN_ID || self.state.system_config().window_functions()
     }
 
+    fn default_timestamp_interval(&self) -> Duration {
+        self.state.system_config().default_timestamp_interval()
+    }
+
+    fn default_introspection_interval(&self) -> Duration {
+        self.state.system_config().default_introspection_interval()
+    }
+
     fn now(&self) -> EpochMillis {
         (self.state.config().now)()
     }
@@ -6784,6 +7191,7 @@ mod tests {
                             conn_id: None,
                             depends_on: vec![on_id],
                             compute_instance: ComputeInstanceId::User(1),
+                            lazy: false,
                         })
                     }
                 };
@@ -1131,6 +1131,50 @@ pub const TYPE_NUM_RANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_POINT: BuiltinType<NameReference> = BuiltinType {
+    name: "point",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_POINT_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Point,
+        array_id: None,
+    },
+};
+
+pub const TYPE_POINT_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_point",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_POINT_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_POINT.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_BOX: BuiltinType<NameReference> = BuiltinType {
+    name: "box",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_BOX_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::BoundingBox,
+        array_id: None,
+    },
+};
+
+pub const TYPE_BOX_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_box",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_BOX_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_BOX.name,
+        },
+        array_id: None,
+    },
+};
+
 pub const MZ_DATAFLOW_OPERATORS: BuiltinLog = BuiltinLog {
     name: "mz_dataflow_operators",
     schema: MZ_INTERNAL_SCHEMA,
@@ -1221,6 +1265,12 @@ pub const MZ_RAW_PEEK_DURATIONS: BuiltinLog = BuiltinLog {
     variant: LogVariant::Compute(ComputeLog::PeekDuration),
 };
 
+pub const MZ_COMPUTE_RECENT_UPDATES_INTERNAL: BuiltinLog = BuiltinLog {
+    name: "mz_compute_recent_updates_internal",
+    schema: MZ_INTERNAL_SCHEMA,
+    variant: LogVariant::Compute(ComputeLog::RecentUpdates),
+};
+
 pub const MZ_MESSAGE_COUNTS_RECEIVED_INTERNAL: BuiltinLog = BuiltinLog {
     name: "mz_message_counts_received_internal",
     schema: MZ_INTERNAL_SCHEMA,
@@ -1326,7 +1376,8 @@ pub static MZ_COLUMNS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("nullable", ScalarType::Bool.nullable(false))
         .with_column("type", ScalarType::String.nullable(false))
         .with_column("default", ScalarType::String.nullable(true))
-        .with_column("type_oid", ScalarType::Oid.nullable(false)),
+        .with_column("type_oid", ScalarType::Oid.nullable(false))
+        .with_column("type_mod", ScalarType::Int32.nullable(false)),
     is_retained_metrics_relation: false,
 });
 pub static MZ_INDEXES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1762,7 +1813,36 @@ pub static MZ_SOURCE_STATISTICS: Lazy<BuiltinSource> = Lazy::new(|| BuiltinSourc
         .with_column("messages_received", ScalarType::UInt64.nullable(false))
         .with_column("updates_staged", ScalarType::UInt64.nullable(false))
         .with_column("updates_committed", ScalarType::UInt64.nullable(false))
-        .with_column("bytes_received", ScalarType::UInt64.nullable(false)),
+        .with_column("bytes_received", ScalarType::UInt64.nullable(false))
+        .with_column("messages_quarantined", ScalarType::UInt64.nullable(false))
+        .with_column("last_quarantine_error", ScalarType::String.nullable(true)),
+    is_retained_metrics_relation: true,
+});
+
+pub static MZ_SINK_FRONTIER_LAG_HISTOGRAM: Lazy<BuiltinSource> = Lazy::new(|| BuiltinSource {
+    name: "mz_sink_frontier_lag_histogram",
+    schema: MZ_INTERNAL_SCHEMA,
+    data_source: Some(IntrospectionType::StorageSinkFrontierLagHistogram),
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("lag_ms", ScalarType::UInt64.nullable(false))
+        .with_column("count", ScalarType::UInt64.nullable(false)),
+    is_retained_metrics_relation: true,
+});
+
+pub static MZ_KAFKA_CONSUMER_STATISTICS: Lazy<BuiltinSource> = Lazy::new(|| BuiltinSource {
+    name: "mz_kafka_consumer_statistics",
+    schema: MZ_INTERNAL_SCHEMA,
+    data_source: Some(IntrospectionType::StorageKafkaConsumerStatistics),
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("worker_id", ScalarType::UInt64.nullable(false))
+        .with_column("broker", ScalarType::String.nullable(false))
+        .with_column("state", ScalarType::String.nullable(false))
+        .with_column("rx_errors", ScalarType::UInt64.nullable(false))
+        .with_column("tx_errors", ScalarType::UInt64.nullable(false))
+        .with_column("throttle_time_avg_ms", ScalarType::UInt64.nullable(false))
+        .with_column("rebalances", ScalarType::UInt64.nullable(false)),
     is_retained_metrics_relation: true,
 });
 
@@ -2153,7 +2233,7 @@ pub const PG_ATTRIBUTE: BuiltinView = BuiltinView {
     mz_columns.type_oid AS atttypid,
     pg_type.typlen AS attlen,
     position::int8::int2 as attnum,
-    -1::pg_catalog.int4 as atttypmod,
+    mz_columns.type_mod as atttypmod,
     NOT nullable as attnotnull,
     mz_columns.default IS NOT NULL as atthasdef,
     ''::pg_catalog.\"char\" as attidentity,
@@ -2284,6 +2364,79 @@ pub const MZ_WORKER_COMPUTE_DELAYS: BuiltinView = BuiltinView {
 FROM mz_internal.mz_raw_worker_compute_delays",
 };
 
+pub const MZ_COMPUTE_MAINTENANCE_STATISTICS: BuiltinView = BuiltinView {
+    name: "mz_compute_maintenance_statistics",
+    schema: MZ_INTERNAL_SCHEMA,
+    sql: "CREATE VIEW mz_internal.mz_compute_maintenance_statistics AS
+WITH workers AS (
+    SELECT
+        export_id,
+        pg_catalog.count(*) AS workers
+    FROM
+        mz_internal.mz_compute_exports
+    GROUP BY
+        export_id
+),
+updates AS (
+    SELECT
+        export_id,
+        pg_catalog.sum(CASE WHEN kind = 'input' THEN 1 ELSE 0 END) AS input_updates,
+        pg_catalog.sum(CASE WHEN kind = 'output' THEN 1 ELSE 0 END) AS output_updates
+    FROM
+        mz_internal.mz_compute_recent_updates_internal
+    GROUP BY
+        export_id
+),
+records AS (
+    SELECT
+        operator_id,
+        worker_id,
+        pg_catalog.count(*) AS records
+    FROM
+        mz_internal.mz_arrangement_records_internal
+    GROUP BY
+        operator_id, worker_id
+),
+operator_skew AS (
+    SELECT
+        workers.export_id,
+        records.operator_id,
+        pg_catalog.max(records.records)::pg_catalog.float8 * workers.workers
+            / pg_catalog.sum(records.records)::pg_catalog.float8 AS skew
+    FROM
+        records,
+        mz_internal.mz_dataflow_operator_dataflows,
+        mz_internal.mz_dataflows,
+        mz_internal.mz_compute_exports,
+        workers
+    WHERE
+        mz_dataflow_operator_dataflows.id = records.operator_id AND
+        mz_dataflow_operator_dataflows.worker_id = records.worker_id AND
+        mz_dataflows.id = mz_dataflow_operator_dataflows.dataflow_id AND
+        mz_dataflows.worker_id = mz_dataflow_operator_dataflows.worker_id AND
+        mz_compute_exports.dataflow_local_id = mz_dataflows.local_id AND
+        mz_compute_exports.worker_id = mz_dataflows.worker_id AND
+        workers.export_id = mz_compute_exports.export_id
+    GROUP BY
+        workers.export_id, records.operator_id, workers.workers
+)
+SELECT
+    workers.export_id,
+    pg_catalog.coalesce(updates.input_updates, 0)::pg_catalog.float8 / 60
+        AS input_updates_per_second,
+    pg_catalog.coalesce(updates.output_updates, 0)::pg_catalog.float8 / 60
+        AS output_updates_per_second,
+    skew.skew
+FROM
+    workers
+    LEFT JOIN updates USING (export_id)
+    LEFT JOIN (
+        SELECT export_id, pg_catalog.max(skew) AS skew
+        FROM operator_skew
+        GROUP BY export_id
+    ) AS skew USING (export_id)",
+};
+
 pub const MZ_PEEK_DURATIONS: BuiltinView = BuiltinView {
     name: "mz_peek_durations",
     schema: MZ_INTERNAL_SCHEMA,
@@ -2560,9 +2713,18 @@ SELECT
     c.name AS column_name,
     c.position::int8 AS ordinal_position,
     c.type AS data_type,
-    NULL::pg_catalog.int4 AS character_maximum_length,
-    NULL::pg_catalog.int4 AS numeric_precision,
-    NULL::pg_catalog.int4 AS numeric_scale
+    CASE
+        WHEN c.type IN ('character', 'character varying') AND c.type_mod >= 0
+        THEN c.type_mod - 4
+    END AS character_maximum_length,
+    CASE
+        WHEN c.type = 'numeric' AND c.type_mod >= 0
+        THEN ((c.type_mod - 4) >> 16) & 65535
+    END AS numeric_precision,
+    CASE
+        WHEN c.type = 'numeric' AND c.type_mod >= 0
+        THEN (c.type_mod - 4) & 65535
+    END AS numeric_scale
 FROM mz_catalog.mz_columns c
 JOIN mz_catalog.mz_objects o ON o.id = c.id
 JOIN mz_catalog.mz_schemas s ON s.id = o.schema_id
@@ -2679,6 +2841,124 @@ FROM mz_materialized_views AS mviews
 JOIN mz_clusters AS clusters ON clusters.id = mviews.cluster_id",
 };
 
+pub const MZ_SHOW_FULL_VIEWS: BuiltinView = BuiltinView {
+    name: "mz_show_full_views",
+    schema: MZ_INTERNAL_SCHEMA,
+    sql: "CREATE VIEW mz_internal.mz_show_full_views
+AS SELECT
+    views.name,
+    indexes.on_id IS NOT NULL AS indexed,
+    indexes.frontier,
+    views.schema_id
+FROM mz_catalog.mz_views AS views
+LEFT JOIN (
+    SELECT idxs.on_id, pg_catalog.max(frontiers.time) AS frontier
+    FROM mz_catalog.mz_indexes AS idxs
+    LEFT JOIN mz_internal.mz_cluster_replica_frontiers AS frontiers
+        ON frontiers.export_id = idxs.id
+    GROUP BY idxs.on_id
+) AS indexes ON indexes.on_id = views.id",
+};
+
+pub const MZ_SHOW_FULL_SOURCES: BuiltinView = BuiltinView {
+    name: "mz_show_full_sources",
+    schema: MZ_INTERNAL_SCHEMA,
+    sql: "CREATE VIEW mz_internal.mz_show_full_sources
+AS SELECT
+    sources.name,
+    sources.type,
+    sources.size,
+    usage.size_bytes AS storage_bytes,
+    sources.schema_id
+FROM mz_catalog.mz_sources AS sources
+LEFT JOIN (
+    SELECT DISTINCT ON (object_id) object_id, size_bytes
+    FROM mz_catalog.mz_storage_usage
+    ORDER BY object_id, collection_timestamp DESC
+) AS usage ON usage.object_id = sources.id",
+};
+
+pub const MZ_SHOW_FULL_MATERIALIZED_VIEWS: BuiltinView = BuiltinView {
+    name: "mz_show_full_materialized_views",
+    schema: MZ_INTERNAL_SCHEMA,
+    sql: "CREATE VIEW mz_internal.mz_show_full_materialized_views
+AS SELECT
+    mviews.name,
+    clusters.name AS cluster,
+    frontiers.frontier,
+    usage.size_bytes AS storage_bytes,
+    mviews.schema_id,
+    mviews.cluster_id
+FROM mz_catalog.mz_materialized_views AS mviews
+JOIN mz_catalog.mz_clusters AS clusters ON clusters.id = mviews.cluster_id
+LEFT JOIN (
+    SELECT export_id, pg_catalog.max(time) AS frontier
+    FROM mz_internal.mz_cluster_replica_frontiers
+    GROUP BY export_id
+) AS frontiers ON frontiers.export_id = mviews.id
+LEFT JOIN (
+    SELECT DISTINCT ON (object_id) object_id, size_bytes
+    FROM mz_catalog.mz_storage_usage
+    ORDER BY object_id, collection_timestamp DESC
+) AS usage ON usage.object_id = mviews.id",
+};
+
+pub const MZ_REDUNDANT_INDEXES: BuiltinView = BuiltinView {
+    name: "mz_redundant_indexes",
+    schema: MZ_INTERNAL_SCHEMA,
+    sql: "CREATE VIEW mz_internal.mz_redundant_indexes AS
+WITH index_keys AS (
+    SELECT
+        index_id AS id,
+        pg_catalog.string_agg(
+            coalesce(on_position::pg_catalog.text, on_expression),
+            ', ' ORDER BY index_position::int8
+        ) AS key
+    FROM mz_catalog.mz_index_columns
+    GROUP BY index_id
+),
+indexes AS (
+    SELECT idxs.id, idxs.oid, idxs.name, idxs.on_id, idxs.cluster_id, keys.key, views.definition
+    FROM mz_catalog.mz_indexes AS idxs
+    JOIN index_keys AS keys ON keys.id = idxs.id
+    LEFT JOIN mz_catalog.mz_views AS views ON views.id = idxs.on_id
+)
+SELECT DISTINCT ON (redundant.id)
+    redundant.id,
+    redundant.name,
+    redundant.on_id,
+    redundant.cluster_id,
+    kept.id AS redundant_with,
+    CASE
+        WHEN redundant.on_id = kept.on_id THEN 'duplicate key'
+        ELSE 'identical view'
+    END AS reason
+FROM indexes AS redundant
+JOIN indexes AS kept
+    ON kept.cluster_id = redundant.cluster_id
+    AND kept.key = redundant.key
+    AND (kept.on_id = redundant.on_id OR kept.definition = redundant.definition)
+    AND kept.oid < redundant.oid
+ORDER BY redundant.id, kept.oid",
+};
+
+pub const MZ_REDUNDANT_INDEX_RECORDS: BuiltinView = BuiltinView {
+    name: "mz_redundant_index_records",
+    schema: MZ_INTERNAL_SCHEMA,
+    sql: "CREATE VIEW mz_internal.mz_redundant_index_records AS SELECT
+    redundant.id,
+    pg_catalog.sum(records.records) AS records
+FROM mz_internal.mz_redundant_indexes AS redundant
+JOIN mz_internal.mz_compute_exports AS exports ON exports.export_id = redundant.id
+JOIN mz_internal.mz_dataflows AS dataflows
+    ON dataflows.local_id = exports.dataflow_local_id
+    AND dataflows.worker_id = exports.worker_id
+JOIN mz_internal.mz_records_per_dataflow AS records
+    ON records.id = dataflows.id
+    AND records.worker_id = dataflows.worker_id
+GROUP BY redundant.id",
+};
+
 pub const MZ_SHOW_INDEXES: BuiltinView = BuiltinView {
     name: "mz_show_indexes",
     schema: MZ_INTERNAL_SCHEMA,
@@ -2971,6 +3251,10 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Type(&TYPE_DATE_RANGE_ARRAY),
         Builtin::Type(&TYPE_NUM_RANGE),
         Builtin::Type(&TYPE_NUM_RANGE_ARRAY),
+        Builtin::Type(&TYPE_POINT),
+        Builtin::Type(&TYPE_POINT_ARRAY),
+        Builtin::Type(&TYPE_BOX),
+        Builtin::Type(&TYPE_BOX_ARRAY),
     ];
     for (schema, funcs) in &[
         (PG_CATALOG_SCHEMA, &*mz_sql::func::PG_CATALOG_BUILTINS),
@@ -3009,6 +3293,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Log(&MZ_WORKER_COMPUTE_FRONTIERS),
         Builtin::Log(&MZ_WORKER_COMPUTE_IMPORT_FRONTIERS),
         Builtin::Log(&MZ_RAW_WORKER_COMPUTE_DELAYS),
+        Builtin::Log(&MZ_COMPUTE_RECENT_UPDATES_INTERNAL),
         Builtin::Table(&MZ_VIEW_KEYS),
         Builtin::Table(&MZ_VIEW_FOREIGN_KEYS),
         Builtin::Table(&MZ_KAFKA_SINKS),
@@ -3065,6 +3350,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::View(&MZ_RECORDS_PER_DATAFLOW_OPERATOR),
         Builtin::View(&MZ_RECORDS_PER_DATAFLOW),
         Builtin::View(&MZ_RECORDS_PER_DATAFLOW_GLOBAL),
+        Builtin::View(&MZ_COMPUTE_MAINTENANCE_STATISTICS),
         Builtin::View(&MZ_SCHEDULING_ELAPSED),
         Builtin::View(&MZ_SCHEDULING_PARKS),
         Builtin::View(&MZ_SHOW_MATERIALIZED_VIEWS),
@@ -3101,10 +3387,17 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Source(&MZ_STORAGE_SHARDS),
         Builtin::Source(&MZ_STORAGE_HOST_METRICS),
         Builtin::Source(&MZ_SOURCE_STATISTICS),
+        Builtin::Source(&MZ_SINK_FRONTIER_LAG_HISTOGRAM),
+        Builtin::Source(&MZ_KAFKA_CONSUMER_STATISTICS),
         Builtin::View(&MZ_STORAGE_USAGE),
         Builtin::Table(&MZ_STORAGE_HOST_SIZES),
         Builtin::View(&MZ_SOURCE_UTILIZATION),
         Builtin::View(&MZ_SINK_UTILIZATION),
+        Builtin::View(&MZ_SHOW_FULL_VIEWS),
+        Builtin::View(&MZ_SHOW_FULL_SOURCES),
+        Builtin::View(&MZ_SHOW_FULL_MATERIALIZED_VIEWS),
+        Builtin::View(&MZ_REDUNDANT_INDEXES),
+        Builtin::View(&MZ_REDUNDANT_INDEX_RECORDS),
         Builtin::Index(&MZ_SHOW_DATABASES_IND),
         Builtin::Index(&MZ_SHOW_SCHEMAS_IND),
         Builtin::Index(&MZ_SHOW_CONNECTIONS_IND),
@@ -3547,6 +3840,8 @@ mod tests {
                         | ScalarType::VarChar { .. }
                         | ScalarType::Jsonb
                         | ScalarType::Uuid
+                        | ScalarType::Point
+                        | ScalarType::BoundingBox
                         | ScalarType::Array(_)
                         | ScalarType::Record { .. }
                         | ScalarType::Oid
@@ -23,7 +23,7 @@ use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::jsonb::Jsonb;
-use mz_repr::{Datum, Diff, GlobalId, Row};
+use mz_repr::{Datum, Diff, GlobalId, Row, ScalarType};
 use mz_sql::ast::{CreateIndexStatement, Statement};
 use mz_sql::catalog::{CatalogDatabase, CatalogType, TypeCategory};
 use mz_sql::names::{ResolvedDatabaseSpecifier, SchemaId, SchemaSpecifier};
@@ -287,6 +287,12 @@ impl CatalogState {
                     .map(|d| Datum::String(d))
                     .unwrap_or(Datum::Null);
                 let pgtype = mz_pgrepr::Type::from(&column_type.scalar_type);
+                let typmod = match &column_type.scalar_type {
+                    // The precision of `numeric` is not tracked, so only
+                    // columns with an explicit scale have a type modifier.
+                    ScalarType::Numeric { max_scale: None } => -1,
+                    _ => pgtype.typmod(),
+                };
                 updates.push(BuiltinTableUpdate {
                     id: self.resolve_builtin_table(&MZ_COLUMNS),
                     row: Row::pack_slice(&[
@@ -297,6 +303,7 @@ impl CatalogState {
                         Datum::String(pgtype.name()),
                         default,
                         Datum::UInt32(pgtype.oid()),
+                        Datum::Int32(typmod),
                     ]),
                     diff,
                 });
@@ -583,6 +590,7 @@ impl CatalogState {
                         diff,
                     });
                 }
+                StorageSinkConnection::Elasticsearch(_) => {}
             };
             updates.push(BuiltinTableUpdate {
                 id: self.resolve_builtin_table(&MZ_SINKS),
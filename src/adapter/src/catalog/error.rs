@@ -93,6 +93,11 @@ impl Error {
         Error { kind }
     }
 
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
     /// Reports additional details about the error, if any are available.
     pub fn detail(&self) -> Option<String> {
         match &self.kind {
@@ -196,6 +196,7 @@ async fn migrate(
                 SchemaValue {
                     database_id: None,
                     name: "mz_catalog".into(),
+                    quotas: Default::default(),
                 },
             )?;
             txn.schemas.insert(
@@ -205,6 +206,7 @@ async fn migrate(
                 SchemaValue {
                     database_id: None,
                     name: "pg_catalog".into(),
+                    quotas: Default::default(),
                 },
             )?;
             txn.schemas.insert(
@@ -214,6 +216,7 @@ async fn migrate(
                 SchemaValue {
                     database_id: Some(1),
                     name: "public".into(),
+                    quotas: Default::default(),
                 },
             )?;
             let id = txn.get_and_increment_id(AUDIT_LOG_ID_ALLOC_KEY.to_string())?;
@@ -242,6 +245,7 @@ async fn migrate(
                 SchemaValue {
                     database_id: None,
                     name: "mz_internal".into(),
+                    quotas: Default::default(),
                 },
             )?;
             txn.schemas.insert(
@@ -251,6 +255,7 @@ async fn migrate(
                 SchemaValue {
                     database_id: None,
                     name: "information_schema".into(),
+                    quotas: Default::default(),
                 },
             )?;
             txn.roles.insert(
@@ -656,7 +661,7 @@ impl Connection {
 
     pub async fn load_schemas(
         &mut self,
-    ) -> Result<Vec<(SchemaId, String, Option<DatabaseId>)>, Error> {
+    ) -> Result<Vec<(SchemaId, String, Option<DatabaseId>, catalog::SchemaQuotas)>, Error> {
         Ok(COLLECTION_SCHEMA
             .peek_one(&mut self.stash)
             .await?
@@ -666,6 +671,7 @@ impl Connection {
                     SchemaId::new(k.id),
                     v.name,
                     v.database_id.map(DatabaseId::new),
+                    v.quotas,
                 )
             })
             .collect())
@@ -728,6 +734,65 @@ impl Connection {
             .map(|ev| ev.metric))
     }
 
+    /// Load the schemas stored in the built-in schema registry.
+    pub async fn load_registry_schemas(&mut self) -> Result<Vec<RegistrySchema>, Error> {
+        Ok(COLLECTION_REGISTRY_SCHEMA
+            .peek_one(&mut self.stash)
+            .await?
+            .into_iter()
+            .map(|(k, v)| RegistrySchema {
+                subject: k.subject,
+                version: k.version,
+                id: v.id,
+                schema: v.schema,
+            })
+            .collect())
+    }
+
+    /// Registers `schema` under `subject` in the built-in schema registry and
+    /// returns the ID of the schema.
+    ///
+    /// Like the Confluent Schema Registry, registering a schema that is
+    /// already registered under `subject` returns the existing ID rather than
+    /// creating a new version, and identical schemas share an ID across
+    /// subjects.
+    pub async fn register_registry_schema(
+        &mut self,
+        subject: &str,
+        schema: &str,
+    ) -> Result<i32, Error> {
+        let schemas = self.load_registry_schemas().await?;
+        if let Some(existing) = schemas
+            .iter()
+            .find(|s| s.subject == subject && s.schema == schema)
+        {
+            return Ok(existing.id);
+        }
+        let id = match schemas.iter().find(|s| s.schema == schema) {
+            Some(existing) => existing.id,
+            None => schemas.iter().map(|s| s.id).max().unwrap_or(0) + 1,
+        };
+        let version = schemas
+            .iter()
+            .filter(|s| s.subject == subject)
+            .map(|s| s.version)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let key = RegistrySchemaKey {
+            subject: subject.to_string(),
+            version,
+        };
+        let value = RegistrySchemaValue {
+            id,
+            schema: schema.to_string(),
+        };
+        COLLECTION_REGISTRY_SCHEMA
+            .upsert(&mut self.stash, once((key, value)))
+            .await?;
+        Ok(id)
+    }
+
     /// Load the persisted mapping of system object to global ID. Key is (schema-name, object-name).
     pub async fn load_system_gids(
         &mut self,
@@ -1130,6 +1195,7 @@ impl<'a> Transaction<'a> {
             SchemaValue {
                 database_id: Some(database_id.0),
                 name: schema_name.to_string(),
+                quotas: Default::default(),
             },
         ) {
             Ok(_) => Ok(SchemaId::new(id)),
@@ -1139,6 +1205,33 @@ impl<'a> Transaction<'a> {
         }
     }
 
+    /// Updates the quotas of schema `schema_id` in the transaction.
+    ///
+    /// Returns an error if `schema_id` is not found.
+    pub fn update_schema_quotas(
+        &mut self,
+        schema_id: &SchemaId,
+        quotas: catalog::SchemaQuotas,
+    ) -> Result<(), Error> {
+        let n = self.schemas.update(|k, v| {
+            if k.id == schema_id.0 {
+                Some(SchemaValue {
+                    database_id: v.database_id,
+                    name: v.name.clone(),
+                    quotas: quotas.clone(),
+                })
+            } else {
+                None
+            }
+        })?;
+        assert!(n <= 1);
+        if n == 1 {
+            Ok(())
+        } else {
+            Err(SqlCatalogError::UnknownSchema(schema_id.to_string()).into())
+        }
+    }
+
     pub fn insert_user_role(&mut self, role_name: &str) -> Result<RoleId, Error> {
         self.insert_role(role_name, USER_ROLE_ID_ALLOC_KEY, RoleId::User)
     }
@@ -1808,6 +1901,7 @@ pub async fn initialize_stash(stash: &mut Stash) -> Result<(), Error> {
     add_batch(stash, &mut batches, &COLLECTION_SYSTEM_CONFIGURATION).await?;
     add_batch(stash, &mut batches, &COLLECTION_AUDIT_LOG).await?;
     add_batch(stash, &mut batches, &COLLECTION_STORAGE_USAGE).await?;
+    add_batch(stash, &mut batches, &COLLECTION_REGISTRY_SCHEMA).await?;
     stash.append(&batches).await.map_err(|e| e.into())
 }
 
@@ -1897,6 +1991,8 @@ pub struct SchemaKey {
 pub struct SchemaValue {
     database_id: Option<u64>,
     name: String,
+    #[serde(default)]
+    quotas: catalog::SchemaQuotas,
 }
 
 #[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord, Hash, Debug)]
@@ -1956,6 +2052,31 @@ pub struct ServerConfigurationValue {
     value: String,
 }
 
+#[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord, Hash)]
+pub struct RegistrySchemaKey {
+    subject: String,
+    version: i32,
+}
+
+#[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord)]
+pub struct RegistrySchemaValue {
+    id: i32,
+    schema: String,
+}
+
+/// A schema stored in the built-in schema registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistrySchema {
+    /// The subject under which the schema is registered.
+    pub subject: String,
+    /// The version of the schema within the subject, starting at 1.
+    pub version: i32,
+    /// The ID of the schema, which is shared by identical schemas.
+    pub id: i32,
+    /// The text of the schema.
+    pub schema: String,
+}
+
 pub static COLLECTION_CONFIG: TypedCollection<String, ConfigValue> = TypedCollection::new("config");
 pub static COLLECTION_SETTING: TypedCollection<SettingKey, SettingValue> =
     TypedCollection::new("setting");
@@ -1987,6 +2108,8 @@ pub static COLLECTION_AUDIT_LOG: TypedCollection<AuditLogKey, ()> =
     TypedCollection::new("audit_log");
 pub static COLLECTION_STORAGE_USAGE: TypedCollection<StorageUsageKey, ()> =
     TypedCollection::new("storage_usage");
+pub static COLLECTION_REGISTRY_SCHEMA: TypedCollection<RegistrySchemaKey, RegistrySchemaValue> =
+    TypedCollection::new("registry_schema");
 
 pub static ALL_COLLECTIONS: &[&str] = &[
     COLLECTION_CONFIG.name(),
@@ -2004,4 +2127,5 @@ pub static ALL_COLLECTIONS: &[&str] = &[
     COLLECTION_SYSTEM_CONFIGURATION.name(),
     COLLECTION_AUDIT_LOG.name(),
     COLLECTION_STORAGE_USAGE.name(),
+    COLLECTION_REGISTRY_SCHEMA.name(),
 ];
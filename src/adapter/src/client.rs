@@ -26,6 +26,7 @@ use mz_ore::thread::JoinOnDropHandle;
 use mz_repr::{GlobalId, Row, ScalarType};
 use mz_sql::ast::{Raw, Statement};
 
+use crate::catalog::storage::RegistrySchema;
 use crate::catalog::INTROSPECTION_USER;
 use crate::command::{Canceled, Command, ExecuteResponse, Response, StartupResponse};
 use crate::error::AdapterError;
@@ -396,6 +397,28 @@ impl SessionClient {
             .await
     }
 
+    /// Returns the schemas stored in the built-in schema registry.
+    pub async fn registry_schemas(&mut self) -> Result<Vec<RegistrySchema>, AdapterError> {
+        self.send(|tx, session| Command::RegistrySchemas { session, tx })
+            .await
+    }
+
+    /// Registers a schema under a subject in the built-in schema registry and
+    /// returns the ID of the schema.
+    pub async fn register_schema(
+        &mut self,
+        subject: String,
+        schema: String,
+    ) -> Result<i32, AdapterError> {
+        self.send(|tx, session| Command::RegisterSchema {
+            subject,
+            schema,
+            session,
+            tx,
+        })
+        .await
+    }
+
     /// Inserts a set of rows into the given table.
     ///
     /// The rows only contain the columns positions in `columns`, so they
@@ -466,6 +489,8 @@ impl SessionClient {
                     | Command::Commit { .. }
                     | Command::CancelRequest { .. }
                     | Command::DumpCatalog { .. }
+                    | Command::RegistrySchemas { .. }
+                    | Command::RegisterSchema { .. }
                     | Command::CopyRows { .. }
                     | Command::Terminate { .. } => {}
                 };
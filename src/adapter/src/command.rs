@@ -28,6 +28,7 @@ use mz_repr::{GlobalId, Row, ScalarType};
 use mz_sql::ast::{FetchDirection, ObjectType, Raw, Statement};
 use mz_sql::plan::ExecuteTimeout;
 
+use crate::catalog::storage::RegistrySchema;
 use crate::client::ConnectionId;
 use crate::coord::peek::PeekResponseUnary;
 use crate::error::AdapterError;
@@ -94,6 +95,18 @@ pub enum Command {
         tx: oneshot::Sender<Response<String>>,
     },
 
+    RegistrySchemas {
+        session: Session,
+        tx: oneshot::Sender<Response<Vec<RegistrySchema>>>,
+    },
+
+    RegisterSchema {
+        subject: String,
+        schema: String,
+        session: Session,
+        tx: oneshot::Sender<Response<i32>>,
+    },
+
     CopyRows {
         id: GlobalId,
         columns: Vec<usize>,
@@ -384,9 +397,8 @@ impl ExecuteResponse {
 
         match plan {
             AbortTransaction => vec![TransactionRolledBack],
-            AlterItemRename | AlterNoop | AlterSecret | AlterSink | AlterSource | RotateKeys => {
-                vec![AlteredObject]
-            }
+            AlterItemRename | AlterNoop | AlterSchema | AlterSecret | AlterSink | AlterSource
+            | RotateKeys => vec![AlteredObject],
             AlterIndexSetOptions | AlterIndexResetOptions => {
                 vec![AlteredObject, AlteredIndexLogicalCompaction]
             }
@@ -431,7 +443,7 @@ impl ExecuteResponse {
                 DroppedSecret,
             ],
             PlanKind::EmptyQuery => vec![ExecuteResponseKind::EmptyQuery],
-            Explain | Peek | SendRows | ShowAllVariables | ShowVariable => {
+            Explain | Peek | SendRows | ShowAllVariables | ShowVariable | ShowCreateCatalog => {
                 vec![CopyTo, SendingRows]
             }
             Execute | ReadThenWrite | SendDiffs => vec![Deleted, Inserted, SendingRows, Updated],
@@ -88,6 +88,7 @@ use uuid::Uuid;
 use mz_build_info::BuildInfo;
 use mz_cloud_resources::{CloudResourceController, VpcEndpointConfig};
 use mz_compute_client::controller::{ComputeInstanceEvent, ComputeInstanceId, ReplicaId};
+use mz_compute_client::protocol::command::CompactionSchedule;
 use mz_expr::{MirRelationExpr, OptimizedMirRelationExpr, RowSetFinishing};
 use mz_orchestrator::ServiceProcessMetrics;
 use mz_ore::cast::CastFrom;
@@ -108,7 +109,7 @@ use mz_sql::catalog::EnvironmentId;
 use mz_sql::names::Aug;
 use mz_sql::plan::{self, CopyFormat, MutationKind, Params, QueryWhen};
 use mz_storage_client::controller::{
-    CollectionDescription, CreateExportToken, DataSource, StorageError,
+    CollectionDescription, CreateExportToken, DataSource, ReadPolicy, StorageError,
 };
 use mz_storage_client::types::connections::ConnectionContext;
 use mz_storage_client::types::hosts::StorageHostConfig;
@@ -127,7 +128,7 @@ use crate::command::{Canceled, Command, ExecuteResponse};
 use crate::config::SystemParameterFrontend;
 use crate::coord::appends::{BuiltinTableUpdateSource, Deferred, PendingWriteTxn};
 use crate::coord::id_bundle::CollectionIdBundle;
-use crate::coord::peek::PendingPeek;
+use crate::coord::peek::{PendingPeek, QueuedPeek};
 use crate::coord::read_policy::ReadCapability;
 use crate::coord::timeline::{TimelineContext, TimelineState, WriteTimestamp};
 use crate::coord::timestamp_selection::TimestampContext;
@@ -206,6 +207,13 @@ pub enum Message<T = mz_repr::Timestamp> {
         transient_revision: u64,
         real_time_recency_ts: Timestamp,
     },
+    /// Fails the queued peek with the given id, if it is still waiting.
+    PeekQueueTimeout {
+        id: Uuid,
+    },
+    /// Updates the compute configuration if the arrangement compaction window
+    /// started or ended.
+    CheckCompactionWindow,
 }
 
 #[derive(Derivative)]
@@ -476,6 +484,10 @@ pub struct Coordinator {
     pending_peeks: HashMap<Uuid, PendingPeek>,
     /// A map from client connection ids to a set of all pending peeks for that client.
     client_pending_peeks: HashMap<ConnectionId, BTreeMap<Uuid, ComputeInstanceId>>,
+    /// A map from roles to the peeks that wait for one of the role's
+    /// `max_concurrent_peeks_per_role` slots, in the order they arrived. Queues
+    /// are never empty.
+    queued_peeks: HashMap<String, VecDeque<QueuedPeek>>,
 
     /// A map from client connection ids to a pending real time recency timestamps.
     pending_real_time_recency_timestamp: HashMap<ConnectionId, RealTimeRecencyContext>,
@@ -483,6 +495,10 @@ pub struct Coordinator {
     /// A map from pending subscribes to the subscribe description.
     pending_subscribes: HashMap<GlobalId, PendingSubscribe>,
 
+    /// The lazy indexes whose dataflows have not been built yet, with the read
+    /// policy to apply to them once they are.
+    unhydrated_indexes: HashMap<GlobalId, Option<ReadPolicy<Timestamp>>>,
+
     /// Serializes accesses to write critical sections.
     write_lock: Arc<tokio::sync::Mutex<()>>,
     /// Holds plans deferred due to write lock.
@@ -513,6 +529,10 @@ pub struct Coordinator {
     /// The interval at which to collect storage usage information.
     storage_usage_collection_interval: Duration,
 
+    /// The schedule for the merging of arrangement batches that was last sent
+    /// to the compute controller.
+    compaction_schedule: CompactionSchedule,
+
     /// Segment analytics client.
     segment_client: Option<mz_segment::Client>,
 
@@ -534,6 +554,9 @@ impl Coordinator {
 
         // Inform the controllers about their initial configuration.
         let compute_config = self.catalog.compute_config();
+        if let Some(schedule) = &compute_config.arrangement_compaction_schedule {
+            self.compaction_schedule = schedule.clone();
+        }
         self.controller.compute.update_configuration(compute_config);
         let storage_config = self.catalog.storage_config();
         self.controller.storage.update_configuration(storage_config);
@@ -548,6 +571,7 @@ impl Coordinator {
         // u64 anyway.
         let mut policies_to_set: BTreeMap<Timestamp, CollectionIdBundle> = Default::default();
         policies_to_set.insert(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS, Default::default());
+        let introspection_compaction_window = self.persisted_introspection_compaction_window_ms();
 
         info!("coordinator init: creating compute replicas");
         for instance in self.catalog.compute_instances() {
@@ -581,21 +605,15 @@ impl Coordinator {
                     .await
                     .unwrap();
 
-                // TODO - Should these windows be configurable?
-                policies_to_set
-                    .get_mut(&DEFAULT_LOGICAL_COMPACTION_WINDOW_TS)
-                    .expect(
-                        "Default policy map was inserted just after `policies_to_set` was created.",
-                    )
+                let policies = policies_to_set
+                    .entry(introspection_compaction_window)
+                    .or_insert_with(Default::default);
+                policies
                     .compute_ids
                     .entry(instance.id)
                     .or_insert_with(BTreeSet::new)
                     .extend(replica.config.logging.source_ids());
-                policies_to_set
-                    .get_mut(&DEFAULT_LOGICAL_COMPACTION_WINDOW_TS)
-                    .expect(
-                        "Default policy map was inserted just after `policies_to_set` was created.",
-                    )
+                policies
                     .storage_ids
                     .extend(replica.config.logging.source_ids());
 
@@ -764,6 +782,13 @@ impl Coordinator {
                         continue;
                     }
 
+                    // The dataflows of lazy indexes are built by the first
+                    // query that uses them.
+                    if idx.lazy {
+                        self.unhydrated_indexes.insert(entry.id(), None);
+                        continue;
+                    }
+
                     let policy_entry = policies_to_set
                         .entry(policy.expect("indexes have a compaction window"))
                         .or_insert_with(Default::default);
@@ -917,7 +942,7 @@ impl Coordinator {
         //
         // TODO -- Improve `initialize_read_policies` API so we can avoid calling this in a loop.
         //
-        // As of this writing, there can only be at most two keys in `policies_to_set`,
+        // As of this writing, there can only be a handful of keys in `policies_to_set`,
         // so the extra load isn't crazy, but that might not be true in general if we
         // open up custom compaction windows to users.
         for (ts, policies) in policies_to_set {
@@ -1125,6 +1150,23 @@ impl Coordinator {
             }
         });
 
+        // The arrangement compaction window has a resolution of minutes, so
+        // checking whether it started or ended once a minute is enough.
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        spawn(|| "coord compaction window", async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                interval.tick().await;
+                if internal_cmd_tx
+                    .send(Message::CheckCompactionWindow)
+                    .is_err()
+                {
+                    // If sending fails, the main thread has shutdown.
+                    break;
+                }
+            }
+        });
+
         self.schedule_storage_usage_collection();
 
         loop {
@@ -1331,8 +1373,10 @@ pub async fn serve(
                 txn_reads: Default::default(),
                 pending_peeks: HashMap::new(),
                 client_pending_peeks: HashMap::new(),
+                queued_peeks: HashMap::new(),
                 pending_real_time_recency_timestamp: HashMap::new(),
                 pending_subscribes: HashMap::new(),
+                unhydrated_indexes: HashMap::new(),
                 write_lock: Arc::new(tokio::sync::Mutex::new(())),
                 write_lock_wait_group: VecDeque::new(),
                 pending_writes: Vec::new(),
@@ -1342,6 +1386,7 @@ pub async fn serve(
                 transient_replica_metadata: HashMap::new(),
                 storage_usage_client,
                 storage_usage_collection_interval,
+                compaction_schedule: CompactionSchedule::Always,
                 segment_client,
                 metrics,
             };
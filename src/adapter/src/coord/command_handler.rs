@@ -91,7 +91,7 @@ impl Coordinator {
                 conn_id,
                 secret_key,
             } => {
-                self.handle_cancel(conn_id, secret_key);
+                self.handle_cancel(conn_id, secret_key).await;
             }
 
             Command::DumpCatalog { session, tx } => {
@@ -104,6 +104,29 @@ impl Coordinator {
                 });
             }
 
+            Command::RegistrySchemas { session, tx } => {
+                let result = self
+                    .catalog
+                    .registry_schemas()
+                    .await
+                    .map_err(AdapterError::from);
+                let _ = tx.send(Response { result, session });
+            }
+
+            Command::RegisterSchema {
+                subject,
+                schema,
+                session,
+                tx,
+            } => {
+                let result = self
+                    .catalog
+                    .register_registry_schema(&subject, &schema)
+                    .await
+                    .map_err(AdapterError::from);
+                let _ = tx.send(Response { result, session });
+            }
+
             Command::CopyRows {
                 id,
                 columns,
@@ -349,7 +372,8 @@ impl Coordinator {
                     | Statement::ResetVariable(_)
                     | Statement::StartTransaction(_)
                     | Statement::Subscribe(_)
-                    | Statement::Raise(_) => {
+                    | Statement::Raise(_)
+                    | Statement::ValidateView(_) => {
                         // Always safe.
                     }
 
@@ -369,6 +393,7 @@ impl Coordinator {
                     // Statements below must by run singly (in Started).
                     Statement::AlterConnection(_)
                     | Statement::AlterIndex(_)
+                    | Statement::AlterSchema(_)
                     | Statement::AlterSecret(_)
                     | Statement::AlterSink(_)
                     | Statement::AlterSource(_)
@@ -489,7 +514,7 @@ impl Coordinator {
 
     /// Instruct the dataflow layer to cancel any ongoing, interactive work for
     /// the named `conn_id`.
-    fn handle_cancel(&mut self, conn_id: ConnectionId, secret_key: u32) {
+    async fn handle_cancel(&mut self, conn_id: ConnectionId, secret_key: u32) {
         if let Some(conn_meta) = self.active_conns.get(&conn_id) {
             // If the secret key specified by the client doesn't match the
             // actual secret key for the target connection, we treat this as a
@@ -542,15 +567,19 @@ impl Coordinator {
             // Inform the target session (if it asks) about the cancellation.
             let _ = conn_meta.cancel_tx.send(Canceled::Canceled);
 
+            self.cancel_queued_peeks(conn_id);
+
             for PendingPeek {
                 sender: rows_tx,
                 conn_id: _,
+                role,
             } in self.cancel_pending_peeks(&conn_id)
             {
                 // Cancel messages can be sent after the connection has hung
                 // up, but before the connection's state has been cleaned up.
                 // So we ignore errors when sending the response.
                 let _ = rows_tx.send(PeekResponse::Canceled);
+                self.sequence_queued_peeks(&role).await;
             }
         }
     }
@@ -571,6 +600,9 @@ impl Coordinator {
             .with_label_values(&[session_type])
             .dec();
         self.active_conns.remove(&session.conn_id());
-        self.cancel_pending_peeks(&session.conn_id());
+        self.cancel_queued_peeks(session.conn_id());
+        for peek in self.cancel_pending_peeks(&session.conn_id()) {
+            self.sequence_queued_peeks(&peek.role).await;
+        }
     }
 }
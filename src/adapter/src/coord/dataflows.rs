@@ -266,6 +266,19 @@ impl<'a> DataflowBuilder<'a, mz_repr::Timestamp> {
                             source.desc.typ().clone(),
                             self.monotonic_source(source),
                         );
+                        // Sources with a `RETENTION` only present the rows that
+                        // have not yet expired, which whole-dataflow optimization
+                        // may later combine with other pushed-down operators.
+                        if let Some(filter) = &source.retention_filter {
+                            let (import, _monotonic) = dataflow
+                                .source_imports
+                                .get_mut(id)
+                                .expect("source was just imported");
+                            import.arguments.operators = Some(
+                                MapFilterProject::new(source.desc.arity())
+                                    .filter(Some(filter.clone())),
+                            );
+                        }
                     }
                     CatalogItem::View(view) => {
                         let expr = view.optimized_expr.clone();
@@ -429,6 +442,10 @@ impl<'a> DataflowBuilder<'a, mz_repr::Timestamp> {
 
     /// Determine the given source's monotonicity.
     fn monotonic_source(&self, source: &Source) -> bool {
+        // Rows of sources with a `RETENTION` are retracted when they expire.
+        if source.retention_filter.is_some() {
+            return false;
+        }
         // TODO(petrosagg): store an inverse mapping of subsource -> source in the catalog so that
         // we can retrieve monotonicity information from the parent source.
         match &source.data_source {
@@ -539,7 +556,7 @@ pub fn prep_relation_expr(
                     let mfp =
                         MapFilterProject::new(input.arity()).filter(predicates.iter().cloned());
                     match mfp.into_plan() {
-                        Err(e) => coord_bail!("{:?}", e),
+                        Err(e) => Err(AdapterError::InvalidTemporalFilter(e)),
                         Ok(mut mfp) => {
                             for s in mfp.iter_nontemporal_exprs() {
                                 prep_scalar_expr(catalog, s, style)?;
@@ -32,7 +32,7 @@ use mz_storage_client::types::sources::{
 };
 
 use crate::catalog::{
-    CatalogItem, CatalogState, DataSourceDesc, Op, Sink, StorageSinkConnectionState,
+    CatalogItem, CatalogState, DataSourceDesc, Op, Schema, Sink, StorageSinkConnectionState,
     TransactionResult, SYSTEM_CONN_ID,
 };
 use crate::client::ConnectionId;
@@ -87,6 +87,7 @@ impl Coordinator {
         let mut log_sources_to_drop = vec![];
         let mut tables_to_drop = vec![];
         let mut storage_sinks_to_drop = vec![];
+        let mut sink_progress_to_drop = vec![];
         let mut indexes_to_drop = vec![];
         let mut materialized_views_to_drop = vec![];
         let mut replication_slots_to_drop: Vec<(mz_postgres_util::Config, String)> = vec![];
@@ -123,8 +124,14 @@ impl Coordinator {
                         }
                     }
                     CatalogItem::Sink(catalog::Sink { connection, .. }) => match connection {
-                        StorageSinkConnectionState::Ready(_) => {
+                        StorageSinkConnectionState::Ready(connection) => {
                             storage_sinks_to_drop.push(*id);
+                            match connection {
+                                StorageSinkConnection::Kafka(connection) => {
+                                    sink_progress_to_drop.push((*id, connection.clone()));
+                                }
+                                StorageSinkConnection::Elasticsearch(_) => {}
+                            }
                         }
                         StorageSinkConnectionState::Pending(_) => (),
                     },
@@ -295,6 +302,31 @@ impl Coordinator {
                     }
                 });
             }
+
+            // Likewise, move the removal of the progress records of dropped
+            // sinks to a separate task, so as not to block the coordinator on
+            // an external Kafka cluster.
+            if !sink_progress_to_drop.is_empty() {
+                let connection_context = self.connection_context.clone();
+                task::spawn(|| "drop_sink_progress", async move {
+                    for (id, connection) in sink_progress_to_drop {
+                        let result = Retry::default()
+                            .max_duration(Duration::from_secs(30))
+                            .retry_async(|_state| async {
+                                mz_storage_client::sink::drop_kafka_sink_progress(
+                                    &connection,
+                                    id,
+                                    &connection_context,
+                                )
+                                .await
+                            })
+                            .await;
+                        if let Err(e) = result {
+                            warn!("failed to drop progress records of sink {id}: {e:#}");
+                        }
+                    }
+                });
+            }
         }
         .await;
 
@@ -360,6 +392,10 @@ impl Coordinator {
     pub(crate) fn drop_indexes(&mut self, indexes: Vec<(ComputeInstanceId, GlobalId)>) {
         let mut by_compute_instance: HashMap<_, Vec<_>> = HashMap::new();
         for (compute_instance, id) in indexes {
+            if self.unhydrated_indexes.remove(&id).is_some() {
+                // The dataflow of the lazy index was never built.
+                continue;
+            }
             if self.drop_compute_read_policy(&id) {
                 by_compute_instance
                     .entry(compute_instance)
@@ -483,9 +519,11 @@ impl Coordinator {
                 ))
                 .unwrap()
                 .into_owned(),
+            from_projection: sink.from_projection.clone(),
             connection,
-            envelope: Some(sink.envelope),
+            envelope: Some(sink.envelope.clone()),
             as_of,
+            track_latency: sink.track_latency,
             status_id,
             from_storage_metadata: (),
         };
@@ -576,6 +614,9 @@ impl Coordinator {
         let mut new_databases = 0;
         let mut new_schemas_per_database = HashMap::new();
         let mut new_objects_per_schema = HashMap::new();
+        let mut new_sources_per_schema = HashMap::new();
+        let mut new_indexes_per_schema = HashMap::new();
+        let mut new_materialized_views_per_schema = HashMap::new();
         let mut new_secrets = 0;
         let mut new_roles = 0;
         for op in ops {
@@ -610,12 +651,11 @@ impl Coordinator {
                     *new_replicas_per_cluster.entry(on_cluster_name).or_insert(0) += 1;
                 }
                 Op::CreateItem { name, item, .. } => {
-                    *new_objects_per_schema
-                        .entry((
-                            name.qualifiers.database_spec.clone(),
-                            name.qualifiers.schema_spec.clone(),
-                        ))
-                        .or_insert(0) += 1;
+                    let schema = (
+                        name.qualifiers.database_spec.clone(),
+                        name.qualifiers.schema_spec.clone(),
+                    );
+                    *new_objects_per_schema.entry(schema.clone()).or_insert(0) += 1;
                     match item {
                         CatalogItem::Connection(connection) => match connection.connection {
                             mz_storage_client::types::connections::Connection::AwsPrivatelink(
@@ -632,19 +672,23 @@ impl Coordinator {
                             if source.is_external() {
                                 // Only sources that ingest data from an external system count
                                 // towards resource limits.
-                                new_sources += 1
+                                new_sources += 1;
+                                *new_sources_per_schema.entry(schema).or_insert(0) += 1;
                             }
                         }
                         CatalogItem::Sink(_) => new_sinks += 1,
                         CatalogItem::MaterializedView(_) => {
                             new_materialized_views += 1;
+                            *new_materialized_views_per_schema.entry(schema).or_insert(0) += 1;
+                        }
+                        CatalogItem::Index(_) => {
+                            *new_indexes_per_schema.entry(schema).or_insert(0) += 1;
                         }
                         CatalogItem::Secret(_) => {
                             new_secrets += 1;
                         }
                         CatalogItem::Log(_)
                         | CatalogItem::View(_)
-                        | CatalogItem::Index(_)
                         | CatalogItem::Type(_)
                         | CatalogItem::Func(_) => {}
                     }
@@ -666,12 +710,11 @@ impl Coordinator {
                 }
                 Op::DropItem(id) => {
                     let entry = self.catalog.get_entry(id);
-                    *new_objects_per_schema
-                        .entry((
-                            entry.name().qualifiers.database_spec.clone(),
-                            entry.name().qualifiers.schema_spec.clone(),
-                        ))
-                        .or_insert(0) -= 1;
+                    let schema = (
+                        entry.name().qualifiers.database_spec.clone(),
+                        entry.name().qualifiers.schema_spec.clone(),
+                    );
+                    *new_objects_per_schema.entry(schema.clone()).or_insert(0) -= 1;
                     match entry.item() {
                         CatalogItem::Connection(connection) => match connection.connection {
                             mz_storage_client::types::connections::Connection::AwsPrivatelink(
@@ -689,24 +732,30 @@ impl Coordinator {
                                 // Only sources that ingest data from an external system count
                                 // towards resource limits.
                                 new_sources -= 1;
+                                *new_sources_per_schema.entry(schema).or_insert(0) -= 1;
                             }
                         }
                         CatalogItem::Sink(_) => new_sinks -= 1,
                         CatalogItem::MaterializedView(_) => {
                             new_materialized_views -= 1;
+                            *new_materialized_views_per_schema.entry(schema).or_insert(0) -= 1;
+                        }
+                        CatalogItem::Index(_) => {
+                            *new_indexes_per_schema.entry(schema).or_insert(0) -= 1;
                         }
                         CatalogItem::Secret(_) => {
                             new_secrets -= 1;
                         }
                         CatalogItem::Log(_)
                         | CatalogItem::View(_)
-                        | CatalogItem::Index(_)
                         | CatalogItem::Type(_)
                         | CatalogItem::Func(_) => {}
                     }
                 }
-                Op::AlterSink { .. }
+                Op::AlterSchemaQuotas { .. }
+                | Op::AlterSink { .. }
                 | Op::AlterSource { .. }
+                | Op::AlterSourceTimestampInterval { .. }
                 | Op::DropTimeline(_)
                 | Op::RenameItem { .. }
                 | Op::UpdateComputeReplicaStatus { .. }
@@ -818,6 +867,44 @@ impl Coordinator {
                 "Objects per schema",
             )?;
         }
+        for ((database_spec, schema_spec), new_sources) in new_sources_per_schema {
+            let schema = self
+                .catalog
+                .get_schema(&database_spec, &schema_spec, conn_id);
+            self.validate_schema_quota(
+                schema,
+                new_sources,
+                schema.quotas.max_sources,
+                |item| matches!(item, CatalogItem::Source(source) if source.is_external()),
+                "Sources per schema",
+            )?;
+        }
+        for ((database_spec, schema_spec), new_indexes) in new_indexes_per_schema {
+            let schema = self
+                .catalog
+                .get_schema(&database_spec, &schema_spec, conn_id);
+            self.validate_schema_quota(
+                schema,
+                new_indexes,
+                schema.quotas.max_indexes,
+                |item| matches!(item, CatalogItem::Index(_)),
+                "Indexes per schema",
+            )?;
+        }
+        for ((database_spec, schema_spec), new_materialized_views) in
+            new_materialized_views_per_schema
+        {
+            let schema = self
+                .catalog
+                .get_schema(&database_spec, &schema_spec, conn_id);
+            self.validate_schema_quota(
+                schema,
+                new_materialized_views,
+                schema.quotas.max_materialized_views,
+                |item| matches!(item, CatalogItem::MaterializedView(_)),
+                "Materialized views per schema",
+            )?;
+        }
         self.validate_resource_limit(
             self.catalog.user_secrets().count(),
             new_secrets,
@@ -845,6 +932,43 @@ impl Coordinator {
         F: Fn(&SystemVars) -> u32,
     {
         let limit = resource_limit(self.catalog.system_config());
+        Self::check_resource_limit(limit, current_amount, new_instances, resource_type)
+    }
+
+    /// Validate a quota of `schema` and return an error if that quota is exceeded. The items of
+    /// the schema for which `counts_towards_quota` returns true count towards the quota.
+    //
+    // TODO: Quotas only count objects. Per-role quotas need objects to have
+    // owners, and a quota on arrangement memory needs a per-object size that
+    // survives restarts; the estimate of `peek::estimate_dataflow_footprint`
+    // counts records of a single dataflow and is only computed for peeks.
+    fn validate_schema_quota(
+        &self,
+        schema: &Schema,
+        new_instances: i32,
+        quota: Option<u32>,
+        counts_towards_quota: impl Fn(&CatalogItem) -> bool,
+        resource_type: &str,
+    ) -> Result<(), AdapterError> {
+        let Some(limit) = quota else {
+            return Ok(());
+        };
+        let current_amount = schema
+            .items
+            .values()
+            .filter(|id| counts_towards_quota(self.catalog.get_entry(id).item()))
+            .count();
+        Self::check_resource_limit(limit, current_amount, new_instances, resource_type)
+    }
+
+    /// Return an error if creating `new_instances` more instances of a resource, of which there
+    /// are `current_amount` instances, would exceed `limit`.
+    fn check_resource_limit(
+        limit: u32,
+        current_amount: usize,
+        new_instances: i32,
+        resource_type: &str,
+    ) -> Result<(), AdapterError> {
         let exceeds_limit = match (u32::try_from(current_amount), u32::try_from(new_instances)) {
             // 0 new instances are always ok.
             (_, Ok(new_instances)) if new_instances == 0 => false,
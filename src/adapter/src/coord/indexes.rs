@@ -17,6 +17,8 @@ use mz_transform::IndexOracle;
 use crate::catalog::{CatalogItem, CatalogState, Index, Log};
 use crate::coord::dataflows::DataflowBuilder;
 use crate::coord::{CollectionIdBundle, Coordinator};
+use crate::session::Session;
+use crate::AdapterError;
 
 /// Answers questions about the indexes available on a particular compute
 /// instance.
@@ -37,6 +39,58 @@ impl Coordinator {
             compute: self.controller.compute.instance_ref(instance).unwrap(),
         }
     }
+
+    /// Builds the dataflows of the lazy indexes on the specified compute
+    /// instance that a query reading from the identifiers in `ids` could use.
+    ///
+    /// Like [`ComputeInstanceIndexOracle::sufficient_collections`], this looks
+    /// through unindexed views to the collections they read from. The query
+    /// does not return results until the new indexes are hydrated.
+    pub(crate) async fn hydrate_lazy_indexes<I>(
+        &mut self,
+        session: &Session,
+        instance: ComputeInstanceId,
+        ids: I,
+    ) -> Result<(), AdapterError>
+    where
+        I: IntoIterator<Item = GlobalId>,
+    {
+        // A transaction that has already acquired its read holds cannot read
+        // from new indexes, as they are not part of its timedomain.
+        if self.unhydrated_indexes.is_empty() || self.txn_reads.contains_key(&session.conn_id()) {
+            return Ok(());
+        }
+
+        let mut to_hydrate = BTreeSet::new();
+        let mut todo: BTreeSet<GlobalId> = ids.into_iter().collect();
+        while let Some(id) = todo.iter().rev().next().cloned() {
+            let mut indexes = self
+                .catalog
+                .state()
+                .get_indexes_on(id, instance)
+                .map(|(id, _)| id)
+                .peekable();
+            if indexes.peek().is_some() {
+                to_hydrate.extend(indexes.filter(|id| self.unhydrated_indexes.contains_key(id)));
+            } else if let view @ CatalogItem::View(_) = self.catalog.get_entry(&id).item() {
+                todo.extend(view.uses());
+            }
+            todo.remove(&id);
+        }
+
+        for id in to_hydrate {
+            let dataflow = self.dataflow_builder(instance).build_index_dataflow(id)?;
+            let policy = self
+                .unhydrated_indexes
+                .remove(&id)
+                .expect("index is unhydrated");
+            self.ship_dataflow(dataflow, instance).await;
+            if let Some(policy) = policy {
+                self.update_compute_base_read_policy(instance, id, policy);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T: Copy> DataflowBuilder<'_, T> {
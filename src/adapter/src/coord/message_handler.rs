@@ -19,6 +19,7 @@ use rand::{rngs, Rng, SeedableRng};
 use tracing::{event, warn, Level};
 
 use mz_compute_client::controller::ComputeInstanceEvent;
+use mz_compute_client::protocol::command::ComputeParameters;
 use mz_controller::ControllerResponse;
 use mz_ore::now::EpochMillis;
 use mz_ore::task;
@@ -72,7 +73,9 @@ impl Coordinator {
             // in any situation where you use it, you must also have a code
             // path that responds to the client (e.g. reporting an error).
             Message::RemovePendingPeeks { conn_id } => {
-                self.cancel_pending_peeks(&conn_id);
+                for peek in self.cancel_pending_peeks(&conn_id) {
+                    self.sequence_queued_peeks(&peek.role).await;
+                }
             }
             Message::LinearizeReads(pending_read_txns) => {
                 self.message_linearize_reads(pending_read_txns).await;
@@ -98,6 +101,27 @@ impl Coordinator {
                 )
                 .await;
             }
+            Message::PeekQueueTimeout { id } => {
+                self.handle_peek_queue_timeout(id);
+            }
+            Message::CheckCompactionWindow => {
+                self.check_compaction_window();
+            }
+        }
+    }
+
+    /// Informs the compute controller when the arrangement compaction schedule
+    /// changes because the `arrangement_compaction_window` started or ended.
+    fn check_compaction_window(&mut self) {
+        let schedule = self.catalog.compaction_schedule();
+        if schedule != self.compaction_schedule {
+            self.compaction_schedule = schedule.clone();
+            self.controller
+                .compute
+                .update_configuration(ComputeParameters {
+                    arrangement_compaction_schedule: Some(schedule),
+                    ..Default::default()
+                });
         }
     }
 
@@ -253,7 +277,9 @@ impl Coordinator {
         event!(Level::TRACE, message = format!("{:?}", message));
         match message {
             ControllerResponse::PeekResponse(uuid, response, otel_ctx) => {
-                self.send_peek_response(uuid, response, otel_ctx);
+                if let Some(role) = self.send_peek_response(uuid, response, otel_ctx) {
+                    self.sequence_queued_peeks(&role).await;
+                }
             }
             ControllerResponse::SubscribeResponse(sink_id, response) => {
                 // We use an `if let` here because the peek could have been canceled already.
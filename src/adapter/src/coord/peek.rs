@@ -26,25 +26,44 @@ use mz_compute_client::controller::{ComputeInstanceId, ReplicaId};
 use mz_compute_client::protocol::response::PeekResponse;
 use mz_compute_client::types::dataflows::DataflowDescription;
 use mz_expr::explain::Indices;
-use mz_expr::{EvalError, Id, MirScalarExpr, OptimizedMirRelationExpr, RowSetFinishing};
+use mz_expr::{
+    EvalError, Id, JoinImplementation, JoinInputMapper, LocalId, MirRelationExpr, MirScalarExpr,
+    OptimizedMirRelationExpr, RowSetFinishing,
+};
 use mz_ore::cast::CastFrom;
+use mz_ore::stack::maybe_grow;
 use mz_ore::str::Indent;
 use mz_ore::str::StrExt;
+use mz_ore::task;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_repr::explain_new::{fmt_text_constant_rows, separated_text, DisplayText, ExprHumanizer};
 use mz_repr::{Diff, GlobalId, RelationType, Row};
 
 use crate::client::ConnectionId;
 use crate::coord::timestamp_selection::TimestampContext;
+use crate::coord::Message;
 use crate::explain_new::Displayable;
-use crate::util::send_immediate_rows;
-use crate::{AdapterError, AdapterNotice};
+use crate::metrics;
+use crate::session::Session;
+use crate::util::{send_immediate_rows, ClientTransmitter};
+use crate::{AdapterError, AdapterNotice, ExecuteResponse};
 
 use super::id_bundle::CollectionIdBundle;
 
 pub(crate) struct PendingPeek {
     pub(crate) sender: oneshot::Sender<PeekResponse>,
     pub(crate) conn_id: ConnectionId,
+    /// The role of the session that initiated the peek.
+    pub(crate) role: String,
+}
+
+/// A peek that waits for a running peek of its role to complete, because the
+/// role has `max_concurrent_peeks_per_role` peeks executing.
+pub(crate) struct QueuedPeek {
+    pub(crate) id: Uuid,
+    pub(crate) tx: ClientTransmitter<ExecuteResponse>,
+    pub(crate) session: Session,
+    pub(crate) plan: mz_sql::plan::PeekPlan,
 }
 
 /// The response from a `Peek`, with row multiplicities represented in unary.
@@ -137,8 +156,12 @@ pub struct PlannedPeek {
     pub read_holds: Option<CollectionIdBundle>,
     pub timestamp_context: TimestampContext<mz_repr::Timestamp>,
     pub conn_id: ConnectionId,
+    pub role: String,
     pub source_arity: usize,
     pub id_bundle: CollectionIdBundle,
+    /// The number of records that the dataflow of a slow path peek is
+    /// estimated to hold in memory, see [`estimate_dataflow_footprint`].
+    pub estimated_records: u64,
 }
 
 /// Possible ways in which the coordinator could produce the result for a goal view.
@@ -240,6 +263,196 @@ pub fn create_fast_path_plan<T: timely::progress::Timestamp>(
     Ok(None)
 }
 
+/// Estimates the number of records that the operators of a one-off dataflow
+/// will hold in memory, based on the sizes of the arrangements it imports.
+///
+/// The estimate follows the runtime memory footprints documented on
+/// [`MirRelationExpr`]: operators that arrange their input hold as many
+/// records as the input, a differential join additionally holds the result of
+/// each prefix of its join order, and the exported index holds the entire
+/// output. Cardinalities are upper bounds: filters and aggregations are
+/// assumed to retain every record, inputs related by a join equivalence
+/// produce as many records as the largest of them, and unrelated inputs
+/// produce their cross product. Collections whose size is not known, like
+/// unindexed sources, count as a single record, so the estimate only reflects
+/// the inputs that are known to be large.
+pub fn estimate_dataflow_footprint<T>(
+    dataflow: &DataflowDescription<OptimizedMirRelationExpr, (), T>,
+    arrangement_size: impl Fn(GlobalId) -> Option<u64>,
+) -> u64 {
+    let mut estimator = FootprintEstimator::default();
+    for (index_id, (desc, _typ, _monotonic)) in dataflow.index_imports.iter() {
+        if let Some(size) = arrangement_size(*index_id) {
+            let cardinality = estimator.globals.entry(desc.on_id).or_insert(0);
+            *cardinality = std::cmp::max(*cardinality, size);
+        }
+        estimator.arranged.insert(desc.on_id);
+    }
+    for build in dataflow.objects_to_build.iter() {
+        let cardinality = estimator.cardinality(build.plan.as_inner());
+        estimator.globals.insert(build.id, cardinality);
+    }
+    // Each exported index arranges the entire collection it is built on.
+    for (desc, _typ) in dataflow.index_exports.values() {
+        let cardinality = estimator.globals.get(&desc.on_id).copied().unwrap_or(1);
+        estimator.hold(cardinality);
+    }
+    estimator.footprint
+}
+
+#[derive(Default)]
+struct FootprintEstimator {
+    /// Estimated cardinalities of imported and built collections.
+    globals: BTreeMap<GlobalId, u64>,
+    /// Estimated cardinalities of the bindings in scope.
+    locals: BTreeMap<LocalId, u64>,
+    /// Collections for which the dataflow imports an arrangement.
+    arranged: BTreeSet<GlobalId>,
+    /// The number of records held by the operators visited so far.
+    footprint: u64,
+}
+
+impl FootprintEstimator {
+    /// Returns the estimated cardinality of `expr`, and adds the records held
+    /// by its operators to `self.footprint`.
+    fn cardinality(&mut self, expr: &MirRelationExpr) -> u64 {
+        maybe_grow(|| match expr {
+            MirRelationExpr::Constant { rows, .. } => match rows {
+                Ok(rows) => u64::cast_from(rows.len()),
+                Err(_) => 0,
+            },
+            MirRelationExpr::Get { id, .. } => match id {
+                Id::Global(id) => self.globals.get(id),
+                Id::Local(id) => self.locals.get(id),
+            }
+            .copied()
+            .unwrap_or(1),
+            MirRelationExpr::Let { id, value, body } => {
+                let cardinality = self.cardinality(value);
+                self.locals.insert(*id, cardinality);
+                self.cardinality(body)
+            }
+            MirRelationExpr::LetRec { ids, values, body } => {
+                for (id, value) in ids.iter().zip(values) {
+                    let cardinality = self.cardinality(value);
+                    self.locals.insert(*id, cardinality);
+                }
+                self.cardinality(body)
+            }
+            MirRelationExpr::Project { input, .. }
+            | MirRelationExpr::Map { input, .. }
+            | MirRelationExpr::FlatMap { input, .. }
+            | MirRelationExpr::Filter { input, .. }
+            | MirRelationExpr::Negate { input } => self.cardinality(input),
+            MirRelationExpr::Reduce { input, .. } | MirRelationExpr::Threshold { input } => {
+                let cardinality = self.cardinality(input);
+                self.hold(cardinality);
+                cardinality
+            }
+            MirRelationExpr::TopK {
+                input,
+                group_key,
+                limit,
+                ..
+            } => {
+                let mut cardinality = self.cardinality(input);
+                self.hold(cardinality);
+                if let (true, Some(limit)) = (group_key.is_empty(), limit) {
+                    cardinality = std::cmp::min(cardinality, u64::cast_from(*limit));
+                }
+                cardinality
+            }
+            MirRelationExpr::ArrangeBy { input, .. } => {
+                let cardinality = self.cardinality(input);
+                let imported = match &**input {
+                    MirRelationExpr::Get {
+                        id: Id::Global(id), ..
+                    } => self.arranged.contains(id),
+                    _ => false,
+                };
+                if !imported {
+                    self.hold(cardinality);
+                }
+                cardinality
+            }
+            MirRelationExpr::Union { base, inputs } => {
+                let mut cardinality = self.cardinality(base);
+                for input in inputs {
+                    cardinality = cardinality.saturating_add(self.cardinality(input));
+                }
+                cardinality
+            }
+            MirRelationExpr::Join {
+                inputs,
+                equivalences,
+                implementation,
+            } => {
+                let cardinalities = inputs
+                    .iter()
+                    .map(|input| self.cardinality(input))
+                    .collect::<Vec<_>>();
+                let components = join_components(inputs, equivalences);
+                if let JoinImplementation::Differential((start, _), order) = implementation {
+                    // Every prefix of the join order except the last is arranged.
+                    let mut prefix = vec![*start];
+                    for (input, _, _) in order.iter().take(order.len().saturating_sub(1)) {
+                        prefix.push(*input);
+                        let cardinality = join_cardinality(&prefix, &components, &cardinalities);
+                        self.hold(cardinality);
+                    }
+                }
+                let all = (0..inputs.len()).collect::<Vec<_>>();
+                join_cardinality(&all, &components, &cardinalities)
+            }
+        })
+    }
+
+    fn hold(&mut self, records: u64) {
+        self.footprint = self.footprint.saturating_add(records);
+    }
+}
+
+/// Assigns each input of a join to a connected component, where two inputs
+/// are connected if they are related by some equivalence.
+fn join_components(inputs: &[MirRelationExpr], equivalences: &[Vec<MirScalarExpr>]) -> Vec<usize> {
+    let input_mapper = JoinInputMapper::new(inputs);
+    let mut components = (0..inputs.len()).collect::<Vec<_>>();
+    fn root(components: &[usize], mut input: usize) -> usize {
+        while components[input] != input {
+            input = components[input];
+        }
+        input
+    }
+    for class in equivalences {
+        let mut related = class
+            .iter()
+            .flat_map(|expr| input_mapper.lookup_inputs(expr));
+        if let Some(first) = related.next() {
+            let first = root(&components, first);
+            for other in related {
+                let other = root(&components, other);
+                components[other] = first;
+            }
+        }
+    }
+    (0..inputs.len())
+        .map(|input| root(&components, input))
+        .collect()
+}
+
+/// Estimates the cardinality of joining `inputs`: the product, over the
+/// connected components among them, of the largest input in each component.
+fn join_cardinality(inputs: &[usize], components: &[usize], cardinalities: &[u64]) -> u64 {
+    let mut largest = BTreeMap::new();
+    for input in inputs {
+        let cardinality = largest.entry(components[*input]).or_insert(0);
+        *cardinality = std::cmp::max(*cardinality, cardinalities[*input]);
+    }
+    largest.into_values().fold(1, |product: u64, cardinality| {
+        product.saturating_mul(cardinality)
+    })
+}
+
 impl crate::coord::Coordinator {
     /// Creates a [`PeekPlan`] for the given `dataflow`.
     ///
@@ -300,10 +513,17 @@ impl crate::coord::Coordinator {
             read_holds: _,
             timestamp_context,
             conn_id,
+            role,
             source_arity,
             id_bundle: _,
+            estimated_records: _,
         } = plan;
 
+        self.metrics
+            .peeks_total
+            .with_label_values(&[metrics::peek_plan_label_value(&fast_path)])
+            .inc();
+
         // If the dataflow optimizes to a constant expression, we can immediately return the result.
         if let PeekPlan::FastPath(FastPathPlan::Constant(rows, _)) = fast_path {
             let rows = match rows {
@@ -422,6 +642,7 @@ impl crate::coord::Coordinator {
             PendingPeek {
                 sender: rows_tx,
                 conn_id,
+                role,
             },
         );
         self.client_pending_peeks
@@ -500,17 +721,20 @@ impl crate::coord::Coordinator {
         }
     }
 
+    /// Forwards the response of a peek to its client, and returns the role that
+    /// initiated the peek.
     pub(crate) fn send_peek_response(
         &mut self,
         uuid: Uuid,
         response: PeekResponse,
         otel_ctx: OpenTelemetryContext,
-    ) {
+    ) -> Option<String> {
         // We expect exactly one peek response, which we forward. Then we clean up the
         // peek's state in the coordinator.
         if let Some(PendingPeek {
             sender: rows_tx,
             conn_id,
+            role,
         }) = self.remove_pending_peek(&uuid)
         {
             otel_ctx.attach_as_parent();
@@ -523,9 +747,12 @@ impl crate::coord::Coordinator {
                     self.client_pending_peeks.remove(&conn_id);
                 }
             }
+            Some(role)
+        } else {
+            // Cancellation may cause us to receive responses for peeks no
+            // longer in `self.pending_peeks`, so we quietly ignore them.
+            None
         }
-        // Cancellation may cause us to receive responses for peeks no
-        // longer in `self.pending_peeks`, so we quietly ignore them.
     }
 
     /// Clean up a peek's state.
@@ -533,6 +760,126 @@ impl crate::coord::Coordinator {
         self.pending_peeks.remove(uuid)
     }
 
+    /// Returns the number of peeks of `role` that are executing.
+    fn running_peeks(&self, role: &str) -> usize {
+        self.pending_peeks
+            .values()
+            .filter(|peek| peek.role == role)
+            .count()
+    }
+
+    /// Reports whether a peek of `session` must wait for a running peek of its
+    /// role to complete before it can be sequenced.
+    pub(crate) fn must_queue_peek(&self, session: &Session) -> bool {
+        let limit = self.catalog.system_config().max_concurrent_peeks_per_role();
+        // Internal users are exempt, so that operators can always inspect the
+        // system.
+        if limit == 0 || session.user().is_internal() {
+            return false;
+        }
+        let role = &session.user().name;
+        // Peeks that are already queued go first.
+        self.queued_peeks.contains_key(role) || self.running_peeks(role) >= usize::cast_from(limit)
+    }
+
+    /// Queues a peek until a running peek of its role completes, or fails it
+    /// if it waits for longer than `max_peek_queue_time`.
+    pub(crate) fn queue_peek(
+        &mut self,
+        tx: ClientTransmitter<ExecuteResponse>,
+        session: Session,
+        plan: mz_sql::plan::PeekPlan,
+    ) {
+        let id = Uuid::new_v4();
+        let timeout = self.catalog.system_config().max_peek_queue_time();
+        self.queued_peeks
+            .entry(session.user().name.clone())
+            .or_default()
+            .push_back(QueuedPeek {
+                id,
+                tx,
+                session,
+                plan,
+            });
+        self.metrics.queued_peeks.inc();
+
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "peek_queue_timeout", async move {
+            tokio::time::sleep(timeout).await;
+            // It is not an error for the timeout to fire after `internal_cmd_rx`
+            // has been dropped, or after the peek has left the queue.
+            let _ = internal_cmd_tx.send(Message::PeekQueueTimeout { id });
+        });
+    }
+
+    /// Removes the first queued peek for which `f` returns true.
+    pub(crate) fn remove_queued_peek<F>(&mut self, f: F) -> Option<QueuedPeek>
+    where
+        F: Fn(&QueuedPeek) -> bool,
+    {
+        let (role, idx) = self.queued_peeks.iter().find_map(|(role, queue)| {
+            queue
+                .iter()
+                .position(|peek| f(peek))
+                .map(|idx| (role.clone(), idx))
+        })?;
+        let queue = self.queued_peeks.get_mut(&role).expect("known to exist");
+        let peek = queue.remove(idx);
+        if queue.is_empty() {
+            self.queued_peeks.remove(&role);
+        }
+        self.metrics.queued_peeks.dec();
+        peek
+    }
+
+    /// Removes the queued peeks of the connection `conn_id` from their queues,
+    /// and responds to them that they were canceled.
+    pub(crate) fn cancel_queued_peeks(&mut self, conn_id: ConnectionId) {
+        while let Some(QueuedPeek { tx, session, .. }) =
+            self.remove_queued_peek(|peek| peek.session.conn_id() == conn_id)
+        {
+            tx.send(Ok(ExecuteResponse::Canceled), session);
+        }
+    }
+
+    /// Fails the queued peek with `id`, if it is still waiting.
+    pub(crate) fn handle_peek_queue_timeout(&mut self, id: Uuid) {
+        if let Some(QueuedPeek { tx, session, .. }) = self.remove_queued_peek(|peek| peek.id == id)
+        {
+            let system_config = self.catalog.system_config();
+            let err = AdapterError::PeekQueueTimeout {
+                role: session.user().name.clone(),
+                limit: system_config.max_concurrent_peeks_per_role(),
+                timeout: system_config.max_peek_queue_time(),
+            };
+            tx.send(Err(err), session);
+        }
+    }
+
+    /// Sequences the queued peeks of `role`, in order, for as long as it has
+    /// fewer than `max_concurrent_peeks_per_role` running peeks.
+    pub(crate) async fn sequence_queued_peeks(&mut self, role: &str) {
+        let limit = usize::cast_from(self.catalog.system_config().max_concurrent_peeks_per_role());
+        while limit == 0 || self.running_peeks(role) < limit {
+            let Some(queue) = self.queued_peeks.get_mut(role) else {
+                break;
+            };
+            let peek = queue.pop_front().expect("queues are never empty");
+            if queue.is_empty() {
+                self.queued_peeks.remove(role);
+            }
+            self.metrics.queued_peeks.dec();
+            if peek.tx.is_closed() {
+                // The client hung up while the peek was waiting. Sending the
+                // response fails, which terminates the session.
+                peek.tx.send(Ok(ExecuteResponse::Canceled), peek.session);
+                continue;
+            }
+            self.sequence_peek_begin(peek.tx, peek.session, peek.plan)
+                .await;
+        }
+    }
+
     /// Publishes a notice message to all sessions.
     pub(crate) fn broadcast_notice(&mut self, notice: AdapterNotice) {
         for meta in self.active_conns.values() {
@@ -556,6 +903,7 @@ fn consolidate_constant_updates(rows: Vec<(Row, Diff)>) -> Vec<(Row, Diff)> {
 
 #[cfg(test)]
 mod tests {
+    use mz_compute_client::types::dataflows::{DataflowDesc, IndexDesc};
     use mz_expr::{func::IsNull, MapFilterProject, UnaryFunc};
     use mz_ore::str::Indent;
     use mz_repr::{
@@ -633,4 +981,81 @@ mod tests {
             constant_exp2
         );
     }
+
+    #[test]
+    fn test_estimate_dataflow_footprint() {
+        let typ = RelationType::new(vec![ColumnType {
+            scalar_type: ScalarType::Int64,
+            nullable: false,
+        }]);
+        let arranged_get = |id| MirRelationExpr::ArrangeBy {
+            input: Box::new(MirRelationExpr::global_get(id, typ.clone())),
+            keys: vec![vec![MirScalarExpr::column(0)]],
+        };
+        let dataflow_for = |equivalences| {
+            let mut dataflow = DataflowDesc::new("test".into());
+            for (index_id, on_id) in [(GlobalId::User(3), 1), (GlobalId::User(4), 2)] {
+                let desc = IndexDesc {
+                    on_id: GlobalId::User(on_id),
+                    key: vec![MirScalarExpr::column(0)],
+                };
+                dataflow.import_index(index_id, desc, typ.clone(), false);
+            }
+            let join = MirRelationExpr::Join {
+                inputs: vec![
+                    arranged_get(GlobalId::User(1)),
+                    arranged_get(GlobalId::User(2)),
+                    MirRelationExpr::global_get(GlobalId::User(5), typ.clone()),
+                ],
+                equivalences,
+                implementation: JoinImplementation::Differential(
+                    (0, None),
+                    vec![
+                        (1, vec![MirScalarExpr::column(0)], None),
+                        (2, vec![MirScalarExpr::column(0)], None),
+                    ],
+                ),
+            };
+            dataflow.insert_plan(
+                GlobalId::Transient(1),
+                OptimizedMirRelationExpr::declare_optimized(join),
+            );
+            dataflow.export_index(
+                GlobalId::Transient(2),
+                IndexDesc {
+                    on_id: GlobalId::Transient(1),
+                    key: vec![MirScalarExpr::column(0)],
+                },
+                typ.clone(),
+            );
+            dataflow
+        };
+        let arrangement_size = |id| match id {
+            GlobalId::User(3) => Some(1_000),
+            GlobalId::User(4) => Some(2_000),
+            _ => None,
+        };
+
+        // The imported arrangements are not counted, but the cross product is
+        // held by the arranged prefix and by the exported index. The source
+        // of unknown size counts as a single record.
+        let cross_join = dataflow_for(vec![]);
+        assert_eq!(
+            estimate_dataflow_footprint(&cross_join, arrangement_size),
+            4_000_000
+        );
+
+        // Equi-joined inputs produce as many records as the largest of them.
+        let equi_join = dataflow_for(vec![vec![
+            MirScalarExpr::column(0),
+            MirScalarExpr::column(1),
+        ]]);
+        assert_eq!(
+            estimate_dataflow_footprint(&equi_join, arrangement_size),
+            4_000
+        );
+
+        // Without known sizes, every collection counts as a single record.
+        assert_eq!(estimate_dataflow_footprint(&cross_join, |_| None), 2);
+    }
 }
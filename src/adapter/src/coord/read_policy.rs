@@ -306,6 +306,19 @@ impl crate::coord::Coordinator {
         policy.into()
     }
 
+    /// Returns the compaction window of the persisted introspection sources of replicas, as
+    /// configured by the `persisted_introspection_retention` system variable.
+    pub(crate) fn persisted_introspection_compaction_window_ms(&self) -> Timestamp {
+        let duration = self
+            .catalog
+            .system_config()
+            .persisted_introspection_retention();
+        Timestamp::new(u64::try_from(duration.as_millis()).unwrap_or_else(|_e| {
+            tracing::error!("Absurd persisted introspection retention duration: {duration:?}.");
+            u64::MAX
+        }))
+    }
+
     pub(crate) fn update_storage_base_read_policies(
         &mut self,
         base_policies: Vec<(GlobalId, ReadPolicy<mz_repr::Timestamp>)>,
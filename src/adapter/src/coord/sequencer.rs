@@ -20,11 +20,11 @@ use maplit::btreeset;
 use timely::progress::{Antichain, Timestamp as TimelyTimestamp};
 use tokio::sync::{mpsc, oneshot, OwnedMutexGuard};
 use tracing::{event, warn, Level};
+use uncased::UncasedStr;
 
 use mz_cloud_resources::VpcEndpointConfig;
 use mz_compute_client::controller::{
     ComputeInstanceId, ComputeReplicaConfig, ComputeReplicaLogging, ReplicaId,
-    DEFAULT_COMPUTE_REPLICA_LOGGING_INTERVAL_MICROS,
 };
 use mz_compute_client::types::dataflows::{BuildDesc, DataflowDesc, IndexDesc};
 use mz_compute_client::types::sinks::{
@@ -34,6 +34,7 @@ use mz_expr::{
     permutation_for_arrangement, CollectionPlan, MirRelationExpr, MirScalarExpr,
     OptimizedMirRelationExpr, RowSetFinishing,
 };
+use mz_ore::cast::CastFrom;
 use mz_ore::task;
 use mz_repr::explain_new::{ExplainFormat, Explainee};
 use mz_repr::{Datum, Diff, GlobalId, RelationDesc, Row, RowArena, Timestamp};
@@ -45,20 +46,19 @@ use mz_sql::catalog::{
 use mz_sql::names::QualifiedObjectName;
 use mz_sql::plan::{
     AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan,
-    AlterOptionParameter, AlterSecretPlan, AlterSinkPlan, AlterSourcePlan, AlterSystemResetAllPlan,
-    AlterSystemResetPlan, AlterSystemSetPlan, CopyFormat, CreateComputeInstancePlan,
-    CreateComputeReplicaPlan, CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan,
-    CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan,
-    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, DropComputeInstancesPlan,
-    DropComputeReplicasPlan, DropDatabasePlan, DropItemsPlan, DropRolesPlan, DropSchemaPlan,
-    ExecutePlan, ExplainPlan, FetchPlan, IndexOption, InsertPlan, MaterializedView, MutationKind,
-    OptimizerConfig, PeekPlan, Plan, PlanKind, QueryWhen, RaisePlan, ReadThenWritePlan,
-    ResetVariablePlan, RotateKeysPlan, SendDiffsPlan, SetVariablePlan, ShowVariablePlan,
-    StorageHostConfig, SubscribeFrom, SubscribePlan, View,
+    AlterOptionParameter, AlterSchemaPlan, AlterSecretPlan, AlterSinkPlan, AlterSourcePlan,
+    AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan, CopyFormat,
+    CreateComputeInstancePlan, CreateComputeReplicaPlan, CreateConnectionPlan, CreateDatabasePlan,
+    CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
+    CreateViewPlan, DropComputeInstancesPlan, DropComputeReplicasPlan, DropDatabasePlan,
+    DropItemsPlan, DropRolesPlan, DropSchemaPlan, ExecutePlan, ExplainPlan, FetchPlan, IndexOption,
+    InsertPlan, MaterializedView, MutationKind, OptimizerConfig, PeekPlan, Plan, PlanKind,
+    QueryWhen, RaisePlan, ReadThenWritePlan, ResetVariablePlan, RotateKeysPlan, SendDiffsPlan,
+    SetVariablePlan, ShowVariablePlan, StorageHostConfig, SubscribeFrom, SubscribePlan, View,
 };
 use mz_ssh_util::keys::SshKeyPairSet;
 use mz_storage_client::controller::{CollectionDescription, DataSource, ReadPolicy, StorageError};
-use mz_storage_client::types::sinks::StorageSinkConnectionBuilder;
 use mz_storage_client::types::sources::{IngestionDescription, SourceExport};
 
 use crate::catalog::builtin::{
@@ -66,7 +66,7 @@ use crate::catalog::builtin::{
     MZ_INTROSPECTION_ROLE, MZ_SYSTEM_COMPUTE_INSTANCE, PG_CATALOG_SCHEMA,
 };
 use crate::catalog::{
-    self, Catalog, CatalogItem, ComputeInstance, Connection, DataSourceDesc, Ingestion,
+    self, Catalog, CatalogItem, ComputeInstance, Connection, DataSourceDesc,
     SerializedComputeReplicaLocation, StorageSinkConnectionState, SYSTEM_USER,
 };
 use crate::command::{Command, ExecuteResponse, Response};
@@ -131,6 +131,10 @@ impl Coordinator {
             }
         }
 
+        if let Err(e) = self.validate_read_only_mode(&plan) {
+            return tx.send(Err(e), session);
+        }
+
         match plan {
             Plan::CreateSource(plan) => {
                 let source_id = return_if_err!(self.catalog.allocate_user_id().await, tx, session);
@@ -255,6 +259,9 @@ impl Coordinator {
             Plan::ShowVariable(plan) => {
                 tx.send(self.sequence_show_variable(&session, plan), session);
             }
+            Plan::ShowCreateCatalog => {
+                tx.send(Ok(self.sequence_show_create_catalog()), session);
+            }
             Plan::SetVariable(plan) => {
                 tx.send(self.sequence_set_variable(&mut session, plan), session);
             }
@@ -289,7 +296,11 @@ impl Coordinator {
                 self.sequence_end_transaction(tx, session, action);
             }
             Plan::Peek(plan) => {
-                self.sequence_peek_begin(tx, session, plan).await;
+                if self.must_queue_peek(&session) {
+                    self.queue_peek(tx, session, plan);
+                } else {
+                    self.sequence_peek_begin(tx, session, plan).await;
+                }
             }
             Plan::Subscribe(plan) => {
                 tx.send(
@@ -341,6 +352,9 @@ impl Coordinator {
             Plan::AlterIndexResetOptions(plan) => {
                 tx.send(self.sequence_alter_index_reset_options(plan), session);
             }
+            Plan::AlterSchema(plan) => {
+                tx.send(self.sequence_alter_schema(&session, plan).await, session);
+            }
             Plan::AlterSecret(plan) => {
                 tx.send(self.sequence_alter_secret(&session, plan).await, session);
             }
@@ -496,7 +510,16 @@ impl Coordinator {
             })
             .collect::<HashMap<_, _>>();
 
+        let max_source_columns = self.catalog.system_config().max_source_columns();
         for (source_id, plan, depends_on) in plans {
+            let columns = plan.source.desc.arity();
+            if columns > usize::cast_from(max_source_columns) {
+                return Err(AdapterError::SourceColumnLimit {
+                    name: plan.name.item,
+                    columns,
+                    limit: max_source_columns,
+                });
+            }
             let source_oid = self.catalog.allocate_oid()?;
             let source = catalog::Source {
                 create_sql: plan.source.create_sql,
@@ -525,6 +548,7 @@ impl Coordinator {
                 depends_on,
                 custom_logical_compaction_window: None,
                 is_retained_metrics_relation: false,
+                retention_filter: plan.source.retention_filter,
             };
             ops.push(catalog::Op::CreateItem {
                 id: source_id,
@@ -953,15 +977,16 @@ impl Coordinator {
         }
 
         if !persisted_introspection_source_ids.is_empty() {
+            let compaction_window = self.persisted_introspection_compaction_window_ms();
             self.initialize_compute_read_policies(
                 persisted_introspection_source_ids.clone(),
                 instance_id,
-                Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
+                Some(compaction_window),
             )
             .await;
             self.initialize_storage_read_policies(
                 persisted_introspection_source_ids,
-                Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
+                Some(compaction_window),
             )
             .await;
         }
@@ -1094,17 +1119,15 @@ impl Coordinator {
             .unwrap();
 
         if !log_source_ids.is_empty() {
+            let compaction_window = self.persisted_introspection_compaction_window_ms();
             self.initialize_compute_read_policies(
                 log_source_ids.clone(),
                 instance_id,
-                Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
-            )
-            .await;
-            self.initialize_storage_read_policies(
-                log_source_ids,
-                Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
+                Some(compaction_window),
             )
             .await;
+            self.initialize_storage_read_policies(log_source_ids, Some(compaction_window))
+                .await;
         }
 
         Ok(ExecuteResponse::CreatedComputeReplica)
@@ -1259,6 +1282,7 @@ impl Coordinator {
             name,
             sink,
             with_snapshot,
+            track_latency,
             if_not_exists,
             host_config: plan_host_config,
         } = plan;
@@ -1281,10 +1305,6 @@ impl Coordinator {
             session
         );
 
-        // Knowing that we're only handling kafka sinks here helps us simplify.
-        let StorageSinkConnectionBuilder::Kafka(connection_builder) =
-            sink.connection_builder.clone();
-
         // Then try to create a placeholder catalog item with an unknown
         // connection. If that fails, we're done, though if the client specified
         // `if_not_exists` we'll tell the client we succeeded.
@@ -1295,11 +1315,11 @@ impl Coordinator {
         let catalog_sink = catalog::Sink {
             create_sql: sink.create_sql,
             from: sink.from,
-            connection: StorageSinkConnectionState::Pending(StorageSinkConnectionBuilder::Kafka(
-                connection_builder,
-            )),
+            from_projection: sink.from_projection,
+            connection: StorageSinkConnectionState::Pending(sink.connection_builder.clone()),
             envelope: sink.envelope,
             with_snapshot,
+            track_latency,
             depends_on,
             host_config,
         };
@@ -1597,6 +1617,7 @@ impl Coordinator {
 
         // An index must be created on a specific compute instance.
         let compute_instance = index.compute_instance;
+        let lazy = index.lazy;
 
         let id = self.catalog.allocate_user_id().await?;
         let index = catalog::Index {
@@ -1606,6 +1627,7 @@ impl Coordinator {
             conn_id: None,
             depends_on,
             compute_instance,
+            lazy: index.lazy,
         };
         let oid = self.catalog.allocate_oid()?;
         let op = catalog::Op::CreateItem {
@@ -1616,14 +1638,24 @@ impl Coordinator {
         };
         match self
             .catalog_transact_with(Some(session), vec![op], |txn| {
+                // The dataflow of a lazy index is built by the first query
+                // that uses it.
+                if lazy {
+                    return Ok(None);
+                }
                 let mut builder = txn.dataflow_builder(compute_instance);
                 let df = builder.build_index_dataflow(id)?;
-                Ok(df)
+                Ok(Some(df))
             })
             .await
         {
             Ok(df) => {
-                self.ship_dataflow(df, compute_instance).await;
+                match df {
+                    Some(df) => self.ship_dataflow(df, compute_instance).await,
+                    None => {
+                        self.unhydrated_indexes.insert(id, None);
+                    }
+                }
                 self.set_index_options(id, options).expect("index enabled");
                 Ok(ExecuteResponse::CreatedIndex)
             }
@@ -1844,6 +1876,18 @@ impl Coordinator {
         ))
     }
 
+    fn sequence_show_create_catalog(&self) -> ExecuteResponse {
+        send_immediate_rows(
+            self.catalog
+                .dump_sql()
+                .into_iter()
+                .map(|(name, create_sql)| {
+                    Row::pack_slice(&[Datum::String(&name), Datum::String(&create_sql)])
+                })
+                .collect(),
+        )
+    }
+
     fn sequence_show_variable(
         &self,
         session: &Session,
@@ -1928,7 +1972,11 @@ impl Coordinator {
         session: &mut Session,
         plan: ResetVariablePlan,
     ) -> Result<ExecuteResponse, AdapterError> {
-        session.vars_mut().reset(&plan.name, false)?;
+        if UncasedStr::new(&plan.name) == "all" {
+            session.vars_mut().reset_all();
+        } else {
+            session.vars_mut().reset(&plan.name, false)?;
+        }
         Ok(ExecuteResponse::SetVariable {
             name: plan.name,
             reset: true,
@@ -2035,12 +2083,23 @@ impl Coordinator {
     /// be a simple read out of an existing arrangement, or required a new dataflow to build
     /// the results to return.
     #[tracing::instrument(level = "debug", skip_all)]
-    async fn sequence_peek_begin(
+    pub(crate) async fn sequence_peek_begin(
         &mut self,
         tx: ClientTransmitter<ExecuteResponse>,
         mut session: Session,
         plan: PeekPlan,
     ) {
+        // The peek reads from the lazy indexes it can use once they are
+        // hydrated, which requires building their dataflows first.
+        let compute_instance =
+            return_if_err!(self.catalog.active_compute_instance(&session), tx, session).id;
+        return_if_err!(
+            self.hydrate_lazy_indexes(&session, compute_instance, plan.source.depends_on())
+                .await,
+            tx,
+            session
+        );
+
         let (
             source,
             finishing,
@@ -2265,6 +2324,10 @@ impl Coordinator {
         }
 
         let timestamp = peek_plan.timestamp_context.timestamp().cloned();
+        let estimated_records = match peek_plan.plan {
+            peek::PeekPlan::SlowPath(_) => Some(peek_plan.estimated_records),
+            peek::PeekPlan::FastPath(_) => None,
+        };
 
         // Implement the peek, and capture the response.
         let resp = self
@@ -2277,6 +2340,10 @@ impl Coordinator {
             }
         }
 
+        if session.vars().emit_query_statistics() {
+            session.add_notice(AdapterNotice::QueryStatistics { estimated_records });
+        }
+
         match copy_to {
             None => Ok(resp),
             Some(format) => Ok(ExecuteResponse::CopyTo {
@@ -2442,6 +2509,16 @@ impl Coordinator {
         // Optimize the dataflow across views, and any other ways that appeal.
         mz_transform::optimize_dataflow(&mut dataflow, &builder.index_oracle())?;
 
+        // Estimate how many records the dataflow would hold in memory, in case
+        // we end up having to build it.
+        let estimated_records = peek::estimate_dataflow_footprint(&dataflow, |id| {
+            self.controller
+                .compute
+                .collection(compute_instance, id)
+                .ok()
+                .and_then(|collection| collection.arrangement_size())
+        });
+
         // At this point, `dataflow_plan` contains our best optimized dataflow.
         // We will check the plan to see if there is a fast path to escape full dataflow construction.
         let peek_plan = self.create_peek_plan(
@@ -2454,13 +2531,28 @@ impl Coordinator {
             thinning.len(),
         )?;
 
+        // Refuse to build dataflows that are likely to exhaust the memory of
+        // the compute instance. Fast path peeks do not build a dataflow.
+        let limit = self.catalog.system_config().max_query_estimated_records();
+        if matches!(peek_plan, peek::PeekPlan::SlowPath(_))
+            && session.vars().query_admission_control()
+            && estimated_records > u64::cast_from(limit)
+        {
+            return Err(AdapterError::QueryAdmissionDenied {
+                estimated_records,
+                limit,
+            });
+        }
+
         Ok(PlannedPeek {
             plan: peek_plan,
             read_holds,
             timestamp_context,
             conn_id,
+            role: session.user().name.clone(),
             source_arity: source.arity(),
             id_bundle,
+            estimated_records,
         })
     }
 
@@ -2498,10 +2590,13 @@ impl Coordinator {
             copy_to,
             emit_progress,
             up_to,
+            partition,
         } = plan;
 
+        let compute_instance_id = self.catalog.active_compute_instance(session)?.id;
+        self.hydrate_lazy_indexes(session, compute_instance_id, depends_on.iter().copied())
+            .await?;
         let compute_instance = self.catalog.active_compute_instance(session)?;
-        let compute_instance_id = compute_instance.id;
 
         // SUBSCRIBE AS OF, similar to peeks, doesn't need to worry about transaction
         // timestamp semantics.
@@ -2553,7 +2648,9 @@ impl Coordinator {
             Ok::<_, AdapterError>(ComputeSinkDesc {
                 from,
                 from_desc,
-                connection: ComputeSinkConnection::Subscribe(SubscribeSinkConnection::default()),
+                connection: ComputeSinkConnection::Subscribe(SubscribeSinkConnection {
+                    partition,
+                }),
                 as_of: SinkAsOf {
                     frontier,
                     strict: !with_snapshot,
@@ -3547,6 +3644,7 @@ impl Coordinator {
                         DEFAULT_LOGICAL_COMPACTION_WINDOW_TS.into(),
                     )))
                 }
+                IndexOptionName::Lazy => unreachable!("the LAZY option cannot be reset"),
             });
         }
 
@@ -3555,6 +3653,50 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Index))
     }
 
+    async fn sequence_alter_schema(
+        &mut self,
+        session: &Session,
+        AlterSchemaPlan {
+            database_id,
+            schema_id,
+            max_sources,
+            max_indexes,
+            max_materialized_views,
+        }: AlterSchemaPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        // Quotas restrict what the users of a schema can create, so they must
+        // not be able to change them.
+        if session.user() != &*SYSTEM_USER {
+            return Err(AdapterError::Unauthorized(format!(
+                "only user '{}' is allowed to alter the quotas of a schema",
+                SYSTEM_USER.name,
+            )));
+        }
+
+        let mut quotas = self.catalog.get_database(&database_id).schemas_by_id[&schema_id]
+            .quotas
+            .clone();
+        for (quota, parameter) in [
+            (&mut quotas.max_sources, max_sources),
+            (&mut quotas.max_indexes, max_indexes),
+            (&mut quotas.max_materialized_views, max_materialized_views),
+        ] {
+            match parameter {
+                AlterOptionParameter::Set(value) => *quota = Some(value),
+                AlterOptionParameter::Reset => *quota = None,
+                AlterOptionParameter::Unchanged => (),
+            }
+        }
+
+        let op = catalog::Op::AlterSchemaQuotas {
+            database_id,
+            schema_id,
+            quotas,
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Schema))
+    }
+
     fn set_index_options(
         &mut self,
         id: GlobalId,
@@ -3574,7 +3716,12 @@ impl Coordinator {
                         Some(time) => ReadPolicy::lag_writes_by(time.try_into()?),
                         None => ReadPolicy::ValidFrom(Antichain::from_elem(Timestamp::minimum())),
                     };
-                    self.update_compute_base_read_policy(compute_instance, id, policy);
+                    match self.unhydrated_indexes.get_mut(&id) {
+                        // The policy is applied once the dataflow of the index
+                        // is built.
+                        Some(pending_policy) => *pending_policy = Some(policy),
+                        None => self.update_compute_base_read_policy(compute_instance, id, policy),
+                    }
                 }
             }
         }
@@ -3633,7 +3780,12 @@ impl Coordinator {
     async fn sequence_alter_source(
         &mut self,
         session: &Session,
-        AlterSourcePlan { id, size, remote }: AlterSourcePlan,
+        AlterSourcePlan {
+            id,
+            size,
+            remote,
+            timestamp_interval,
+        }: AlterSourcePlan,
     ) -> Result<ExecuteResponse, AdapterError> {
         let source = self
             .catalog
@@ -3646,24 +3798,46 @@ impl Coordinator {
                 coord_bail!("cannot ALTER this type of source");
             }
         }
+
+        let mut ops = vec![];
+        let timestamp_interval = match timestamp_interval {
+            AlterOptionParameter::Set(interval) => Some(Some(interval)),
+            AlterOptionParameter::Reset => Some(None),
+            AlterOptionParameter::Unchanged => None,
+        };
+        if let Some(timestamp_interval) = timestamp_interval {
+            ops.push(catalog::Op::AlterSourceTimestampInterval {
+                id,
+                timestamp_interval,
+            });
+        }
         let host_config = alter_storage_host_config(size, remote)?;
-        if let Some(host_config) = host_config {
-            let mut ops = vec![catalog::Op::AlterSource {
+        if let Some(host_config) = &host_config {
+            ops.push(catalog::Op::AlterSource {
                 id,
                 host_config: host_config.clone(),
-            }];
-            ops.extend(self.alter_linked_cluster_ops(id, &host_config)?);
-            self.catalog_transact(Some(session), ops).await?;
+            });
+            ops.extend(self.alter_linked_cluster_ops(id, host_config)?);
+        }
+        if ops.is_empty() {
+            return Ok(ExecuteResponse::AlteredObject(ObjectType::Source));
+        }
+        self.catalog_transact(Some(session), ops).await?;
 
-            // Re-fetch the updated item from the catalog
-            let entry = self.catalog.get_entry(&id);
-            let updated_source = entry.source().expect("known to be source");
-            if let DataSourceDesc::Ingestion(Ingestion { host_config, .. }) =
-                &updated_source.data_source
-            {
+        // Re-fetch the updated item from the catalog
+        let entry = self.catalog.get_entry(&id);
+        let updated_source = entry.source().expect("known to be source");
+        if let DataSourceDesc::Ingestion(ingestion) = &updated_source.data_source {
+            if timestamp_interval.is_some() {
+                self.controller
+                    .storage
+                    .alter_timestamp_intervals(vec![(id, ingestion.desc.timestamp_interval)])
+                    .await?;
+            }
+            if host_config.is_some() {
                 self.controller
                     .storage
-                    .alter_collections(vec![(id, host_config.clone())])
+                    .alter_collections(vec![(id, ingestion.host_config.clone())])
                     .await?;
             }
         }
@@ -3731,6 +3905,8 @@ impl Coordinator {
         let update_compute_config = session::vars::is_compute_config_var(&name);
         let update_storage_config = session::vars::is_storage_config_var(&name);
         let update_metrics_retention = name == session::vars::METRICS_RETENTION.name();
+        let update_introspection_retention =
+            name == session::vars::PERSISTED_INTROSPECTION_RETENTION.name();
         let op = match value {
             SetVariableValue::Default => catalog::Op::ResetSystemConfiguration { name },
             SetVariableValue::Literal(Value::String(value)) => {
@@ -3755,6 +3931,9 @@ impl Coordinator {
         if update_metrics_retention {
             self.update_metrics_retention();
         }
+        if update_introspection_retention {
+            self.update_introspection_retention();
+        }
         Ok(ExecuteResponse::AlteredSystemConfiguration)
     }
 
@@ -3767,6 +3946,8 @@ impl Coordinator {
         let update_compute_config = session::vars::is_compute_config_var(&name);
         let update_storage_config = session::vars::is_storage_config_var(&name);
         let update_metrics_retention = name == session::vars::METRICS_RETENTION.name();
+        let update_introspection_retention =
+            name == session::vars::PERSISTED_INTROSPECTION_RETENTION.name();
         let op = catalog::Op::ResetSystemConfiguration { name };
         self.catalog_transact(Some(session), vec![op]).await?;
         if update_compute_config {
@@ -3778,6 +3959,9 @@ impl Coordinator {
         if update_metrics_retention {
             self.update_metrics_retention();
         }
+        if update_introspection_retention {
+            self.update_introspection_retention();
+        }
         Ok(ExecuteResponse::AlteredSystemConfiguration)
     }
 
@@ -3792,6 +3976,7 @@ impl Coordinator {
         self.update_compute_config();
         self.update_storage_config();
         self.update_metrics_retention();
+        self.update_introspection_retention();
         Ok(ExecuteResponse::AlteredSystemConfiguration)
     }
 
@@ -3808,6 +3993,9 @@ impl Coordinator {
 
     fn update_compute_config(&mut self) {
         let config_params = self.catalog.compute_config();
+        if let Some(schedule) = &config_params.arrangement_compaction_schedule {
+            self.compaction_schedule = schedule.clone();
+        }
         self.controller.compute.update_configuration(config_params);
     }
 
@@ -3833,6 +4021,33 @@ impl Coordinator {
         self.update_storage_base_read_policies(policies)
     }
 
+    fn update_introspection_retention(&mut self) {
+        let policy = ReadPolicy::lag_writes_by(self.persisted_introspection_compaction_window_ms());
+        let mut compute_policies = Vec::new();
+        for instance in self.catalog.compute_instances() {
+            if instance.linked_object_id.is_some() {
+                continue;
+            }
+            for replica in instance.replicas_by_id.values() {
+                compute_policies.extend(
+                    replica
+                        .config
+                        .logging
+                        .source_ids()
+                        .map(|id| (instance.id, id)),
+                );
+            }
+        }
+        let storage_policies = compute_policies
+            .iter()
+            .map(|(_, id)| (*id, policy.clone()))
+            .collect();
+        for (instance_id, id) in compute_policies {
+            self.update_compute_base_read_policy(instance_id, id, policy.clone());
+        }
+        self.update_storage_base_read_policies(storage_policies)
+    }
+
     // Returns the name of the portal to execute.
     fn sequence_execute(
         &mut self,
@@ -3863,6 +4078,77 @@ impl Coordinator {
     ///  All of the privileges are hard coded into this function. In the future if we ever add
     ///  a more robust privileges framework, then this function should be replaced with that
     ///  framework.
+    /// Returns an error if the system is in read-only mode and `plan` would
+    /// change the catalog or write to a table. `ALTER SYSTEM` is allowed, so that
+    /// the mode can be left again.
+    fn validate_read_only_mode(&self, plan: &Plan) -> Result<(), AdapterError> {
+        if !self.catalog.system_config().read_only_mode() {
+            return Ok(());
+        }
+
+        match plan {
+            Plan::Subscribe(_)
+            | Plan::Peek(_)
+            | Plan::SendRows(_)
+            | Plan::Explain(_)
+            | Plan::ShowAllVariables
+            | Plan::ShowVariable(_)
+            | Plan::ShowCreateCatalog
+            | Plan::SetVariable(_)
+            | Plan::ResetVariable(_)
+            | Plan::StartTransaction(_)
+            | Plan::CommitTransaction
+            | Plan::AbortTransaction
+            | Plan::EmptyQuery
+            | Plan::DiscardTemp
+            | Plan::DiscardAll
+            | Plan::Declare(_)
+            | Plan::Fetch(_)
+            | Plan::Close(_)
+            | Plan::Prepare(_)
+            | Plan::Execute(_)
+            | Plan::Deallocate(_)
+            | Plan::Raise(_)
+            | Plan::AlterSystemSet(_)
+            | Plan::AlterSystemReset(_)
+            | Plan::AlterSystemResetAll(_) => Ok(()),
+
+            Plan::CreateConnection(_)
+            | Plan::CreateDatabase(_)
+            | Plan::CreateSchema(_)
+            | Plan::CreateRole(_)
+            | Plan::CreateComputeInstance(_)
+            | Plan::CreateComputeReplica(_)
+            | Plan::CreateSource(_)
+            | Plan::CreateSecret(_)
+            | Plan::CreateSink(_)
+            | Plan::CreateTable(_)
+            | Plan::CreateView(_)
+            | Plan::CreateMaterializedView(_)
+            | Plan::CreateIndex(_)
+            | Plan::CreateType(_)
+            | Plan::DropDatabase(_)
+            | Plan::DropSchema(_)
+            | Plan::DropRoles(_)
+            | Plan::DropComputeInstances(_)
+            | Plan::DropComputeReplicas(_)
+            | Plan::DropItems(_)
+            | Plan::CopyFrom(_)
+            | Plan::SendDiffs(_)
+            | Plan::Insert(_)
+            | Plan::AlterNoop(_)
+            | Plan::AlterIndexSetOptions(_)
+            | Plan::AlterIndexResetOptions(_)
+            | Plan::AlterSchema(_)
+            | Plan::AlterSink(_)
+            | Plan::AlterSource(_)
+            | Plan::AlterItemRename(_)
+            | Plan::AlterSecret(_)
+            | Plan::ReadThenWrite(_)
+            | Plan::RotateKeys(_) => Err(AdapterError::ReadOnlyMode),
+        }
+    }
+
     fn mz_introspection_user_privilege_hack(
         &self,
         session: &Session,
@@ -3881,6 +4167,7 @@ impl Coordinator {
             | Plan::Explain(_)
             | Plan::ShowAllVariables
             | Plan::ShowVariable(_)
+            | Plan::ShowCreateCatalog
             | Plan::SetVariable(_)
             | Plan::ResetVariable(_)
             | Plan::StartTransaction(_)
@@ -3921,6 +4208,7 @@ impl Coordinator {
             | Plan::AlterNoop(_)
             | Plan::AlterIndexSetOptions(_)
             | Plan::AlterIndexResetOptions(_)
+            | Plan::AlterSchema(_)
             | Plan::AlterSink(_)
             | Plan::AlterSource(_)
             | Plan::AlterItemRename(_)
@@ -4021,9 +4309,7 @@ impl Coordinator {
         let logging = {
             ComputeReplicaLogging {
                 log_logging: false,
-                interval: Some(Duration::from_micros(
-                    DEFAULT_COMPUTE_REPLICA_LOGGING_INTERVAL_MICROS.into(),
-                )),
+                interval: Some(self.catalog.system_config().default_introspection_interval()),
                 sources: vec![],
                 views: vec![],
             }
@@ -10,6 +10,7 @@
 use std::error::Error;
 use std::fmt;
 use std::num::TryFromIntError;
+use std::time::Duration;
 
 use dec::TryFromDecimalError;
 use mz_repr::adt::timestamp::TimestampError;
@@ -97,6 +98,9 @@ pub enum AdapterError {
     },
     /// The selection value for a table mutation operation refers to an invalid object.
     InvalidTableMutationSelection,
+    /// A maintained object contains a filter on `mz_now()` that cannot be
+    /// turned into a temporal filter.
+    InvalidTemporalFilter(String),
     /// Expression violated a column's constraint
     ConstraintViolation(NotNullViolation),
     /// Target cluster has no replicas to service query.
@@ -105,12 +109,28 @@ pub enum AdapterError {
     OperationProhibitsTransaction(String),
     /// The named operation requires an active transaction.
     OperationRequiresTransaction(String),
+    /// A query waited for longer than allowed by the system configuration for
+    /// one of the concurrency slots of its role.
+    PeekQueueTimeout {
+        role: String,
+        limit: u32,
+        timeout: Duration,
+    },
     /// An error occurred while planning the statement.
     PlanError(PlanError),
     /// The named prepared statement already exists.
     PreparedStatementExists(String),
     /// An error occurred in the QGM stage of the optimizer.
     QGM(QGMError),
+    /// A one-off query was estimated to hold more records in memory than
+    /// allowed by the system configuration.
+    QueryAdmissionDenied {
+        estimated_records: u64,
+        limit: usize,
+    },
+    /// The system is in read-only mode, and the statement would change the
+    /// catalog or write to a table.
+    ReadOnlyMode,
     /// The transaction is in read-only mode.
     ReadOnlyTransaction,
     /// The specified session parameter is read-only.
@@ -136,6 +156,12 @@ pub enum AdapterError {
     ResultSize(String),
     /// The specified feature is not permitted in safe mode.
     SafeModeViolation(String),
+    /// A source has more columns than is allowed in the system configuration.
+    SourceColumnLimit {
+        name: String,
+        columns: usize,
+        limit: u32,
+    },
     /// Waiting on a query timed out.
     ///
     /// Note this differs slightly from PG's implementation/semantics.
@@ -240,9 +266,38 @@ impl AdapterError {
                 "The object depends on the following log sources:\n    {}",
                 log_names.join("\n    "),
             )),
-            AdapterError::UnmaterializableFunction(UnmaterializableFunc::CurrentTimestamp) => {
-                Some("See: https://materialize.com/docs/sql/functions/now_and_mz_now/".into())
-            }
+            AdapterError::InvalidTemporalFilter(_) => {
+                Some("See: https://materialize.com/docs/sql/patterns/temporal-filters/".into())
+            }
+            AdapterError::UnmaterializableFunction(func) => Some(match func {
+                UnmaterializableFunc::CurrentTimestamp => format!(
+                    "{func} returns the time at which a query starts, which changes while \
+                     the object is maintained. \
+                     See: https://materialize.com/docs/sql/functions/now_and_mz_now/"
+                ),
+                UnmaterializableFunc::MzNow => format!(
+                    "{func} can only be maintained as part of a temporal filter. \
+                     See: https://materialize.com/docs/sql/patterns/temporal-filters/"
+                ),
+                UnmaterializableFunc::CurrentDatabase
+                | UnmaterializableFunc::CurrentSchemasWithSystem
+                | UnmaterializableFunc::CurrentSchemasWithoutSystem
+                | UnmaterializableFunc::CurrentUser
+                | UnmaterializableFunc::PgBackendPid => format!(
+                    "{func} depends on the session that runs a query, but maintained objects \
+                     are shared by all sessions."
+                ),
+                UnmaterializableFunc::MzEnvironmentId
+                | UnmaterializableFunc::MzSessionId
+                | UnmaterializableFunc::MzUptime
+                | UnmaterializableFunc::MzVersion
+                | UnmaterializableFunc::MzVersionNum
+                | UnmaterializableFunc::PgPostmasterStartTime
+                | UnmaterializableFunc::Version => format!(
+                    "{func} depends on the running Materialize process, and can change while \
+                     the object is maintained."
+                ),
+            }),
             AdapterError::UnstableDependency { unstable_dependencies, .. } => Some(format!(
                 "The object depends on the following unstable objects:\n    {}",
                 unstable_dependencies.join("\n    "),
@@ -293,8 +348,38 @@ impl AdapterError {
                 Some("You can create cluster replicas using CREATE CLUSTER REPLICA".into())
             }
             AdapterError::UnmaterializableFunction(UnmaterializableFunc::CurrentTimestamp) => {
-                Some("Try using `mz_now()` here instead.".into())
+                Some("Try using `mz_now()` in a temporal filter instead.".into())
             }
+            AdapterError::UnmaterializableFunction(UnmaterializableFunc::MzNow)
+            | AdapterError::InvalidTemporalFilter(_) => Some(
+                "Compare `mz_now()` directly to an expression that does not call `mz_now()`, \
+                 in a WHERE clause, for example `WHERE mz_now() <= created_at + INTERVAL '1 day'`."
+                    .into(),
+            ),
+            AdapterError::UnmaterializableFunction(func) => Some(format!(
+                "Call {func} in a one-off SELECT instead, or use its result as a constant in \
+                 the definition of the object."
+            )),
+            AdapterError::SourceColumnLimit { .. } => Some(
+                "Use the FIELDS option of CREATE SOURCE to only decode the fields of Avro records \
+                 that you need."
+                    .into(),
+            ),
+            AdapterError::QueryAdmissionDenied { .. } => Some(
+                "Add join conditions or filters on indexed columns to reduce the size of the \
+                 query, or use `SET query_admission_control = off` to run it anyway."
+                    .into(),
+            ),
+            AdapterError::PeekQueueTimeout { .. } => Some(
+                "Wait for the running queries of the role to complete, or ask an administrator \
+                 to raise `max_concurrent_peeks_per_role` or `max_peek_queue_time`."
+                    .into(),
+            ),
+            AdapterError::ReadOnlyMode => Some(
+                "Only queries are allowed until an administrator runs \
+                 `ALTER SYSTEM SET read_only_mode = false`."
+                    .into(),
+            ),
             AdapterError::UntargetedLogRead { .. } => Some(
                 "Use `SET cluster_replica = <replica-name>` to target a specific replica in the \
                  active cluster. Note that subsequent `SELECT` queries will only be answered by \
@@ -381,6 +466,7 @@ impl fmt::Display for AdapterError {
             AdapterError::InvalidTableMutationSelection => {
                 f.write_str("invalid selection: operation may only refer to user-defined tables")
             }
+            AdapterError::InvalidTemporalFilter(e) => f.write_str(e),
             AdapterError::ConstraintViolation(not_null_violation) => {
                 write!(f, "{}", not_null_violation)
             }
@@ -397,11 +483,32 @@ impl fmt::Display for AdapterError {
             AdapterError::OperationRequiresTransaction(op) => {
                 write!(f, "{} can only be used in transaction blocks", op)
             }
+            AdapterError::PeekQueueTimeout {
+                role,
+                limit,
+                timeout,
+            } => write!(
+                f,
+                "query waited for longer than {timeout:?} for one of the {limit} concurrent \
+                 query slots of role {}",
+                role.quoted()
+            ),
             AdapterError::PlanError(e) => e.fmt(f),
             AdapterError::PreparedStatementExists(name) => {
                 write!(f, "prepared statement {} already exists", name.quoted())
             }
             AdapterError::QGM(e) => e.fmt(f),
+            AdapterError::QueryAdmissionDenied {
+                estimated_records,
+                limit,
+            } => write!(
+                f,
+                "query is estimated to hold {estimated_records} records in memory, \
+                 which exceeds the limit of {limit} records"
+            ),
+            AdapterError::ReadOnlyMode => {
+                f.write_str("cannot execute this statement while the system is in read-only mode")
+            }
             AdapterError::ReadOnlyTransaction => f.write_str("transaction in read-only mode"),
             AdapterError::ReadOnlyParameter(p) => {
                 write!(f, "parameter {} cannot be changed", p.name().quoted())
@@ -443,6 +550,17 @@ impl fmt::Display for AdapterError {
             AdapterError::SafeModeViolation(feature) => {
                 write!(f, "cannot create {} in safe mode", feature)
             }
+            AdapterError::SourceColumnLimit {
+                name,
+                columns,
+                limit,
+            } => {
+                write!(
+                    f,
+                    "source {} has {columns} columns, which exceeds the limit of {limit} columns",
+                    name.quoted()
+                )
+            }
             AdapterError::SqlCatalog(e) => e.fmt(f),
             AdapterError::SubscribeOnlyTransaction => {
                 f.write_str("SUBSCRIBE in transactions must be the only read statement")
@@ -613,6 +613,10 @@ impl<'a> DisplayText<PlanRenderingContext<'_, Plan>> for Displayable<'a, Accumul
             Displayable::from(agg).fmt_text(f, &mut ())?;
             writeln!(f, ")")?;
         }
+        // pre_aggregate
+        if self.0.pre_aggregate {
+            writeln!(f, "{}pre_aggregate=true", ctx.indent)?;
+        }
         Ok(())
     }
 }
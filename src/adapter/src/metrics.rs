@@ -7,12 +7,13 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use prometheus::{HistogramVec, IntCounterVec, IntGaugeVec};
+use prometheus::{HistogramVec, IntCounterVec, IntGauge, IntGaugeVec};
 
 use mz_ore::metric;
 use mz_ore::metrics::MetricsRegistry;
 use mz_sql::ast::{AstInfo, Statement, StatementKind};
 
+use crate::coord::peek::{FastPathPlan, PeekPlan};
 use crate::session::Session;
 
 #[derive(Debug, Clone)]
@@ -23,6 +24,8 @@ pub struct Metrics {
     pub queue_busy_seconds: HistogramVec,
     pub determine_timestamp: IntCounterVec,
     pub commands: IntCounterVec,
+    pub peeks_total: IntCounterVec,
+    pub queued_peeks: IntGauge,
     pub storage_usage_collection_time_seconds: HistogramVec,
 }
 
@@ -58,6 +61,15 @@ impl Metrics {
                 help: "The total number of adapter commands issued of the given type since process start.",
                 var_labels: ["command_type", "status"],
             )),
+            peeks_total: registry.register(metric!(
+                name: "mz_peeks_total",
+                help: "The total number of peeks issued since process start, by how they were implemented.",
+                var_labels: ["plan"],
+            )),
+            queued_peeks: registry.register(metric!(
+                name: "mz_queued_peeks",
+                help: "The number of peeks waiting for a running peek of their role to complete.",
+            )),
             storage_usage_collection_time_seconds: registry.register(metric!(
                 name: "mz_storage_usage_collection_time_seconds",
                 help: "The number of seconds the coord spends collecting usage metrics from storage.",
@@ -73,6 +85,15 @@ pub(crate) fn session_type_label_value(session: &Session) -> &'static str {
     }
 }
 
+pub(crate) fn peek_plan_label_value(plan: &PeekPlan) -> &'static str {
+    match plan {
+        PeekPlan::FastPath(FastPathPlan::Constant(..)) => "constant",
+        PeekPlan::FastPath(FastPathPlan::PeekExisting(_, None, _)) => "index_scan",
+        PeekPlan::FastPath(FastPathPlan::PeekExisting(_, Some(_), _)) => "index_lookup",
+        PeekPlan::SlowPath(_) => "dataflow",
+    }
+}
+
 pub(crate) fn statement_type_label_value<T>(stmt: &Statement<T>) -> &'static str
 where
     T: AstInfo,
@@ -101,6 +122,7 @@ where
         StatementKind::CreateSecret => "create_secret",
         StatementKind::AlterObjectRename => "alter_object_rename",
         StatementKind::AlterIndex => "alter_index",
+        StatementKind::AlterSchema => "alter_schema",
         StatementKind::AlterSecret => "alter_secret",
         StatementKind::AlterSink => "alter_sink",
         StatementKind::AlterSource => "alter_source",
@@ -131,5 +153,6 @@ where
         StatementKind::Execute => "execute",
         StatementKind::Deallocate => "deallocate",
         StatementKind::Raise => "raise",
+        StatementKind::ValidateView => "validate_view",
     }
 }
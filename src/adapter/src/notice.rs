@@ -62,6 +62,11 @@ pub enum AdapterNotice {
     QueryTimestamp {
         timestamp: mz_repr::Timestamp,
     },
+    /// The resources that a query is estimated to use. `estimated_records` is
+    /// `None` if the query did not build a dataflow.
+    QueryStatistics {
+        estimated_records: Option<u64>,
+    },
     EqualSubscribeBounds {
         bound: mz_repr::Timestamp,
     },
@@ -149,6 +154,14 @@ impl fmt::Display for AdapterNotice {
             AdapterNotice::QueryTimestamp { timestamp } => {
                 write!(f, "query timestamp: {}", timestamp)
             }
+            AdapterNotice::QueryStatistics { estimated_records } => match estimated_records {
+                Some(records) => write!(
+                    f,
+                    "query built a temporary dataflow that is estimated to arrange at most {} records",
+                    records
+                ),
+                None => write!(f, "query did not build a dataflow"),
+            },
             AdapterNotice::EqualSubscribeBounds { bound } => {
                 write!(f, "subscribe as of {bound} (inclusive) up to the same bound {bound} (exclusive) is guaranteed to be empty")
             }
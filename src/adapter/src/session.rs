@@ -190,7 +190,11 @@ impl<T: TimestampManipulation> Session<T> {
                 let id = self.next_transaction_id;
                 self.next_transaction_id = self.next_transaction_id.wrapping_add(1);
                 self.transaction = TransactionStatus::InTransaction(Transaction {
-                    pcx: PlanContext::new(wall_time, self.vars.qgm_optimizations()),
+                    pcx: PlanContext::new(
+                        wall_time,
+                        self.vars.qgm_optimizations(),
+                        self.vars.forbid_materialized_view_wildcards(),
+                    ),
                     ops: TransactionOps::None,
                     write_lock_guard: None,
                     access,
@@ -224,7 +228,11 @@ impl<T: TimestampManipulation> Session<T> {
             let id = self.next_transaction_id;
             self.next_transaction_id = self.next_transaction_id.wrapping_add(1);
             let txn = Transaction {
-                pcx: PlanContext::new(wall_time, self.vars.qgm_optimizations()),
+                pcx: PlanContext::new(
+                    wall_time,
+                    self.vars.qgm_optimizations(),
+                    self.vars.forbid_materialized_view_wildcards(),
+                ),
                 ops: TransactionOps::None,
                 write_lock_guard: None,
                 access: None,
@@ -551,6 +559,19 @@ impl<T: TimestampManipulation> Session<T> {
         self.portals.remove(portal_name).is_some()
     }
 
+    /// Removes the specified portal and returns it.
+    ///
+    /// If there is no such portal, returns `None`.
+    pub fn take_portal(&mut self, portal_name: &str) -> Option<Portal> {
+        self.portals.remove(portal_name)
+    }
+
+    /// Installs an existing portal under the specified name, returning the
+    /// portal that it replaces, if any.
+    pub fn insert_portal(&mut self, portal_name: String, portal: Portal) -> Option<Portal> {
+        self.portals.insert(portal_name, portal)
+    }
+
     /// Retrieves a reference to the specified portal.
     ///
     /// If there is no such portal, returns `None`.
@@ -602,7 +623,12 @@ impl<T: TimestampManipulation> Session<T> {
     pub fn reset(&mut self) {
         let _ = self.clear_transaction();
         self.prepared_statements.clear();
-        self.vars = SessionVars::new(self.vars.build_info());
+        // Variables are reset to the session's defaults rather than the
+        // server's, so that any parameters supplied when the connection was
+        // established survive, as in PostgreSQL. The transaction has already
+        // been cleared, so commit the reset values immediately.
+        self.vars.reset_all();
+        self.vars.end_transaction(EndTransactionAction::Commit);
     }
 
     /// Returns the user who owns this session.
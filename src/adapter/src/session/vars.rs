@@ -8,6 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::borrow::Borrow;
+use std::collections::BTreeSet;
 use std::fmt;
 use std::time::Duration;
 
@@ -17,6 +18,8 @@ use serde::Serialize;
 use uncased::UncasedStr;
 
 use mz_build_info::BuildInfo;
+use mz_compute_client::logging::LogFamily;
+use mz_compute_client::protocol::command::CompactionWindow;
 use mz_ore::cast;
 use mz_persist_client::PersistConfig;
 use mz_sql::ast::{Ident, SetVariableValue, Value as AstValue};
@@ -25,6 +28,7 @@ use mz_sql_parser::ast::TransactionIsolationLevel;
 use mz_sql_parser::parser::parse_set_variable_value;
 
 use crate::catalog::SYSTEM_USER;
+use crate::coord::DEFAULT_LOGICAL_COMPACTION_WINDOW;
 use crate::error::AdapterError;
 use crate::session::{EndTransactionAction, User};
 
@@ -285,6 +289,13 @@ const MAX_OBJECTS_PER_SCHEMA: ServerVar<u32> = ServerVar {
     internal: false,
 };
 
+const MAX_SOURCE_COLUMNS: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("max_source_columns"),
+    value: &1000,
+    description: "The maximum number of columns in a source (Materialize).",
+    internal: false,
+};
+
 const MAX_SECRETS: ServerVar<u32> = ServerVar {
     name: UncasedStr::new("max_secrets"),
     value: &100,
@@ -310,6 +321,55 @@ pub const MAX_RESULT_SIZE: ServerVar<u32> = ServerVar {
     internal: false,
 };
 
+/// The maximum number of records that the operators of a one-off query
+/// dataflow are estimated to hold, based on the sizes of the arrangements the
+/// query reads from.
+const MAX_QUERY_ESTIMATED_RECORDS: ServerVar<usize> = ServerVar {
+    name: UncasedStr::new("max_query_estimated_records"),
+    value: &1_000_000_000,
+    description: "The maximum number of records that a single query's dataflow is estimated to hold in memory (Materialize).",
+    internal: false,
+};
+
+/// The maximum number of peeks that the sessions of a single role can have
+/// executing at once. Further peeks are queued until a running one completes.
+const MAX_CONCURRENT_PEEKS_PER_ROLE: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("max_concurrent_peeks_per_role"),
+    value: &0,
+    description: "The maximum number of queries that the sessions of a single role can execute \
+                  at once. Further queries wait for a running one to complete. A value of zero \
+                  disables the limit (Materialize).",
+    internal: false,
+};
+
+/// The maximum amount of time that a peek can wait for one of the
+/// `max_concurrent_peeks_per_role` slots of its role.
+const MAX_PEEK_QUEUE_TIME: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("max_peek_queue_time"),
+    value: &Duration::from_secs(30),
+    description: "The maximum amount of time that a query waits for a running query of its role \
+                  to complete before it fails (Materialize).",
+    internal: false,
+};
+
+/// The `TIMESTAMP INTERVAL` of sources that do not specify one.
+const DEFAULT_TIMESTAMP_INTERVAL: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("default_timestamp_interval"),
+    value: &Duration::from_secs(1),
+    description: "The interval at which sources created without a TIMESTAMP INTERVAL advance \
+                  their timestamps (Materialize).",
+    internal: false,
+};
+
+/// The `INTROSPECTION INTERVAL` of cluster replicas that do not specify one.
+const DEFAULT_INTROSPECTION_INTERVAL: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("default_introspection_interval"),
+    value: &Duration::from_secs(1),
+    description: "The interval at which cluster replicas created without an INTROSPECTION \
+                  INTERVAL update their introspection sources (Materialize).",
+    internal: false,
+};
+
 /// The logical compaction window for builtin tables and sources that have the
 /// `retained_metrics_relation` flag set.
 ///
@@ -332,6 +392,13 @@ static ALLOWED_CLUSTER_REPLICA_SIZES: Lazy<ServerVar<Vec<String>>> = Lazy::new(|
 });
 
 /// Feature flag indicating whether window functions are enabled.
+static READ_ONLY_MODE: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("read_only_mode"),
+    value: &false,
+    description: "Boolean flag indicating whether to refuse statements that change the catalog or write to tables (Materialize).",
+    internal: false,
+};
+
 static WINDOW_FUNCTIONS: ServerVar<bool> = ServerVar {
     name: UncasedStr::new("window_functions"),
     value: &true,
@@ -356,6 +423,66 @@ const PERSIST_COMPACTION_MINIMUM_TIMEOUT: ServerVar<Duration> = ServerVar {
     internal: true,
 };
 
+/// Controls [`mz_compute_client::protocol::command::ComputeParameters::arrangement_maintenance_budget`].
+const ARRANGEMENT_MAINTENANCE_BUDGET: ServerVar<usize> = ServerVar {
+    name: UncasedStr::new("arrangement_maintenance_budget"),
+    value: &0,
+    description: "The number of records whose arrangements a compute worker releases for merging \
+                  at once. A value of zero releases all arrangements at once (Materialize).",
+    internal: false,
+};
+
+/// The off-peak window outside of which the merging of large arrangements is
+/// [`mz_compute_client::protocol::command::CompactionSchedule::Deferred`], if any.
+const ARRANGEMENT_COMPACTION_WINDOW: ServerVar<Option<CompactionWindow>> = ServerVar {
+    name: UncasedStr::new("arrangement_compaction_window"),
+    value: &None,
+    description: "The daily window, as HH:MM-HH:MM in UTC, to which the merging of large \
+                  arrangements is deferred. If empty, arrangements are merged as their batches \
+                  arrive (Materialize).",
+    internal: false,
+};
+
+/// The minimum number of records of the arrangements whose merging is deferred
+/// to the `arrangement_compaction_window`.
+const ARRANGEMENT_COMPACTION_MIN_RECORDS: ServerVar<usize> = ServerVar {
+    name: UncasedStr::new("arrangement_compaction_min_records"),
+    value: &1_000_000,
+    description: "The minimum number of records that an arrangement holds on a compute worker for \
+                  its merging to be deferred to the arrangement_compaction_window (Materialize).",
+    internal: false,
+};
+
+static DEFAULT_INTROSPECTION_DISABLED_LOGS: Lazy<BTreeSet<LogFamily>> = Lazy::new(BTreeSet::new);
+/// Controls [`mz_compute_client::protocol::command::ComputeParameters::disabled_log_families`].
+static INTROSPECTION_DISABLED_LOGS: Lazy<ServerVar<BTreeSet<LogFamily>>> =
+    Lazy::new(|| ServerVar {
+        name: UncasedStr::new("introspection_disabled_logs"),
+        value: &DEFAULT_INTROSPECTION_DISABLED_LOGS,
+        description: "The log families (timely, reachability, differential, compute) that \
+                      replicas created afterwards do not record (Materialize).",
+        internal: false,
+    });
+
+/// Controls [`mz_compute_client::protocol::command::ComputeParameters::schedule_sample_rate`].
+const INTROSPECTION_SCHEDULING_SAMPLE_RATE: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("introspection_scheduling_sample_rate"),
+    value: &1,
+    description: "Record only one in this many operator schedulings in the introspection sources \
+                  of replicas created afterwards (Materialize).",
+    internal: false,
+};
+
+/// The logical compaction window of the persisted introspection sources of
+/// replicas.
+pub const PERSISTED_INTROSPECTION_RETENTION: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("persisted_introspection_retention"),
+    value: &DEFAULT_LOGICAL_COMPACTION_WINDOW,
+    description: "The time to retain the history of the persisted introspection sources of \
+                  replicas (Materialize).",
+    internal: false,
+};
+
 /// Boolean flag indicating that the remote configuration was synchronized at
 /// least once with the persistent [SessionVars].
 pub static CONFIG_HAS_SYNCED_ONCE: ServerVar<bool> = ServerVar {
@@ -390,6 +517,28 @@ static EMIT_TRACE_ID_NOTICE: ServerVar<bool> = ServerVar {
     internal: false,
 };
 
+static EMIT_QUERY_STATISTICS: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("emit_query_statistics"),
+    value: &false,
+    description:
+        "Boolean flag indicating whether to send a NOTICE with the estimated resource usage of queries (Materialize).",
+    internal: false,
+};
+
+static QUERY_ADMISSION_CONTROL: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("query_admission_control"),
+    value: &true,
+    description: "Boolean flag indicating whether to refuse queries that are estimated to hold more than max_query_estimated_records records in memory (Materialize).",
+    internal: false,
+};
+
+static FORBID_MATERIALIZED_VIEW_WILDCARDS: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("forbid_materialized_view_wildcards"),
+    value: &false,
+    description: "Prohibits wildcards in the queries of materialized views, so that their columns are spelled out when they are created (Materialize).",
+    internal: false,
+};
+
 /// Session variables.
 ///
 /// Materialize roughly follows the PostgreSQL configuration model, which works
@@ -445,6 +594,9 @@ pub struct SessionVars {
     real_time_recency: SessionVar<bool>,
     emit_timestamp_notice: SessionVar<bool>,
     emit_trace_id_notice: SessionVar<bool>,
+    emit_query_statistics: SessionVar<bool>,
+    query_admission_control: SessionVar<bool>,
+    forbid_materialized_view_wildcards: SessionVar<bool>,
 }
 
 impl SessionVars {
@@ -478,20 +630,27 @@ impl SessionVars {
             real_time_recency: SessionVar::new(&REAL_TIME_RECENCY),
             emit_timestamp_notice: SessionVar::new(&EMIT_TIMESTAMP_NOTICE),
             emit_trace_id_notice: SessionVar::new(&EMIT_TRACE_ID_NOTICE),
+            emit_query_statistics: SessionVar::new(&EMIT_QUERY_STATISTICS),
+            query_admission_control: SessionVar::new(&QUERY_ADMISSION_CONTROL),
+            forbid_materialized_view_wildcards: SessionVar::new(
+                &FORBID_MATERIALIZED_VIEW_WILDCARDS,
+            ),
         }
     }
 
     /// Returns a new SessionVars with the cluster variable set to `cluster`.
     pub fn for_cluster(build_info: &'static BuildInfo, cluster_name: &str) -> Self {
         let mut vars = SessionVars::new(build_info);
-        vars.cluster.session_value = Some(cluster_name.into());
+        vars.cluster
+            .set_default(cluster_name)
+            .expect("cluster names are valid strings");
         vars
     }
 
     /// Returns an iterator over the configuration parameters and their current
     /// values for this session.
     pub fn iter(&self) -> impl Iterator<Item = &dyn Var> {
-        let vars: [&dyn Var; 25] = [
+        let vars: [&dyn Var; 28] = [
             &self.application_name,
             self.build_info,
             &self.client_encoding,
@@ -517,6 +676,9 @@ impl SessionVars {
             &self.real_time_recency,
             &self.emit_timestamp_notice,
             &self.emit_trace_id_notice,
+            &self.emit_query_statistics,
+            &self.query_admission_control,
+            &self.forbid_materialized_view_wildcards,
         ];
         vars.into_iter()
     }
@@ -606,6 +768,12 @@ impl SessionVars {
             Ok(&self.emit_timestamp_notice)
         } else if name == EMIT_TRACE_ID_NOTICE.name {
             Ok(&self.emit_trace_id_notice)
+        } else if name == EMIT_QUERY_STATISTICS.name {
+            Ok(&self.emit_query_statistics)
+        } else if name == QUERY_ADMISSION_CONTROL.name {
+            Ok(&self.query_admission_control)
+        } else if name == FORBID_MATERIALIZED_VIEW_WILDCARDS.name {
+            Ok(&self.forbid_materialized_view_wildcards)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -748,6 +916,12 @@ impl SessionVars {
             self.emit_timestamp_notice.set(value, local)
         } else if name == EMIT_TRACE_ID_NOTICE.name {
             self.emit_trace_id_notice.set(value, local)
+        } else if name == EMIT_QUERY_STATISTICS.name {
+            self.emit_query_statistics.set(value, local)
+        } else if name == QUERY_ADMISSION_CONTROL.name {
+            self.query_admission_control.set(value, local)
+        } else if name == FORBID_MATERIALIZED_VIEW_WILDCARDS.name {
+            self.forbid_materialized_view_wildcards.set(value, local)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -796,6 +970,12 @@ impl SessionVars {
             self.emit_timestamp_notice.reset(local);
         } else if name == EMIT_TRACE_ID_NOTICE.name {
             self.emit_trace_id_notice.reset(local);
+        } else if name == EMIT_QUERY_STATISTICS.name {
+            self.emit_query_statistics.reset(local);
+        } else if name == QUERY_ADMISSION_CONTROL.name {
+            self.query_admission_control.reset(local);
+        } else if name == FORBID_MATERIALIZED_VIEW_WILDCARDS.name {
+            self.forbid_materialized_view_wildcards.reset(local);
         } else if name == CLIENT_ENCODING.name
             || name == DATE_STYLE.name
             || name == FAILPOINTS.name
@@ -812,6 +992,126 @@ impl SessionVars {
         Ok(())
     }
 
+    /// Sets the default value of the configuration parameter named `name` to
+    /// the value represented by `value`, and sets the parameter to that value.
+    ///
+    /// The default value is the value that [`SessionVars::reset`] restores. It
+    /// is used for the parameters supplied when a connection is established,
+    /// which PostgreSQL treats as the defaults for the session. Unlike with
+    /// [`SessionVars::set`], the new value takes effect immediately, and is not
+    /// subject to transaction semantics.
+    ///
+    /// Values are validated and parameter names are matched as in
+    /// [`SessionVars::set`].
+    pub fn set_default(&mut self, name: &str, value: &str) -> Result<(), AdapterError> {
+        // Reuse the validation performed by `set`. The staged value is
+        // discarded by `SessionVar::set_default` below.
+        self.set(name, value, false)?;
+        if name == APPLICATION_NAME.name {
+            self.application_name.set_default(value)
+        } else if name == CLIENT_MIN_MESSAGES.name {
+            self.client_min_messages.set_default(value)
+        } else if name == CLUSTER.name {
+            self.cluster.set_default(value)
+        } else if name == CLUSTER_REPLICA.name {
+            self.cluster_replica.set_default(value)
+        } else if name == DATABASE.name {
+            self.database.set_default(value)
+        } else if name == EXTRA_FLOAT_DIGITS.name {
+            self.extra_float_digits.set_default(value)
+        } else if name == QGM_OPTIMIZATIONS.name {
+            self.qgm_optimizations.set_default(value)
+        } else if name == SEARCH_PATH.name {
+            self.search_path.set_default(value)
+        } else if name == SQL_SAFE_UPDATES.name {
+            self.sql_safe_updates.set_default(value)
+        } else if name == STATEMENT_TIMEOUT.name {
+            self.statement_timeout.set_default(value)
+        } else if name == IDLE_IN_TRANSACTION_SESSION_TIMEOUT.name {
+            self.idle_in_transaction_session_timeout.set_default(value)
+        } else if name == TIMEZONE.name {
+            self.timezone.set_default(value)
+        } else if name == TRANSACTION_ISOLATION.name {
+            self.transaction_isolation.set_default(value)
+        } else if name == REAL_TIME_RECENCY.name {
+            self.real_time_recency.set_default(value)
+        } else if name == EMIT_TIMESTAMP_NOTICE.name {
+            self.emit_timestamp_notice.set_default(value)
+        } else if name == EMIT_TRACE_ID_NOTICE.name {
+            self.emit_trace_id_notice.set_default(value)
+        } else if name == EMIT_QUERY_STATISTICS.name {
+            self.emit_query_statistics.set_default(value)
+        } else if name == QUERY_ADMISSION_CONTROL.name {
+            self.query_admission_control.set_default(value)
+        } else if name == FORBID_MATERIALIZED_VIEW_WILDCARDS.name {
+            self.forbid_materialized_view_wildcards.set_default(value)
+        } else {
+            // `set` has already rejected unknown parameters, so this is a
+            // fixed value parameter.
+            Ok(())
+        }
+    }
+
+    /// Sets all configuration parameters to their default values, as for
+    /// `RESET ALL`.
+    ///
+    /// As with [`SessionVars::reset`], the new values may be either committed or
+    /// rolled back by the next call to [`SessionVars::end_transaction`].
+    pub fn reset_all(&mut self) {
+        // IMPORTANT: if you've added a new `SessionVar`, add a corresponding
+        // call to `reset` below.
+        let SessionVars {
+            application_name,
+            build_info: _,
+            client_encoding: _,
+            client_min_messages,
+            cluster,
+            cluster_replica,
+            database,
+            date_style: _,
+            extra_float_digits,
+            failpoints: _,
+            integer_datetimes: _,
+            interval_style: _,
+            qgm_optimizations,
+            search_path,
+            server_version: _,
+            server_version_num: _,
+            sql_safe_updates,
+            standard_conforming_strings: _,
+            statement_timeout,
+            idle_in_transaction_session_timeout,
+            timezone,
+            transaction_isolation,
+            real_time_recency,
+            emit_timestamp_notice,
+            emit_trace_id_notice,
+            emit_query_statistics,
+            query_admission_control,
+            forbid_materialized_view_wildcards,
+        } = self;
+        let local = false;
+        application_name.reset(local);
+        client_min_messages.reset(local);
+        cluster.reset(local);
+        cluster_replica.reset(local);
+        database.reset(local);
+        extra_float_digits.reset(local);
+        qgm_optimizations.reset(local);
+        search_path.reset(local);
+        sql_safe_updates.reset(local);
+        statement_timeout.reset(local);
+        idle_in_transaction_session_timeout.reset(local);
+        timezone.reset(local);
+        transaction_isolation.reset(local);
+        real_time_recency.reset(local);
+        emit_timestamp_notice.reset(local);
+        emit_trace_id_notice.reset(local);
+        emit_query_statistics.reset(local);
+        query_admission_control.reset(local);
+        forbid_materialized_view_wildcards.reset(local);
+    }
+
     /// Commits or rolls back configuration parameter updates made via
     /// [`SessionVars::set`] since the last call to `end_transaction`.
     pub fn end_transaction(&mut self, action: EndTransactionAction) {
@@ -843,6 +1143,9 @@ impl SessionVars {
             real_time_recency,
             emit_timestamp_notice,
             emit_trace_id_notice,
+            emit_query_statistics,
+            query_admission_control,
+            forbid_materialized_view_wildcards,
         } = self;
         application_name.end_transaction(action);
         client_min_messages.end_transaction(action);
@@ -860,6 +1163,9 @@ impl SessionVars {
         real_time_recency.end_transaction(action);
         emit_timestamp_notice.end_transaction(action);
         emit_trace_id_notice.end_transaction(action);
+        emit_query_statistics.end_transaction(action);
+        query_admission_control.end_transaction(action);
+        forbid_materialized_view_wildcards.end_transaction(action);
     }
 
     /// Returns the value of the `application_name` configuration parameter.
@@ -992,6 +1298,22 @@ impl SessionVars {
     pub fn emit_trace_id_notice(&self) -> bool {
         *self.emit_trace_id_notice.value()
     }
+
+    /// Returns the value of `emit_query_statistics` configuration parameter.
+    pub fn emit_query_statistics(&self) -> bool {
+        *self.emit_query_statistics.value()
+    }
+
+    /// Returns the value of `query_admission_control` configuration parameter.
+    pub fn query_admission_control(&self) -> bool {
+        *self.query_admission_control.value()
+    }
+
+    /// Returns the value of `forbid_materialized_view_wildcards` configuration
+    /// parameter.
+    pub fn forbid_materialized_view_wildcards(&self) -> bool {
+        *self.forbid_materialized_view_wildcards.value()
+    }
 }
 
 /// On disk variables.
@@ -1013,20 +1335,35 @@ pub struct SystemVars {
     max_databases: SystemVar<u32>,
     max_schemas_per_database: SystemVar<u32>,
     max_objects_per_schema: SystemVar<u32>,
+    max_source_columns: SystemVar<u32>,
     max_secrets: SystemVar<u32>,
     max_roles: SystemVar<u32>,
     max_result_size: SystemVar<u32>,
+    max_query_estimated_records: SystemVar<usize>,
+    max_concurrent_peeks_per_role: SystemVar<u32>,
+    max_peek_queue_time: SystemVar<Duration>,
     allowed_cluster_replica_sizes: SystemVar<Vec<String>>, // TODO: BTreeSet<String> will be better
 
     // features
+    read_only_mode: SystemVar<bool>,
     window_functions: SystemVar<bool>,
 
     // persist configuration
     persist_blob_target_size: SystemVar<usize>,
     persist_compaction_minimum_timeout: SystemVar<Duration>,
 
+    // compute configuration
+    arrangement_maintenance_budget: SystemVar<usize>,
+    arrangement_compaction_window: SystemVar<Option<CompactionWindow>>,
+    arrangement_compaction_min_records: SystemVar<usize>,
+    introspection_disabled_logs: SystemVar<BTreeSet<LogFamily>>,
+    introspection_scheduling_sample_rate: SystemVar<u32>,
+
     // misc
+    default_timestamp_interval: SystemVar<Duration>,
+    default_introspection_interval: SystemVar<Duration>,
     metrics_retention: SystemVar<Duration>,
+    persisted_introspection_retention: SystemVar<Duration>,
 }
 
 impl Default for SystemVars {
@@ -1043,14 +1380,29 @@ impl Default for SystemVars {
             max_databases: SystemVar::new(&MAX_DATABASES),
             max_schemas_per_database: SystemVar::new(&MAX_SCHEMAS_PER_DATABASE),
             max_objects_per_schema: SystemVar::new(&MAX_OBJECTS_PER_SCHEMA),
+            max_source_columns: SystemVar::new(&MAX_SOURCE_COLUMNS),
             max_secrets: SystemVar::new(&MAX_SECRETS),
             max_roles: SystemVar::new(&MAX_ROLES),
             max_result_size: SystemVar::new(&MAX_RESULT_SIZE),
+            max_query_estimated_records: SystemVar::new(&MAX_QUERY_ESTIMATED_RECORDS),
+            max_concurrent_peeks_per_role: SystemVar::new(&MAX_CONCURRENT_PEEKS_PER_ROLE),
+            max_peek_queue_time: SystemVar::new(&MAX_PEEK_QUEUE_TIME),
             allowed_cluster_replica_sizes: SystemVar::new(&ALLOWED_CLUSTER_REPLICA_SIZES),
+            read_only_mode: SystemVar::new(&READ_ONLY_MODE),
             window_functions: SystemVar::new(&WINDOW_FUNCTIONS),
             persist_blob_target_size: SystemVar::new(&PERSIST_BLOB_TARGET_SIZE),
             persist_compaction_minimum_timeout: SystemVar::new(&PERSIST_COMPACTION_MINIMUM_TIMEOUT),
+            arrangement_maintenance_budget: SystemVar::new(&ARRANGEMENT_MAINTENANCE_BUDGET),
+            arrangement_compaction_window: SystemVar::new(&ARRANGEMENT_COMPACTION_WINDOW),
+            arrangement_compaction_min_records: SystemVar::new(&ARRANGEMENT_COMPACTION_MIN_RECORDS),
+            introspection_disabled_logs: SystemVar::new(&INTROSPECTION_DISABLED_LOGS),
+            introspection_scheduling_sample_rate: SystemVar::new(
+                &INTROSPECTION_SCHEDULING_SAMPLE_RATE,
+            ),
+            default_timestamp_interval: SystemVar::new(&DEFAULT_TIMESTAMP_INTERVAL),
+            default_introspection_interval: SystemVar::new(&DEFAULT_INTROSPECTION_INTERVAL),
             metrics_retention: SystemVar::new(&METRICS_RETENTION),
+            persisted_introspection_retention: SystemVar::new(&PERSISTED_INTROSPECTION_RETENTION),
         }
     }
 }
@@ -1059,7 +1411,7 @@ impl SystemVars {
     /// Returns an iterator over the configuration parameters and their current
     /// values on disk.
     pub fn iter(&self) -> impl Iterator<Item = &dyn Var> {
-        let vars: [&dyn Var; 19] = [
+        let vars: [&dyn Var; 32] = [
             &self.config_has_synced_once,
             &self.max_aws_privatelink_connections,
             &self.max_tables,
@@ -1071,14 +1423,27 @@ impl SystemVars {
             &self.max_databases,
             &self.max_schemas_per_database,
             &self.max_objects_per_schema,
+            &self.max_source_columns,
             &self.max_secrets,
             &self.max_roles,
             &self.max_result_size,
+            &self.max_query_estimated_records,
+            &self.max_concurrent_peeks_per_role,
+            &self.max_peek_queue_time,
             &self.allowed_cluster_replica_sizes,
+            &self.read_only_mode,
             &self.window_functions,
             &self.persist_blob_target_size,
             &self.persist_compaction_minimum_timeout,
+            &self.arrangement_maintenance_budget,
+            &self.arrangement_compaction_window,
+            &self.arrangement_compaction_min_records,
+            &self.introspection_disabled_logs,
+            &self.introspection_scheduling_sample_rate,
+            &self.default_timestamp_interval,
+            &self.default_introspection_interval,
             &self.metrics_retention,
+            &self.persisted_introspection_retention,
         ];
         vars.into_iter()
     }
@@ -1128,22 +1493,48 @@ impl SystemVars {
             Ok(&self.max_schemas_per_database)
         } else if name == MAX_OBJECTS_PER_SCHEMA.name {
             Ok(&self.max_objects_per_schema)
+        } else if name == MAX_SOURCE_COLUMNS.name {
+            Ok(&self.max_source_columns)
         } else if name == MAX_SECRETS.name {
             Ok(&self.max_secrets)
         } else if name == MAX_ROLES.name {
             Ok(&self.max_roles)
         } else if name == MAX_RESULT_SIZE.name {
             Ok(&self.max_result_size)
+        } else if name == MAX_QUERY_ESTIMATED_RECORDS.name {
+            Ok(&self.max_query_estimated_records)
+        } else if name == MAX_CONCURRENT_PEEKS_PER_ROLE.name {
+            Ok(&self.max_concurrent_peeks_per_role)
+        } else if name == MAX_PEEK_QUEUE_TIME.name {
+            Ok(&self.max_peek_queue_time)
         } else if name == ALLOWED_CLUSTER_REPLICA_SIZES.name {
             Ok(&self.allowed_cluster_replica_sizes)
+        } else if name == READ_ONLY_MODE.name {
+            Ok(&self.read_only_mode)
         } else if name == WINDOW_FUNCTIONS.name {
             Ok(&self.window_functions)
         } else if name == PERSIST_BLOB_TARGET_SIZE.name {
             Ok(&self.persist_blob_target_size)
         } else if name == PERSIST_COMPACTION_MINIMUM_TIMEOUT.name {
             Ok(&self.persist_compaction_minimum_timeout)
+        } else if name == ARRANGEMENT_MAINTENANCE_BUDGET.name {
+            Ok(&self.arrangement_maintenance_budget)
+        } else if name == ARRANGEMENT_COMPACTION_WINDOW.name {
+            Ok(&self.arrangement_compaction_window)
+        } else if name == ARRANGEMENT_COMPACTION_MIN_RECORDS.name {
+            Ok(&self.arrangement_compaction_min_records)
+        } else if name == INTROSPECTION_DISABLED_LOGS.name {
+            Ok(&self.introspection_disabled_logs)
+        } else if name == INTROSPECTION_SCHEDULING_SAMPLE_RATE.name {
+            Ok(&self.introspection_scheduling_sample_rate)
+        } else if name == DEFAULT_TIMESTAMP_INTERVAL.name {
+            Ok(&self.default_timestamp_interval)
+        } else if name == DEFAULT_INTROSPECTION_INTERVAL.name {
+            Ok(&self.default_introspection_interval)
         } else if name == METRICS_RETENTION.name {
             Ok(&self.metrics_retention)
+        } else if name == PERSISTED_INTROSPECTION_RETENTION.name {
+            Ok(&self.persisted_introspection_retention)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -1181,22 +1572,48 @@ impl SystemVars {
             self.max_schemas_per_database.is_default(value)
         } else if name == MAX_OBJECTS_PER_SCHEMA.name {
             self.max_objects_per_schema.is_default(value)
+        } else if name == MAX_SOURCE_COLUMNS.name {
+            self.max_source_columns.is_default(value)
         } else if name == MAX_SECRETS.name {
             self.max_secrets.is_default(value)
         } else if name == MAX_ROLES.name {
             self.max_roles.is_default(value)
         } else if name == MAX_RESULT_SIZE.name {
             self.max_result_size.is_default(value)
+        } else if name == MAX_QUERY_ESTIMATED_RECORDS.name {
+            self.max_query_estimated_records.is_default(value)
+        } else if name == MAX_CONCURRENT_PEEKS_PER_ROLE.name {
+            self.max_concurrent_peeks_per_role.is_default(value)
+        } else if name == MAX_PEEK_QUEUE_TIME.name {
+            self.max_peek_queue_time.is_default(value)
         } else if name == ALLOWED_CLUSTER_REPLICA_SIZES.name {
             self.allowed_cluster_replica_sizes.is_default(value)
+        } else if name == READ_ONLY_MODE.name {
+            self.read_only_mode.is_default(value)
         } else if name == WINDOW_FUNCTIONS.name {
             self.window_functions.is_default(value)
         } else if name == PERSIST_BLOB_TARGET_SIZE.name {
             self.persist_blob_target_size.is_default(value)
         } else if name == PERSIST_COMPACTION_MINIMUM_TIMEOUT.name {
             self.persist_compaction_minimum_timeout.is_default(value)
+        } else if name == ARRANGEMENT_MAINTENANCE_BUDGET.name {
+            self.arrangement_maintenance_budget.is_default(value)
+        } else if name == ARRANGEMENT_COMPACTION_WINDOW.name {
+            self.arrangement_compaction_window.is_default(value)
+        } else if name == ARRANGEMENT_COMPACTION_MIN_RECORDS.name {
+            self.arrangement_compaction_min_records.is_default(value)
+        } else if name == INTROSPECTION_DISABLED_LOGS.name {
+            self.introspection_disabled_logs.is_default(value)
+        } else if name == INTROSPECTION_SCHEDULING_SAMPLE_RATE.name {
+            self.introspection_scheduling_sample_rate.is_default(value)
+        } else if name == DEFAULT_TIMESTAMP_INTERVAL.name {
+            self.default_timestamp_interval.is_default(value)
+        } else if name == DEFAULT_INTROSPECTION_INTERVAL.name {
+            self.default_introspection_interval.is_default(value)
         } else if name == METRICS_RETENTION.name {
             self.metrics_retention.is_default(value)
+        } else if name == PERSISTED_INTROSPECTION_RETENTION.name {
+            self.persisted_introspection_retention.is_default(value)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -1243,22 +1660,48 @@ impl SystemVars {
             self.max_schemas_per_database.set(value)
         } else if name == MAX_OBJECTS_PER_SCHEMA.name {
             self.max_objects_per_schema.set(value)
+        } else if name == MAX_SOURCE_COLUMNS.name {
+            self.max_source_columns.set(value)
         } else if name == MAX_SECRETS.name {
             self.max_secrets.set(value)
         } else if name == MAX_ROLES.name {
             self.max_roles.set(value)
         } else if name == MAX_RESULT_SIZE.name {
             self.max_result_size.set(value)
+        } else if name == MAX_QUERY_ESTIMATED_RECORDS.name {
+            self.max_query_estimated_records.set(value)
+        } else if name == MAX_CONCURRENT_PEEKS_PER_ROLE.name {
+            self.max_concurrent_peeks_per_role.set(value)
+        } else if name == MAX_PEEK_QUEUE_TIME.name {
+            self.max_peek_queue_time.set(value)
         } else if name == ALLOWED_CLUSTER_REPLICA_SIZES.name {
             self.allowed_cluster_replica_sizes.set(value)
+        } else if name == READ_ONLY_MODE.name {
+            self.read_only_mode.set(value)
         } else if name == WINDOW_FUNCTIONS.name {
             self.window_functions.set(value)
         } else if name == PERSIST_BLOB_TARGET_SIZE.name {
             self.persist_blob_target_size.set(value)
         } else if name == PERSIST_COMPACTION_MINIMUM_TIMEOUT.name {
             self.persist_compaction_minimum_timeout.set(value)
+        } else if name == ARRANGEMENT_MAINTENANCE_BUDGET.name {
+            self.arrangement_maintenance_budget.set(value)
+        } else if name == ARRANGEMENT_COMPACTION_WINDOW.name {
+            self.arrangement_compaction_window.set(value)
+        } else if name == ARRANGEMENT_COMPACTION_MIN_RECORDS.name {
+            self.arrangement_compaction_min_records.set(value)
+        } else if name == INTROSPECTION_DISABLED_LOGS.name {
+            self.introspection_disabled_logs.set(value)
+        } else if name == INTROSPECTION_SCHEDULING_SAMPLE_RATE.name {
+            self.introspection_scheduling_sample_rate.set(value)
+        } else if name == DEFAULT_TIMESTAMP_INTERVAL.name {
+            self.default_timestamp_interval.set(value)
+        } else if name == DEFAULT_INTROSPECTION_INTERVAL.name {
+            self.default_introspection_interval.set(value)
         } else if name == METRICS_RETENTION.name {
             self.metrics_retention.set(value)
+        } else if name == PERSISTED_INTROSPECTION_RETENTION.name {
+            self.persisted_introspection_retention.set(value)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -1300,22 +1743,48 @@ impl SystemVars {
             Ok(self.max_schemas_per_database.reset())
         } else if name == MAX_OBJECTS_PER_SCHEMA.name {
             Ok(self.max_objects_per_schema.reset())
+        } else if name == MAX_SOURCE_COLUMNS.name {
+            Ok(self.max_source_columns.reset())
         } else if name == MAX_SECRETS.name {
             Ok(self.max_secrets.reset())
         } else if name == MAX_ROLES.name {
             Ok(self.max_roles.reset())
         } else if name == MAX_RESULT_SIZE.name {
             Ok(self.max_result_size.reset())
+        } else if name == MAX_QUERY_ESTIMATED_RECORDS.name {
+            Ok(self.max_query_estimated_records.reset())
+        } else if name == MAX_CONCURRENT_PEEKS_PER_ROLE.name {
+            Ok(self.max_concurrent_peeks_per_role.reset())
+        } else if name == MAX_PEEK_QUEUE_TIME.name {
+            Ok(self.max_peek_queue_time.reset())
         } else if name == ALLOWED_CLUSTER_REPLICA_SIZES.name {
             Ok(self.allowed_cluster_replica_sizes.reset())
+        } else if name == READ_ONLY_MODE.name {
+            Ok(self.read_only_mode.reset())
         } else if name == WINDOW_FUNCTIONS.name {
             Ok(self.window_functions.reset())
         } else if name == PERSIST_BLOB_TARGET_SIZE.name {
             Ok(self.persist_blob_target_size.reset())
         } else if name == PERSIST_COMPACTION_MINIMUM_TIMEOUT.name {
             Ok(self.persist_compaction_minimum_timeout.reset())
+        } else if name == ARRANGEMENT_MAINTENANCE_BUDGET.name {
+            Ok(self.arrangement_maintenance_budget.reset())
+        } else if name == ARRANGEMENT_COMPACTION_WINDOW.name {
+            Ok(self.arrangement_compaction_window.reset())
+        } else if name == ARRANGEMENT_COMPACTION_MIN_RECORDS.name {
+            Ok(self.arrangement_compaction_min_records.reset())
+        } else if name == INTROSPECTION_DISABLED_LOGS.name {
+            Ok(self.introspection_disabled_logs.reset())
+        } else if name == INTROSPECTION_SCHEDULING_SAMPLE_RATE.name {
+            Ok(self.introspection_scheduling_sample_rate.reset())
+        } else if name == DEFAULT_TIMESTAMP_INTERVAL.name {
+            Ok(self.default_timestamp_interval.reset())
+        } else if name == DEFAULT_INTROSPECTION_INTERVAL.name {
+            Ok(self.default_introspection_interval.reset())
         } else if name == METRICS_RETENTION.name {
             Ok(self.metrics_retention.reset())
+        } else if name == PERSISTED_INTROSPECTION_RETENTION.name {
+            Ok(self.persisted_introspection_retention.reset())
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -1376,6 +1845,11 @@ impl SystemVars {
         *self.max_objects_per_schema.value()
     }
 
+    /// Returns the value of the `max_source_columns` configuration parameter.
+    pub fn max_source_columns(&self) -> u32 {
+        *self.max_source_columns.value()
+    }
+
     /// Returns the value of the `max_secrets` configuration parameter.
     pub fn max_secrets(&self) -> u32 {
         *self.max_secrets.value()
@@ -1391,11 +1865,31 @@ impl SystemVars {
         *self.max_result_size.value()
     }
 
+    /// Returns the value of the `max_query_estimated_records` configuration parameter.
+    pub fn max_query_estimated_records(&self) -> usize {
+        *self.max_query_estimated_records.value()
+    }
+
+    /// Returns the value of the `max_concurrent_peeks_per_role` configuration parameter.
+    pub fn max_concurrent_peeks_per_role(&self) -> u32 {
+        *self.max_concurrent_peeks_per_role.value()
+    }
+
+    /// Returns the value of the `max_peek_queue_time` configuration parameter.
+    pub fn max_peek_queue_time(&self) -> Duration {
+        *self.max_peek_queue_time.value()
+    }
+
     /// Returns the value of the `allowed_cluster_replica_sizes` configuration parameter.
     pub fn allowed_cluster_replica_sizes(&self) -> &Vec<String> {
         self.allowed_cluster_replica_sizes.value()
     }
 
+    /// Returns the `read_only_mode` configuration parameter.
+    pub fn read_only_mode(&self) -> bool {
+        *self.read_only_mode.value()
+    }
+
     /// Returns the `window_functions` configuration parameter.
     pub fn window_functions(&self) -> bool {
         *self.window_functions.value()
@@ -1411,9 +1905,49 @@ impl SystemVars {
         *self.persist_compaction_minimum_timeout.value()
     }
 
+    /// Returns the `arrangement_maintenance_budget` configuration parameter.
+    pub fn arrangement_maintenance_budget(&self) -> usize {
+        *self.arrangement_maintenance_budget.value()
+    }
+
+    /// Returns the `arrangement_compaction_window` configuration parameter.
+    pub fn arrangement_compaction_window(&self) -> Option<CompactionWindow> {
+        *self.arrangement_compaction_window.value()
+    }
+
+    /// Returns the `arrangement_compaction_min_records` configuration parameter.
+    pub fn arrangement_compaction_min_records(&self) -> usize {
+        *self.arrangement_compaction_min_records.value()
+    }
+
+    /// Returns the `introspection_disabled_logs` configuration parameter.
+    pub fn introspection_disabled_logs(&self) -> BTreeSet<LogFamily> {
+        self.introspection_disabled_logs.value().clone()
+    }
+
+    /// Returns the `introspection_scheduling_sample_rate` configuration parameter.
+    pub fn introspection_scheduling_sample_rate(&self) -> u32 {
+        *self.introspection_scheduling_sample_rate.value()
+    }
+
+    /// Returns the `default_timestamp_interval` configuration parameter.
+    pub fn default_timestamp_interval(&self) -> Duration {
+        *self.default_timestamp_interval.value()
+    }
+
+    /// Returns the `default_introspection_interval` configuration parameter.
+    pub fn default_introspection_interval(&self) -> Duration {
+        *self.default_introspection_interval.value()
+    }
+
     pub fn metrics_retention(&self) -> Duration {
         *self.metrics_retention.value()
     }
+
+    /// Returns the `persisted_introspection_retention` configuration parameter.
+    pub fn persisted_introspection_retention(&self) -> Duration {
+        *self.persisted_introspection_retention.value()
+    }
 }
 
 /// A `Var` represents a configuration parameter of an arbitrary type.
@@ -1573,13 +2107,14 @@ where
 }
 
 /// A `SessionVar` is the session value for a configuration parameter. If unset,
-/// the server default is used instead.
+/// the session default is used instead, which in turn defaults to the server
+/// default.
 #[derive(Debug)]
 struct SessionVar<V>
 where
     V: Value + fmt::Debug + ?Sized + 'static,
 {
-    default_value: &'static V,
+    default_value: Option<V::Owned>,
     local_value: Option<V::Owned>,
     staged_value: Option<V::Owned>,
     session_value: Option<V::Owned>,
@@ -1592,7 +2127,7 @@ where
 {
     fn new(parent: &'static ServerVar<V>) -> SessionVar<V> {
         SessionVar {
-            default_value: parent.value,
+            default_value: None,
             local_value: None,
             staged_value: None,
             session_value: None,
@@ -1615,8 +2150,27 @@ where
         }
     }
 
+    fn set_default(&mut self, s: &str) -> Result<(), AdapterError> {
+        match V::parse(s) {
+            Ok(v) => {
+                let default: &V = v.borrow();
+                self.default_value = Some(default.to_owned());
+                self.local_value = None;
+                self.staged_value = None;
+                self.session_value = Some(v);
+                Ok(())
+            }
+            Err(()) => Err(AdapterError::InvalidParameterType(self.parent)),
+        }
+    }
+
     fn reset(&mut self, local: bool) {
-        let value = self.default_value.to_owned();
+        let value = self
+            .default_value
+            .as_ref()
+            .map(|v| v.borrow())
+            .unwrap_or(self.parent.value)
+            .to_owned();
         if local {
             self.local_value = Some(value);
         } else {
@@ -1974,6 +2528,24 @@ impl Value for Option<String> {
     }
 }
 
+impl Value for Option<CompactionWindow> {
+    const TYPE_NAME: &'static str = "optional compaction window";
+
+    fn parse(s: &str) -> Result<Option<CompactionWindow>, ()> {
+        match s {
+            "" => Ok(None),
+            _ => s.parse().map(Some).map_err(|_| ()),
+        }
+    }
+
+    fn format(&self) -> String {
+        match self {
+            Some(window) => window.to_string(),
+            None => "".into(),
+        }
+    }
+}
+
 /// Severity levels can used to be used to filter which messages get sent
 /// to a client.
 ///
@@ -2047,6 +2619,24 @@ impl ClientSeverity {
     }
 }
 
+impl Value for BTreeSet<LogFamily> {
+    const TYPE_NAME: &'static str = "log family list";
+
+    fn parse(s: &str) -> Result<BTreeSet<LogFamily>, ()> {
+        <Vec<String> as Value>::parse(s)?
+            .iter()
+            .map(|family| family.parse().map_err(|_| ()))
+            .collect()
+    }
+
+    fn format(&self) -> String {
+        self.iter()
+            .map(|family| family.name().to_string())
+            .collect::<Vec<_>>()
+            .format()
+    }
+}
+
 impl Value for ClientSeverity {
     const TYPE_NAME: &'static str = "string";
 
@@ -2198,7 +2788,13 @@ impl From<TransactionIsolationLevel> for IsolationLevel {
 
 /// Returns whether the named variable is a compute configuration parameter.
 pub(crate) fn is_compute_config_var(name: &str) -> bool {
-    name == MAX_RESULT_SIZE.name() || is_persist_config_var(name)
+    name == MAX_RESULT_SIZE.name()
+        || name == ARRANGEMENT_MAINTENANCE_BUDGET.name()
+        || name == ARRANGEMENT_COMPACTION_WINDOW.name()
+        || name == ARRANGEMENT_COMPACTION_MIN_RECORDS.name()
+        || name == INTROSPECTION_DISABLED_LOGS.name()
+        || name == INTROSPECTION_SCHEDULING_SAMPLE_RATE.name()
+        || is_persist_config_var(name)
 }
 
 /// Returns whether the named variable is a storage configuration parameter.
@@ -83,6 +83,11 @@ impl<T: Transmittable> ClientTransmitter<T> {
         }
     }
 
+    /// Reports whether the client has stopped waiting for the response.
+    pub fn is_closed(&self) -> bool {
+        self.tx.as_ref().map_or(true, |tx| tx.is_closed())
+    }
+
     pub fn take(mut self) -> oneshot::Sender<Response<T>> {
         self.tx.take().unwrap()
     }
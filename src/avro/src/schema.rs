@@ -386,6 +386,64 @@ impl Schema {
     pub fn try_lookup_name(&self, name: &FullName) -> Option<&NamedSchemaPiece> {
         self.indices.get(name).map(|&idx| &self.named[idx])
     }
+
+    /// Returns a copy of this schema in which the top-level record only
+    /// contains the named fields, in the order in which they appear in this
+    /// schema.
+    ///
+    /// Using the result as a reader schema skips the other fields whenever a
+    /// datum is decoded. Named types that are only referenced by the removed
+    /// fields are not included in the serialized form of the result.
+    pub fn project_fields(&self, names: &[String]) -> Result<Schema, ParseSchemaError> {
+        let idx = match self.top {
+            SchemaPieceOrNamed::Named(idx) => idx,
+            SchemaPieceOrNamed::Piece(_) => {
+                return Err(ParseSchemaError::new(
+                    "only the fields of record schemas can be selected",
+                ))
+            }
+        };
+        let named = &self.named[idx];
+        let (doc, fields, lookup) = match &named.piece {
+            SchemaPiece::Record {
+                doc,
+                fields,
+                lookup,
+            } => (doc, fields, lookup),
+            _ => {
+                return Err(ParseSchemaError::new(
+                    "only the fields of record schemas can be selected",
+                ))
+            }
+        };
+        if let Some(name) = names.iter().find(|name| !lookup.contains_key(*name)) {
+            return Err(ParseSchemaError::new(format!(
+                "record {} has no field named {}",
+                named.name.base_name(),
+                name
+            )));
+        }
+        let fields: Vec<_> = fields
+            .iter()
+            .filter(|field| names.contains(&field.name))
+            .enumerate()
+            .map(|(position, field)| RecordField {
+                position,
+                ..field.clone()
+            })
+            .collect();
+        let lookup = fields
+            .iter()
+            .map(|field| (field.name.clone(), field.position))
+            .collect();
+        let mut schema = self.clone();
+        schema.named[idx].piece = SchemaPiece::Record {
+            doc: doc.clone(),
+            fields,
+            lookup,
+        };
+        Ok(schema)
+    }
 }
 
 /// This type is used to simplify enum variant comparison between `Schema` and `types::Value`.
@@ -2389,6 +2447,50 @@ mod tests {
         check_schema(schema, expected);
     }
 
+    #[test]
+    fn test_project_fields() {
+        let schema = Schema::from_str(
+            r#"
+                {
+                    "type": "record",
+                    "name": "test",
+                    "fields": [
+                        {"name": "a", "type": {"type": "fixed", "name": "f", "size": 2}},
+                        {"name": "b", "type": "f"},
+                        {"name": "c", "type": "string"}
+                    ]
+                }
+            "#,
+        )
+        .unwrap();
+
+        let field_types = |schema: &Schema| -> Vec<(String, SchemaPiece)> {
+            let node = schema.top_node();
+            match node.inner {
+                SchemaPiece::Record { fields, .. } => fields
+                    .iter()
+                    .map(|f| (f.name.clone(), node.step(&f.schema).inner.clone()))
+                    .collect(),
+                _ => unreachable!(),
+            }
+        };
+
+        let projected = schema.project_fields(&["c".into(), "b".into()]).unwrap();
+        let expected = vec![
+            ("b".to_string(), SchemaPiece::Fixed { size: 2 }),
+            ("c".to_string(), SchemaPiece::String),
+        ];
+        assert_eq!(field_types(&projected), expected);
+
+        // `b` refers to a type that is defined by `a`, so the serialized form
+        // of the projection must define it in `b` instead.
+        let reparsed = Schema::from_str(&projected.to_string()).unwrap();
+        assert_eq!(field_types(&reparsed), expected);
+
+        let err = schema.project_fields(&["d".into()]).unwrap_err();
+        assert_eq!(err.to_string(), "record test has no field named d");
+    }
+
     #[test]
     fn test_enum_schema() {
         let schema = r#"{"type": "enum", "name": "Suit", "symbols": ["diamonds", "spades", "jokers", "clubs", "hearts"], "default": "jokers"}"#;
@@ -173,6 +173,7 @@ async fn main() {
     let args = cli::parse_args(CliConfig {
         env_prefix: Some("CLUSTERD_"),
         enable_version_flag: true,
+        enable_config_file: false,
     });
     if let Err(err) = run(args).await {
         eprintln!("clusterd: fatal: {:#}", err);
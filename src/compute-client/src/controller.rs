@@ -233,10 +233,6 @@ impl ComputeReplicaAllocation {
     }
 }
 
-/// The default logging interval for [`ComputeReplicaLogging`], in number
-/// of microseconds.
-pub const DEFAULT_COMPUTE_REPLICA_LOGGING_INTERVAL_MICROS: u32 = 1_000_000;
-
 /// Logging configuration of a replica.
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ComputeReplicaLogging {
@@ -608,6 +604,15 @@ where
             .idle_arrangement_merge_effort
             .unwrap_or(DEFAULT_IDLE_ARRANGEMENT_MERGE_EFFORT);
 
+        // The logging parameters are only applied to replicas when they are created.
+        let disabled_families = self
+            .compute
+            .config
+            .disabled_log_families
+            .clone()
+            .unwrap_or_default();
+        let schedule_sample_rate = self.compute.config.schedule_sample_rate.unwrap_or(1);
+
         let replica_config = ReplicaConfig {
             location: config.location,
             logging: LoggingConfig {
@@ -616,6 +621,8 @@ where
                 log_logging: config.logging.log_logging,
                 index_logs: Default::default(),
                 sink_logs,
+                disabled_families,
+                schedule_sample_rate,
             },
             idle_arrangement_merge_effort,
         };
@@ -845,6 +852,9 @@ pub struct CollectionState<T> {
     write_frontier: Antichain<T>,
     /// The write frontiers reported by individual replicas.
     replica_write_frontiers: BTreeMap<ReplicaId, Antichain<T>>,
+    /// The number of updates in the collection's arrangement, as most recently reported by any
+    /// replica, if the collection is an index.
+    arrangement_size: Option<u64>,
 }
 
 impl<T: Timestamp> CollectionState<T> {
@@ -866,6 +876,7 @@ impl<T: Timestamp> CollectionState<T> {
             compute_dependencies,
             write_frontier: Antichain::from_elem(Timestamp::minimum()),
             replica_write_frontiers: BTreeMap::new(),
+            arrangement_size: None,
         }
     }
 
@@ -890,4 +901,11 @@ impl<T: Timestamp> CollectionState<T> {
     pub fn write_frontier(&self) -> AntichainRef<T> {
         self.write_frontier.borrow()
     }
+
+    /// Reports the number of updates in the collection's arrangement, if known.
+    ///
+    /// The size is periodically reported by the replicas and is only approximate.
+    pub fn arrangement_size(&self) -> Option<u64> {
+        self.arrangement_size
+    }
 }
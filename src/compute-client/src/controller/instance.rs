@@ -982,6 +982,10 @@ where
             ComputeResponse::SubscribeResponse(id, response) => {
                 self.handle_subscribe_response(id, response, replica_id)
             }
+            ComputeResponse::ArrangementSizes(list) => {
+                self.handle_arrangement_sizes(list);
+                None
+            }
         }
     }
 
@@ -1021,6 +1025,16 @@ where
         self.update_write_frontiers(replica_id, &updates);
     }
 
+    fn handle_arrangement_sizes(&mut self, list: Vec<(GlobalId, u64)>) {
+        // All replicas maintain the same arrangements, so we simply remember the size most
+        // recently reported by any of them.
+        for (id, size) in list {
+            if let Ok(collection) = self.compute.collection_mut(id) {
+                collection.arrangement_size = Some(size);
+            }
+        }
+    }
+
     fn handle_peek_response(
         &mut self,
         uuid: Uuid,
@@ -9,7 +9,9 @@
 
 //! Compute layer logging configuration.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::str::FromStr;
 use std::time::Duration;
 
 use once_cell::sync::Lazy;
@@ -42,6 +44,12 @@ pub struct LoggingConfig {
     pub index_logs: BTreeMap<LogVariant, GlobalId>,
     /// Logs to be written to persist
     pub sink_logs: BTreeMap<LogVariant, (GlobalId, CollectionMetadata)>,
+    /// Log families whose events are dropped
+    ///
+    /// The logging dataflows of disabled families are still installed, but their logs stay empty.
+    pub disabled_families: BTreeSet<LogFamily>,
+    /// Log only one in this many operator schedulings, or all of them if this is zero or one
+    pub schedule_sample_rate: u32,
 }
 
 impl LoggingConfig {
@@ -61,6 +69,8 @@ impl RustType<ProtoLoggingConfig> for LoggingConfig {
             log_logging: self.log_logging,
             index_logs: self.index_logs.into_proto(),
             sink_logs: self.sink_logs.into_proto(),
+            disabled_families: self.disabled_families.into_proto(),
+            schedule_sample_rate: self.schedule_sample_rate,
         }
     }
 
@@ -73,6 +83,8 @@ impl RustType<ProtoLoggingConfig> for LoggingConfig {
             log_logging: proto.log_logging,
             index_logs: proto.index_logs.into_rust()?,
             sink_logs: proto.sink_logs.into_rust()?,
+            disabled_families: proto.disabled_families.into_rust()?,
+            schedule_sample_rate: proto.schedule_sample_rate,
         })
     }
 }
@@ -148,6 +160,98 @@ impl RustType<ProtoLogVariant> for LogVariant {
     }
 }
 
+/// A group of logs that are produced by the same logger.
+///
+/// Logging can be disabled per family, which avoids the overhead of processing the events of
+/// its logger, but not the overhead of emitting them.
+#[derive(
+    Arbitrary, Copy, Hash, Eq, Ord, PartialEq, PartialOrd, Debug, Clone, Serialize, Deserialize,
+)]
+pub enum LogFamily {
+    /// Timely logs other than reachability.
+    Timely,
+    /// Timely reachability logs.
+    Reachability,
+    /// Differential logs.
+    Differential,
+    /// Compute logs.
+    Compute,
+}
+
+impl LogFamily {
+    /// All log families.
+    pub const ALL: [LogFamily; 4] = [
+        LogFamily::Timely,
+        LogFamily::Reachability,
+        LogFamily::Differential,
+        LogFamily::Compute,
+    ];
+
+    /// The name of the family, as accepted by [`LogFamily::from_str`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogFamily::Timely => "timely",
+            LogFamily::Reachability => "reachability",
+            LogFamily::Differential => "differential",
+            LogFamily::Compute => "compute",
+        }
+    }
+}
+
+impl fmt::Display for LogFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for LogFamily {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LogFamily::ALL
+            .into_iter()
+            .find(|family| family.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("unknown log family: {s}"))
+    }
+}
+
+impl RustType<ProtoLogFamily> for LogFamily {
+    fn into_proto(&self) -> ProtoLogFamily {
+        use proto_log_family::Kind::*;
+        ProtoLogFamily {
+            kind: Some(match self {
+                LogFamily::Timely => Timely(()),
+                LogFamily::Reachability => Reachability(()),
+                LogFamily::Differential => Differential(()),
+                LogFamily::Compute => Compute(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoLogFamily) -> Result<Self, TryFromProtoError> {
+        use proto_log_family::Kind::*;
+        match proto.kind {
+            Some(Timely(())) => Ok(LogFamily::Timely),
+            Some(Reachability(())) => Ok(LogFamily::Reachability),
+            Some(Differential(())) => Ok(LogFamily::Differential),
+            Some(Compute(())) => Ok(LogFamily::Compute),
+            None => Err(TryFromProtoError::missing_field("ProtoLogFamily::kind")),
+        }
+    }
+}
+
+impl RustType<ProtoLogFamilies> for BTreeSet<LogFamily> {
+    fn into_proto(&self) -> ProtoLogFamilies {
+        ProtoLogFamilies {
+            families: self.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoLogFamilies) -> Result<Self, TryFromProtoError> {
+        proto.families.into_rust()
+    }
+}
+
 #[derive(Arbitrary, Hash, Eq, Ord, PartialEq, PartialOrd, Debug, Clone, Serialize, Deserialize)]
 pub enum TimelyLog {
     Operates,
@@ -237,6 +341,7 @@ pub enum ComputeLog {
     PeekDuration,
     FrontierDelay,
     SourceFrontierCurrent,
+    RecentUpdates,
 }
 
 impl RustType<ProtoComputeLog> for ComputeLog {
@@ -251,6 +356,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
                 ComputeLog::PeekDuration => PeekDuration(()),
                 ComputeLog::FrontierDelay => FrontierDelay(()),
                 ComputeLog::SourceFrontierCurrent => SourceFrontierCurrent(()),
+                ComputeLog::RecentUpdates => RecentUpdates(()),
             }),
         }
     }
@@ -265,6 +371,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
             Some(PeekDuration(())) => Ok(ComputeLog::PeekDuration),
             Some(FrontierDelay(())) => Ok(ComputeLog::FrontierDelay),
             Some(SourceFrontierCurrent(())) => Ok(ComputeLog::SourceFrontierCurrent),
+            Some(RecentUpdates(())) => Ok(ComputeLog::RecentUpdates),
             None => Err(TryFromProtoError::missing_field("ProtoComputeLog::kind")),
         }
     }
@@ -291,6 +398,7 @@ pub static DEFAULT_LOG_VARIANTS: Lazy<Vec<LogVariant>> = Lazy::new(|| {
         LogVariant::Compute(ComputeLog::FrontierDelay),
         LogVariant::Compute(ComputeLog::PeekCurrent),
         LogVariant::Compute(ComputeLog::PeekDuration),
+        LogVariant::Compute(ComputeLog::RecentUpdates),
     ];
 
     default_logs
@@ -317,6 +425,7 @@ pub enum LogView {
     MzSchedulingElapsed,
     MzSchedulingParks,
     MzWorkerComputeDelays,
+    MzComputeMaintenanceStatistics,
 }
 
 pub static DEFAULT_LOG_VIEWS: Lazy<Vec<LogView>> = Lazy::new(|| {
@@ -339,6 +448,7 @@ pub static DEFAULT_LOG_VIEWS: Lazy<Vec<LogView>> = Lazy::new(|| {
         LogView::MzComputeOperatorDurations,
         LogView::MzSchedulingParks,
         LogView::MzWorkerComputeDelays,
+        LogView::MzComputeMaintenanceStatistics,
     ]
 });
 
@@ -602,6 +712,77 @@ impl LogView {
                     mz_internal.mz_raw_worker_compute_delays_{}",
                 "mz_worker_compute_delays_{}",
             ),
+
+            LogView::MzComputeMaintenanceStatistics => (
+                "WITH workers AS (
+                    SELECT
+                        export_id,
+                        pg_catalog.count(*) AS workers
+                    FROM
+                        mz_internal.mz_compute_exports_{}
+                    GROUP BY
+                        export_id
+                ),
+                updates AS (
+                    SELECT
+                        export_id,
+                        pg_catalog.sum(CASE WHEN kind = 'input' THEN 1 ELSE 0 END) AS input_updates,
+                        pg_catalog.sum(CASE WHEN kind = 'output' THEN 1 ELSE 0 END) AS output_updates
+                    FROM
+                        mz_internal.mz_compute_recent_updates_internal_{}
+                    GROUP BY
+                        export_id
+                ),
+                records AS (
+                    SELECT
+                        operator_id,
+                        worker_id,
+                        pg_catalog.count(*) AS records
+                    FROM
+                        mz_internal.mz_arrangement_records_internal_{}
+                    GROUP BY
+                        operator_id, worker_id
+                ),
+                operator_skew AS (
+                    SELECT
+                        workers.export_id,
+                        records.operator_id,
+                        pg_catalog.max(records.records)::pg_catalog.float8 * workers.workers
+                            / pg_catalog.sum(records.records)::pg_catalog.float8 AS skew
+                    FROM
+                        records,
+                        mz_internal.mz_dataflow_operator_dataflows_{},
+                        mz_internal.mz_dataflows_{},
+                        mz_internal.mz_compute_exports_{},
+                        workers
+                    WHERE
+                        mz_dataflow_operator_dataflows_{}.id = records.operator_id AND
+                        mz_dataflow_operator_dataflows_{}.worker_id = records.worker_id AND
+                        mz_dataflows_{}.id = mz_dataflow_operator_dataflows_{}.dataflow_id AND
+                        mz_dataflows_{}.worker_id = mz_dataflow_operator_dataflows_{}.worker_id AND
+                        mz_compute_exports_{}.dataflow_local_id = mz_dataflows_{}.local_id AND
+                        mz_compute_exports_{}.worker_id = mz_dataflows_{}.worker_id AND
+                        workers.export_id = mz_compute_exports_{}.export_id
+                    GROUP BY
+                        workers.export_id, records.operator_id, workers.workers
+                )
+                SELECT
+                    workers.export_id,
+                    pg_catalog.coalesce(updates.input_updates, 0)::pg_catalog.float8 / 60
+                        AS input_updates_per_second,
+                    pg_catalog.coalesce(updates.output_updates, 0)::pg_catalog.float8 / 60
+                        AS output_updates_per_second,
+                    skew.skew
+                FROM
+                    workers
+                    LEFT JOIN updates USING (export_id)
+                    LEFT JOIN (
+                        SELECT export_id, pg_catalog.max(skew) AS skew
+                        FROM operator_skew
+                        GROUP BY export_id
+                    ) AS skew USING (export_id)",
+                "mz_compute_maintenance_statistics_{}",
+            ),
         }
     }
 }
@@ -699,6 +880,7 @@ impl LogVariant {
             LogVariant::Compute(ComputeLog::DataflowCurrent) => RelationDesc::empty()
                 .with_column("export_id", ScalarType::String.nullable(false))
                 .with_column("worker_id", ScalarType::UInt64.nullable(false))
+                .with_column("dataflow_local_id", ScalarType::UInt64.nullable(false))
                 .with_key(vec![0, 1]),
 
             LogVariant::Compute(ComputeLog::DataflowDependency) => RelationDesc::empty()
@@ -737,6 +919,11 @@ impl LogVariant {
                 .with_column("duration_ns", ScalarType::UInt64.nullable(false))
                 .with_column("count", ScalarType::UInt64.nullable(false))
                 .with_key(vec![0, 1]),
+
+            LogVariant::Compute(ComputeLog::RecentUpdates) => RelationDesc::empty()
+                .with_column("export_id", ScalarType::String.nullable(false))
+                .with_column("worker_id", ScalarType::UInt64.nullable(false))
+                .with_column("kind", ScalarType::String.nullable(false)),
         }
     }
 
@@ -786,6 +973,7 @@ impl LogVariant {
             LogVariant::Compute(ComputeLog::FrontierDelay) => vec![],
             LogVariant::Compute(ComputeLog::PeekCurrent) => vec![],
             LogVariant::Compute(ComputeLog::PeekDuration) => vec![],
+            LogVariant::Compute(ComputeLog::RecentUpdates) => vec![],
         }
     }
 }
@@ -256,8 +256,19 @@ pub struct AccumulablePlan {
     pub simple_aggrs: Vec<(usize, usize, AggregateExpr)>,
     /// Same as above but for all of the `DISTINCT` accumulable aggregations.
     pub distinct_aggrs: Vec<(usize, usize, AggregateExpr)>,
+    /// Whether the input of each group key is pre-aggregated on each worker
+    /// before it is exchanged for the final reduction.
+    ///
+    /// Pre-aggregating spreads the work of accumulating large groups across
+    /// workers, so that the worker maintaining a skewed group key receives one
+    /// update per worker and batch, instead of one update per input record.
+    pub pre_aggregate: bool,
 }
 
+/// The smallest expected group size for which accumulable aggregations are
+/// pre-aggregated.
+const PRE_AGGREGATION_MIN_GROUP_SIZE: usize = 1024;
+
 impl RustType<proto_accumulable_plan::ProtoAggr> for (usize, usize, AggregateExpr) {
     fn into_proto(&self) -> proto_accumulable_plan::ProtoAggr {
         proto_accumulable_plan::ProtoAggr {
@@ -282,6 +293,7 @@ impl RustType<ProtoAccumulablePlan> for AccumulablePlan {
             full_aggrs: self.full_aggrs.into_proto(),
             simple_aggrs: self.simple_aggrs.into_proto(),
             distinct_aggrs: self.distinct_aggrs.into_proto(),
+            pre_aggregate: self.pre_aggregate,
         }
     }
 
@@ -290,6 +302,7 @@ impl RustType<ProtoAccumulablePlan> for AccumulablePlan {
             full_aggrs: proto.full_aggrs.into_rust()?,
             simple_aggrs: proto.simple_aggrs.into_rust()?,
             distinct_aggrs: proto.distinct_aggrs.into_rust()?,
+            pre_aggregate: proto.pre_aggregate,
         })
     }
 }
@@ -641,10 +654,16 @@ impl ReducePlan {
                         simple_aggrs.push((accumulable_index, datum_index, aggr));
                     };
                 }
+                // Groups that are expected to be large are likely to be skewed, so
+                // we pre-aggregate them on each worker to spread their updates
+                // across workers.
+                let pre_aggregate = expected_group_size
+                    .map_or(false, |size| size >= PRE_AGGREGATION_MIN_GROUP_SIZE);
                 ReducePlan::Accumulable(AccumulablePlan {
                     full_aggrs,
                     simple_aggrs,
                     distinct_aggrs,
+                    pre_aggregate,
                 })
             }
             ReductionType::Hierarchical => {
@@ -887,7 +906,9 @@ fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::DenseRank { .. }
         | AggregateFunc::LagLead { .. }
         | AggregateFunc::FirstValue { .. }
-        | AggregateFunc::LastValue { .. } => ReductionType::Basic,
+        | AggregateFunc::LastValue { .. }
+        | AggregateFunc::SessionWindows
+        | AggregateFunc::BucketDeltas => ReductionType::Basic,
     }
 }
 
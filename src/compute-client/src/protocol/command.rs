@@ -10,7 +10,9 @@
 //! Compute protocol commands.
 
 use std::collections::BTreeSet;
+use std::fmt;
 use std::num::NonZeroI64;
+use std::str::FromStr;
 
 use proptest::prelude::{any, Arbitrary};
 use proptest::strategy::{BoxedStrategy, Strategy, Union};
@@ -27,7 +29,7 @@ use mz_storage_client::client::ProtoAllowCompaction;
 use mz_storage_client::controller::CollectionMetadata;
 use mz_storage_client::types::parameters::PersistParameters;
 
-use crate::logging::LoggingConfig;
+use crate::logging::{LogFamily, LoggingConfig};
 use crate::types::dataflows::DataflowDescription;
 
 include!(concat!(
@@ -483,6 +485,25 @@ pub struct ComputeParameters {
     pub max_result_size: Option<u32>,
     /// Persist client configuration.
     pub persist: PersistParameters,
+    /// The number of records whose arrangements a worker releases for merging
+    /// in each step, or zero to release all arrangements in each step.
+    ///
+    /// Workers cycle through their arrangements, so that the merge work of
+    /// large numbers of arrangements is spread over multiple steps. The merge
+    /// work of an arrangement is proportional to its size, so each arrangement
+    /// uses up as much of the budget as it holds records.
+    pub arrangement_maintenance_budget: Option<usize>,
+    /// Whether workers currently defer the merging of large arrangements.
+    pub arrangement_compaction_schedule: Option<CompactionSchedule>,
+    /// The log families whose events replicas drop.
+    ///
+    /// Unlike the other parameters, this one is not applied by replicas upon receiving it, but by
+    /// the controller in the [`LoggingConfig`] of replicas that are created afterwards.
+    pub disabled_log_families: Option<BTreeSet<LogFamily>>,
+    /// Log only one in this many operator schedulings.
+    ///
+    /// Like `disabled_log_families`, this is applied to replicas created afterwards.
+    pub schedule_sample_rate: Option<u32>,
 }
 
 impl ComputeParameters {
@@ -491,12 +512,29 @@ impl ComputeParameters {
         if let Some(v) = other.max_result_size {
             self.max_result_size = Some(v);
         }
+        if let Some(v) = other.arrangement_maintenance_budget {
+            self.arrangement_maintenance_budget = Some(v);
+        }
+        if let Some(v) = other.arrangement_compaction_schedule {
+            self.arrangement_compaction_schedule = Some(v);
+        }
+        if let Some(v) = other.disabled_log_families {
+            self.disabled_log_families = Some(v);
+        }
+        if let Some(v) = other.schedule_sample_rate {
+            self.schedule_sample_rate = Some(v);
+        }
         self.persist.update(other.persist);
     }
 
     /// Return whether all parameters are unset.
     pub fn all_unset(&self) -> bool {
-        self.max_result_size.is_none() && self.persist.all_unset()
+        self.max_result_size.is_none()
+            && self.arrangement_maintenance_budget.is_none()
+            && self.arrangement_compaction_schedule.is_none()
+            && self.disabled_log_families.is_none()
+            && self.schedule_sample_rate.is_none()
+            && self.persist.all_unset()
     }
 }
 
@@ -505,6 +543,10 @@ impl RustType<ProtoComputeParameters> for ComputeParameters {
         ProtoComputeParameters {
             max_result_size: self.max_result_size.into_proto(),
             persist: Some(self.persist.into_proto()),
+            arrangement_maintenance_budget: self.arrangement_maintenance_budget.into_proto(),
+            arrangement_compaction_schedule: self.arrangement_compaction_schedule.into_proto(),
+            disabled_log_families: self.disabled_log_families.into_proto(),
+            schedule_sample_rate: self.schedule_sample_rate,
         }
     }
 
@@ -514,10 +556,134 @@ impl RustType<ProtoComputeParameters> for ComputeParameters {
             persist: proto
                 .persist
                 .into_rust_if_some("ProtoComputeParameters::persist")?,
+            arrangement_maintenance_budget: proto.arrangement_maintenance_budget.into_rust()?,
+            arrangement_compaction_schedule: proto.arrangement_compaction_schedule.into_rust()?,
+            disabled_log_families: proto.disabled_log_families.into_rust()?,
+            schedule_sample_rate: proto.schedule_sample_rate,
+        })
+    }
+}
+
+/// A schedule for the merging of arrangement batches.
+///
+/// Merging the batches of large arrangements is expensive, and delays the
+/// processing of other work on the same workers. Deferring it to an off-peak
+/// window trades memory, for the batches that wait to be merged, for more
+/// predictable latencies outside of the window. The controller decides when
+/// the window starts and ends, and updates the schedule of the replicas
+/// accordingly.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Arbitrary)]
+pub enum CompactionSchedule {
+    /// Release new batches for merging as soon as they are sealed.
+    Always,
+    /// Do not release the new batches of arrangements holding at least
+    /// `min_records` records on a worker. Smaller arrangements are merged as
+    /// soon as their batches are sealed.
+    Deferred { min_records: u64 },
+}
+
+impl CompactionSchedule {
+    /// Reports whether the batches of an arrangement holding `records` records
+    /// can be merged.
+    pub fn allows_merging(&self, records: u64) -> bool {
+        match self {
+            CompactionSchedule::Always => true,
+            CompactionSchedule::Deferred { min_records } => records < *min_records,
+        }
+    }
+}
+
+impl RustType<ProtoCompactionSchedule> for CompactionSchedule {
+    fn into_proto(&self) -> ProtoCompactionSchedule {
+        use proto_compaction_schedule::Kind::*;
+        use proto_compaction_schedule::*;
+        ProtoCompactionSchedule {
+            kind: Some(match self {
+                CompactionSchedule::Always => Always(()),
+                CompactionSchedule::Deferred { min_records } => Deferred(ProtoDeferred {
+                    min_records: *min_records,
+                }),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoCompactionSchedule) -> Result<Self, TryFromProtoError> {
+        use proto_compaction_schedule::Kind::*;
+        use proto_compaction_schedule::*;
+        match proto.kind {
+            Some(Always(())) => Ok(CompactionSchedule::Always),
+            Some(Deferred(ProtoDeferred { min_records })) => {
+                Ok(CompactionSchedule::Deferred { min_records })
+            }
+            None => Err(TryFromProtoError::missing_field(
+                "ProtoCompactionSchedule::kind",
+            )),
+        }
+    }
+}
+
+/// A daily window of time, in UTC.
+///
+/// Windows whose start is after their end wrap around midnight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactionWindow {
+    /// The minute of the day at which the window starts, inclusive.
+    pub start_minute: u32,
+    /// The minute of the day at which the window ends, exclusive.
+    pub end_minute: u32,
+}
+
+impl CompactionWindow {
+    /// Reports whether `minute`, a minute of the day, falls within the window.
+    pub fn contains(&self, minute: u32) -> bool {
+        if self.start_minute <= self.end_minute {
+            self.start_minute <= minute && minute < self.end_minute
+        } else {
+            self.start_minute <= minute || minute < self.end_minute
+        }
+    }
+
+    /// Reports whether `time`, in milliseconds since the Unix epoch, falls
+    /// within the window.
+    pub fn contains_time(&self, time: u64) -> bool {
+        let minute = time / (60 * 1000) % (24 * 60);
+        self.contains(u32::try_from(minute).expect("less than a day"))
+    }
+}
+
+impl FromStr for CompactionWindow {
+    type Err = String;
+
+    /// Parses a window of the form `HH:MM-HH:MM`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse_minute(s: &str) -> Option<u32> {
+            let (hours, minutes) = s.trim().split_once(':')?;
+            let (hours, minutes): (u32, u32) = (hours.parse().ok()?, minutes.parse().ok()?);
+            (hours < 24 && minutes < 60).then(|| hours * 60 + minutes)
+        }
+
+        let err = || format!("invalid compaction window {s:?}: expected HH:MM-HH:MM");
+        let (start, end) = s.split_once('-').ok_or_else(err)?;
+        Ok(CompactionWindow {
+            start_minute: parse_minute(start).ok_or_else(err)?,
+            end_minute: parse_minute(end).ok_or_else(err)?,
         })
     }
 }
 
+impl fmt::Display for CompactionWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}-{:02}:{:02}",
+            self.start_minute / 60,
+            self.start_minute % 60,
+            self.end_minute / 60,
+            self.end_minute % 60,
+        )
+    }
+}
+
 /// Peek at an arrangement.
 ///
 /// This request elicits data from the worker, by naming an
@@ -622,6 +788,13 @@ mod tests {
             assert_eq!(actual.unwrap(), expect);
         }
 
+        #[test]
+        fn compute_parameters_protobuf_roundtrip(expect in any::<ComputeParameters>() ) {
+            let actual = protobuf_roundtrip::<_, ProtoComputeParameters>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+
         #[test]
         fn compute_command_protobuf_roundtrip(expect in any::<ComputeCommand<mz_repr::Timestamp>>() ) {
             let actual = protobuf_roundtrip::<_, ProtoComputeCommand>(&expect);
@@ -629,4 +802,26 @@ mod tests {
             assert_eq!(actual.unwrap(), expect);
         }
     }
+
+    #[test]
+    fn compaction_window() {
+        let window: CompactionWindow = "22:30-04:00".parse().unwrap();
+        assert_eq!(window.to_string(), "22:30-04:00");
+        assert!(window.contains(23 * 60));
+        assert!(window.contains(0));
+        assert!(!window.contains(4 * 60));
+        assert!(!window.contains(12 * 60));
+
+        let window: CompactionWindow = "01:00-05:00".parse().unwrap();
+        assert!(window.contains(60));
+        assert!(!window.contains(5 * 60));
+        assert!(!window.contains(23 * 60));
+        assert!(window.contains_time(2 * 60 * 60 * 1000));
+        assert!(window.contains_time((24 + 2) * 60 * 60 * 1000));
+        assert!(!window.contains_time(6 * 60 * 60 * 1000));
+
+        for invalid in ["", "01:00", "24:00-01:00", "01:60-02:00", "1-2"] {
+            assert!(invalid.parse::<CompactionWindow>().is_err(), "{invalid}");
+        }
+    }
 }
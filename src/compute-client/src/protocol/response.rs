@@ -122,6 +122,18 @@ pub enum ComputeResponse<T = mz_repr::Timestamp> {
     /// [`AllowCompaction` command]: super::command::ComputeCommand::AllowCompaction
     /// [#16247]: https://github.com/MaterializeInc/materialize/issues/16247
     SubscribeResponse(GlobalId, SubscribeResponse<T>),
+
+    /// `ArrangementSizes` reports the number of updates held in the arrangements of the specified
+    /// compute collections. The response contains a mapping of collection IDs to their sizes.
+    ///
+    /// Sizes are reported periodically rather than after every change, so they are only
+    /// approximate. The controller uses them to estimate the resources that new dataflows reading
+    /// from these collections will require.
+    ///
+    /// Replicas may send `ArrangementSizes` responses for collections that are indexes. The
+    /// replica must not send `ArrangementSizes` responses for a collection that it has reported to
+    /// have advanced to the empty frontier.
+    ArrangementSizes(Vec<(GlobalId, u64)>),
 }
 
 impl RustType<ProtoComputeResponse> for ComputeResponse<mz_repr::Timestamp> {
@@ -144,6 +156,17 @@ impl RustType<ProtoComputeResponse> for ComputeResponse<mz_repr::Timestamp> {
                         resp: Some(resp.into_proto()),
                     })
                 }
+                ComputeResponse::ArrangementSizes(sizes) => {
+                    ArrangementSizes(ProtoArrangementSizesKind {
+                        sizes: sizes
+                            .iter()
+                            .map(|(id, size)| ProtoArrangementSize {
+                                id: Some(id.into_proto()),
+                                size: *size,
+                            })
+                            .collect(),
+                    })
+                }
             }),
         }
     }
@@ -165,6 +188,18 @@ impl RustType<ProtoComputeResponse> for ComputeResponse<mz_repr::Timestamp> {
                 resp.resp
                     .into_rust_if_some("ProtoSubscribeResponseKind::resp")?,
             )),
+            Some(ArrangementSizes(sizes)) => Ok(ComputeResponse::ArrangementSizes(
+                sizes
+                    .sizes
+                    .into_iter()
+                    .map(|size| {
+                        Ok((
+                            size.id.into_rust_if_some("ProtoArrangementSize::id")?,
+                            size.size,
+                        ))
+                    })
+                    .collect::<Result<_, TryFromProtoError>>()?,
+            )),
             None => Err(TryFromProtoError::missing_field(
                 "ProtoComputeResponse::kind",
             )),
@@ -189,6 +224,9 @@ impl Arbitrary for ComputeResponse<mz_repr::Timestamp> {
             (any::<GlobalId>(), any::<SubscribeResponse>())
                 .prop_map(|(id, resp)| ComputeResponse::SubscribeResponse(id, resp))
                 .boxed(),
+            proptest::collection::vec((any::<GlobalId>(), any::<u64>()), 1..4)
+                .prop_map(ComputeResponse::ArrangementSizes)
+                .boxed(),
         ])
     }
 }
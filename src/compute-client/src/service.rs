@@ -111,6 +111,8 @@ pub struct PartitionedComputeState<T> {
     /// subscribe is permanently borked.
     pending_subscribes:
         HashMap<GlobalId, Option<(MutableAntichain<T>, Result<Vec<(T, Row, Diff)>, String>)>>,
+    /// Arrangement sizes for indexes, as last reported by each individual partition.
+    arrangement_sizes: HashMap<GlobalId, Vec<u64>>,
 }
 
 impl<T> Partitionable<ComputeCommand<T>, ComputeResponse<T>>
@@ -126,6 +128,7 @@ where
             uppers: HashMap::new(),
             peek_responses: HashMap::new(),
             pending_subscribes: HashMap::new(),
+            arrangement_sizes: HashMap::new(),
         }
     }
 }
@@ -140,10 +143,12 @@ where
             uppers,
             peek_responses,
             pending_subscribes,
+            arrangement_sizes,
         } = self;
         uppers.clear();
         peek_responses.clear();
         pending_subscribes.clear();
+        arrangement_sizes.clear();
     }
 
     /// Observes commands that move past, and prepares state for responses.
@@ -178,6 +183,7 @@ where
             previous.is_some(),
             "ceasing frontier tracking for absent identifier {id}",
         );
+        self.arrangement_sizes.remove(&id);
     }
 }
 
@@ -249,6 +255,20 @@ where
                     Some(Ok(ComputeResponse::FrontierUppers(new_uppers)))
                 }
             }
+            ComputeResponse::ArrangementSizes(list) => {
+                // The partitions each hold a disjoint part of every arrangement, so the size of an
+                // arrangement is the sum of the sizes last reported by each partition.
+                let mut new_sizes = Vec::new();
+                for (id, shard_size) in list {
+                    let shard_sizes = self
+                        .arrangement_sizes
+                        .entry(id)
+                        .or_insert_with(|| vec![0; self.parts]);
+                    shard_sizes[shard_id] = shard_size;
+                    new_sizes.push((id, shard_sizes.iter().sum()));
+                }
+                Some(Ok(ComputeResponse::ArrangementSizes(new_sizes)))
+            }
             ComputeResponse::PeekResponse(uuid, response, otel_ctx) => {
                 // Incorporate new peek responses; awaiting all responses.
                 let entry = self
@@ -9,13 +9,16 @@
 
 //! Types for describing dataflow sinks.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use timely::progress::Antichain;
 
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
-use mz_repr::{GlobalId, RelationDesc};
+use mz_repr::{Datum, GlobalId, RelationDesc};
 use mz_storage_client::controller::CollectionMetadata;
 
 include!(concat!(
@@ -118,7 +121,9 @@ impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMe
         use proto_compute_sink_connection::Kind;
         ProtoComputeSinkConnection {
             kind: Some(match self {
-                ComputeSinkConnection::Subscribe(_) => Kind::Subscribe(()),
+                ComputeSinkConnection::Subscribe(subscribe) => {
+                    Kind::Subscribe(subscribe.into_proto())
+                }
                 ComputeSinkConnection::Persist(persist) => Kind::Persist(persist.into_proto()),
             }),
         }
@@ -130,14 +135,75 @@ impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMe
             .kind
             .ok_or_else(|| TryFromProtoError::missing_field("ProtoComputeSinkConnection::kind"))?;
         Ok(match kind {
-            Kind::Subscribe(_) => ComputeSinkConnection::Subscribe(SubscribeSinkConnection {}),
+            Kind::Subscribe(subscribe) => ComputeSinkConnection::Subscribe(subscribe.into_rust()?),
             Kind::Persist(persist) => ComputeSinkConnection::Persist(persist.into_rust()?),
         })
     }
 }
 
 #[derive(Arbitrary, Default, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
-pub struct SubscribeSinkConnection {}
+pub struct SubscribeSinkConnection {
+    /// If set, only the updates of the given partition are emitted.
+    pub partition: Option<SubscribePartition>,
+}
+
+impl RustType<ProtoSubscribeSinkConnection> for SubscribeSinkConnection {
+    fn into_proto(&self) -> ProtoSubscribeSinkConnection {
+        ProtoSubscribeSinkConnection {
+            partition: self.partition.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoSubscribeSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(SubscribeSinkConnection {
+            partition: proto.partition.into_rust()?,
+        })
+    }
+}
+
+/// A partition of the updates of a subscribe, by the hash of some of their
+/// columns.
+///
+/// Subscribes that agree on `key` and `count` and have distinct `index`es
+/// receive disjoint sets of updates, and together receive all updates.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SubscribePartition {
+    /// The columns whose values determine the partition of an update.
+    pub key: Vec<usize>,
+    /// The number of partitions.
+    pub count: u64,
+    /// The partition to emit, in `0..count`.
+    pub index: u64,
+}
+
+impl SubscribePartition {
+    /// Reports whether a row with the given datums belongs to this partition.
+    pub fn contains(&self, datums: &[Datum]) -> bool {
+        let mut hasher = DefaultHasher::new();
+        for i in &self.key {
+            datums[*i].hash(&mut hasher);
+        }
+        hasher.finish() % self.count == self.index
+    }
+}
+
+impl RustType<ProtoSubscribePartition> for SubscribePartition {
+    fn into_proto(&self) -> ProtoSubscribePartition {
+        ProtoSubscribePartition {
+            key: self.key.into_proto(),
+            count: self.count,
+            index: self.index,
+        }
+    }
+
+    fn from_proto(proto: ProtoSubscribePartition) -> Result<Self, TryFromProtoError> {
+        Ok(SubscribePartition {
+            key: proto.key.into_rust()?,
+            count: proto.count,
+            index: proto.index,
+        })
+    }
+}
 
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PersistSinkConnection<S> {
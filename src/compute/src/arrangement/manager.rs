@@ -10,7 +10,8 @@
 //! Management of arrangements across dataflows.
 
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
 use std::rc::Rc;
 use std::time::Instant;
 
@@ -19,6 +20,8 @@ use differential_dataflow::trace::TraceReader;
 use prometheus::core::{AtomicF64, AtomicU64};
 use timely::progress::frontier::{Antichain, AntichainRef};
 
+use mz_compute_client::protocol::command::CompactionSchedule;
+use mz_ore::cast::CastFrom;
 use mz_ore::metric;
 use mz_ore::metrics::{
     CounterVec, CounterVecExt, DeleteOnDropCounter, DeleteOnDropGauge, GaugeVecExt,
@@ -86,7 +89,7 @@ impl MaintenanceMetrics {
 /// A `TraceManager` stores maps from global identifiers to the primary arranged
 /// representation of that collection.
 pub struct TraceManager {
-    pub(crate) traces: HashMap<GlobalId, TraceBundle>,
+    pub(crate) traces: BTreeMap<GlobalId, TraceBundle>,
     worker_id: usize,
     maintenance_metrics: HashMap<GlobalId, MaintenanceMetrics>,
     /// 1 if this worker is currently doing maintenance.
@@ -96,6 +99,13 @@ pub struct TraceManager {
     /// maintenance completes
     doing_maintenance: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
     metrics: TraceMetrics,
+    /// The number of records whose traces to maintain in each call to `maintenance`, or zero
+    /// to maintain all traces.
+    maintenance_budget: u64,
+    /// The last trace that `maintenance` maintained, after which the next call starts.
+    maintenance_cursor: Option<GlobalId>,
+    /// When to enable the physical merging of batches.
+    compaction_schedule: CompactionSchedule,
 }
 
 impl TraceManager {
@@ -103,11 +113,14 @@ impl TraceManager {
     pub fn new(metrics: TraceMetrics, worker_id: usize) -> Self {
         let doing_maintenance = metrics.maintenance_flag_metric(worker_id);
         TraceManager {
-            traces: HashMap::new(),
+            traces: BTreeMap::new(),
             worker_id,
             metrics,
             maintenance_metrics: HashMap::new(),
             doing_maintenance,
+            maintenance_budget: 0,
+            maintenance_cursor: None,
+            compaction_schedule: CompactionSchedule::Always,
         }
     }
 
@@ -118,12 +131,46 @@ impl TraceManager {
     /// will not be physically merged until the method is called again. This is mostly due to limitations
     /// of differential dataflow, which requires users to perform this explicitly; if that changes we may
     /// be able to remove this code.
-    pub fn maintenance(&mut self) {
+    ///
+    /// Each call maintains traces in the order of their identifiers, resuming after the last trace
+    /// that the previous call maintained, until it has maintained traces holding
+    /// `maintenance_budget` records, as the merge work that maintenance enables is proportional to
+    /// the size of a trace. Each call maintains at least one trace, so that traces larger than the
+    /// budget are maintained as well. Traces whose merging the `compaction_schedule` does not
+    /// currently allow are skipped. `sizes` are the last known numbers of records in the traces.
+    pub fn maintenance(&mut self, sizes: &HashMap<GlobalId, u64>) {
+        let mut fuel = match self.maintenance_budget {
+            0 => u64::MAX,
+            budget => budget,
+        };
+
         let mut antichain = Antichain::new();
-        for (arrangement_id, bundle) in self.traces.iter_mut() {
+        // Visit each trace at most once per call.
+        let mut remaining = self.traces.len();
+        while fuel > 0 && remaining > 0 {
+            remaining -= 1;
+            // The trace after the cursor, wrapping around to the first trace.
+            let arrangement_id = self
+                .maintenance_cursor
+                .and_then(|cursor| {
+                    self.traces
+                        .range((Bound::Excluded(cursor), Bound::Unbounded))
+                        .next()
+                })
+                .or_else(|| self.traces.iter().next())
+                .map(|(id, _)| *id)
+                .expect("traces not empty");
+            let records = sizes.get(&arrangement_id).copied().unwrap_or(0);
+            self.maintenance_cursor = Some(arrangement_id);
+            if !self.compaction_schedule.allows_merging(records) {
+                continue;
+            }
+            fuel = fuel.saturating_sub(records.max(1));
+
+            let bundle = self.traces.get_mut(&arrangement_id).expect("trace exists");
             // Update maintenance metrics
             // Entry is guaranteed to exist as it gets created when we initialize the partition.
-            let maintenance_metrics = self.maintenance_metrics.get_mut(arrangement_id).unwrap();
+            let maintenance_metrics = self.maintenance_metrics.get_mut(&arrangement_id).unwrap();
 
             // signal that maintenance is happening
             self.doing_maintenance.set(1);
@@ -142,6 +189,17 @@ impl TraceManager {
         }
     }
 
+    /// Sets the number of records whose traces to maintain in each call to `maintenance`, or
+    /// zero to maintain all traces in each call.
+    pub fn set_maintenance_budget(&mut self, budget: usize) {
+        self.maintenance_budget = u64::cast_from(budget);
+    }
+
+    /// Sets the schedule for the physical merging of batches.
+    pub fn set_compaction_schedule(&mut self, schedule: CompactionSchedule) {
+        self.compaction_schedule = schedule;
+    }
+
     /// Enables compaction of traces associated with the identifier.
     ///
     /// Compaction may not occur immediately, but once this method is called the
@@ -16,7 +16,7 @@ use std::time::{Duration, Instant};
 
 use bytesize::ByteSize;
 use differential_dataflow::operators::arrange::arrangement::Arrange;
-use differential_dataflow::trace::TraceReader;
+use differential_dataflow::trace::{BatchReader, TraceReader};
 use differential_dataflow::Collection;
 use timely::communication::Allocate;
 use timely::logging::Logger;
@@ -28,7 +28,7 @@ use tokio::sync::{mpsc, Mutex};
 use tracing::{error, info, span, Level};
 use uuid::Uuid;
 
-use mz_compute_client::logging::LoggingConfig;
+use mz_compute_client::logging::{LogFamily, LoggingConfig};
 use mz_compute_client::plan::Plan;
 use mz_compute_client::protocol::command::{ComputeCommand, ComputeParameters, Peek};
 use mz_compute_client::protocol::history::ComputeCommandHistory;
@@ -49,6 +49,9 @@ use crate::logging;
 use crate::logging::compute::ComputeEvent;
 use crate::metrics::ComputeMetrics;
 
+/// The minimum amount of time between two reports of arrangement sizes.
+const ARRANGEMENT_SIZE_REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Worker-local state that is maintained across dataflows.
 ///
 /// This state is restricted to the COMPUTE state, the deterministic, idempotent work
@@ -77,6 +80,10 @@ pub struct ComputeState {
     pub pending_peeks: HashMap<Uuid, PendingPeek>,
     /// Tracks the frontier information that has been sent over `response_tx`.
     pub reported_frontiers: HashMap<GlobalId, Antichain<Timestamp>>,
+    /// Tracks the arrangement sizes that have been sent over `response_tx`.
+    pub reported_arrangement_sizes: HashMap<GlobalId, u64>,
+    /// The time at which arrangement sizes were last reported.
+    pub last_arrangement_size_report: Instant,
     /// Collections that were recently dropped and whose removal needs to be reported.
     pub dropped_collections: Vec<GlobalId>,
     /// The logger, from Timely's logging framework, if logs are enabled.
@@ -160,9 +167,20 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
         if let Some(v) = params.max_result_size {
             self.compute_state.max_result_size = v;
         }
+        if let Some(v) = params.arrangement_maintenance_budget {
+            self.compute_state.traces.set_maintenance_budget(v);
+        }
+        if let Some(v) = params.arrangement_compaction_schedule {
+            self.compute_state.traces.set_compaction_schedule(v);
+        }
 
         // TODO(#16753): apply config to `self.compute_state.persist_clients`
         let _ = params.persist;
+
+        // The logging parameters are applied by the controller to the `LoggingConfig` of replicas
+        // that it creates, since the loggers cannot be changed after initialization.
+        let _ = params.disabled_log_families;
+        let _ = params.schedule_sample_rate;
     }
 
     fn handle_create_dataflows(
@@ -182,6 +200,9 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
                 .map(|(idx_id, (idx, _))| (*idx_id, idx.on_id));
             let exported_ids = index_ids.chain(sink_ids);
 
+            // The dataflow about to be built will be assigned the next dataflow index.
+            let dataflow_index = self.timely_worker.next_dataflow_index();
+
             // Initialize frontiers for each object, and optionally log their construction.
             for (object_id, collection_id) in exported_ids {
                 if let Some(frontier) = self.compute_state.reported_frontiers.insert(
@@ -195,7 +216,10 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
 
                 // Log dataflow construction, frontier construction, and any dependencies.
                 if let Some(logger) = self.compute_state.compute_logger.as_mut() {
-                    logger.log(ComputeEvent::Dataflow(object_id, true));
+                    logger.log(ComputeEvent::Export {
+                        id: object_id,
+                        dataflow_index,
+                    });
                     logger.log(ComputeEvent::Frontier(
                         object_id,
                         timely::progress::Timestamp::minimum(),
@@ -236,7 +260,7 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
                     .remove(&id)
                     .expect("Dropped compute collection with no frontier");
                 if let Some(logger) = self.compute_state.compute_logger.as_mut() {
-                    logger.log(ComputeEvent::Dataflow(id, false));
+                    logger.log(ComputeEvent::ExportDropped { id });
                     if let Some(time) = prev_frontier.get(0) {
                         logger.log(ComputeEvent::Frontier(id, *time, -1));
                     }
@@ -352,6 +376,13 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
         let mut d_traces = HashMap::new();
         let mut c_traces = HashMap::new();
 
+        // Loggers of disabled families drop their events, but still publish (empty) batches, so
+        // that the logging dataflows, which are installed regardless, make progress.
+        let t_enabled = !logging.disabled_families.contains(&LogFamily::Timely);
+        let r_enabled = !logging.disabled_families.contains(&LogFamily::Reachability);
+        let d_enabled = !logging.disabled_families.contains(&LogFamily::Differential);
+        let c_enabled = !logging.disabled_families.contains(&LogFamily::Compute);
+
         let activate_after = 128;
 
         let t_activator = RcActivator::new("t_activator".into(), activate_after);
@@ -400,6 +431,9 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
                 start_offset,
                 self.timely_worker.index(),
                 move |time, data| {
+                    if !t_enabled {
+                        data.clear();
+                    }
                     t_logger.publish_batch(time, data);
                     activator.activate();
                 },
@@ -414,6 +448,9 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
                 start_offset,
                 self.timely_worker.index(),
                 move |time, data: &mut Vec<(Duration, usize, TrackerEvent)>| {
+                    if !r_enabled {
+                        data.clear();
+                    }
                     let mut converted_updates = Vec::new();
                     for event in data.drain(..) {
                         match event.2 {
@@ -465,6 +502,9 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
                 start_offset,
                 self.timely_worker.index(),
                 move |time, data| {
+                    if !d_enabled {
+                        data.clear();
+                    }
                     d_logger.publish_batch(time, data);
                     activator.activate();
                 },
@@ -479,6 +519,9 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
                 start_offset,
                 self.timely_worker.index(),
                 move |time, data| {
+                    if !c_enabled {
+                        data.clear();
+                    }
                     c_logger.publish_batch(time, data);
                     activator.activate();
                 },
@@ -639,6 +682,40 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
         }
     }
 
+    /// Send arrangement size information to the coordinator.
+    ///
+    /// Determining the size of an arrangement requires visiting all of its batches, so sizes are
+    /// reported at most once per [`ARRANGEMENT_SIZE_REPORT_INTERVAL`].
+    pub fn report_arrangement_sizes(&mut self) {
+        let compute_state = &mut *self.compute_state;
+        if compute_state.last_arrangement_size_report.elapsed() < ARRANGEMENT_SIZE_REPORT_INTERVAL {
+            return;
+        }
+        compute_state.last_arrangement_size_report = Instant::now();
+
+        let traces = &compute_state.traces.traces;
+        compute_state
+            .reported_arrangement_sizes
+            .retain(|id, _| traces.contains_key(id));
+
+        let mut new_sizes = Vec::new();
+        for (id, bundle) in compute_state.traces.traces.iter_mut() {
+            let mut size = 0;
+            bundle
+                .oks_mut()
+                .map_batches(|batch| size += u64::cast_from(batch.len()));
+            let reported = compute_state.reported_arrangement_sizes.get(id);
+            if reported != Some(&size) {
+                compute_state.reported_arrangement_sizes.insert(*id, size);
+                new_sizes.push((*id, size));
+            }
+        }
+
+        if !new_sizes.is_empty() {
+            self.send_compute_response(ComputeResponse::ArrangementSizes(new_sizes));
+        }
+    }
+
     /// Report dropped collections to the controller.
     pub fn report_dropped_collections(&mut self) {
         let dropped_collections = std::mem::take(&mut self.compute_state.dropped_collections);
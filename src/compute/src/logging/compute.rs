@@ -27,7 +27,7 @@ use uuid::Uuid;
 
 use mz_expr::{permutation_for_arrangement, MirScalarExpr};
 use mz_ore::cast::CastFrom;
-use mz_repr::{Datum, DatumVec, GlobalId, Row, Timestamp};
+use mz_repr::{Datum, DatumVec, Diff, GlobalId, Row, Timestamp};
 use mz_timely_util::activator::RcActivator;
 use mz_timely_util::replay::MzReplay;
 
@@ -42,8 +42,18 @@ pub type Logger = timely::logging_core::Logger<ComputeEvent, WorkerIdentifier>;
 /// A logged compute event.
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub enum ComputeEvent {
-    /// Dataflow command, true for create and false for drop.
-    Dataflow(GlobalId, bool),
+    /// A compute collection was exported by a dataflow.
+    Export {
+        /// Globally unique identifier for the exported collection.
+        id: GlobalId,
+        /// The Timely index of the dataflow exporting the collection.
+        dataflow_index: usize,
+    },
+    /// A compute collection was dropped.
+    ExportDropped {
+        /// Globally unique identifier for the dropped collection.
+        id: GlobalId,
+    },
     /// Dataflow depends on a named source of data.
     DataflowDependency {
         /// Globally unique identifier for the dataflow.
@@ -57,8 +67,20 @@ pub enum ComputeEvent {
     Frontier(GlobalId, Timestamp, i64),
     // Available frontier information for source instantiations.
     SourceFrontier(GlobalId, GlobalId, Timestamp, i8),
+    /// A batch of updates passed into or out of the dataflow maintaining an export.
+    Updates {
+        /// Globally unique identifier for the export.
+        export: GlobalId,
+        /// True for updates to the inputs of the dataflow, false for updates to the export.
+        input: bool,
+        /// The number of updates in the batch.
+        count: usize,
+    },
 }
 
+/// The length of the window over which `ComputeEvent::Updates` are counted.
+const UPDATE_COUNT_WINDOW: Duration = Duration::from_secs(60);
+
 /// A logged peek event.
 #[derive(
     Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -119,6 +141,7 @@ pub fn construct<A: Allocate>(
         let (mut frontier_delay_out, frontier_delay) = demux.new_output();
         let (mut peek_out, peek) = demux.new_output();
         let (mut peek_duration_out, peek_duration) = demux.new_output();
+        let (mut updates_out, updates) = demux.new_output();
 
         let mut demux_buffer = Vec::new();
         demux.build(move |_capability| {
@@ -136,6 +159,7 @@ pub fn construct<A: Allocate>(
                 let mut frontier_delay = frontier_delay_out.activate();
                 let mut peek = peek_out.activate();
                 let mut peek_duration = peek_duration_out.activate();
+                let mut updates = updates_out.activate();
 
                 input.for_each(|time, data| {
                     data.swap(&mut demux_buffer);
@@ -147,6 +171,7 @@ pub fn construct<A: Allocate>(
                     let mut frontier_delay_session = frontier_delay.session(&time);
                     let mut peek_session = peek.session(&time);
                     let mut peek_duration_session = peek_duration.session(&time);
+                    let mut updates_session = updates.session(&time);
 
                     for (time, worker, datum) in demux_buffer.drain(..) {
                         let time_ms = (((time.as_millis() / interval_ms) + 1) * interval_ms)
@@ -154,47 +179,50 @@ pub fn construct<A: Allocate>(
                             .expect("must fit");
 
                         match datum {
-                            ComputeEvent::Dataflow(id, is_create) => {
-                                let diff = if is_create { 1 } else { -1 };
-                                dataflow_session.give(((id, worker), time_ms, diff));
+                            ComputeEvent::Export { id, dataflow_index } => {
+                                dataflow_session.give(((id, worker, dataflow_index), time_ms, 1));
 
                                 // For now we know that these always happen in
                                 // the correct order, but it may be necessary
                                 // down the line to have dataflows keep a
                                 // reference to their own sources and a logger
                                 // that is called on them in a `with_drop` handler
-                                if is_create {
-                                    active_dataflows.insert((id, worker), vec![]);
-                                } else {
-                                    let key = &(id, worker);
-                                    match active_dataflows.remove(key) {
-                                        Some(sources) => {
-                                            for (source, worker) in sources {
-                                                let n = key.0;
-                                                dependency_session.give((
-                                                    (n, source, worker),
-                                                    time_ms,
-                                                    -1,
-                                                ));
-                                            }
+                                active_dataflows.insert((id, worker), (dataflow_index, vec![]));
+                            }
+                            ComputeEvent::ExportDropped { id } => {
+                                let key = &(id, worker);
+                                match active_dataflows.remove(key) {
+                                    Some((dataflow_index, sources)) => {
+                                        dataflow_session.give((
+                                            (id, worker, dataflow_index),
+                                            time_ms,
+                                            -1,
+                                        ));
+                                        for (source, worker) in sources {
+                                            let n = key.0;
+                                            dependency_session.give((
+                                                (n, source, worker),
+                                                time_ms,
+                                                -1,
+                                            ));
                                         }
-                                        None => error!(
-                                            "no active dataflow exists at time of drop. \
-                                             name={} worker={}",
-                                            key.0, worker
-                                        ),
                                     }
-                                    // dataflow may or may not be associated to a storage
-                                    // source instantiation. Report removal if so.
-                                    if let Some(source_map) = storage_sources.remove(key) {
-                                        for (source_id, (_, delay_map)) in source_map {
-                                            for (delay_ns, delay_count) in delay_map {
-                                                frontier_delay_session.give((
-                                                    (id, source_id, worker, delay_ns),
-                                                    time_ms,
-                                                    -delay_count,
-                                                ));
-                                            }
+                                    None => error!(
+                                        "no active dataflow exists at time of drop. \
+                                         name={} worker={}",
+                                        key.0, worker
+                                    ),
+                                }
+                                // dataflow may or may not be associated to a storage
+                                // source instantiation. Report removal if so.
+                                if let Some(source_map) = storage_sources.remove(key) {
+                                    for (source_id, (_, delay_map)) in source_map {
+                                        for (delay_ns, delay_count) in delay_map {
+                                            frontier_delay_session.give((
+                                                (id, source_id, worker, delay_ns),
+                                                time_ms,
+                                                -delay_count,
+                                            ));
                                         }
                                     }
                                 }
@@ -203,7 +231,7 @@ pub fn construct<A: Allocate>(
                                 dependency_session.give(((dataflow, source, worker), time_ms, 1));
                                 let key = (dataflow, worker);
                                 match active_dataflows.get_mut(&key) {
-                                    Some(existing_sources) => {
+                                    Some((_, existing_sources)) => {
                                         existing_sources.push((source, worker))
                                     }
                                     None => error!(
@@ -316,6 +344,26 @@ pub fn construct<A: Allocate>(
                                     }
                                 }
                             }
+                            ComputeEvent::Updates {
+                                export,
+                                input,
+                                count,
+                            } => {
+                                // Each batch of updates is counted for the duration of
+                                // `UPDATE_COUNT_WINDOW`, so that the accumulated counts
+                                // reflect the recent rate of updates.
+                                let window_end_ms =
+                                    (((time + UPDATE_COUNT_WINDOW).as_millis() / interval_ms) + 1)
+                                        * interval_ms;
+                                let window_end_ms = window_end_ms.try_into().expect("must fit");
+                                let count = Diff::try_from(count).expect("must fit");
+                                updates_session.give(((export, worker, input), time_ms, count));
+                                updates_session.give((
+                                    (export, worker, input),
+                                    window_end_ms,
+                                    -count,
+                                ));
+                            }
                         }
                     }
                 });
@@ -323,10 +371,11 @@ pub fn construct<A: Allocate>(
         });
 
         let dataflow_current = dataflow.as_collection().map({
-            move |(name, worker)| {
+            move |(name, worker, dataflow_index)| {
                 Row::pack_slice(&[
                     Datum::String(&name.to_string()),
                     Datum::UInt64(u64::cast_from(worker)),
+                    Datum::UInt64(u64::cast_from(dataflow_index)),
                 ])
             }
         });
@@ -387,6 +436,16 @@ pub fn construct<A: Allocate>(
                 }
             });
 
+        let updates_current = updates.as_collection().map({
+            move |(export, worker, input)| {
+                Row::pack_slice(&[
+                    Datum::String(&export.to_string()),
+                    Datum::UInt64(u64::cast_from(worker)),
+                    Datum::String(if input { "input" } else { "output" }),
+                ])
+            }
+        });
+
         let logs = vec![
             (
                 LogVariant::Compute(ComputeLog::DataflowCurrent),
@@ -410,6 +469,10 @@ pub fn construct<A: Allocate>(
             ),
             (LogVariant::Compute(ComputeLog::PeekCurrent), peek_current),
             (LogVariant::Compute(ComputeLog::PeekDuration), peek_duration),
+            (
+                LogVariant::Compute(ComputeLog::RecentUpdates),
+                updates_current,
+            ),
         ];
 
         let mut result = std::collections::HashMap::new();
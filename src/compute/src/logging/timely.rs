@@ -52,6 +52,7 @@ pub fn construct<A: Allocate>(
     activator: RcActivator,
 ) -> HashMap<LogVariant, (KeysValsHandle, Rc<dyn Any>)> {
     let interval_ms = std::cmp::max(1, config.interval.as_millis());
+    let schedule_sample_rate = std::cmp::max(1, config.schedule_sample_rate);
     let peers = worker.peers();
 
     // A dataflow for multiple log-derived arrangements.
@@ -91,6 +92,8 @@ pub fn construct<A: Allocate>(
             let mut channels_data = HashMap::new();
             let mut parks_data = HashMap::new();
             let mut schedules_stash = HashMap::new();
+            // The number of schedulings of each operator since the last sampled one.
+            let mut schedules_counts: HashMap<_, u32> = HashMap::new();
             let mut messages_sent_data: HashMap<_, Vec<Diff>> = HashMap::new();
             let mut messages_received_data: HashMap<_, Vec<Diff>> = HashMap::new();
             let mut schedules_data: HashMap<_, Vec<(isize, Diff)>> = HashMap::new();
@@ -167,6 +170,7 @@ pub fn construct<A: Allocate>(
                                     );
 
                                     // Retract schedules information for the operator
+                                    schedules_counts.remove(&(event.id, worker));
                                     if let Some(schedules) =
                                         schedules_data.remove(&(event.id, worker))
                                     {
@@ -288,15 +292,29 @@ pub fn construct<A: Allocate>(
                                 let key = (event.id, worker);
                                 match event.start_stop {
                                     timely::logging::StartStop::Start => {
-                                        debug_assert!(!schedules_stash.contains_key(&key));
-                                        schedules_stash.insert(key, time_ns);
+                                        // Only one in `schedule_sample_rate` schedulings is
+                                        // recorded, and stands in for the others.
+                                        let count = schedules_counts.entry(key).or_insert(0);
+                                        let sampled = *count == 0;
+                                        *count = (*count + 1) % schedule_sample_rate;
+                                        if sampled {
+                                            debug_assert!(!schedules_stash.contains_key(&key));
+                                            schedules_stash.insert(key, time_ns);
+                                        }
                                     }
                                     timely::logging::StartStop::Stop => {
-                                        debug_assert!(schedules_stash.contains_key(&key));
-                                        let start = schedules_stash
-                                            .remove(&key)
-                                            .expect("start event absent");
+                                        let start = match schedules_stash.remove(&key) {
+                                            Some(start) => start,
+                                            None => {
+                                                debug_assert!(
+                                                    schedule_sample_rate > 1,
+                                                    "start event absent"
+                                                );
+                                                continue;
+                                            }
+                                        };
                                         let elapsed_ns = time_ns - start;
+                                        let weight = Diff::from(schedule_sample_rate);
 
                                         // Record count and elapsed for retraction
                                         // Note that we store the histogram for retraction with
@@ -308,14 +326,16 @@ pub fn construct<A: Allocate>(
                                             .or_insert_with(|| vec![(0, 0); 64])[usize::cast_from(
                                             elapsed_ns.next_power_of_two().trailing_zeros(),
                                         )];
-                                        *count += 1;
-                                        let elapsed_ns_diff = Diff::try_from(elapsed_ns).unwrap();
+                                        *count += isize::cast_from(schedule_sample_rate);
+                                        let elapsed_ns_diff =
+                                            Diff::try_from(elapsed_ns).unwrap() * weight;
                                         *duration += elapsed_ns_diff;
 
                                         schedules_duration_session
                                             .give(&cap, (key, time_ms, elapsed_ns_diff));
                                         let d = (event.id, worker, elapsed_ns.next_power_of_two());
-                                        schedules_histogram_session.give(&cap, (d, time_ms, 1));
+                                        schedules_histogram_session
+                                            .give(&cap, (d, time_ms, weight));
                                     }
                                 }
                             }
@@ -78,6 +78,8 @@ where
     pub debug_name: String,
     /// The Timely ID of the dataflow associated with this context.
     pub dataflow_id: usize,
+    /// The identifiers of the collections exported by the dataflow.
+    pub export_ids: Vec<GlobalId>,
     /// Indicates a frontier that can be used to compact input timestamps
     /// without affecting the results. We *should* apply it, to sources and
     /// imported traces, both because it improves performance, and because
@@ -110,6 +112,7 @@ where
             scope,
             debug_name: dataflow.debug_name.clone(),
             dataflow_id,
+            export_ids: dataflow.export_ids().collect(),
             as_of_frontier,
             until: dataflow.until.clone(),
             bindings: BTreeMap::new(),
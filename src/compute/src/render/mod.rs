@@ -105,10 +105,11 @@ use std::rc::Rc;
 use std::sync::Arc;
 
 use differential_dataflow::lattice::Lattice;
+use differential_dataflow::trace::BatchReader;
 use differential_dataflow::AsCollection;
 use timely::communication::Allocate;
 use timely::dataflow::operators::to_stream::ToStream;
-use timely::dataflow::operators::InspectCore;
+use timely::dataflow::operators::{Inspect, InspectCore};
 use timely::dataflow::scopes::Child;
 use timely::dataflow::{Scope, Stream};
 use timely::order::Product;
@@ -232,7 +233,8 @@ pub fn build_compute_dataflow<A: Allocate>(
                 // For the moment, assert that it is either trivial or `None`.
                 assert!(mfp.map(|x| x.is_identity()).unwrap_or(true));
 
-                // If logging is enabled, intercept frontier advancements coming from persist to track materialization lags.
+                // If logging is enabled, intercept frontier advancements coming from persist to track materialization lags,
+                // as well as the updates passed into the dataflow.
                 // Note that we do this here instead of in the server.rs worker loop since we want to catch the wall-clock
                 // time of the frontier advancement for each dataflow as early as possible.
                 if let Some(logger) = compute_state.compute_logger.clone() {
@@ -403,7 +405,8 @@ pub fn build_compute_dataflow<A: Allocate>(
 }
 
 // This helper function adds an operator to track source instantiation frontier advancements
-// in a dataflow. The tracking supports instrospection sources populated by compute logging.
+// and updates in a dataflow. The tracking supports instrospection sources populated by compute
+// logging.
 fn intercept_source_instantiation_frontiers<G>(
     source_instantiation: &Stream<G, (Row, mz_repr::Timestamp, Diff)>,
     logger: Logger,
@@ -414,8 +417,9 @@ where
     G: Scope<Timestamp = mz_repr::Timestamp>,
 {
     let mut previous_time = None;
-    source_instantiation.inspect_container(move |event| {
-        if let Err(frontier) = event {
+    source_instantiation.inspect_container(move |event| match event {
+        Ok((_time, data)) => log_updates(&logger, &dataflow_ids, true, data.len()),
+        Err(frontier) => {
             if let Some(previous) = previous_time {
                 for dataflow_id in dataflow_ids.iter() {
                     logger.log(ComputeEvent::SourceFrontier(
@@ -443,6 +447,19 @@ where
     })
 }
 
+/// Logs that `count` updates passed into or out of the dataflow maintaining `exports`.
+pub(crate) fn log_updates(logger: &Logger, exports: &[GlobalId], input: bool, count: usize) {
+    if count > 0 {
+        for export in exports {
+            logger.log(ComputeEvent::Updates {
+                export: *export,
+                input,
+                count,
+            });
+        }
+    }
+}
+
 // This implementation block allows child timestamps to vary from parent timestamps,
 // but requires the parent timestamp to be `repr::Timestamp`.
 impl<'g, G, T> Context<Child<'g, G, T>, Row>
@@ -476,6 +493,12 @@ where
                 self.as_of_frontier.clone(),
                 self.until.clone(),
             );
+            if let Some(logger) = compute_state.compute_logger.clone() {
+                let export_ids = self.export_ids.clone();
+                ok_arranged.stream.inspect(move |batch| {
+                    log_updates(&logger, &export_ids, true, batch.len());
+                });
+            }
             let ok_arranged = ok_arranged.enter(&self.scope);
             let err_arranged = err_arranged.enter(&self.scope);
             self.update_id(
@@ -544,6 +567,11 @@ where
         // Set up probes to notify on index frontier advancement.
         if let Some(arr) = &arrangement {
             let (collection, _) = arr.as_collection();
+            if let Some(logger) = compute_state.compute_logger.clone() {
+                collection.inspect_batch(move |_time, data| {
+                    log_updates(&logger, &[idx_id], false, data.len());
+                });
+            }
             let stream = collection.inner;
             for handle in probes.iter_mut() {
                 stream.probe_notify_with(handle);
@@ -616,6 +644,11 @@ where
         if let Some(arr) = &arrangement {
             let (collection, _) = arr.as_collection();
             let stream = collection.leave().inner;
+            if let Some(logger) = compute_state.compute_logger.clone() {
+                stream.inspect_batch(move |_time, data| {
+                    log_updates(&logger, &[idx_id], false, data.len());
+                });
+            }
             for handle in probes.iter_mut() {
                 stream.probe_notify_with(handle);
             }
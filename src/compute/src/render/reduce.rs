@@ -15,6 +15,7 @@ use std::collections::BTreeMap;
 
 use dec::OrderedDecimal;
 use differential_dataflow::collection::AsCollection;
+use differential_dataflow::consolidation::consolidate_updates;
 use differential_dataflow::difference::Multiply;
 use differential_dataflow::difference::Semigroup;
 use differential_dataflow::hashable::Hashable;
@@ -25,6 +26,8 @@ use differential_dataflow::operators::{Consolidate, Reduce, Threshold};
 use differential_dataflow::Collection;
 use mz_expr::MirScalarExpr;
 use serde::{Deserialize, Serialize};
+use timely::dataflow::channels::pact::Pipeline;
+use timely::dataflow::operators::Operator;
 use timely::dataflow::Scope;
 use timely::progress::{timestamp::Refines, Timestamp};
 use tracing::error;
@@ -996,6 +999,7 @@ fn build_accumulable<G>(
         full_aggrs,
         simple_aggrs,
         distinct_aggrs,
+        pre_aggregate,
     }: AccumulablePlan,
 ) -> Arrangement<G, Row>
 where
@@ -1267,6 +1271,32 @@ where
         differential_dataflow::collection::concatenate(&mut collection.scope(), to_aggregate)
     };
 
+    // If requested, pre-aggregate the input on each worker before it is exchanged by key to
+    // be arranged. This consolidates the updates of each key in a batch into a single update,
+    // so that the worker responsible for a large or skewed group key receives at most one
+    // update per worker and batch, instead of one update per input record. Each batch of
+    // updates is consolidated on its own, so that, unlike an arrangement, no state is kept
+    // across batches.
+    let collection = if pre_aggregate {
+        collection
+            .inner
+            .unary(Pipeline, "PreAggregateAccumulable", |_capability, _info| {
+                let mut buffer = Vec::new();
+                let mut updates = Vec::new();
+                move |input, output| {
+                    input.for_each(|time, data| {
+                        data.swap(&mut buffer);
+                        updates.append(&mut buffer);
+                        consolidate_updates(&mut updates);
+                        output.session(&time).give_iterator(updates.drain(..));
+                    });
+                }
+            })
+            .as_collection()
+    } else {
+        collection
+    };
+
     collection
         .arrange_named::<RowKeySpine<_, _, (Vec<Accum>, Diff)>>("ArrangeAccumulable")
         .reduce_abelian::<_, RowSpine<_, _, _, _>>("ReduceAccumulable", {
@@ -1581,7 +1611,9 @@ pub mod monoids {
             | AggregateFunc::DenseRank { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
-            | AggregateFunc::LastValue { .. } => None,
+            | AggregateFunc::LastValue { .. }
+            | AggregateFunc::SessionWindows
+            | AggregateFunc::BucketDeltas => None,
         }
     }
 }
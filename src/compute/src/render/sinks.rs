@@ -24,7 +24,7 @@ use mz_storage_client::types::errors::DataflowError;
 use mz_timely_util::probe;
 
 use crate::compute_state::SinkToken;
-use crate::render::{context::Context, RenderTimestamp};
+use crate::render::{context::Context, log_updates, RenderTimestamp};
 
 impl<'g, G, T> Context<Child<'g, G, T>, Row>
 where
@@ -76,6 +76,12 @@ where
         let ok_collection = ok_collection.leave();
         let err_collection = err_collection.leave();
 
+        if let Some(logger) = compute_state.compute_logger.clone() {
+            ok_collection.inspect_batch(move |_time, data| {
+                log_updates(&logger, &[sink_id], false, data.len());
+            });
+        }
+
         let sink_token = sink_render.render_continuous_sink(
             compute_state,
             sink,
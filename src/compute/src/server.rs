@@ -14,6 +14,7 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 use std::fmt::{Debug, Formatter};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use anyhow::{anyhow, Error};
 use async_trait::async_trait;
@@ -547,7 +548,9 @@ impl<'w, A: Allocate> Worker<'w, A> {
         while !shutdown {
             // Enable trace compaction.
             if let Some(compute_state) = &mut self.compute_state {
-                compute_state.traces.maintenance();
+                compute_state
+                    .traces
+                    .maintenance(&compute_state.reported_arrangement_sizes);
             }
 
             self.timely_worker.step_or_park(None);
@@ -555,6 +558,7 @@ impl<'w, A: Allocate> Worker<'w, A> {
             // Report frontier information back the coordinator.
             if let Some(mut compute_state) = self.activate_compute(&mut response_tx) {
                 compute_state.report_compute_frontiers();
+                compute_state.report_arrangement_sizes();
                 compute_state.report_dropped_collections();
             }
 
@@ -606,6 +610,8 @@ impl<'w, A: Allocate> Worker<'w, A> {
                     flow_control_probes: HashMap::new(),
                     pending_peeks: HashMap::new(),
                     reported_frontiers: HashMap::new(),
+                    reported_arrangement_sizes: HashMap::new(),
+                    last_arrangement_size_report: Instant::now(),
                     dropped_collections: Vec::new(),
                     compute_logger: None,
                     persist_clients: Arc::clone(&self.persist_clients),
@@ -840,6 +846,8 @@ impl<'w, A: Allocate> Worker<'w, A> {
                 }
                 *frontier = timely::progress::Antichain::from_elem(<_>::minimum());
             }
+            // Arrangement sizes must be reported afresh to the new controller.
+            compute_state.reported_arrangement_sizes.clear();
             // Sink tokens should be retained for retained dataflows, and dropped for dropped dataflows.
             compute_state
                 .sink_tokens
@@ -24,8 +24,10 @@ use timely::progress::Antichain;
 use timely::PartialOrder;
 
 use mz_compute_client::protocol::response::{SubscribeBatch, SubscribeResponse};
-use mz_compute_client::types::sinks::{ComputeSinkDesc, SinkAsOf, SubscribeSinkConnection};
-use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_compute_client::types::sinks::{
+    ComputeSinkDesc, SinkAsOf, SubscribePartition, SubscribeSinkConnection,
+};
+use mz_repr::{DatumVec, Diff, GlobalId, Row, Timestamp};
 use mz_storage_client::controller::CollectionMetadata;
 use mz_storage_client::types::errors::DataflowError;
 
@@ -64,6 +66,7 @@ where
             sink_id,
             sink.as_of.clone(),
             sink.up_to.clone(),
+            self.partition.clone(),
             subscribe_protocol_handle,
             probes,
         );
@@ -84,6 +87,7 @@ fn subscribe<G>(
     sink_id: GlobalId,
     as_of: SinkAsOf,
     up_to: Antichain<G::Timestamp>,
+    partition: Option<SubscribePartition>,
     subscribe_protocol_handle: Rc<RefCell<Option<SubscribeProtocol>>>,
     mut probes: Vec<probe::Handle<Timestamp>>,
 ) where
@@ -97,6 +101,7 @@ fn subscribe<G>(
     let mut results = Vec::new();
     let mut finished = false;
     let mut rows = Default::default();
+    let mut datum_vec = DatumVec::new();
     progress_stream = sinked_collection.inner.unary_frontier(
         Pipeline,
         &format!("subscribe-{}", sink_id),
@@ -115,7 +120,11 @@ fn subscribe<G>(
                         } else {
                             as_of.frontier.less_equal(&time)
                         };
-                        let should_emit = should_emit_as_of && !up_to.less_equal(&time);
+                        let should_emit = should_emit_as_of
+                            && !up_to.less_equal(&time)
+                            && partition
+                                .as_ref()
+                                .map_or(true, |p| p.contains(&datum_vec.borrow_with(&row)));
                         if should_emit {
                             results.push((time, row, diff));
                         }
@@ -523,6 +523,7 @@ fn main() {
     let args = cli::parse_args(CliConfig {
         env_prefix: Some("MZ_"),
         enable_version_flag: true,
+        enable_config_file: true,
     });
     if let Err(err) = run(args) {
         eprintln!("environmentd: {:#}", err);
@@ -11,7 +11,8 @@
 //!
 //! environmentd embeds an HTTP server for introspection into the running
 //! process. At the moment, its primary exports are Prometheus metrics, heap
-//! profiles, and catalog dumps.
+//! profiles, catalog dumps, support bundles, and a built-in schema
+//! registry.
 
 // Axum handlers must use async, but often don't actually use `await`.
 #![allow(clippy::unused_async)]
@@ -53,12 +54,16 @@ use crate::server::{ConnectionHandler, Server};
 use crate::BUILD_INFO;
 
 pub use sql::{SqlResponse, WebSocketAuth, WebSocketResponse};
+pub use subscribe::{SubscribeRequest, SubscribeResponse, Update, UpdateBatch};
 
 mod catalog;
 mod memory;
 mod probe;
 mod root;
+mod schema_registry;
 mod sql;
+mod subscribe;
+mod support_bundle;
 
 #[derive(Debug, Clone)]
 pub struct HttpConfig {
@@ -130,6 +135,10 @@ impl HttpServer {
             );
         let ws_router = Router::new()
             .route("/api/experimental/sql", routing::get(sql::handle_sql_ws))
+            .route(
+                "/api/experimental/subscribe",
+                routing::get(subscribe::handle_subscribe_ws),
+            )
             .with_state(WsState {
                 frontegg,
                 adapter_client,
@@ -233,6 +242,10 @@ impl InternalHttpServer {
                 "/api/catalog",
                 routing::get(catalog::handle_internal_catalog),
             )
+            .route(
+                "/api/support-bundle",
+                routing::get(support_bundle::handle_support_bundle),
+            )
             .layer(Extension(AuthedUser {
                 user: SYSTEM_USER.clone(),
                 create_if_not_exists: false,
@@ -533,7 +546,28 @@ fn base_router(BaseRouterConfig { profiling }: BaseRouterConfig) -> Router {
             "/hierarchical-memory",
             routing::get(memory::handle_hierarchical_memory),
         )
-        .route("/static/*path", routing::get(root::handle_static));
+        .route("/static/*path", routing::get(root::handle_static))
+        .route(
+            "/subjects",
+            routing::get(schema_registry::handle_list_subjects),
+        )
+        .route(
+            "/subjects/:subject/versions",
+            routing::get(schema_registry::handle_list_versions)
+                .post(schema_registry::handle_publish),
+        )
+        .route(
+            "/subjects/:subject/versions/:version",
+            routing::get(schema_registry::handle_get_version),
+        )
+        .route(
+            "/schemas/ids/:id",
+            routing::get(schema_registry::handle_get_schema),
+        )
+        .route(
+            "/schemas/ids/:id/versions",
+            routing::get(schema_registry::handle_get_schema_versions),
+        );
     if profiling {
         router = router.nest("/prof/", mz_prof::http::router(&BUILD_INFO));
     }
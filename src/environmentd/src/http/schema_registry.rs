@@ -0,0 +1,276 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A built-in schema registry backed by the catalog.
+//!
+//! The registry implements the subset of the Confluent Schema Registry REST
+//! API that Avro sinks and most consumers rely on, for environments that do
+//! not run a Confluent Schema Registry. Only Avro schemas without references
+//! are supported, and schemas cannot be deleted. Sinks use the registry via a
+//! `CONFLUENT SCHEMA REGISTRY` connection whose URL points at this server.
+
+use axum::extract::Path;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use http::StatusCode;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use mz_adapter::catalog::storage::RegistrySchema;
+use mz_adapter::{AdapterError, SessionClient};
+
+use crate::http::AuthedClient;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishRequest {
+    schema: String,
+    #[serde(default)]
+    schema_type: Option<String>,
+    #[serde(default)]
+    references: Vec<serde_json::Value>,
+}
+
+#[derive(Serialize)]
+pub struct PublishResponse {
+    id: i32,
+}
+
+#[derive(Serialize)]
+pub struct SchemaResponse {
+    schema: String,
+}
+
+#[derive(Serialize)]
+pub struct SubjectVersionResponse {
+    subject: String,
+    version: i32,
+}
+
+#[derive(Serialize)]
+pub struct SubjectResponse {
+    subject: String,
+    version: i32,
+    id: i32,
+    schema: String,
+    references: Vec<serde_json::Value>,
+}
+
+/// An error in the format of the Confluent Schema Registry.
+pub struct RegistryError {
+    status: StatusCode,
+    error_code: i32,
+    message: String,
+}
+
+impl RegistryError {
+    fn subject_not_found() -> RegistryError {
+        RegistryError {
+            status: StatusCode::NOT_FOUND,
+            error_code: 40401,
+            message: "Subject not found.".into(),
+        }
+    }
+
+    fn version_not_found() -> RegistryError {
+        RegistryError {
+            status: StatusCode::NOT_FOUND,
+            error_code: 40402,
+            message: "Version not found.".into(),
+        }
+    }
+
+    fn schema_not_found() -> RegistryError {
+        RegistryError {
+            status: StatusCode::NOT_FOUND,
+            error_code: 40403,
+            message: "Schema not found".into(),
+        }
+    }
+
+    fn invalid_schema(message: String) -> RegistryError {
+        RegistryError {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            error_code: 42201,
+            message,
+        }
+    }
+
+    fn invalid_version(version: &str) -> RegistryError {
+        RegistryError {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            error_code: 42202,
+            message: format!(
+                "The specified version '{}' is not a valid version id. \
+                 Allowed values are between [1, 2^31-1] and the string \"latest\"",
+                version
+            ),
+        }
+    }
+}
+
+impl From<AdapterError> for RegistryError {
+    fn from(e: AdapterError) -> RegistryError {
+        RegistryError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            error_code: 50001,
+            message: e.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for RegistryError {
+    fn into_response(self) -> Response {
+        #[derive(Serialize)]
+        struct ErrorResponse {
+            error_code: i32,
+            message: String,
+        }
+
+        let body = ErrorResponse {
+            error_code: self.error_code,
+            message: self.message,
+        };
+        (self.status, Json(body)).into_response()
+    }
+}
+
+async fn subject_versions(
+    client: &mut SessionClient,
+    subject: &str,
+) -> Result<Vec<RegistrySchema>, RegistryError> {
+    let versions: Vec<_> = client
+        .registry_schemas()
+        .await?
+        .into_iter()
+        .filter(|s| s.subject == subject)
+        .sorted_by_key(|s| s.version)
+        .collect();
+    if versions.is_empty() {
+        return Err(RegistryError::subject_not_found());
+    }
+    Ok(versions)
+}
+
+pub async fn handle_publish(
+    AuthedClient(mut client): AuthedClient,
+    Path(subject): Path<String>,
+    Json(request): Json<PublishRequest>,
+) -> Result<Json<PublishResponse>, RegistryError> {
+    match request.schema_type.as_deref() {
+        None | Some("AVRO") => (),
+        Some(schema_type) => {
+            return Err(RegistryError::invalid_schema(format!(
+                "unsupported schema type: {}",
+                schema_type
+            )))
+        }
+    }
+    if !request.references.is_empty() {
+        return Err(RegistryError::invalid_schema(
+            "schema references are not supported".into(),
+        ));
+    }
+    if let Err(e) = mz_interchange::avro::parse_schema(&request.schema) {
+        return Err(RegistryError::invalid_schema(format!(
+            "invalid Avro schema: {:#}",
+            e
+        )));
+    }
+    let id = client.register_schema(subject, request.schema).await?;
+    Ok(Json(PublishResponse { id }))
+}
+
+pub async fn handle_list_subjects(
+    AuthedClient(mut client): AuthedClient,
+) -> Result<Json<Vec<String>>, RegistryError> {
+    let subjects = client
+        .registry_schemas()
+        .await?
+        .into_iter()
+        .map(|s| s.subject)
+        .sorted()
+        .dedup()
+        .collect();
+    Ok(Json(subjects))
+}
+
+pub async fn handle_list_versions(
+    AuthedClient(mut client): AuthedClient,
+    Path(subject): Path<String>,
+) -> Result<Json<Vec<i32>>, RegistryError> {
+    let versions = subject_versions(&mut client, &subject).await?;
+    Ok(Json(versions.into_iter().map(|s| s.version).collect()))
+}
+
+pub async fn handle_get_version(
+    AuthedClient(mut client): AuthedClient,
+    Path((subject, version)): Path<(String, String)>,
+) -> Result<Json<SubjectResponse>, RegistryError> {
+    let version = match version.as_str() {
+        "latest" => None,
+        _ => match version.parse::<i32>() {
+            Ok(v) if v > 0 => Some(v),
+            _ => return Err(RegistryError::invalid_version(&version)),
+        },
+    };
+    let versions = subject_versions(&mut client, &subject).await?;
+    let schema = match version {
+        None => versions.into_iter().last(),
+        Some(version) => versions.into_iter().find(|s| s.version == version),
+    };
+    match schema {
+        Some(schema) => Ok(Json(SubjectResponse {
+            subject: schema.subject,
+            version: schema.version,
+            id: schema.id,
+            schema: schema.schema,
+            references: vec![],
+        })),
+        None => Err(RegistryError::version_not_found()),
+    }
+}
+
+pub async fn handle_get_schema(
+    AuthedClient(mut client): AuthedClient,
+    Path(id): Path<i32>,
+) -> Result<Json<SchemaResponse>, RegistryError> {
+    match client
+        .registry_schemas()
+        .await?
+        .into_iter()
+        .find(|s| s.id == id)
+    {
+        Some(schema) => Ok(Json(SchemaResponse {
+            schema: schema.schema,
+        })),
+        None => Err(RegistryError::schema_not_found()),
+    }
+}
+
+pub async fn handle_get_schema_versions(
+    AuthedClient(mut client): AuthedClient,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<SubjectVersionResponse>>, RegistryError> {
+    let versions: Vec<_> = client
+        .registry_schemas()
+        .await?
+        .into_iter()
+        .filter(|s| s.id == id)
+        .sorted_by(|a, b| (&a.subject, a.version).cmp(&(&b.subject, b.version)))
+        .map(|s| SubjectVersionResponse {
+            subject: s.subject,
+            version: s.version,
+        })
+        .collect();
+    if versions.is_empty() {
+        return Err(RegistryError::schema_not_found());
+    }
+    Ok(Json(versions))
+}
@@ -52,6 +52,21 @@ pub async fn handle_sql(
     }
 }
 
+/// Executes `query` as a simple [`SqlRequest`], and returns the result of its
+/// last statement.
+pub(super) async fn execute_simple(
+    client: &mut AuthedClient,
+    query: String,
+) -> Result<SqlResult, anyhow::Error> {
+    let mut res = SqlResponse {
+        results: Vec::new(),
+    };
+    execute_request(client, SqlRequest::Simple { query }, &mut res).await?;
+    res.results
+        .pop()
+        .ok_or_else(|| anyhow!("query produced no results"))
+}
+
 #[derive(Serialize)]
 struct ErrorResponse {
     error: String,
@@ -797,6 +812,24 @@ https://github.com/MaterializeInc/materialize/issues/new?assignees=&labels=C-bug
     })
 }
 
+/// Executes a `SUBSCRIBE` statement for the subscribe endpoint. Returns the
+/// description and the output of the subscription, or the error to report to
+/// the client.
+pub(super) async fn execute_subscribe(
+    client: &mut SessionClient,
+    ws: &mut WebSocket,
+    stmt: Statement<Raw>,
+) -> Result<Result<(RelationDesc, RowBatchStream), String>, anyhow::Error> {
+    if let Err(e) = client.start_transaction(Some(1)).await {
+        return Ok(Err(e.to_string()));
+    }
+    Ok(match execute_stmt(client, ws, stmt, vec![]).await? {
+        StatementResult::Subscribe { desc, tag: _, rx } => Ok((desc, rx)),
+        StatementResult::SqlResult(SqlResult::Err { error, notices: _ }) => Err(error),
+        StatementResult::SqlResult(_) => Err("statement did not start a subscription".into()),
+    })
+}
+
 fn make_notices(client: &mut SessionClient) -> Vec<Notice> {
     client
         .session()
@@ -0,0 +1,349 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A WebSocket endpoint that streams the changes to a relation as JSON.
+//!
+//! After authenticating, the client sends a [`SubscribeRequest`], and the
+//! server runs `SUBSCRIBE <relation> WITH (SNAPSHOT, PROGRESS)` in the
+//! client's session. It forwards the snapshot as soon as it is complete, and
+//! then sends the consolidated changes of all completed timestamps at most
+//! once per interval, so that a relation that changes quickly does not flood
+//! a browser with messages.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail};
+use axum::extract::ws::{CloseFrame, Message, WebSocket};
+use axum::extract::{State, WebSocketUpgrade};
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+use tokio::time::{self, MissedTickBehavior};
+use tracing::warn;
+use tungstenite::protocol::frame::coding::CloseCode;
+
+use mz_adapter::{PeekResponseUnary, SessionClient};
+use mz_interchange::encode::TypedDatum;
+use mz_interchange::json::ToJson;
+use mz_repr::{ColumnType, Datum, DatumVec, RelationDesc, Row};
+use mz_sql::ast::{
+    Raw, Statement, SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement,
+    Value, WithOptionValue,
+};
+
+use super::sql::execute_subscribe;
+use super::{init_ws, WsState};
+
+/// The interval between two `Updates` messages if the client does not
+/// request one.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The shortest interval between two `Updates` messages that a client can
+/// request.
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The number of columns that `SUBSCRIBE ... WITH (PROGRESS)` emits before
+/// the columns of the relation: `mz_timestamp`, `mz_progressed`, and
+/// `mz_diff`.
+const METADATA_COLUMNS: usize = 3;
+
+pub async fn handle_subscribe_ws(
+    State(state): State<WsState>,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    ws.on_upgrade(|ws| async move { run_ws(&state, ws).await })
+}
+
+/// A request to subscribe to a relation, sent after the authentication
+/// message.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SubscribeRequest {
+    /// The name of the relation, e.g. `materialize.public.orders`.
+    pub relation: String,
+    /// Whether to send the contents of the relation before its changes.
+    #[serde(default = "default_snapshot")]
+    pub snapshot: bool,
+    /// The minimum time between two `Updates` messages, in milliseconds.
+    #[serde(default)]
+    pub interval_ms: Option<u64>,
+}
+
+fn default_snapshot() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum SubscribeResponse {
+    /// The names of the relation's columns, sent before any other message.
+    Columns(Vec<String>),
+    /// The contents of the relation, if the request asked for a snapshot.
+    Snapshot(UpdateBatch),
+    /// The changes to the relation since the previous message.
+    Updates(UpdateBatch),
+    /// The subscription failed, and the server is closing the connection.
+    Error(String),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateBatch {
+    /// The batch contains all changes at times before this timestamp.
+    pub timestamp: serde_json::Value,
+    /// The changes, consolidated, in no particular order.
+    pub updates: Vec<Update>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Update {
+    /// The number of copies of `row` that were added, or removed if negative.
+    pub diff: i64,
+    pub row: Vec<serde_json::Value>,
+}
+
+async fn run_ws(state: &WsState, mut ws: WebSocket) {
+    let mut client = match init_ws(state, &mut ws).await {
+        Ok(client) => client,
+        Err(e) => {
+            // We omit most detail from the error message we send to the client, to
+            // avoid giving attackers unnecessary information.
+            warn!("WS request failed authentication: {}", e);
+            let _ = ws
+                .send(Message::Close(Some(CloseFrame {
+                    code: CloseCode::Protocol.into(),
+                    reason: "unauthorized".into(),
+                })))
+                .await;
+            return;
+        }
+    };
+
+    let code = match run_subscribe(&mut client.0, &mut ws).await {
+        Ok(()) => CloseCode::Normal,
+        Err(err) => {
+            let _ = send(&mut ws, SubscribeResponse::Error(err.to_string())).await;
+            CloseCode::Error
+        }
+    };
+    let _ = ws
+        .send(Message::Close(Some(CloseFrame {
+            code: code.into(),
+            reason: "".into(),
+        })))
+        .await;
+}
+
+async fn send(ws: &mut WebSocket, msg: SubscribeResponse) -> Result<(), anyhow::Error> {
+    let msg = serde_json::to_string(&msg).expect("must serialize");
+    Ok(ws.send(Message::Text(msg)).await?)
+}
+
+/// Serves a subscription until it fails, ends, or the client disconnects.
+async fn run_subscribe(
+    client: &mut SessionClient,
+    ws: &mut WebSocket,
+) -> Result<(), anyhow::Error> {
+    let req: SubscribeRequest = loop {
+        match ws.recv().await {
+            Some(Ok(Message::Text(data))) => break serde_json::from_str(&data)?,
+            Some(Ok(Message::Binary(data))) => break serde_json::from_slice(&data)?,
+            // Handled automatically by the server.
+            Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+            // Client disconnected.
+            Some(Ok(Message::Close(_)) | Err(_)) | None => return Ok(()),
+        }
+    };
+    let interval = match req.interval_ms {
+        Some(ms) => Duration::from_millis(ms).max(MIN_INTERVAL),
+        None => DEFAULT_INTERVAL,
+    };
+
+    let stmt = subscribe_stmt(&req.relation, req.snapshot)?;
+    let (desc, mut rx) = execute_subscribe(client, ws, stmt)
+        .await?
+        .map_err(|e| anyhow!(e))?;
+    let mut subscription = Subscription::new(desc, req.snapshot);
+    send(ws, SubscribeResponse::Columns(subscription.column_names())).await?;
+
+    let mut tick = time::interval(interval);
+    tick.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        tokio::select! {
+            res = rx.recv() => match res {
+                Some(PeekResponseUnary::Rows(rows)) => {
+                    if let Some(snapshot) = subscription.push(rows) {
+                        send(ws, SubscribeResponse::Snapshot(snapshot)).await?;
+                    }
+                }
+                Some(PeekResponseUnary::Error(err)) => bail!(err),
+                Some(PeekResponseUnary::Canceled) => bail!("subscription canceled"),
+                None => {
+                    if let Some(batch) = subscription.take_updates() {
+                        send(ws, SubscribeResponse::Updates(batch)).await?;
+                    }
+                    return Ok(());
+                }
+            },
+            _ = tick.tick() => match subscription.take_updates() {
+                Some(batch) => send(ws, SubscribeResponse::Updates(batch)).await?,
+                // Ping the client, so that we notice when it goes away while
+                // the relation is idle.
+                None => ws.send(Message::Ping(Vec::new())).await?,
+            },
+            msg = ws.recv() => match msg {
+                Some(Ok(Message::Close(_)) | Err(_)) | None => return Ok(()),
+                // The endpoint does not accept further requests.
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+/// Builds the statement `SUBSCRIBE <relation> WITH (SNAPSHOT = <snapshot>,
+/// PROGRESS)`.
+fn subscribe_stmt(relation: &str, snapshot: bool) -> Result<Statement<Raw>, anyhow::Error> {
+    // Parse the name as part of a statement, and check that the statement
+    // contains nothing else, so that the request cannot smuggle in a query,
+    // options, or further statements.
+    let stmts = mz_sql::parse::parse(&format!("SUBSCRIBE {}", relation)).map_err(|e| anyhow!(e))?;
+    let name = match stmts.as_slice() {
+        [Statement::Subscribe(SubscribeStatement {
+            relation: SubscribeRelation::Name(name),
+            options,
+            as_of: None,
+            up_to: None,
+        })] if options.is_empty() => name.clone(),
+        _ => bail!("invalid relation name: {}", relation),
+    };
+    let option = |name, value| SubscribeOption {
+        name,
+        value: Some(WithOptionValue::Value(Value::Boolean(value))),
+    };
+    Ok(Statement::Subscribe(SubscribeStatement {
+        relation: SubscribeRelation::Name(name),
+        options: vec![
+            option(SubscribeOptionName::Snapshot, snapshot),
+            option(SubscribeOptionName::Progress, true),
+        ],
+        as_of: None,
+        up_to: None,
+    }))
+}
+
+/// The state of a subscription between the rows of its `SUBSCRIBE` and the
+/// messages to the client.
+struct Subscription {
+    /// The description of the `SUBSCRIBE`'s output.
+    desc: RelationDesc,
+    /// Whether the client asked for a snapshot.
+    snapshot: bool,
+    /// Whether the first progress row, which completes the snapshot, has been
+    /// received.
+    started: bool,
+    /// Changes at times that no progress row has completed yet.
+    pending: Vec<(Row, i64)>,
+    /// Completed changes that have not been sent yet, consolidated.
+    ready: BTreeMap<Row, i64>,
+    /// The timestamp of the latest progress row, if it has not been sent yet.
+    frontier: Option<serde_json::Value>,
+    datum_vec: DatumVec,
+}
+
+impl Subscription {
+    fn new(desc: RelationDesc, snapshot: bool) -> Subscription {
+        Subscription {
+            desc,
+            snapshot,
+            started: false,
+            pending: Vec::new(),
+            ready: BTreeMap::new(),
+            frontier: None,
+            datum_vec: DatumVec::new(),
+        }
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.desc
+            .iter_names()
+            .skip(METADATA_COLUMNS)
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    /// Absorbs rows of the `SUBSCRIBE`. Returns the snapshot, if the rows
+    /// complete it and the client asked for one.
+    fn push(&mut self, rows: Vec<Row>) -> Option<UpdateBatch> {
+        let types = &self.desc.typ().column_types;
+        let mut snapshot = None;
+        for row in rows {
+            let datums = self.datum_vec.borrow_with(&row);
+            if datums[1] == Datum::True {
+                // A progress row: all changes before its timestamp are
+                // complete.
+                for (row, diff) in self.pending.drain(..) {
+                    match self.ready.entry(row) {
+                        Entry::Occupied(mut entry) => {
+                            *entry.get_mut() += diff;
+                            if *entry.get() == 0 {
+                                entry.remove();
+                            }
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(diff);
+                        }
+                    }
+                }
+                self.frontier = Some(TypedDatum::new(datums[0], &types[0]).json());
+                if !self.started {
+                    self.started = true;
+                    if self.snapshot {
+                        snapshot = take_batch(&mut self.ready, &mut self.frontier, types);
+                    }
+                }
+            } else {
+                let diff = datums[2].unwrap_int64();
+                let row = Row::pack_slice(&datums[METADATA_COLUMNS..]);
+                self.pending.push((row, diff));
+            }
+        }
+        snapshot
+    }
+
+    /// Returns the completed changes since the previous message, if there
+    /// is anything new to report.
+    fn take_updates(&mut self) -> Option<UpdateBatch> {
+        if !self.started {
+            return None;
+        }
+        let types = &self.desc.typ().column_types;
+        take_batch(&mut self.ready, &mut self.frontier, types)
+    }
+}
+
+fn take_batch(
+    ready: &mut BTreeMap<Row, i64>,
+    frontier: &mut Option<serde_json::Value>,
+    types: &[ColumnType],
+) -> Option<UpdateBatch> {
+    let timestamp = frontier.take()?;
+    let mut datum_vec = DatumVec::new();
+    let updates = std::mem::take(ready)
+        .into_iter()
+        .map(|(row, diff)| {
+            let datums = datum_vec.borrow_with(&row);
+            let row = datums
+                .iter()
+                .zip(&types[METADATA_COLUMNS..])
+                .map(|(d, typ)| TypedDatum::new(*d, typ).json())
+                .collect();
+            Update { diff, row }
+        })
+        .collect();
+    Some(UpdateBatch { timestamp, updates })
+}
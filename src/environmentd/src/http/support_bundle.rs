@@ -0,0 +1,137 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support bundle HTTP endpoint.
+//!
+//! A support bundle captures the state that is needed to diagnose dataflow
+//! performance issues in a single JSON document: the version of
+//! `environmentd`, a dump of the catalog, and the contents of every relation
+//! in the `mz_catalog` and `mz_internal` schemas. The introspection relations
+//! are read on the cluster and replica that the request selects.
+//!
+//! `environmentd` does not retain its logs, so they are not part of the
+//! bundle, and must be collected from wherever the deployment sends them.
+
+use std::collections::BTreeMap;
+
+use axum::extract::Query;
+use axum::response::IntoResponse;
+use axum::Json;
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use mz_ore::now::{to_datetime, SYSTEM_TIME};
+
+use crate::http::sql::{self, SqlResult};
+use crate::http::AuthedClient;
+use crate::BUILD_INFO;
+
+/// The schemas whose relations are captured in a support bundle.
+const SCHEMAS: &[&str] = &["mz_catalog", "mz_internal"];
+
+/// Query parameters for [`handle_support_bundle`].
+#[derive(Deserialize)]
+pub struct SupportBundleParams {
+    /// The cluster whose introspection relations to capture.
+    cluster: Option<String>,
+    /// The replica whose introspection relations to capture.
+    cluster_replica: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SupportBundle {
+    /// The version of `environmentd`.
+    version: String,
+    /// The time at which the bundle was collected.
+    collected_at: String,
+    /// The cluster of the session that queried the relations.
+    cluster: String,
+    /// The replica of the session that queried the relations, if any.
+    cluster_replica: Option<String>,
+    /// The catalog dump, as returned by `/api/catalog`.
+    catalog: serde_json::Value,
+    /// The result of querying each relation, by qualified name. Relations
+    /// that could not be queried have an error result.
+    relations: BTreeMap<String, SqlResult>,
+}
+
+/// Collects a support bundle and returns it as JSON.
+pub async fn handle_support_bundle(
+    mut client: AuthedClient,
+    Query(params): Query<SupportBundleParams>,
+) -> impl IntoResponse {
+    match collect_support_bundle(&mut client, params).await {
+        Ok(bundle) => Ok(Json(bundle)),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+async fn collect_support_bundle(
+    client: &mut AuthedClient,
+    SupportBundleParams {
+        cluster,
+        cluster_replica,
+    }: SupportBundleParams,
+) -> Result<SupportBundle, anyhow::Error> {
+    let vars = client.0.session().vars_mut();
+    if let Some(cluster) = &cluster {
+        vars.set("cluster", cluster, false)?;
+    }
+    if let Some(cluster_replica) = &cluster_replica {
+        vars.set("cluster_replica", cluster_replica, false)?;
+    }
+    let cluster = client.0.session().vars().cluster().to_string();
+
+    let catalog = client.0.dump_catalog().await?;
+    let catalog = serde_json::from_str(&catalog)?;
+
+    let query = format!(
+        "SELECT s.name, r.name
+        FROM mz_catalog.mz_relations r
+        JOIN mz_catalog.mz_schemas s ON r.schema_id = s.id
+        WHERE s.name IN ({})
+        ORDER BY 1, 2",
+        SCHEMAS
+            .iter()
+            .map(|schema| format!("'{schema}'"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let names = match sql::execute_simple(client, query).await? {
+        SqlResult::Rows { rows, .. } => rows
+            .into_iter()
+            .map(|row| match &row[..] {
+                [serde_json::Value::String(schema), serde_json::Value::String(name)] => {
+                    Ok(format!("{schema}.{name}"))
+                }
+                _ => Err(anyhow::anyhow!("unexpected relation name: {row:?}")),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        SqlResult::Err { error, .. } => anyhow::bail!("listing relations: {error}"),
+        SqlResult::Ok { .. } => anyhow::bail!("listing relations returned no rows"),
+    };
+
+    // Each relation is queried in a transaction of its own, so that a
+    // relation that cannot be queried, for example because it is specific to
+    // a replica and no replica was selected, does not fail the bundle.
+    let mut relations = BTreeMap::new();
+    for name in names {
+        let result = sql::execute_simple(client, format!("SELECT * FROM {name}")).await?;
+        relations.insert(name, result);
+    }
+
+    Ok(SupportBundle {
+        version: BUILD_INFO.human_version(),
+        collected_at: to_datetime((SYSTEM_TIME)()).to_rfc3339(),
+        cluster,
+        cluster_replica,
+        catalog,
+        relations,
+    })
+}
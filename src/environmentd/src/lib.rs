@@ -118,7 +118,10 @@ mod http;
 mod server;
 mod telemetry;
 
-pub use crate::http::{SqlResponse, WebSocketAuth, WebSocketResponse};
+pub use crate::http::{
+    SqlResponse, SubscribeRequest, SubscribeResponse, Update, UpdateBatch, WebSocketAuth,
+    WebSocketResponse,
+};
 
 pub const BUILD_INFO: BuildInfo = build_info!();
 
@@ -155,10 +155,8 @@ fn test_bind_params() {
     let err = client
         .query_one("CREATE VIEW v AS SELECT $3", &[])
         .unwrap_db_error();
-    // TODO(benesch): this should be `UNDEFINED_PARAMETER`, but blocked
-    // on #3147.
     assert_eq!(err.message(), "there is no parameter $3");
-    assert_eq!(err.code(), &SqlState::INTERNAL_ERROR);
+    assert_eq!(err.code(), &SqlState::UNDEFINED_PARAMETER);
 
     // Test that `INSERT` statements support prepared statements.
     {
@@ -312,6 +310,24 @@ fn test_conn_startup() {
                 .get::<_, String>(0),
             "hello",
         );
+
+        // Parameters set at connection time are the session's defaults, so
+        // they should survive `RESET ALL` and `DISCARD ALL`, whereas values
+        // set via `SET` should not.
+        for reset in ["RESET ALL", "DISCARD ALL"] {
+            client
+                .batch_execute("SET application_name = 'goodbye'")
+                .unwrap();
+            client.batch_execute("SET extra_float_digits = 2").unwrap();
+            client.batch_execute(reset).unwrap();
+            for (name, expected) in [("application_name", "hello"), ("extra_float_digits", "3")] {
+                let value = client
+                    .query_one(&format!("SHOW {name}"), &[])
+                    .unwrap()
+                    .get::<_, String>(0);
+                assert_eq!(value, expected, "{name} after {reset}");
+            }
+        }
     }
 
     // Test that connecting with an old protocol version is gracefully rejected.
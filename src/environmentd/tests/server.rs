@@ -84,10 +84,11 @@ use anyhow::bail;
 use chrono::{DateTime, Utc};
 use reqwest::blocking::Client;
 use reqwest::Url;
+use serde_json::json;
 use tracing::info;
 use tungstenite::Message;
 
-use mz_environmentd::{WebSocketAuth, WebSocketResponse};
+use mz_environmentd::{SubscribeRequest, SubscribeResponse, WebSocketAuth, WebSocketResponse};
 use mz_ore::retry::Retry;
 use mz_pgrepr::UInt8;
 
@@ -336,6 +337,77 @@ fn test_http_sql() {
     });
 }
 
+// Test the WS subscribe endpoint.
+#[test]
+fn test_http_subscribe() {
+    let server = util::start_server(util::Config::default()).unwrap();
+    let mut client = server.connect(postgres::NoTls).unwrap();
+    client
+        .batch_execute("CREATE TABLE t (a int, b text); INSERT INTO t VALUES (1, 'one')")
+        .unwrap();
+
+    let ws_url = Url::parse(&format!(
+        "ws://{}/api/experimental/subscribe",
+        server.inner.http_local_addr()
+    ))
+    .unwrap();
+    let (mut ws, _resp) = tungstenite::connect(ws_url).unwrap();
+    for msg in [
+        serde_json::to_string(&WebSocketAuth::Basic {
+            user: "materialize".into(),
+            password: "".into(),
+        })
+        .unwrap(),
+        serde_json::to_string(&SubscribeRequest {
+            relation: "t".into(),
+            snapshot: true,
+            interval_ms: Some(100),
+        })
+        .unwrap(),
+    ] {
+        ws.write_message(Message::Text(msg)).unwrap();
+    }
+
+    let mut read_message = || loop {
+        match ws.read_message().unwrap() {
+            Message::Text(msg) => break serde_json::from_str::<SubscribeResponse>(&msg).unwrap(),
+            Message::Ping(_) => continue,
+            resp => panic!("unexpected response: {:?}", resp),
+        }
+    };
+
+    match read_message() {
+        SubscribeResponse::Columns(names) => assert_eq!(names, ["a", "b"]),
+        resp => panic!("unexpected response: {:?}", resp),
+    }
+    match read_message() {
+        SubscribeResponse::Snapshot(batch) => {
+            assert_eq!(batch.updates.len(), 1);
+            assert_eq!(batch.updates[0].diff, 1);
+            assert_eq!(batch.updates[0].row, [json!(1), json!("one")]);
+        }
+        resp => panic!("unexpected response: {:?}", resp),
+    }
+
+    // The retraction and the insertion of the update may arrive in the same
+    // message or in two.
+    client
+        .batch_execute("UPDATE t SET b = 'uno' WHERE a = 1")
+        .unwrap();
+    let mut updates = vec![];
+    while updates.len() < 2 {
+        match read_message() {
+            SubscribeResponse::Updates(batch) => updates.extend(batch.updates),
+            resp => panic!("unexpected response: {:?}", resp),
+        }
+    }
+    updates.sort_by_key(|update| update.diff);
+    assert_eq!(updates[0].diff, -1);
+    assert_eq!(updates[0].row, [json!(1), json!("one")]);
+    assert_eq!(updates[1].diff, 1);
+    assert_eq!(updates[1].row, [json!(1), json!("uno")]);
+}
+
 // Test that the server properly handles cancellation requests.
 #[test]
 fn test_cancel_long_running_query() {
@@ -715,3 +787,149 @@ fn test_default_cluster_sizes() {
         .get(0);
     assert_eq!(builtin_size, "2");
 }
+
+// Test the built-in schema registry.
+#[test]
+fn test_builtin_schema_registry() {
+    let server = util::start_server(util::Config::default()).unwrap();
+    let base_url = format!("http://{}", server.inner.http_local_addr());
+    let client = Client::new();
+    let publish = |subject: &str, schema: &str| {
+        let res = client
+            .post(format!("{}/subjects/{}/versions", base_url, subject))
+            .json(&serde_json::json!({ "schema": schema }))
+            .send()
+            .unwrap();
+        let status = res.status();
+        (status, res.json::<serde_json::Value>().unwrap())
+    };
+    let get = |path: &str| {
+        let res = client.get(format!("{}{}", base_url, path)).send().unwrap();
+        let status = res.status();
+        (status, res.json::<serde_json::Value>().unwrap())
+    };
+
+    let schema_v1 = r#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"}]}"#;
+    let schema_v2 = r#"{"type":"record","name":"r","fields":[{"name":"b","type":"long"}]}"#;
+
+    let (status, res) = publish("topic-value", schema_v1);
+    assert!(status.is_success());
+    assert_eq!(res, serde_json::json!({ "id": 1 }));
+
+    // Publishing the same schema again returns the existing ID, also under a
+    // different subject.
+    let (_, res) = publish("topic-value", schema_v1);
+    assert_eq!(res, serde_json::json!({ "id": 1 }));
+    let (_, res) = publish("other-value", schema_v1);
+    assert_eq!(res, serde_json::json!({ "id": 1 }));
+
+    let (_, res) = publish("topic-value", schema_v2);
+    assert_eq!(res, serde_json::json!({ "id": 2 }));
+
+    let (_, res) = get("/subjects");
+    assert_eq!(res, serde_json::json!(["other-value", "topic-value"]));
+    let (_, res) = get("/subjects/topic-value/versions");
+    assert_eq!(res, serde_json::json!([1, 2]));
+    let (_, res) = get("/subjects/topic-value/versions/latest");
+    assert_eq!(
+        res,
+        serde_json::json!({
+            "subject": "topic-value",
+            "version": 2,
+            "id": 2,
+            "schema": schema_v2,
+            "references": [],
+        })
+    );
+    let (_, res) = get("/subjects/topic-value/versions/1");
+    assert_eq!(res["schema"], schema_v1);
+    let (_, res) = get("/schemas/ids/1");
+    assert_eq!(res, serde_json::json!({ "schema": schema_v1 }));
+    let (_, res) = get("/schemas/ids/1/versions");
+    assert_eq!(
+        res,
+        serde_json::json!([
+            { "subject": "other-value", "version": 1 },
+            { "subject": "topic-value", "version": 1 },
+        ])
+    );
+
+    let (status, res) = get("/subjects/missing/versions/latest");
+    assert_eq!(status, reqwest::StatusCode::NOT_FOUND);
+    assert_eq!(res["error_code"], 40401);
+    let (status, res) = get("/subjects/topic-value/versions/3");
+    assert_eq!(status, reqwest::StatusCode::NOT_FOUND);
+    assert_eq!(res["error_code"], 40402);
+    let (status, res) = get("/schemas/ids/3");
+    assert_eq!(status, reqwest::StatusCode::NOT_FOUND);
+    assert_eq!(res["error_code"], 40403);
+    let (status, res) = publish("topic-value", "not a schema");
+    assert_eq!(status, reqwest::StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(res["error_code"], 42201);
+}
+
+// Test the support bundle endpoint of the internal HTTP server.
+#[test]
+fn test_support_bundle() {
+    let server = util::start_server(util::Config::default()).unwrap();
+    let url = format!(
+        "http://{}/api/support-bundle",
+        server.inner.internal_http_local_addr()
+    );
+    let res = Client::new().get(&url).send().unwrap();
+    assert!(res.status().is_success());
+    let bundle = res.json::<serde_json::Value>().unwrap();
+
+    assert!(bundle["version"].is_string());
+    assert_eq!(bundle["cluster"], "default");
+    assert!(bundle["catalog"].is_object());
+    let relations = bundle["relations"].as_object().unwrap();
+    assert!(relations["mz_catalog.mz_tables"]["rows"].is_array());
+    assert!(relations["mz_internal.mz_dataflow_operators"]["rows"].is_array());
+
+    // Selecting a cluster that does not exist fails the bundle.
+    let res = Client::new()
+        .get(&url)
+        .query(&[("cluster", "no_such_cluster")])
+        .send()
+        .unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+// Test that peeks are counted by plan in the metrics served by the internal
+// HTTP server.
+#[test]
+fn test_peek_metrics() {
+    let server = util::start_server(util::Config::default()).unwrap();
+    let mut client = server.connect(postgres::NoTls).unwrap();
+    client
+        .batch_execute(
+            "CREATE TABLE t (a int);
+             CREATE DEFAULT INDEX ON t;
+             INSERT INTO t VALUES (1), (2);",
+        )
+        .unwrap();
+
+    let url = format!("http://{}/metrics", server.inner.internal_http_local_addr());
+    let peeks_total = |plan: &str| -> u64 {
+        let metrics = Client::new().get(&url).send().unwrap().text().unwrap();
+        let prefix = format!("mz_peeks_total{{plan=\"{plan}\"}} ");
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&prefix))
+            .map(|value| value.parse().unwrap())
+            .unwrap_or(0)
+    };
+
+    for (query, plan, expected) in [
+        ("SELECT * FROM t WHERE a = 1", "index_lookup", 1),
+        ("SELECT * FROM t", "index_scan", 2),
+        ("SELECT 1", "constant", 1),
+        ("SELECT sum(a) FROM t", "dataflow", 1),
+    ] {
+        let before = peeks_total(plan);
+        let rows = client.query(query, &[]).unwrap();
+        assert_eq!(rows.len(), expected, "{query}");
+        assert_eq!(peeks_total(plan), before + 1, "{query}");
+    }
+}
@@ -2174,6 +2174,156 @@ fn test_cancel_on_dropped_cluster() {
     handle.join().unwrap();
 }
 
+#[test]
+fn test_peek_queueing() {
+    let config = util::Config::default().unsafe_mode();
+    let server = util::start_server(config).unwrap();
+
+    // Internal users are exempt from the limit, so they can observe the queue.
+    let mut sys_client = server
+        .pg_config_internal()
+        .user(&SYSTEM_USER.name)
+        .connect(postgres::NoTls)
+        .unwrap();
+    sys_client
+        .batch_execute("ALTER SYSTEM SET max_concurrent_peeks_per_role = 1")
+        .unwrap();
+
+    let queued_peeks = || {
+        server
+            .metrics_registry
+            .gather()
+            .into_iter()
+            .find(|family| family.get_name() == "mz_queued_peeks")
+            .map(|family| family.get_metric()[0].get_gauge().get_value())
+            .unwrap_or(0.0)
+    };
+
+    let mut client = server.connect(postgres::NoTls).unwrap();
+    client.batch_execute("CREATE TABLE t ()").unwrap();
+
+    // Occupy the only slot of the role with a peek that never completes.
+    let mut holding_client = server.connect(postgres::NoTls).unwrap();
+    let holding_cancel = holding_client.cancel_token();
+    let holding = thread::spawn(move || {
+        let err = holding_client
+            .query_one("SELECT * FROM t AS OF 18446744073709551615", &[])
+            .unwrap_db_error();
+        assert_eq!(err.code(), &SqlState::QUERY_CANCELED);
+    });
+    Retry::default()
+        .max_duration(Duration::from_secs(10))
+        .retry(|_| {
+            let count: i64 = sys_client
+                .query_one("SELECT count(*) FROM mz_internal.mz_active_peeks", &[])
+                .unwrap()
+                .get(0);
+            if count == 1 {
+                Ok(())
+            } else {
+                Err(format!("expected 1 active peek, but saw {count}"))
+            }
+        })
+        .unwrap();
+
+    // A second peek waits in the queue, and fails once it has waited for
+    // `max_peek_queue_time`.
+    sys_client
+        .batch_execute("ALTER SYSTEM SET max_peek_queue_time = '1s'")
+        .unwrap();
+    let start = Instant::now();
+    let err = client.query_one("SELECT * FROM t", &[]).unwrap_db_error();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(err.code(), &SqlState::QUERY_CANCELED);
+    assert_eq!(
+        err.message(),
+        "query waited for longer than 1s for one of the 1 concurrent query slots of role \"materialize\""
+    );
+    assert_eq!(queued_peeks(), 0.0);
+
+    // Cancelling a queued peek removes it from the queue.
+    sys_client
+        .batch_execute("ALTER SYSTEM SET max_peek_queue_time = '1h'")
+        .unwrap();
+    let client_cancel = client.cancel_token();
+    let queued = thread::spawn(move || {
+        let err = client.query_one("SELECT * FROM t", &[]).unwrap_db_error();
+        assert_eq!(err.code(), &SqlState::QUERY_CANCELED);
+        assert_eq!(err.message(), "canceling statement due to user request");
+    });
+    Retry::default()
+        .max_duration(Duration::from_secs(10))
+        .retry(|_| match queued_peeks() {
+            count if count == 1.0 => Ok(()),
+            count => Err(format!("expected 1 queued peek, but saw {count}")),
+        })
+        .unwrap();
+    client_cancel.cancel_query(postgres::NoTls).unwrap();
+    queued.join().unwrap();
+    assert_eq!(queued_peeks(), 0.0);
+    let count: i64 = sys_client
+        .query_one("SELECT count(*) FROM mz_internal.mz_active_peeks", &[])
+        .unwrap()
+        .get(0);
+    assert_eq!(count, 1);
+
+    // A queued peek whose client disconnects is not run once a slot frees up,
+    // and its session is terminated.
+    let active_sessions = || {
+        server
+            .metrics_registry
+            .gather()
+            .into_iter()
+            .find(|family| family.get_name() == "mz_active_sessions")
+            .map(|family| {
+                family
+                    .get_metric()
+                    .iter()
+                    .map(|metric| metric.get_gauge().get_value())
+                    .sum::<f64>()
+            })
+            .unwrap_or(0.0)
+    };
+    let sessions_before = server.runtime.block_on(async {
+        let (client, conn_task) = server.connect_async(tokio_postgres::NoTls).await.unwrap();
+        let sessions_before = active_sessions();
+        let query = client.query("SELECT * FROM t", &[]);
+        assert!(tokio::time::timeout(Duration::from_secs(2), query)
+            .await
+            .is_err());
+        assert_eq!(queued_peeks(), 1.0);
+        conn_task.abort();
+        let _ = conn_task.await;
+        sessions_before
+    });
+    // Give the server time to notice that the client is gone.
+    thread::sleep(Duration::from_secs(2));
+
+    holding_cancel.cancel_query(postgres::NoTls).unwrap();
+    holding.join().unwrap();
+    Retry::default()
+        .max_duration(Duration::from_secs(10))
+        .retry(|_| {
+            // Both the holding session and the disconnected session are gone.
+            let sessions = active_sessions();
+            if sessions <= sessions_before - 2.0 {
+                Ok(())
+            } else {
+                Err(format!(
+                    "expected at most {} sessions, but saw {sessions}",
+                    sessions_before - 2.0
+                ))
+            }
+        })
+        .unwrap();
+    assert_eq!(queued_peeks(), 0.0);
+    let count: i64 = sys_client
+        .query_one("SELECT count(*) FROM mz_internal.mz_active_peeks", &[])
+        .unwrap()
+        .get(0);
+    assert_eq!(count, 0);
+}
+
 #[test]
 fn test_emit_timestamp_notice() {
     let config = util::Config::default();
@@ -9,6 +9,7 @@
 
 #![allow(missing_docs)]
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::iter;
 use std::ops::Deref;
@@ -41,7 +42,10 @@ use crate::relation::{
     proto_table_func, ColumnOrder, ProtoAggregateFunc, ProtoTableFunc, WindowFrame,
     WindowFrameBound, WindowFrameUnits,
 };
-use crate::scalar::func::{add_timestamp_months, jsonb_stringify};
+use crate::scalar::func::{
+    add_timestamp_months, add_timestamplike_interval, jsonb_stringify, sub_timestamplike_interval,
+    text_search,
+};
 use crate::EvalError;
 
 include!(concat!(env!("OUT_DIR"), "/mz_expr.relation.func.rs"));
@@ -1077,6 +1081,139 @@ where
     })
 }
 
+fn session_windows<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Decode the (timestamp, gap) records, ignoring events without a timestamp.
+    let mut events = datums
+        .into_iter()
+        .filter(|d| !d.is_null())
+        .filter_map(|d| {
+            let mut iter = d.unwrap_list().iter();
+            let ts = iter.next().unwrap();
+            let gap = iter.next().unwrap();
+            (!ts.is_null()).then_some((ts, gap))
+        })
+        .collect_vec();
+    events.sort_by(|(l, _), (r, _)| l.cmp(r));
+
+    // The latest time at which another event can join the session of the
+    // event at `ts`, or `None` if the session never ends because adding the
+    // gap overflows. A null gap only lets events at the same time join.
+    let extends_until = |ts: Datum<'a>, gap: Datum<'a>| -> Option<Datum<'a>> {
+        let gap = match gap {
+            Datum::Null => return Some(ts),
+            gap => gap.unwrap_interval(),
+        };
+        match ts {
+            Datum::Timestamp(ts) => add_timestamplike_interval(ts, gap).ok(),
+            Datum::TimestampTz(ts) => add_timestamplike_interval(ts, gap).ok(),
+            _ => unreachable!(),
+        }
+    };
+
+    let mut events = events.into_iter();
+    let (first_ts, first_gap) = match events.next() {
+        Some(event) => event,
+        None => return Datum::Null,
+    };
+    // Fold the sorted events into (start, end, count) sessions, while keeping
+    // track of the end of the current session. As events are sorted, an event
+    // either joins the current session or starts a new one. The sessions are
+    // recomputed from all events of a group whenever the group changes, so a
+    // late event that closes the gap between two sessions merges them, and
+    // its retraction splits them again.
+    let mut sessions = vec![];
+    let mut current = (first_ts, first_ts, 1i64);
+    let mut current_end = extends_until(first_ts, first_gap);
+    for (ts, gap) in events {
+        if current_end.map_or(true, |end| ts <= end) {
+            current.1 = ts;
+            current.2 += 1;
+            current_end = match (current_end, extends_until(ts, gap)) {
+                (Some(l), Some(r)) => Some(std::cmp::max(l, r)),
+                _ => None,
+            };
+        } else {
+            sessions.push(current);
+            current = (ts, ts, 1);
+            current_end = extends_until(ts, gap);
+        }
+    }
+    sessions.push(current);
+
+    let sessions = sessions.into_iter().map(|(start, end, count)| {
+        temp_storage.make_datum(|packer| {
+            packer.push_list(vec![start, end, Datum::Int64(count)]);
+        })
+    });
+    temp_storage.make_datum(|packer| {
+        packer.push_list(sessions);
+    })
+}
+
+fn bucket_deltas<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sum the values of each bucket, ignoring rows without a bucket, and keep
+    // the width of the bucket to find the bucket before it.
+    let mut buckets: BTreeMap<Datum<'a>, (Option<f64>, Datum<'a>)> = BTreeMap::new();
+    for d in datums.into_iter().filter(|d| !d.is_null()) {
+        let mut iter = d.unwrap_list().iter();
+        let bucket = iter.next().unwrap();
+        let value = iter.next().unwrap();
+        let width = iter.next().unwrap();
+        if bucket.is_null() {
+            continue;
+        }
+        let (sum, bucket_width) = buckets.entry(bucket).or_insert((None, Datum::Null));
+        if !value.is_null() {
+            *sum = Some(sum.unwrap_or(0.0) + value.unwrap_float64());
+        }
+        if bucket_width.is_null() {
+            *bucket_width = width;
+        }
+    }
+    if buckets.is_empty() {
+        return Datum::Null;
+    }
+
+    let previous_bucket = |bucket: Datum<'a>, width: Datum<'a>| -> Option<Datum<'a>> {
+        if width.is_null() {
+            return None;
+        }
+        match bucket {
+            Datum::Timestamp(ts) => sub_timestamplike_interval(ts, width).ok(),
+            Datum::TimestampTz(ts) => sub_timestamplike_interval(ts, width).ok(),
+            _ => unreachable!(),
+        }
+    };
+
+    // Compare each bucket to the bucket one width before it, whose value is
+    // null if it has no rows.
+    let deltas = buckets.iter().map(|(bucket, (value, width))| {
+        let previous = previous_bucket(*bucket, *width)
+            .and_then(|previous| buckets.get(&previous))
+            .and_then(|(previous, _)| *previous);
+        let delta = value
+            .zip(previous)
+            .map(|(value, previous)| value - previous);
+        temp_storage.make_datum(|packer| {
+            packer.push_list(vec![
+                *bucket,
+                Datum::from(*value),
+                Datum::from(previous),
+                Datum::from(delta),
+            ]);
+        })
+    });
+    temp_storage.make_datum(|packer| {
+        packer.push_list(deltas);
+    })
+}
+
 /// Identify whether the given aggregate function is Lag or Lead, since they share
 /// implementations.
 #[derive(
@@ -1180,6 +1317,16 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    /// Groups `Datum::List`s of `(timestamp, gap)` records into sessions, in
+    /// which each event occurs at most its gap after the end of the previous
+    /// events of the session. Outputs a `Datum::List` of
+    /// `(session_start, session_end, event_count)` records.
+    SessionWindows,
+    /// Sums the values of `Datum::List`s of `(bucket, value, width)` records
+    /// per bucket. Outputs a `Datum::List` of `(bucket, value, previous_value,
+    /// delta)` records that compare the sum of each bucket to the sum of the
+    /// bucket one width before it.
+    BucketDeltas,
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -1244,6 +1391,8 @@ impl Arbitrary for AggregateFunc {
             Just(AggregateFunc::Count).boxed(),
             Just(AggregateFunc::Any).boxed(),
             Just(AggregateFunc::All).boxed(),
+            Just(AggregateFunc::SessionWindows).boxed(),
+            Just(AggregateFunc::BucketDeltas).boxed(),
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::JsonbAgg { order_by })
                 .boxed(),
@@ -1389,6 +1538,8 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     order_by: Some(order_by.into_proto()),
                     window_frame: Some(window_frame.into_proto()),
                 }),
+                AggregateFunc::SessionWindows => Kind::SessionWindows(()),
+                AggregateFunc::BucketDeltas => Kind::BucketDeltas(()),
                 AggregateFunc::Dummy => Kind::Dummy(()),
             }),
         }
@@ -1495,6 +1646,8 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     .window_frame
                     .into_rust_if_some("ProtoWindowFrame::window_frame")?,
             },
+            Kind::SessionWindows(()) => AggregateFunc::SessionWindows,
+            Kind::BucketDeltas(()) => AggregateFunc::BucketDeltas,
             Kind::Dummy(()) => AggregateFunc::Dummy,
         })
     }
@@ -1569,6 +1722,8 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::SessionWindows => session_windows(datums, temp_storage),
+            AggregateFunc::BucketDeltas => bucket_deltas(datums, temp_storage),
             AggregateFunc::Dummy => Datum::Dummy,
         }
     }
@@ -1745,6 +1900,54 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::SessionWindows => {
+                // The input type for SessionWindows is a (Timestamp, Gap) record
+                let ts_type = input_type.scalar_type.unwrap_record_element_type()[0].clone();
+
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Record {
+                        fields: vec![
+                            (
+                                ColumnName::from("session_start"),
+                                ts_type.clone().nullable(false),
+                            ),
+                            (ColumnName::from("session_end"), ts_type.nullable(false)),
+                            (
+                                ColumnName::from("event_count"),
+                                ScalarType::Int64.nullable(false),
+                            ),
+                        ],
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
+            AggregateFunc::BucketDeltas => {
+                // The input type for BucketDeltas is a (Bucket, Value, Width) record
+                let bucket_type = input_type.scalar_type.unwrap_record_element_type()[0].clone();
+
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Record {
+                        fields: vec![
+                            (ColumnName::from("bucket"), bucket_type.nullable(false)),
+                            (
+                                ColumnName::from("value"),
+                                ScalarType::Float64.nullable(true),
+                            ),
+                            (
+                                ColumnName::from("previous_value"),
+                                ScalarType::Float64.nullable(true),
+                            ),
+                            (
+                                ColumnName::from("delta"),
+                                ScalarType::Float64.nullable(true),
+                            ),
+                        ],
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
             // Note AggregateFunc::MaxString, MinString rely on returning input
             // type as output type to support the proper return type for
             // character input.
@@ -1764,6 +1967,9 @@ impl AggregateFunc {
                 },
                 _ => unreachable!(),
             },
+            // The input records are never null, but the output is null if
+            // none of them has a timestamp.
+            AggregateFunc::SessionWindows | AggregateFunc::BucketDeltas => true,
             _ => input_type.nullable,
         };
         scalar_type.nullable(nullable)
@@ -1879,6 +2085,12 @@ fn regexp_extract(a: Datum, r: &AnalyzedRegex) -> Option<(Row, Diff)> {
     Some((Row::pack(datums), 1))
 }
 
+fn tokenize<'a>(a: Datum<'a>) -> impl Iterator<Item = (Row, Diff)> + 'a {
+    text_search::tokenize(a.unwrap_str())
+        .into_iter()
+        .map(|token| (Row::pack_slice(&[Datum::String(&token)]), 1))
+}
+
 fn generate_series<N>(
     start: N,
     stop: N,
@@ -2066,6 +2278,8 @@ impl fmt::Display for AggregateFunc {
             } => f.write_str("lead"),
             AggregateFunc::FirstValue { .. } => f.write_str("first_value"),
             AggregateFunc::LastValue { .. } => f.write_str("last_value"),
+            AggregateFunc::SessionWindows => f.write_str("session_windows"),
+            AggregateFunc::BucketDeltas => f.write_str("bucket_deltas"),
             AggregateFunc::Dummy => f.write_str("dummy"),
         }
     }
@@ -2197,6 +2411,8 @@ pub enum TableFunc {
     },
     RegexpExtract(AnalyzedRegex),
     CsvExtract(usize),
+    /// Splits text into its distinct lowercase alphanumeric tokens.
+    Tokenize,
     GenerateSeriesInt32,
     GenerateSeriesInt64,
     GenerateSeriesTimestamp,
@@ -2232,6 +2448,7 @@ impl RustType<ProtoTableFunc> for TableFunc {
                 TableFunc::JsonbArrayElements { stringify } => Kind::JsonbArrayElements(*stringify),
                 TableFunc::RegexpExtract(x) => Kind::RegexpExtract(x.into_proto()),
                 TableFunc::CsvExtract(x) => Kind::CsvExtract(x.into_proto()),
+                TableFunc::Tokenize => Kind::Tokenize(()),
                 TableFunc::GenerateSeriesInt32 => Kind::GenerateSeriesInt32(()),
                 TableFunc::GenerateSeriesInt64 => Kind::GenerateSeriesInt64(()),
                 TableFunc::GenerateSeriesTimestamp => Kind::GenerateSeriesTimestamp(()),
@@ -2261,6 +2478,7 @@ impl RustType<ProtoTableFunc> for TableFunc {
             Kind::JsonbArrayElements(stringify) => TableFunc::JsonbArrayElements { stringify },
             Kind::RegexpExtract(x) => TableFunc::RegexpExtract(x.into_rust()?),
             Kind::CsvExtract(x) => TableFunc::CsvExtract(x.into_rust()?),
+            Kind::Tokenize(()) => TableFunc::Tokenize,
             Kind::GenerateSeriesInt32(()) => TableFunc::GenerateSeriesInt32,
             Kind::GenerateSeriesInt64(()) => TableFunc::GenerateSeriesInt64,
             Kind::GenerateSeriesTimestamp(()) => TableFunc::GenerateSeriesTimestamp,
@@ -2302,6 +2520,7 @@ impl TableFunc {
             ))),
             TableFunc::RegexpExtract(a) => Ok(Box::new(regexp_extract(datums[0], a).into_iter())),
             TableFunc::CsvExtract(n_cols) => Ok(Box::new(csv_extract(datums[0], *n_cols))),
+            TableFunc::Tokenize => Ok(Box::new(tokenize(datums[0]))),
             TableFunc::GenerateSeriesInt32 => {
                 let res = generate_series(
                     datums[0].unwrap_int32(),
@@ -2400,6 +2619,11 @@ impl TableFunc {
                 let keys = vec![];
                 (column_types, keys)
             }
+            TableFunc::Tokenize => {
+                let column_types = vec![ScalarType::String.nullable(false)];
+                let keys = vec![vec![0]];
+                (column_types, keys)
+            }
             TableFunc::GenerateSeriesInt32 => {
                 let column_types = vec![ScalarType::Int32.nullable(false)];
                 let keys = vec![vec![0]];
@@ -2461,6 +2685,7 @@ impl TableFunc {
             TableFunc::JsonbArrayElements { .. } => 1,
             TableFunc::RegexpExtract(a) => a.capture_groups_len(),
             TableFunc::CsvExtract(n_cols) => *n_cols,
+            TableFunc::Tokenize => 1,
             TableFunc::GenerateSeriesInt32 => 1,
             TableFunc::GenerateSeriesInt64 => 1,
             TableFunc::GenerateSeriesTimestamp => 1,
@@ -2485,6 +2710,7 @@ impl TableFunc {
             | TableFunc::GenerateSubscriptsArray
             | TableFunc::RegexpExtract(_)
             | TableFunc::CsvExtract(_)
+            | TableFunc::Tokenize
             | TableFunc::Repeat
             | TableFunc::UnnestArray { .. }
             | TableFunc::UnnestList { .. } => true,
@@ -2502,6 +2728,7 @@ impl TableFunc {
             TableFunc::JsonbArrayElements { .. } => true,
             TableFunc::RegexpExtract(_) => true,
             TableFunc::CsvExtract(_) => true,
+            TableFunc::Tokenize => true,
             TableFunc::GenerateSeriesInt32 => true,
             TableFunc::GenerateSeriesInt64 => true,
             TableFunc::GenerateSeriesTimestamp => true,
@@ -2523,6 +2750,7 @@ impl fmt::Display for TableFunc {
             TableFunc::JsonbArrayElements { .. } => f.write_str("jsonb_array_elements"),
             TableFunc::RegexpExtract(a) => write!(f, "regexp_extract({:?}, _)", a.0),
             TableFunc::CsvExtract(n_cols) => write!(f, "csv_extract({}, _)", n_cols),
+            TableFunc::Tokenize => f.write_str("tokenize"),
             TableFunc::GenerateSeriesInt32 => f.write_str("generate_series"),
             TableFunc::GenerateSeriesInt64 => f.write_str("generate_series"),
             TableFunc::GenerateSeriesTimestamp => f.write_str("generate_series"),
@@ -2393,6 +2393,81 @@ impl AggregateExpr {
                 }
             }
 
+            // SessionWindows takes a (timestamp, gap) record and outputs a list
+            // containing a single session, or null if there is no timestamp
+            AggregateFunc::SessionWindows => {
+                let ts = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+                let output_type = self.typ(input_type).scalar_type;
+
+                let session = MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::RecordCreate {
+                        field_names: vec![
+                            ColumnName::from("session_start"),
+                            ColumnName::from("session_end"),
+                            ColumnName::from("event_count"),
+                        ],
+                    },
+                    exprs: vec![
+                        ts.clone(),
+                        ts.clone(),
+                        MirScalarExpr::literal_ok(Datum::Int64(1), ScalarType::Int64),
+                    ],
+                };
+                ts.call_unary(UnaryFunc::IsNull(crate::func::IsNull))
+                    .if_then_else(
+                        MirScalarExpr::literal_null(output_type.clone()),
+                        MirScalarExpr::CallVariadic {
+                            func: VariadicFunc::ListCreate {
+                                elem_type: output_type.unwrap_list_element_type().clone(),
+                            },
+                            exprs: vec![session],
+                        },
+                    )
+            }
+
+            // BucketDeltas takes a (bucket, value, width) record and outputs a
+            // list containing a single bucket without a previous value, or null
+            // if there is no bucket
+            AggregateFunc::BucketDeltas => {
+                let field = |i| {
+                    self.expr
+                        .clone()
+                        .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(i)))
+                };
+                let output_type = self.typ(input_type).scalar_type;
+
+                let bucket = MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::RecordCreate {
+                        field_names: vec![
+                            ColumnName::from("bucket"),
+                            ColumnName::from("value"),
+                            ColumnName::from("previous_value"),
+                            ColumnName::from("delta"),
+                        ],
+                    },
+                    exprs: vec![
+                        field(0),
+                        field(1),
+                        MirScalarExpr::literal_null(ScalarType::Float64),
+                        MirScalarExpr::literal_null(ScalarType::Float64),
+                    ],
+                };
+                field(0)
+                    .call_unary(UnaryFunc::IsNull(crate::func::IsNull))
+                    .if_then_else(
+                        MirScalarExpr::literal_null(output_type.clone()),
+                        MirScalarExpr::CallVariadic {
+                            func: VariadicFunc::ListCreate {
+                                elem_type: output_type.unwrap_list_element_type().clone(),
+                            },
+                            exprs: vec![bucket],
+                        },
+                    )
+            }
+
             // All other variants should return the argument to the aggregation.
             AggregateFunc::MaxNumeric
             | AggregateFunc::MaxInt16
@@ -42,6 +42,7 @@ use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
 use mz_repr::adt::datetime::Timezone;
+use mz_repr::adt::geo::{BoundingBox, Point};
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{self, DecimalLike, Numeric, NumericMaxScale};
@@ -62,6 +63,7 @@ mod macros;
 mod encoding;
 mod format;
 pub(crate) mod impls;
+pub(crate) mod text_search;
 
 pub use impls::*;
 
@@ -304,7 +306,7 @@ fn add_float64<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     }
 }
 
-fn add_timestamplike_interval<'a, T>(
+pub(crate) fn add_timestamplike_interval<'a, T>(
     a: CheckedTimestamp<T>,
     b: Interval,
 ) -> Result<Datum<'a>, EvalError>
@@ -319,7 +321,7 @@ where
     T::from_date_time(dt).try_into().err_into()
 }
 
-fn sub_timestamplike_interval<'a, T>(
+pub(crate) fn sub_timestamplike_interval<'a, T>(
     a: CheckedTimestamp<T>,
     b: Datum,
 ) -> Result<Datum<'a>, EvalError>
@@ -448,6 +450,13 @@ fn decode<'a>(
     Ok(Datum::from(temp_storage.push_bytes(out)))
 }
 
+fn text_search_match<'a>(document: Datum<'a>, query: Datum<'a>) -> Datum<'a> {
+    Datum::from(text_search::matches(
+        document.unwrap_str(),
+        query.unwrap_str(),
+    ))
+}
+
 fn encoded_bytes_char_length<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     // Convert PostgreSQL-style encoding names[1] to WHATWG-style encoding names[2],
     // which the encoding library uses[3].
@@ -801,6 +810,95 @@ fn sub_timestamptz<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::from(a.unwrap_timestamptz() - b.unwrap_timestamptz())
 }
 
+fn age_timestamp<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let age = age(
+        a.unwrap_timestamp().to_naive(),
+        b.unwrap_timestamp().to_naive(),
+    )?;
+    Ok(Datum::from(age))
+}
+
+fn age_timestamptz<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let age = age(
+        a.unwrap_timestamptz().to_naive(),
+        b.unwrap_timestamptz().to_naive(),
+    )?;
+    Ok(Datum::from(age))
+}
+
+/// Computes the difference between `t1` and `t2` in years, months, days, and
+/// time, as PostgreSQL's `age` does.
+///
+/// Each field of `t2` is subtracted from the same field of `t1`, and negative
+/// fields borrow from the next larger field. A borrowed month is as long as
+/// the month of the earlier of the two timestamps, so that e.g. the age of
+/// `2001-04-10` relative to `1957-06-13` is `43 years 9 mons 27 days`.
+fn age(t1: NaiveDateTime, t2: NaiveDateTime) -> Result<Interval, EvalError> {
+    use chrono::Datelike;
+
+    fn days_in_month(year: i32, month: u32) -> i64 {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        match (
+            NaiveDate::from_ymd_opt(year, month, 1),
+            NaiveDate::from_ymd_opt(next_year, next_month, 1),
+        ) {
+            (Some(start), Some(end)) => (end - start).num_days(),
+            _ => 31,
+        }
+    }
+
+    // Leap seconds are represented as nanoseconds past the last second.
+    let micros = |t: NaiveDateTime| i64::from(t.nanosecond().min(999_999_999) / 1_000);
+    let mut fields = [
+        i64::from(t1.year()) - i64::from(t2.year()),
+        i64::from(t1.month()) - i64::from(t2.month()),
+        i64::from(t1.day()) - i64::from(t2.day()),
+        i64::from(t1.hour()) - i64::from(t2.hour()),
+        i64::from(t1.minute()) - i64::from(t2.minute()),
+        i64::from(t1.second()) - i64::from(t2.second()),
+        micros(t1) - micros(t2),
+    ];
+    // Compute the age of the later timestamp relative to the earlier one, and
+    // flip the sign of the result at the end.
+    let negative = t1 < t2;
+    if negative {
+        fields.iter_mut().for_each(|f| *f = -*f);
+    }
+    let [years, months, days, hours, minutes, seconds, micros] = &mut fields;
+    let borrow = |field: &mut i64, larger: &mut i64, size: i64| {
+        while *field < 0 {
+            *field += size;
+            *larger -= 1;
+        }
+    };
+    borrow(micros, seconds, 1_000_000);
+    borrow(seconds, minutes, 60);
+    borrow(minutes, hours, 60);
+    borrow(hours, days, 24);
+    let earlier = if negative { t1 } else { t2 };
+    borrow(days, months, days_in_month(earlier.year(), earlier.month()));
+    borrow(months, years, 12);
+    if negative {
+        fields.iter_mut().for_each(|f| *f = -*f);
+    }
+
+    let [years, months, days, hours, minutes, seconds, micros] = fields;
+    let months = years
+        .checked_mul(12)
+        .and_then(|m| m.checked_add(months))
+        .and_then(|m| i32::try_from(m).ok());
+    let days = i32::try_from(days).ok();
+    let micros = ((hours * 60 + minutes) * 60 + seconds) * 1_000_000 + micros;
+    match (months, days) {
+        (Some(months), Some(days)) => Ok(Interval::new(months, days, micros)),
+        _ => Err(EvalError::IntervalOutOfRange),
+    }
+}
+
 fn sub_date<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::from(a.unwrap_date() - b.unwrap_date())
 }
@@ -1255,6 +1353,53 @@ fn get_byte<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     Ok(Datum::from(i32::from(*i)))
 }
 
+/// Returns the byte and the bit within it that `index` refers to, numbering
+/// bits from the least significant bit of the first byte, as PostgreSQL does.
+fn bit_position(bytes: &[u8], index: i64) -> Result<(usize, u8), EvalError> {
+    let bits = i64::try_from(bytes.len())
+        .unwrap_or(i64::MAX)
+        .saturating_mul(8);
+    if index < 0 || index >= bits {
+        return Err(EvalError::IndexOutOfRange {
+            provided: i32::try_from(index).unwrap_or(i32::MAX),
+            valid_end: i32::try_from(bits - 1).unwrap_or(i32::MAX),
+        });
+    }
+    let index = usize::try_from(index).expect("index is non-negative");
+    Ok((index / 8, 1 << (index % 8)))
+}
+
+fn get_bit<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let bytes = a.unwrap_bytes();
+    let (byte, mask) = bit_position(bytes, b.unwrap_int64())?;
+    Ok(Datum::from(i32::from(bytes[byte] & mask != 0)))
+}
+
+fn bytea_concat<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Datum<'a> {
+    let mut buf = a.unwrap_bytes().to_vec();
+    buf.extend_from_slice(b.unwrap_bytes());
+    Datum::Bytes(temp_storage.push_bytes(buf))
+}
+
+fn position_bytes<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let substring = a.unwrap_bytes();
+    let bytes = b.unwrap_bytes();
+    let index = if substring.is_empty() {
+        Some(0)
+    } else {
+        bytes
+            .windows(substring.len())
+            .position(|window| window == substring)
+    };
+    match index {
+        Some(index) => {
+            let index = i32::try_from(index).map_err(|_| EvalError::Int32OutOfRange)?;
+            Ok(Datum::Int32(index + 1))
+        }
+        None => Ok(Datum::Int32(0)),
+    }
+}
+
 fn contains_range_elem<'a, R: RangeOps<'a>>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a>
 where
     <R as TryFrom<Datum<'a>>>::Error: std::fmt::Debug,
@@ -1906,6 +2051,12 @@ pub enum BinaryFunc {
     PowerNumeric,
     GetByte,
     RangeContainsElem { elem_type: ScalarType, rev: bool },
+    TextSearchMatch,
+    AgeTimestamp,
+    AgeTimestampTz,
+    ByteaConcat,
+    GetBit,
+    PositionBytes,
 }
 
 impl BinaryFunc {
@@ -2212,6 +2363,12 @@ impl BinaryFunc {
                 }
                 _ => unreachable!(),
             }),
+            BinaryFunc::TextSearchMatch => Ok(eager!(text_search_match)),
+            BinaryFunc::AgeTimestamp => eager!(age_timestamp),
+            BinaryFunc::AgeTimestampTz => eager!(age_timestamptz),
+            BinaryFunc::ByteaConcat => Ok(eager!(bytea_concat, temp_storage)),
+            BinaryFunc::GetBit => eager!(get_bit),
+            BinaryFunc::PositionBytes => eager!(position_bytes),
         }
     }
 
@@ -2278,7 +2435,9 @@ impl BinaryFunc {
             }
 
             AddInterval | SubInterval | SubTimestamp | SubTimestampTz | MulInterval
-            | DivInterval => ScalarType::Interval.nullable(in_nullable),
+            | DivInterval | AgeTimestamp | AgeTimestampTz => {
+                ScalarType::Interval.nullable(in_nullable)
+            }
 
             AddTimestampInterval
             | SubTimestampInterval
@@ -2363,9 +2522,11 @@ impl BinaryFunc {
                 ScalarType::Numeric { max_scale: None }.nullable(in_nullable)
             }
 
-            GetByte => ScalarType::Int32.nullable(in_nullable),
+            GetByte | GetBit | PositionBytes => ScalarType::Int32.nullable(in_nullable),
 
-            RangeContainsElem { .. } => ScalarType::Bool.nullable(in_nullable),
+            ByteaConcat => ScalarType::Bytes.nullable(in_nullable),
+
+            RangeContainsElem { .. } | TextSearchMatch => ScalarType::Bool.nullable(in_nullable),
         }
     }
 
@@ -2490,6 +2651,12 @@ impl BinaryFunc {
                 | ModFloat64
                 | ModNumeric
                 | RangeContainsElem { .. }
+                | TextSearchMatch
+                | AgeTimestamp
+                | AgeTimestampTz
+                | ByteaConcat
+                | GetBit
+                | PositionBytes
         )
     }
 
@@ -2619,7 +2786,9 @@ impl BinaryFunc {
             | ListListConcat
             | ListElementConcat
             | ElementListConcat
-            | RangeContainsElem { .. } => true,
+            | RangeContainsElem { .. }
+            | TextSearchMatch
+            | ByteaConcat => true,
             ToCharTimestamp
             | ToCharTimestampTz
             | DateBinTimestamp
@@ -2664,7 +2833,11 @@ impl BinaryFunc {
             | ArrayRemove
             | ListRemove
             | LikeEscape
-            | GetByte => false,
+            | GetByte
+            | AgeTimestamp
+            | AgeTimestampTz
+            | GetBit
+            | PositionBytes => false,
         }
     }
 
@@ -2878,6 +3051,12 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::RangeContainsElem { rev, .. } => {
                 f.write_str(if *rev { "<@" } else { "@>" })
             }
+            BinaryFunc::TextSearchMatch => f.write_str("@@"),
+            BinaryFunc::AgeTimestamp => f.write_str("age"),
+            BinaryFunc::AgeTimestampTz => f.write_str("age"),
+            BinaryFunc::ByteaConcat => f.write_str("||"),
+            BinaryFunc::GetBit => f.write_str("get_bit"),
+            BinaryFunc::PositionBytes => f.write_str("position"),
         }
     }
 }
@@ -3079,6 +3258,12 @@ impl Arbitrary for BinaryFunc {
             (bool::arbitrary(), mz_repr::arb_range_type())
                 .prop_map(|(rev, elem_type)| BinaryFunc::RangeContainsElem { elem_type, rev })
                 .boxed(),
+            Just(BinaryFunc::TextSearchMatch).boxed(),
+            Just(BinaryFunc::AgeTimestamp).boxed(),
+            Just(BinaryFunc::AgeTimestampTz).boxed(),
+            Just(BinaryFunc::ByteaConcat).boxed(),
+            Just(BinaryFunc::GetBit).boxed(),
+            Just(BinaryFunc::PositionBytes).boxed(),
         ])
     }
 }
@@ -3261,6 +3446,12 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                     rev: *rev,
                 },
             ),
+            BinaryFunc::TextSearchMatch => TextSearchMatch(()),
+            BinaryFunc::AgeTimestamp => AgeTimestamp(()),
+            BinaryFunc::AgeTimestampTz => AgeTimestampTz(()),
+            BinaryFunc::ByteaConcat => ByteaConcat(()),
+            BinaryFunc::GetBit => GetBit(()),
+            BinaryFunc::PositionBytes => PositionBytes(()),
         };
         ProtoBinaryFunc { kind: Some(kind) }
     }
@@ -3449,6 +3640,12 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                         .into_rust_if_some("ProtoRangeContainsElemInner::elem_type")?,
                     rev: inner.rev,
                 }),
+                TextSearchMatch(()) => Ok(BinaryFunc::TextSearchMatch),
+                AgeTimestamp(()) => Ok(BinaryFunc::AgeTimestamp),
+                AgeTimestampTz(()) => Ok(BinaryFunc::AgeTimestampTz),
+                ByteaConcat(()) => Ok(BinaryFunc::ByteaConcat),
+                GetBit(()) => Ok(BinaryFunc::GetBit),
+                PositionBytes(()) => Ok(BinaryFunc::PositionBytes),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoBinaryFunc::kind"))
@@ -3749,6 +3946,8 @@ derive_unary!(
     CastStringToInterval,
     CastStringToNumeric,
     CastStringToUuid,
+    CastStringToPoint,
+    CastStringToBoundingBox,
     CastStringToChar,
     PadChar,
     CastStringToVarChar,
@@ -3783,6 +3982,8 @@ derive_unary!(
     CastJsonbToNumeric,
     CastJsonbToBool,
     CastUuidToString,
+    CastPointToString,
+    CastBoundingBoxToString,
     CastRecordToString,
     CastRecord1ToRecord2,
     CastArrayToString,
@@ -3878,7 +4079,9 @@ derive_unary!(
     RangeLowerInc,
     RangeUpperInc,
     RangeLowerInf,
-    RangeUpperInf
+    RangeUpperInf,
+    Xxhash64Bytes,
+    Xxhash64String
 );
 
 impl UnaryFunc {
@@ -4112,6 +4315,8 @@ impl Arbitrary for UnaryFunc {
             CastStringToInterval::arbitrary().prop_map_into().boxed(),
             CastStringToNumeric::arbitrary().prop_map_into().boxed(),
             CastStringToUuid::arbitrary().prop_map_into().boxed(),
+            CastStringToPoint::arbitrary().prop_map_into().boxed(),
+            CastStringToBoundingBox::arbitrary().prop_map_into().boxed(),
             CastStringToChar::arbitrary().prop_map_into().boxed(),
             PadChar::arbitrary().prop_map_into().boxed(),
             CastStringToVarChar::arbitrary().prop_map_into().boxed(),
@@ -4152,6 +4357,8 @@ impl Arbitrary for UnaryFunc {
             CastJsonbToNumeric::arbitrary().prop_map_into().boxed(),
             CastJsonbToBool::arbitrary().prop_map_into().boxed(),
             CastUuidToString::arbitrary().prop_map_into().boxed(),
+            CastPointToString::arbitrary().prop_map_into().boxed(),
+            CastBoundingBoxToString::arbitrary().prop_map_into().boxed(),
             CastRecordToString::arbitrary().prop_map_into().boxed(),
             (
                 any::<ScalarType>(),
@@ -4270,6 +4477,8 @@ impl Arbitrary for UnaryFunc {
             RangeUpperInc::arbitrary().prop_map_into().boxed(),
             RangeLowerInf::arbitrary().prop_map_into().boxed(),
             RangeUpperInf::arbitrary().prop_map_into().boxed(),
+            Xxhash64Bytes::arbitrary().prop_map_into().boxed(),
+            Xxhash64String::arbitrary().prop_map_into().boxed(),
         ])
     }
 }
@@ -4449,6 +4658,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CastStringToInterval(_) => CastStringToInterval(()),
             UnaryFunc::CastStringToNumeric(func) => CastStringToNumeric(func.0.into_proto()),
             UnaryFunc::CastStringToUuid(_) => CastStringToUuid(()),
+            UnaryFunc::CastStringToPoint(_) => CastStringToPoint(()),
+            UnaryFunc::CastStringToBoundingBox(_) => CastStringToBoundingBox(()),
             UnaryFunc::CastStringToChar(func) => CastStringToChar(ProtoCastStringToChar {
                 length: func.length.into_proto(),
                 fail_on_len: func.fail_on_len,
@@ -4491,6 +4702,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CastJsonbToNumeric(func) => CastJsonbToNumeric(func.0.into_proto()),
             UnaryFunc::CastJsonbToBool(_) => CastJsonbToBool(()),
             UnaryFunc::CastUuidToString(_) => CastUuidToString(()),
+            UnaryFunc::CastPointToString(_) => CastPointToString(()),
+            UnaryFunc::CastBoundingBoxToString(_) => CastBoundingBoxToString(()),
             UnaryFunc::CastRecordToString(func) => CastRecordToString(func.ty.into_proto()),
             UnaryFunc::CastRecord1ToRecord2(inner) => {
                 CastRecord1ToRecord2(ProtoCastRecord1ToRecord2 {
@@ -4609,6 +4822,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::RangeUpperInc(_) => RangeUpperInc(()),
             UnaryFunc::RangeLowerInf(_) => RangeLowerInf(()),
             UnaryFunc::RangeUpperInf(_) => RangeUpperInf(()),
+            UnaryFunc::Xxhash64Bytes(_) => Xxhash64Bytes(()),
+            UnaryFunc::Xxhash64String(_) => Xxhash64String(()),
         };
         ProtoUnaryFunc { kind: Some(kind) }
     }
@@ -4817,6 +5032,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                     Ok(impls::CastStringToNumeric(max_scale.into_rust()?).into())
                 }
                 CastStringToUuid(()) => Ok(impls::CastStringToUuid.into()),
+                CastStringToPoint(()) => Ok(impls::CastStringToPoint.into()),
+                CastStringToBoundingBox(()) => Ok(impls::CastStringToBoundingBox.into()),
                 CastStringToChar(func) => Ok(impls::CastStringToChar {
                     length: func.length.into_rust()?,
                     fail_on_len: func.fail_on_len,
@@ -4864,6 +5081,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 }
                 CastJsonbToBool(()) => Ok(impls::CastJsonbToBool.into()),
                 CastUuidToString(()) => Ok(impls::CastUuidToString.into()),
+                CastPointToString(()) => Ok(impls::CastPointToString.into()),
+                CastBoundingBoxToString(()) => Ok(impls::CastBoundingBoxToString.into()),
                 CastRecordToString(ty) => Ok(impls::CastRecordToString {
                     ty: ty.into_rust()?,
                 }
@@ -5015,6 +5234,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 RangeUpperInc(_) => Ok(impls::RangeUpperInc.into()),
                 RangeLowerInf(_) => Ok(impls::RangeLowerInf.into()),
                 RangeUpperInf(_) => Ok(impls::RangeUpperInf.into()),
+                Xxhash64Bytes(_) => Ok(impls::Xxhash64Bytes.into()),
+                Xxhash64String(_) => Ok(impls::Xxhash64String.into()),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoUnaryFunc::kind"))
@@ -5332,6 +5553,63 @@ pub fn hmac_string<'a>(
     hmac_inner(to_digest, key, typ, temp_storage)
 }
 
+fn substr_bytes<'a>(datums: &[Datum<'a>]) -> Result<Datum<'a>, EvalError> {
+    let bytes: &'a [u8] = datums[0].unwrap_bytes();
+    let start = datums[1].unwrap_int64();
+    // Positions are 1-based, and positions before the first byte are allowed,
+    // but count against the length.
+    let end = match datums.get(2) {
+        Some(length) => match length.unwrap_int64() {
+            l if l < 0 => {
+                return Err(EvalError::InvalidParameterValue(
+                    "negative substring length not allowed".to_owned(),
+                ))
+            }
+            l => Some(start.saturating_add(l).saturating_sub(1)),
+        },
+        None => None,
+    };
+    let clamp = |i: i64| {
+        usize::try_from(i.max(0))
+            .unwrap_or(usize::MAX)
+            .min(bytes.len())
+    };
+    let start = clamp(start.saturating_sub(1));
+    let end = end.map_or(bytes.len(), clamp).max(start);
+    Ok(Datum::Bytes(&bytes[start..end]))
+}
+
+fn set_byte<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Result<Datum<'a>, EvalError> {
+    let mut bytes = datums[0].unwrap_bytes().to_vec();
+    let index = datums[1].unwrap_int32();
+    let err = EvalError::IndexOutOfRange {
+        provided: index,
+        valid_end: i32::try_from(bytes.len()).unwrap_or(i32::MAX) - 1,
+    };
+    let byte = usize::try_from(index)
+        .ok()
+        .and_then(|index| bytes.get_mut(index))
+        .ok_or(err)?;
+    // Like PostgreSQL, only the low 8 bits of the new value are used.
+    *byte = datums[2].unwrap_int32().to_le_bytes()[0];
+    Ok(Datum::Bytes(temp_storage.push_bytes(bytes)))
+}
+
+fn set_bit<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Result<Datum<'a>, EvalError> {
+    let mut bytes = datums[0].unwrap_bytes().to_vec();
+    let (byte, mask) = bit_position(&bytes, datums[1].unwrap_int64())?;
+    match datums[2].unwrap_int32() {
+        0 => bytes[byte] &= !mask,
+        1 => bytes[byte] |= mask,
+        _ => {
+            return Err(EvalError::InvalidParameterValue(
+                "new bit must be 0 or 1".to_owned(),
+            ))
+        }
+    }
+    Ok(Datum::Bytes(temp_storage.push_bytes(bytes)))
+}
+
 pub fn hmac_bytes<'a>(
     datums: &[Datum<'a>],
     temp_storage: &'a RowArena,
@@ -5572,6 +5850,10 @@ where
         }
         Jsonb => Ok(strconv::format_jsonb(buf, JsonbRef::from_datum(d))),
         Uuid => Ok(strconv::format_uuid(buf, d.unwrap_uuid())),
+        // Spelled out in full, as `Point` and `BoundingBox` would otherwise
+        // resolve to the types from `mz_repr::adt::geo`.
+        ScalarType::Point => Ok(strconv::format_point(buf, d.unwrap_point())),
+        ScalarType::BoundingBox => Ok(strconv::format_bounding_box(buf, d.unwrap_bounding_box())),
         Record { fields, .. } => {
             let mut fields = fields.iter();
             strconv::format_record(buf, &d.unwrap_list(), |buf, d| {
@@ -5735,6 +6017,40 @@ fn list_slice_linear<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Da
     })
 }
 
+fn geo_point_create<'a>(datums: &[Datum<'a>]) -> Datum<'a> {
+    Datum::from(Point::new(
+        datums[0].unwrap_float64(),
+        datums[1].unwrap_float64(),
+    ))
+}
+
+fn geo_box_create<'a>(datums: &[Datum<'a>]) -> Datum<'a> {
+    Datum::from(BoundingBox::new(
+        datums[0].unwrap_point(),
+        datums[1].unwrap_point(),
+    ))
+}
+
+fn geo_distance<'a>(datums: &[Datum<'a>]) -> Datum<'a> {
+    let a = datums[0].unwrap_point();
+    let b = datums[1].unwrap_point();
+    Datum::from(a.distance(&b))
+}
+
+fn geo_distance_sphere<'a>(datums: &[Datum<'a>]) -> Result<Datum<'a>, EvalError> {
+    let a = datums[0].unwrap_point();
+    let b = datums[1].unwrap_point();
+    match a.distance_sphere(&b) {
+        Ok(d) => Ok(Datum::from(d)),
+        Err(e) => Err(EvalError::InvalidParameterValue(e.to_string())),
+    }
+}
+
+fn geo_box_contains<'a>(datums: &[Datum<'a>]) -> Datum<'a> {
+    let bbox = datums[0].unwrap_bounding_box();
+    Datum::from(bbox.contains(&datums[1].unwrap_point()))
+}
+
 fn create_range<'a>(
     datums: &[Datum<'a>],
     temp_storage: &'a RowArena,
@@ -6267,6 +6583,14 @@ pub enum VariadicFunc {
     RangeCreate {
         elem_type: ScalarType,
     },
+    GeoDistance,
+    GeoDistanceSphere,
+    GeoBoxContains,
+    SubstrBytes,
+    SetByte,
+    SetBit,
+    GeoPointCreate,
+    GeoBoxCreate,
 }
 
 impl VariadicFunc {
@@ -6331,6 +6655,14 @@ impl VariadicFunc {
             VariadicFunc::And => and(datums, temp_storage, exprs),
             VariadicFunc::Or => or(datums, temp_storage, exprs),
             VariadicFunc::RangeCreate { .. } => eager!(create_range, temp_storage),
+            VariadicFunc::GeoDistance => Ok(eager!(geo_distance)),
+            VariadicFunc::GeoDistanceSphere => eager!(geo_distance_sphere),
+            VariadicFunc::GeoBoxContains => Ok(eager!(geo_box_contains)),
+            VariadicFunc::SubstrBytes => eager!(substr_bytes),
+            VariadicFunc::SetByte => eager!(set_byte, temp_storage),
+            VariadicFunc::SetBit => eager!(set_bit, temp_storage),
+            VariadicFunc::GeoPointCreate => Ok(eager!(geo_point_create)),
+            VariadicFunc::GeoBoxCreate => Ok(eager!(geo_box_create)),
         }
     }
 
@@ -6363,7 +6695,15 @@ impl VariadicFunc {
             | VariadicFunc::ErrorIfNull
             | VariadicFunc::DateBinTimestamp
             | VariadicFunc::DateBinTimestampTz
-            | VariadicFunc::RangeCreate { .. } => false,
+            | VariadicFunc::RangeCreate { .. }
+            | VariadicFunc::GeoDistance
+            | VariadicFunc::GeoDistanceSphere
+            | VariadicFunc::GeoBoxContains
+            | VariadicFunc::SubstrBytes
+            | VariadicFunc::SetByte
+            | VariadicFunc::SetBit
+            | VariadicFunc::GeoPointCreate
+            | VariadicFunc::GeoBoxCreate => false,
         }
     }
 
@@ -6436,6 +6776,11 @@ impl VariadicFunc {
                 element_type: Box::new(elem_type.clone()),
             }
             .nullable(in_nullable),
+            GeoDistance | GeoDistanceSphere => ScalarType::Float64.nullable(in_nullable),
+            GeoBoxContains => ScalarType::Bool.nullable(in_nullable),
+            SubstrBytes | SetByte | SetBit => ScalarType::Bytes.nullable(in_nullable),
+            GeoPointCreate => ScalarType::Point.nullable(in_nullable),
+            GeoBoxCreate => ScalarType::BoundingBox.nullable(in_nullable),
         }
     }
 
@@ -6472,7 +6817,12 @@ impl VariadicFunc {
         // Note the negation
         !matches!(
             self,
-            And | Or //todo: add more
+            And | Or
+                | GeoDistance
+                | GeoDistanceSphere
+                | GeoBoxContains
+                | GeoPointCreate
+                | GeoBoxCreate //todo: add more
         )
     }
 
@@ -6555,6 +6905,14 @@ impl fmt::Display for VariadicFunc {
                 ScalarType::Numeric { .. } => "numrange",
                 _ => unreachable!(),
             }),
+            VariadicFunc::GeoDistance => f.write_str("st_distance"),
+            VariadicFunc::GeoDistanceSphere => f.write_str("st_distance_sphere"),
+            VariadicFunc::GeoBoxContains => f.write_str("st_box_contains"),
+            VariadicFunc::GeoPointCreate => f.write_str("point"),
+            VariadicFunc::GeoBoxCreate => f.write_str("box"),
+            VariadicFunc::SubstrBytes => f.write_str("substr"),
+            VariadicFunc::SetByte => f.write_str("set_byte"),
+            VariadicFunc::SetBit => f.write_str("set_bit"),
         }
     }
 }
@@ -6611,6 +6969,14 @@ impl Arbitrary for VariadicFunc {
             mz_repr::arb_range_type()
                 .prop_map(|elem_type| VariadicFunc::RangeCreate { elem_type })
                 .boxed(),
+            Just(VariadicFunc::GeoDistance).boxed(),
+            Just(VariadicFunc::GeoDistanceSphere).boxed(),
+            Just(VariadicFunc::GeoBoxContains).boxed(),
+            Just(VariadicFunc::SubstrBytes).boxed(),
+            Just(VariadicFunc::SetByte).boxed(),
+            Just(VariadicFunc::SetBit).boxed(),
+            Just(VariadicFunc::GeoPointCreate).boxed(),
+            Just(VariadicFunc::GeoBoxCreate).boxed(),
         ])
     }
 }
@@ -6649,6 +7015,14 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::And => And(()),
             VariadicFunc::Or => Or(()),
             VariadicFunc::RangeCreate { elem_type } => RangeCreate(elem_type.into_proto()),
+            VariadicFunc::GeoDistance => GeoDistance(()),
+            VariadicFunc::GeoDistanceSphere => GeoDistanceSphere(()),
+            VariadicFunc::GeoBoxContains => GeoBoxContains(()),
+            VariadicFunc::SubstrBytes => SubstrBytes(()),
+            VariadicFunc::SetByte => SetByte(()),
+            VariadicFunc::SetBit => SetBit(()),
+            VariadicFunc::GeoPointCreate => GeoPointCreate(()),
+            VariadicFunc::GeoBoxCreate => GeoBoxCreate(()),
         };
         ProtoVariadicFunc { kind: Some(kind) }
     }
@@ -6697,6 +7071,14 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                 RangeCreate(elem_type) => Ok(VariadicFunc::RangeCreate {
                     elem_type: elem_type.into_rust()?,
                 }),
+                GeoDistance(()) => Ok(VariadicFunc::GeoDistance),
+                GeoDistanceSphere(()) => Ok(VariadicFunc::GeoDistanceSphere),
+                GeoBoxContains(()) => Ok(VariadicFunc::GeoBoxContains),
+                SubstrBytes(()) => Ok(VariadicFunc::SubstrBytes),
+                SetByte(()) => Ok(VariadicFunc::SetByte),
+                SetBit(()) => Ok(VariadicFunc::SetBit),
+                GeoPointCreate(()) => Ok(VariadicFunc::GeoPointCreate),
+                GeoBoxCreate(()) => Ok(VariadicFunc::GeoBoxCreate),
             }
         } else {
             Err(TryFromProtoError::missing_field(
@@ -15,6 +15,7 @@ mod date;
 mod datum;
 mod float32;
 mod float64;
+mod geo;
 mod int16;
 mod int2vector;
 mod int32;
@@ -48,6 +49,7 @@ pub use date::*;
 pub use datum::*;
 pub use float32::*;
 pub use float64::*;
+pub use geo::*;
 pub use int16::*;
 pub use int2vector::*;
 pub use int32::*;
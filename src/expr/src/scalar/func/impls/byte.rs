@@ -7,6 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::hash::Hasher;
+
+use twox_hash::XxHash64;
+
 use mz_repr::strconv;
 
 use crate::EvalError;
@@ -35,3 +39,19 @@ sqlfunc!(
         i32::try_from(a.len()).or(Err(EvalError::Int32OutOfRange))
     }
 );
+
+/// Computes the 64-bit xxHash of `a` with a seed of zero, which is the value
+/// that other implementations of the hash return by default, reinterpreted as
+/// a signed integer.
+pub(super) fn xxhash64(a: &[u8]) -> i64 {
+    let mut hasher = XxHash64::with_seed(0);
+    hasher.write(a);
+    i64::from_ne_bytes(hasher.finish().to_ne_bytes())
+}
+
+sqlfunc!(
+    #[sqlname = "xxhash64"]
+    fn xxhash64_bytes<'a>(a: &'a [u8]) -> i64 {
+        xxhash64(a)
+    }
+);
@@ -0,0 +1,33 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_repr::adt::geo::{BoundingBox, Point};
+use mz_repr::strconv;
+
+sqlfunc!(
+    #[sqlname = "point_to_text"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastStringToPoint)]
+    fn cast_point_to_string(p: Point) -> String {
+        let mut buf = String::new();
+        strconv::format_point(&mut buf, p);
+        buf
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "box_to_text"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastStringToBoundingBox)]
+    fn cast_bounding_box_to_string(b: BoundingBox) -> String {
+        let mut buf = String::new();
+        strconv::format_bounding_box(&mut buf, b);
+        buf
+    }
+);
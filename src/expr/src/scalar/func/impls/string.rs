@@ -23,6 +23,7 @@ use mz_ore::str::StrExt;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char::{format_str_trim, Char};
 use mz_repr::adt::date::Date;
+use mz_repr::adt::geo::{BoundingBox, Point};
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::Jsonb;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
@@ -235,6 +236,24 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "text_to_point"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastPointToString)]
+    fn cast_string_to_point<'a>(a: &'a str) -> Result<Point, EvalError> {
+        strconv::parse_point(a).err_into()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "text_to_box"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastBoundingBoxToString)]
+    fn cast_string_to_bounding_box<'a>(a: &'a str) -> Result<BoundingBox, EvalError> {
+        strconv::parse_bounding_box(a).err_into()
+    }
+);
+
 #[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
 pub struct CastStringToArray {
     // Target array's type.
@@ -748,6 +767,13 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "xxhash64"]
+    fn xxhash64_string<'a>(a: &'a str) -> i64 {
+        super::byte::xxhash64(a.as_bytes())
+    }
+);
+
 sqlfunc!(
     fn upper<'a>(a: &'a str) -> String {
         a.to_uppercase()
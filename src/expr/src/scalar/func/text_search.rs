@@ -0,0 +1,72 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Basic full-text search support.
+//!
+//! This is deliberately much simpler than PostgreSQL's `tsvector`/`tsquery`
+//! machinery: text is split into lowercase alphanumeric tokens, with no
+//! stemming, stop words, or positional information. That is enough for the
+//! common case of keyword filters over log and event data, and maps naturally
+//! onto an inverted index built by arranging the output of the `tokenize`
+//! table function by token.
+
+use std::collections::BTreeSet;
+
+/// Splits `text` into its distinct tokens.
+///
+/// A token is a maximal run of alphanumeric characters, folded to lowercase.
+/// The returned set is ordered, so iterating it produces tokens in a
+/// deterministic order.
+pub fn tokenize(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+/// Reports whether `document` contains every token of `query`.
+///
+/// A query that contains no tokens matches no documents, which mirrors
+/// PostgreSQL's behavior for queries that consist only of stop words.
+pub fn matches(document: &str, query: &str) -> bool {
+    let query = tokenize(query);
+    if query.is_empty() {
+        return false;
+    }
+    let document = tokenize(document);
+    query.is_subset(&document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = tokenize("GET /api/v1/Users?id=42 -- user-agent: curl/7.1");
+        let expected = [
+            "1", "42", "7", "agent", "api", "curl", "get", "id", "user", "users", "v1",
+        ];
+        assert_eq!(
+            tokens.iter().map(String::as_str).collect::<Vec<_>>(),
+            expected
+        );
+        assert!(tokenize("").is_empty());
+        assert!(tokenize(" -- !! ").is_empty());
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("Connection reset by peer", "RESET peer"));
+        assert!(matches("Connection reset by peer", "peer, reset"));
+        assert!(!matches("Connection reset by peer", "reset timeout"));
+        assert!(!matches("Connection reset by peer", ""));
+        assert!(!matches("", "reset"));
+    }
+}
@@ -21,7 +21,9 @@ pub use self::encode::{
     encode_datums_as_avro, encode_debezium_transaction_unchecked, get_debezium_transaction_schema,
     AvroEncoder, AvroSchemaGenerator,
 };
-pub use self::schema::{parse_schema, schema_to_relationdesc, ConfluentAvroResolver};
+pub use self::schema::{
+    parse_schema, schema_to_relationdesc, ConfluentAvroResolver, SchemaResolutionError,
+};
 
 fn is_null(schema: &SchemaPieceOrNamed) -> bool {
     matches!(schema, SchemaPieceOrNamed::Piece(SchemaPiece::Null))
@@ -75,6 +77,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    /// Test that the generated schemas annotate numerics, dates and timestamps
+    /// with Avro logical types, so that consumers decode them back into the
+    /// same SQL types.
+    fn logical_types_round_trip() -> anyhow::Result<()> {
+        let desc = RelationDesc::empty()
+            .with_column(
+                "n",
+                ScalarType::Numeric {
+                    max_scale: Some(NumericMaxScale::try_from(2_i64)?),
+                }
+                .nullable(false),
+            )
+            .with_column("d", ScalarType::Date.nullable(false))
+            .with_column("ts", ScalarType::Timestamp.nullable(true));
+        let schema_generator =
+            AvroSchemaGenerator::new(None, None, None, desc.clone(), None).unwrap();
+
+        let schema = serde_json::to_value(schema_generator.value_writer_schema())?;
+        let fields = schema["fields"].as_array().unwrap();
+        assert_eq!(fields[0]["type"]["logicalType"], "decimal");
+        assert_eq!(fields[0]["type"]["precision"], 39);
+        assert_eq!(fields[0]["type"]["scale"], 2);
+        assert_eq!(fields[1]["type"]["logicalType"], "date");
+        assert_eq!(fields[2]["type"][1]["logicalType"], "timestamp-micros");
+
+        let decoded = schema_to_relationdesc(schema_generator.value_writer_schema().clone())?;
+        assert_eq!(decoded, desc);
+        Ok(())
+    }
+
     #[test]
     /// Test that primitive Avro Schema types are allow Datums to be correctly
     /// serialized into Avro Values.
@@ -165,7 +198,7 @@ mod tests {
         ];
         for (typ, datum, expected) in valid_pairings {
             let desc = RelationDesc::empty().with_column("column1", typ.nullable(false));
-            let schema_generator = AvroSchemaGenerator::new(None, None, None, desc, false).unwrap();
+            let schema_generator = AvroSchemaGenerator::new(None, None, None, desc, None).unwrap();
             let avro_value =
                 encode_datums_as_avro(std::iter::once(datum), schema_generator.value_columns());
             assert_eq!(
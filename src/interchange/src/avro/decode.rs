@@ -44,6 +44,8 @@ pub struct Decoder<C> {
 
 #[cfg(test)]
 mod tests {
+    use chrono::NaiveDateTime;
+    use mz_repr::adt::timestamp::CheckedTimestamp;
     use mz_repr::{Datum, Row};
 
     use crate::avro::Decoder;
@@ -56,7 +58,8 @@ mod tests {
 "fields": [{"name": "f1", "type": "int"}, {"name": "f2", "type": "int"}]
 }"#;
         let mut decoder =
-            Decoder::<Box<mz_ccsr::Client>>::new(schema, None, "Test".to_string(), false).unwrap();
+            Decoder::<Box<mz_ccsr::Client>>::new(schema, None, None, "Test".to_string(), false)
+                .unwrap();
         // This is not a valid Avro blob for the given schema
         let mut bad_bytes: &[u8] = &[0];
         assert!(decoder.decode(&mut bad_bytes).await.is_err());
@@ -68,6 +71,31 @@ mod tests {
             Row::pack([Datum::Int32(0), Datum::Int32(0)])
         );
     }
+
+    #[tokio::test]
+    async fn test_timestamp_logical_types() {
+        let schema = r#"{
+"type": "record",
+"name": "test",
+"fields": [
+    {"name": "ms", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+    {"name": "us", "type": {"type": "long", "logicalType": "timestamp-micros"}}
+]
+}"#;
+        let mut decoder =
+            Decoder::<Box<mz_ccsr::Client>>::new(schema, None, None, "Test".to_string(), false)
+                .unwrap();
+        // 1500 milliseconds and 1500000 microseconds after the epoch.
+        let mut bytes: &[u8] = &[184, 23, 192, 141, 183, 1];
+        let ts = CheckedTimestamp::from_timestamplike(
+            NaiveDateTime::from_timestamp_opt(1, 500_000_000).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            decoder.decode(&mut bytes).await.unwrap(),
+            Row::pack([Datum::Timestamp(ts), Datum::Timestamp(ts)])
+        );
+    }
 }
 
 impl<C: Deref<Target = mz_ccsr::Client>> Decoder<C> {
@@ -76,14 +104,22 @@ impl<C: Deref<Target = mz_ccsr::Client>> Decoder<C> {
     /// The provided schema is called the "reader schema", which is the schema
     /// that we are expecting to use to decode records. The records may indicate
     /// that they are encoded with a different schema; as long as those.
+    ///
+    /// Without a schema registry, records are assumed to be encoded with
+    /// `writer_schema`, if provided, and with the reader schema otherwise.
     pub fn new(
         reader_schema: &str,
+        writer_schema: Option<&str>,
         ccsr_client: Option<C>,
         debug_name: String,
         confluent_wire_format: bool,
     ) -> anyhow::Result<Decoder<C>> {
-        let csr_avro =
-            ConfluentAvroResolver::new(reader_schema, ccsr_client, confluent_wire_format)?;
+        let csr_avro = ConfluentAvroResolver::new(
+            reader_schema,
+            writer_schema,
+            ccsr_client,
+            confluent_wire_format,
+        )?;
 
         Ok(Decoder {
             csr_avro,
@@ -21,10 +21,10 @@ use mz_avro::Schema;
 use mz_ore::cast::CastFrom;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{self, NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
-use mz_repr::{ColumnName, ColumnType, Datum, RelationDesc, Row, ScalarType};
+use mz_repr::{strconv, ColumnName, ColumnType, Datum, RelationDesc, Row, ScalarType};
 
 use crate::encode::{column_names_and_types, Encode, TypedDatum};
-use crate::envelopes::{self, ENVELOPE_CUSTOM_NAMES};
+use crate::envelopes::{self, DbzEnvelope, ENVELOPE_CUSTOM_NAMES};
 use crate::json::build_row_schema_json;
 
 // TODO(rkhaitan): this schema intentionally omits the data_collections field
@@ -124,11 +124,11 @@ impl AvroSchemaGenerator {
         value_fullname: Option<&str>,
         key_desc: Option<RelationDesc>,
         value_desc: RelationDesc,
-        debezium: bool,
+        debezium: Option<DbzEnvelope>,
     ) -> Result<Self, anyhow::Error> {
         let mut value_columns = column_names_and_types(value_desc);
-        if debezium {
-            value_columns = envelopes::dbz_envelope(value_columns);
+        if let Some(envelope) = debezium {
+            value_columns = envelopes::dbz_envelope(value_columns, envelope);
         }
         let row_schema = build_row_schema_json(
             &value_columns,
@@ -345,6 +345,16 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                 }
                 ScalarType::Jsonb => Value::Json(JsonbRef::from_datum(datum).to_serde_json()),
                 ScalarType::Uuid => Value::Uuid(datum.unwrap_uuid()),
+                ScalarType::Point => {
+                    let mut buf = String::new();
+                    strconv::format_point(&mut buf, datum.unwrap_point());
+                    Value::String(buf)
+                }
+                ScalarType::BoundingBox => {
+                    let mut buf = String::new();
+                    strconv::format_bounding_box(&mut buf, datum.unwrap_bounding_box());
+                    Value::String(buf)
+                }
                 ty @ (ScalarType::Array(..) | ScalarType::Int2Vector | ScalarType::List { .. }) => {
                     let list = match ty {
                         ScalarType::Array(_) | ScalarType::Int2Vector => {
@@ -293,20 +293,34 @@ fn validate_schema_2(
 
 pub struct ConfluentAvroResolver<C> {
     reader_schema: Schema,
+    /// The schema to decode records with when there is no schema registry to
+    /// look up their writer's schema in.
+    default_schema: Schema,
     writer_schemas: Option<SchemaCache<C>>,
     confluent_wire_format: bool,
 }
 
 impl<C: Deref<Target = mz_ccsr::Client>> ConfluentAvroResolver<C> {
+    /// Creates a new resolver.
+    ///
+    /// `writer_schema` is the schema that records were written with, if it
+    /// differs from `reader_schema` and the records are not resolved against
+    /// the schemas in a schema registry.
     pub fn new(
         reader_schema: &str,
+        writer_schema: Option<&str>,
         ccsr_client: Option<C>,
         confluent_wire_format: bool,
     ) -> anyhow::Result<Self> {
         let reader_schema = parse_schema(reader_schema)?;
+        let default_schema = match writer_schema {
+            Some(writer_schema) => resolve_schemas(&parse_schema(writer_schema)?, &reader_schema)?,
+            None => reader_schema.clone(),
+        };
         let writer_schemas = ccsr_client.map(SchemaCache::new).transpose()?;
         Ok(Self {
             reader_schema,
+            default_schema,
             writer_schemas,
             confluent_wire_format,
         })
@@ -326,25 +340,21 @@ impl<C: Deref<Target = mz_ccsr::Client>> ConfluentAvroResolver<C> {
                 // XXX(guswynn): use destructuring assignments when they are stable
                 let (schema_id, adjusted_bytes) = crate::confluent::extract_avro_header(bytes)?;
                 bytes = adjusted_bytes;
-                let schema = cache
-                    .get(schema_id, &self.reader_schema)
-                    .await
-                    .with_context(|| {
-                        format!("failed to resolve Avro schema (id = {})", schema_id)
-                    })?;
+                let schema = cache.get(schema_id, &self.reader_schema).await?;
                 (schema, Some(schema_id))
             }
 
             // If we haven't been asked to use a schema registry, we have no way
             // to discover the writer's schema. That's ok; we'll just use the
-            // reader's schema and hope it lines up.
+            // writer's schema we were given, or the reader's schema, and hope
+            // it lines up.
             None => {
                 if self.confluent_wire_format {
                     // validate and just move the bytes buffer ahead
                     let (_, adjusted_bytes) = crate::confluent::extract_avro_header(bytes)?;
                     bytes = adjusted_bytes;
                 }
-                (&self.reader_schema, None)
+                (&self.default_schema, None)
             }
         };
         Ok((bytes, resolved_schema, schema_id))
@@ -367,6 +377,30 @@ impl<C> fmt::Debug for ConfluentAvroResolver<C> {
     }
 }
 
+/// An error resolving the schema that a record was written with against the
+/// reader schema.
+///
+/// Unlike errors fetching the writer schema, this error is permanent: all
+/// records written with the schema fail to decode.
+#[derive(Debug)]
+pub struct SchemaResolutionError {
+    /// The ID of the writer schema in the schema registry.
+    pub schema_id: i32,
+    cause: AvroError,
+}
+
+impl fmt::Display for SchemaResolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to resolve Avro schema (id = {})", self.schema_id)
+    }
+}
+
+impl std::error::Error for SchemaResolutionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.cause)
+    }
+}
+
 #[derive(Debug)]
 struct SchemaCache<C> {
     cache: HashMap<i32, Result<Schema, AvroError>>,
@@ -408,7 +442,8 @@ impl<C: Deref<Target = mz_ccsr::Client>> SchemaCache<C> {
                             _ => res,
                         }
                     })
-                    .await?;
+                    .await
+                    .with_context(|| format!("failed to resolve Avro schema (id = {})", id))?;
                 // Now, we've gotten some json back, so we want to cache it (regardless of whether it's a valid
                 // avro schema, it won't change).
                 //
@@ -424,6 +459,11 @@ impl<C: Deref<Target = mz_ccsr::Client>> SchemaCache<C> {
                 v.insert(result)
             }
         };
-        entry.as_ref().map_err(|e| anyhow::Error::new(e.clone()))
+        entry.as_ref().map_err(|e| {
+            anyhow::Error::new(SchemaResolutionError {
+                schema_id: id,
+                cause: e.clone(),
+            })
+        })
     }
 }
@@ -52,21 +52,90 @@ pub fn extract_avro_header(buf: &[u8]) -> Result<(i32, &[u8])> {
     extract_schema_id(buf, "avro")
 }
 
-pub fn extract_protobuf_header(buf: &[u8]) -> Result<(i32, &[u8])> {
-    let (schema_id, buf) = extract_schema_id(buf, "protobuf")?;
+/// Extracts the schema_id and the message indexes placed in front of a serialized Protobuf
+/// message by the confluent stack.
+///
+/// The message indexes are the path of the message's descriptor within the schema: the index of
+/// a top-level message in the `.proto` file, followed by the indexes of any nested messages. They
+/// are encoded as an array of zigzag varints preceded by its length, except that the common case
+/// of the first top-level message is encoded as a single 0 byte.
+///
+/// This function returns the schema_id, the message indexes and a subslice of the rest of the
+/// buffer.
+pub fn extract_protobuf_header(buf: &[u8]) -> Result<(i32, Vec<i32>, &[u8])> {
+    let (schema_id, mut buf) = extract_schema_id(buf, "protobuf")?;
 
-    match buf.get(0) {
-        Some(0) => Ok((schema_id, &buf[1..])),
-        Some(message_id) => bail!(
-            "unsupported Confluent-style protobuf message descriptor id: \
-                expected 0, but found: {}. \
-                See https://github.com/MaterializeInc/materialize/issues/9250",
-            message_id
-        ),
-        None => bail!(
-            "Confluent-style protobuf datum is too few bytes: expected a message id after magic \
-            and schema id, got a buffer of length {}",
-            buf.len()
+    let len = read_zigzag_varint(&mut buf)?;
+    let message_indexes = match len {
+        0 => vec![0],
+        len if len < 0 => bail!(
+            "invalid Confluent-style protobuf message indexes: negative length {}",
+            len
         ),
+        len => {
+            let mut message_indexes = vec![];
+            for _ in 0..len {
+                message_indexes.push(read_zigzag_varint(&mut buf)?);
+            }
+            message_indexes
+        }
+    };
+
+    Ok((schema_id, message_indexes, buf))
+}
+
+/// Reads a zigzag-encoded varint from the front of `buf`, advancing it past the varint.
+fn read_zigzag_varint(buf: &mut &[u8]) -> Result<i32> {
+    let mut value: u32 = 0;
+    // A 32-bit varint is at most five bytes long.
+    for i in 0..5 {
+        let byte = match buf.split_first() {
+            Some((byte, rest)) => {
+                *buf = rest;
+                *byte
+            }
+            None => bail!(
+                "Confluent-style protobuf datum is too few bytes: \
+                expected message indexes after magic and schema id"
+            ),
+        };
+        value |= u32::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            // Undo the zigzag encoding, which maps 0, -1, 1, -2, ... to 0, 1, 2, 3, ...
+            return Ok(((value >> 1) as i32) ^ -((value & 1) as i32));
+        }
+    }
+    bail!("invalid Confluent-style protobuf message indexes: varint is too long")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_protobuf_header() {
+        let header = |buf: &[u8]| {
+            extract_protobuf_header(buf)
+                .map(|(schema_id, message_indexes, rest)| {
+                    (schema_id, message_indexes, rest.to_vec())
+                })
+                .map_err(|e| e.to_string())
+        };
+
+        // The first message is encoded as a single 0 byte.
+        assert_eq!(header(&[0, 0, 0, 0, 7, 0, 42]), Ok((7, vec![0], vec![42])));
+        // Other messages are encoded as a length followed by the indexes.
+        assert_eq!(
+            header(&[0, 0, 0, 1, 0, 4, 2, 0, 42]),
+            Ok((256, vec![1, 0], vec![42]))
+        );
+        assert_eq!(
+            header(&[0, 0, 0, 0, 1, 2, 0xac, 0x02]),
+            Ok((1, vec![150], vec![]))
+        );
+        assert!(header(&[0, 0, 0, 0, 1, 123]).is_err());
+        assert!(header(&[0, 0, 0, 0, 1, 4, 2]).is_err());
+        assert!(header(&[0, 0, 0, 0, 1]).is_err());
+        assert!(header(&[1, 0, 0, 0, 1, 0]).is_err());
     }
 }
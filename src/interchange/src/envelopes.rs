@@ -125,16 +125,29 @@ where
 // the sink is created.
 pub(crate) const TRANSACTION_TYPE_ID: GlobalId = GlobalId::Transient(1);
 pub(crate) const DBZ_ROW_TYPE_ID: GlobalId = GlobalId::Transient(2);
+pub(crate) const DBZ_SOURCE_TYPE_ID: GlobalId = GlobalId::Transient(3);
 
 pub static ENVELOPE_CUSTOM_NAMES: Lazy<HashMap<GlobalId, String>> = Lazy::new(|| {
     hashmap! {
         TRANSACTION_TYPE_ID => "transaction".into(),
         DBZ_ROW_TYPE_ID => "row".into(),
+        DBZ_SOURCE_TYPE_ID => "source".into(),
     }
 });
 
+/// The flavors of Debezium envelope that sinks can wrap their values in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbzEnvelope {
+    /// Only the `before` and `after` fields.
+    Plain,
+    /// The `before` and `after` fields, followed by the `source`, `op` and
+    /// `ts_ms` fields of the envelope that Debezium itself emits.
+    Full,
+}
+
 pub(crate) fn dbz_envelope(
     names_and_types: Vec<(ColumnName, ColumnType)>,
+    envelope: DbzEnvelope,
 ) -> Vec<(ColumnName, ColumnType)> {
     let row = ColumnType {
         nullable: true,
@@ -143,7 +156,30 @@ pub(crate) fn dbz_envelope(
             custom_id: Some(DBZ_ROW_TYPE_ID),
         },
     };
-    vec![("before".into(), row.clone()), ("after".into(), row)]
+    let mut columns = vec![("before".into(), row.clone()), ("after".into(), row)];
+    if envelope == DbzEnvelope::Full {
+        let string = ScalarType::String.nullable(false);
+        let int64 = ScalarType::Int64.nullable(false);
+        let source = ScalarType::Record {
+            fields: vec![
+                ("version".into(), string.clone()),
+                ("connector".into(), string.clone()),
+                ("name".into(), string.clone()),
+                ("ts_ms".into(), int64.clone()),
+                ("snapshot".into(), string.clone()),
+                ("db".into(), string.clone()),
+                ("schema".into(), string.clone()),
+                ("table".into(), string.clone()),
+            ],
+            custom_id: Some(DBZ_SOURCE_TYPE_ID),
+        };
+        columns.extend([
+            ("source".into(), source.nullable(false)),
+            ("op".into(), string),
+            ("ts_ms".into(), int64),
+        ]);
+    }
+    columns
 }
 
 pub fn dbz_format(rp: &mut RowPacker, dp: DiffPair<Row>) {
@@ -159,6 +195,58 @@ pub fn dbz_format(rp: &mut RowPacker, dp: DiffPair<Row>) {
     }
 }
 
+/// The contents of the `source` field of a full Debezium envelope, which
+/// describes where an update originated.
+#[derive(Debug, Clone, Copy)]
+pub struct DbzSource<'a> {
+    /// The version of Materialize that emitted the update.
+    pub version: &'a str,
+    /// The name of the sink that emitted the update.
+    pub name: &'a str,
+    /// The database, schema and name of the relation that the sink exports.
+    pub db: &'a str,
+    pub schema: &'a str,
+    pub table: &'a str,
+}
+
+/// Like [`dbz_format`], but additionally packs the `source`, `op` and `ts_ms`
+/// fields of a full Debezium envelope describing an update that happened at
+/// `ts_ms`.
+///
+/// Updates that are part of the initial snapshot of a sink are reported as
+/// reads (`r`); all other updates as creates (`c`), updates (`u`) or deletes
+/// (`d`), depending on which of `before` and `after` they carry.
+pub fn dbz_full_format(
+    rp: &mut RowPacker,
+    dp: DiffPair<Row>,
+    source: DbzSource,
+    ts_ms: i64,
+    snapshot: bool,
+) {
+    let op = if snapshot {
+        "r"
+    } else {
+        match (&dp.before, &dp.after) {
+            (None, _) => "c",
+            (Some(_), Some(_)) => "u",
+            (Some(_), None) => "d",
+        }
+    };
+    dbz_format(rp, dp);
+    rp.push_list_with(|rp| {
+        rp.push(Datum::String(source.version));
+        rp.push(Datum::String("materialize"));
+        rp.push(Datum::String(source.name));
+        rp.push(Datum::Int64(ts_ms));
+        rp.push(Datum::String(if snapshot { "true" } else { "false" }));
+        rp.push(Datum::String(source.db));
+        rp.push(Datum::String(source.schema));
+        rp.push(Datum::String(source.table));
+    });
+    rp.push(Datum::String(op));
+    rp.push(Datum::Int64(ts_ms));
+}
+
 pub fn upsert_format(dps: Vec<DiffPair<Row>>, sink_id: GlobalId, from: GlobalId) -> Option<Row> {
     let dp = dps.expect_element(|| {
         format!(
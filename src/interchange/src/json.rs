@@ -15,10 +15,10 @@ use serde_json::{json, Map};
 use mz_repr::adt::char;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
-use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, ScalarType};
+use mz_repr::{strconv, ColumnName, ColumnType, Datum, GlobalId, RelationDesc, ScalarType};
 
 use crate::encode::{column_names_and_types, Encode, TypedDatum};
-use crate::envelopes;
+use crate::envelopes::{self, DbzEnvelope};
 
 const AVRO_NAMESPACE: &str = "com.materialize.sink";
 
@@ -29,10 +29,14 @@ pub struct JsonEncoder {
 }
 
 impl JsonEncoder {
-    pub fn new(key_desc: Option<RelationDesc>, value_desc: RelationDesc, debezium: bool) -> Self {
+    pub fn new(
+        key_desc: Option<RelationDesc>,
+        value_desc: RelationDesc,
+        debezium: Option<DbzEnvelope>,
+    ) -> Self {
         let mut value_columns = column_names_and_types(value_desc);
-        if debezium {
-            value_columns = envelopes::dbz_envelope(value_columns);
+        if let Some(envelope) = debezium {
+            value_columns = envelopes::dbz_envelope(value_columns, envelope);
         }
         JsonEncoder {
             key_columns: if let Some(desc) = key_desc {
@@ -154,6 +158,16 @@ impl ToJson for TypedDatum<'_> {
                 }
                 ScalarType::Jsonb => JsonbRef::from_datum(datum).to_serde_json(),
                 ScalarType::Uuid => json!(datum.unwrap_uuid()),
+                ScalarType::Point => {
+                    let mut buf = String::new();
+                    strconv::format_point(&mut buf, datum.unwrap_point());
+                    serde_json::Value::String(buf)
+                }
+                ScalarType::BoundingBox => {
+                    let mut buf = String::new();
+                    strconv::format_bounding_box(&mut buf, datum.unwrap_bounding_box());
+                    serde_json::Value::String(buf)
+                }
                 ty @ (ScalarType::Array(..) | ScalarType::Int2Vector | ScalarType::List { .. }) => {
                     let list = match typ.scalar_type {
                         ScalarType::Array(_) | ScalarType::Int2Vector => {
@@ -253,13 +267,19 @@ fn build_row_schema_field(
             "type": "long",
             "logicalType": "time-micros",
         }),
+        // Timestamps have microsecond precision, which `timestamp-millis`
+        // would truncate. Sources decode both.
         ScalarType::Timestamp | ScalarType::TimestampTz => json!({
             "type": "long",
             "logicalType": "timestamp-micros"
         }),
         ScalarType::Interval => type_namer.interval_type(),
         ScalarType::Bytes => json!("bytes"),
-        ScalarType::String | ScalarType::Char { .. } | ScalarType::VarChar { .. } => {
+        ScalarType::String
+        | ScalarType::Char { .. }
+        | ScalarType::VarChar { .. }
+        | ScalarType::Point
+        | ScalarType::BoundingBox => {
             json!("string")
         }
         ScalarType::Jsonb => json!({
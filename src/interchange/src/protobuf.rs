@@ -25,6 +25,7 @@ pub struct DecodedDescriptors {
     message_descriptor: MessageDescriptor,
     columns: Vec<(ColumnName, ColumnType)>,
     message_name: String,
+    message_indexes: Vec<i32>,
 }
 
 impl DecodedDescriptors {
@@ -46,10 +47,12 @@ impl DecodedDescriptors {
             let ty = derive_column_type(&mut seen_messages, &field)?;
             columns.push((name, ty))
         }
+        let message_indexes = message_indexes(&message_descriptor);
         Ok(DecodedDescriptors {
             message_descriptor,
             columns,
             message_name,
+            message_indexes,
         })
     }
 
@@ -103,7 +106,21 @@ impl Decoder {
             // allocations).
             //
             // [0]: https://developers.google.com/protocol-buffers/docs/overview
-            let (_schema_id, adjusted_bytes) = crate::confluent::extract_protobuf_header(bytes)?;
+            //
+            // The message indexes identify the message within the schema, and
+            // must be those of the message we know about, as the schema may
+            // contain other messages that it merely uses as field types.
+            let (_schema_id, message_indexes, adjusted_bytes) =
+                crate::confluent::extract_protobuf_header(bytes)?;
+            if message_indexes != self.descriptors.message_indexes {
+                bail!(
+                    "Confluent-style protobuf message indexes {:?} do not match the indexes {:?} \
+                    of message {}",
+                    message_indexes,
+                    self.descriptors.message_indexes,
+                    self.descriptors.message_name.quoted(),
+                );
+            }
             bytes = adjusted_bytes;
         }
         let message = DynamicMessage::decode(self.descriptors.message_descriptor.clone(), bytes)?;
@@ -113,6 +130,70 @@ impl Decoder {
     }
 }
 
+/// Returns the fully qualified names of the top-level messages of the file
+/// `file_name` in an encoded `FileDescriptorSet` that are not used as the type
+/// of a field by any other message in the file.
+///
+/// A schema registry schema describes a single message, but may define other
+/// messages for use as field types, as Debezium schemas do for the `before`
+/// and `after` fields of their envelopes. The messages that no other message
+/// uses are the candidates for the message the schema describes.
+pub fn root_message_names(bytes: &[u8], file_name: &str) -> Result<Vec<String>, anyhow::Error> {
+    let pool = DescriptorPool::decode(bytes).context("decoding file descriptor set")?;
+    let file = pool.get_file_by_name(file_name).ok_or_else(|| {
+        anyhow!(
+            "file {} not found in file descriptor set",
+            file_name.quoted()
+        )
+    })?;
+
+    fn collect_field_types(message: &MessageDescriptor, used: &mut HashSet<String>) {
+        for field in message.fields() {
+            if let Kind::Message(m) = field.kind() {
+                used.insert(m.full_name().to_owned());
+            }
+        }
+        for child in message.child_messages() {
+            collect_field_types(&child, used);
+        }
+    }
+
+    let mut used = HashSet::new();
+    for message in file.messages() {
+        collect_field_types(&message, &mut used);
+    }
+    Ok(file
+        .messages()
+        .map(|message| message.full_name().to_owned())
+        .filter(|name| !used.contains(name))
+        .collect())
+}
+
+/// Returns the path of a message's descriptor within its file, as it is
+/// encoded in the Confluent wire format: the index of its top-level message,
+/// followed by the indexes of the nested messages that lead to it.
+pub fn message_indexes(message: &MessageDescriptor) -> Vec<i32> {
+    let mut indexes = vec![];
+    let mut message = message.clone();
+    loop {
+        let parent = message.parent_message();
+        let mut siblings: Box<dyn Iterator<Item = MessageDescriptor>> = match &parent {
+            Some(parent) => Box::new(parent.child_messages()),
+            None => Box::new(message.parent_file().messages()),
+        };
+        let index = siblings
+            .position(|sibling| sibling.full_name() == message.full_name())
+            .expect("message is a child of its parent");
+        indexes.push(i32::try_from(index).expect("message index fits in an i32"));
+        match parent {
+            Some(parent) => message = parent,
+            None => break,
+        }
+    }
+    indexes.reverse();
+    indexes
+}
+
 fn derive_column_type(
     seen_messages: &mut HashSet<String>,
     field: &FieldDescriptor,
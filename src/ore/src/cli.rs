@@ -15,11 +15,15 @@
 
 //! Command-line parsing utilities.
 
-use std::ffi::OsString;
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::{OsStr, OsString};
 use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
 use std::str::FromStr;
 
-use clap::Parser;
+use clap::{Arg, Command, ErrorKind, Parser};
 
 /// A help template for use with clap that does not include the name of the
 /// binary or the version in the help output.
@@ -30,6 +34,10 @@ USAGE:
 
 {all-args}";
 
+/// The ID and long name of the argument that names the config file, if
+/// [`CliConfig::enable_config_file`] is set.
+const CONFIG_FILE_ARG: &str = "config-file";
+
 /// Configures command-line parsing via [`parse_args`].
 #[derive(Debug, Default, Clone)]
 pub struct CliConfig<'a> {
@@ -44,6 +52,18 @@ pub struct CliConfig<'a> {
     /// We disable this by default because most of our binaries are not
     /// meaningfully versioned.
     pub enable_version_flag: bool,
+    /// Enable a `--config-file` flag, which names a TOML file that provides
+    /// values for the other arguments.
+    ///
+    /// Each key in the file is the long name of an argument, like
+    /// `sql-listen-addr`, and strings can refer to environment variables as
+    /// `${NAME}`. Arguments that take several values, like the defaults of
+    /// system parameters, take an array of strings. Values from the command
+    /// line and from environment variables take precedence over values from
+    /// the file. Flags can't be disabled on the command line, so a flag that
+    /// the file enables can only be disabled by setting its environment
+    /// variable to a false value, like `false` or `0`.
+    pub enable_config_file: bool,
 }
 
 /// Parses command-line arguments according to a clap `Parser` after
@@ -67,8 +87,28 @@ where
         })
         .collect();
 
+    // Likewise, read the config file, if any, before constructing `clap`,
+    // whose defaults borrow from it.
+    let config_file_env = match (config.enable_config_file, config.env_prefix) {
+        (true, Some(prefix)) => {
+            let mut env = OsString::from(prefix);
+            env.push("CONFIG_FILE");
+            Some(env)
+        }
+        _ => None,
+    };
+    let config_file = if config.enable_config_file {
+        ConfigFile::load(O::command(), config_file_env.as_deref())
+    } else {
+        ConfigFile::default()
+    };
+
     let mut clap = O::command().args_override_self(true);
 
+    if config.enable_config_file {
+        clap = clap.arg(config_file_arg(config_file_env.as_deref()));
+    }
+
     if !config.enable_version_flag {
         clap = clap.disable_version_flag(true);
         clap = clap.help_template(NO_VERSION_HELP_TEMPLATE);
@@ -78,7 +118,195 @@ where
         clap = clap.mut_arg(*arg, |arg| arg.env_os(env));
     }
 
-    O::from_arg_matches(&clap.get_matches()).unwrap()
+    // Values from the config file become the defaults of their arguments, so
+    // that the command line and the environment take precedence. Flags have
+    // no defaults, so the flags that the file enables are passed as if they
+    // were the first command-line arguments, unless their environment
+    // variable is set, which then takes precedence even if it disables them.
+    for (arg, values) in &config_file.values {
+        let values: Vec<_> = values.iter().map(|value| value.as_str()).collect();
+        clap = clap.mut_arg(arg.as_str(), |arg| {
+            arg.required(false).default_values(&values)
+        });
+    }
+    let command = O::command();
+    let flag_env_is_set = |id: &str| {
+        let env = match arg_envs.iter().find(|(arg, _)| *arg == id) {
+            Some((_, env)) => Some(env.as_os_str()),
+            None => command
+                .get_arguments()
+                .find(|arg| arg.get_id() == id)
+                .and_then(|arg| arg.get_env()),
+        };
+        env.map_or(false, |env| env::var_os(env).is_some())
+    };
+    let mut args = env::args_os();
+    let args: Vec<_> = args
+        .next()
+        .into_iter()
+        .chain(
+            config_file
+                .flags
+                .iter()
+                .filter(|(id, _)| !flag_env_is_set(id))
+                .map(|(_, long)| OsString::from(format!("--{}", long))),
+        )
+        .chain(args)
+        .collect();
+
+    O::from_arg_matches(&clap.get_matches_from(args)).unwrap()
+}
+
+fn config_file_arg<'a>(env: Option<&'a OsStr>) -> Arg<'a> {
+    let arg = Arg::new(CONFIG_FILE_ARG)
+        .long(CONFIG_FILE_ARG)
+        .value_name("PATH")
+        .takes_value(true)
+        .help("A TOML file that provides values for the other options.");
+    match env {
+        Some(env) => arg.env_os(env),
+        None => arg,
+    }
+}
+
+/// Argument values read from a config file.
+#[derive(Debug, Default, PartialEq)]
+struct ConfigFile {
+    /// The values of the arguments that take values, by argument ID.
+    values: Vec<(String, Vec<String>)>,
+    /// The IDs and long names of the flags that the file enables.
+    flags: Vec<(String, String)>,
+}
+
+impl ConfigFile {
+    /// Reads the config file named by the command line or the environment,
+    /// if any, or exits with an error.
+    fn load<'a>(cmd: Command<'a>, env: Option<&'a OsStr>) -> ConfigFile {
+        // A first pass over the arguments finds the config file. Errors in
+        // other arguments are reported by the second pass, which knows about
+        // the values in the file.
+        let mut cmd = cmd.arg(config_file_arg(env));
+        let path = match cmd.clone().ignore_errors(true).try_get_matches() {
+            Ok(matches) => matches.value_of_os(CONFIG_FILE_ARG).map(PathBuf::from),
+            Err(_) => None,
+        };
+        let path = match path {
+            Some(path) => path,
+            None => return ConfigFile::default(),
+        };
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => cmd
+                .error(ErrorKind::Io, format!("reading {}: {}", path.display(), e))
+                .exit(),
+        };
+        match ConfigFile::parse(&text, &cmd, &|name: &str| env::var(name).ok()) {
+            Ok(config_file) => config_file,
+            Err(e) => cmd
+                .error(
+                    ErrorKind::InvalidValue,
+                    format!("{}: {}", path.display(), e),
+                )
+                .exit(),
+        }
+    }
+
+    /// Parses the text of a config file for the arguments of `cmd`, looking
+    /// up the environment variables that the file refers to with `lookup`.
+    fn parse(
+        text: &str,
+        cmd: &Command,
+        lookup: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<ConfigFile, String> {
+        let table: BTreeMap<toml::Spanned<String>, toml::Spanned<toml::Value>> =
+            toml::from_str(text).map_err(|e| e.to_string())?;
+        let mut config_file = ConfigFile::default();
+        for (key, value) in table {
+            let key_at = location(text, key.start());
+            let key = key.into_inner();
+            let at = location(text, value.start());
+            let arg = cmd
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(key.as_str()) && arg.get_id() != CONFIG_FILE_ARG)
+                .ok_or_else(|| format!("unknown option `{}` {}", key, key_at))?;
+            let value = value.into_inner();
+            if !arg.is_takes_value_set() {
+                match value {
+                    toml::Value::Boolean(true) => {
+                        config_file.flags.push((arg.get_id().to_string(), key))
+                    }
+                    toml::Value::Boolean(false) => (),
+                    _ => return Err(format!("option `{}` must be a boolean {}", key, at)),
+                }
+                continue;
+            }
+            let values = match value {
+                toml::Value::Array(values)
+                    if arg.is_multiple_values_set() || arg.is_multiple_occurrences_set() =>
+                {
+                    values
+                }
+                toml::Value::Array(_) => {
+                    return Err(format!("option `{}` takes a single value {}", key, at))
+                }
+                value => vec![value],
+            };
+            let values = values
+                .into_iter()
+                .map(|value| match value {
+                    toml::Value::String(s) => interpolate(&s, lookup)
+                        .map_err(|e| format!("option `{}`: {} {}", key, e, at)),
+                    toml::Value::Integer(i) => Ok(i.to_string()),
+                    toml::Value::Float(f) => Ok(f.to_string()),
+                    toml::Value::Boolean(b) => Ok(b.to_string()),
+                    _ => Err(format!(
+                        "option `{}` must be a string, number, or boolean {}",
+                        key, at
+                    )),
+                })
+                .collect::<Result<_, _>>()?;
+            config_file.values.push((arg.get_id().to_string(), values));
+        }
+        Ok(config_file)
+    }
+}
+
+/// Describes the position of the byte at `offset` in `text`, in the format
+/// of TOML syntax errors.
+fn location(text: &str, offset: usize) -> String {
+    let before = &text[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    format!("at line {} column {}", line, column)
+}
+
+/// Replaces each `${NAME}` in `s` with the value of the environment variable
+/// `NAME`, and each `$$` with `$`.
+fn interpolate(s: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<String, String> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('$') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+        if let Some(after) = rest.strip_prefix('$') {
+            out.push('$');
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('{') {
+            let end = after
+                .find('}')
+                .ok_or_else(|| "unterminated `${`".to_string())?;
+            let name = &after[..end];
+            let value = lookup(name)
+                .ok_or_else(|| format!("environment variable `{}` is not set", name))?;
+            out.push_str(&value);
+            rest = &after[end + 1..];
+        } else {
+            out.push('$');
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
 }
 
 /// A command-line argument of the form `KEY=VALUE`.
@@ -140,3 +368,89 @@ impl FromStr for DefaultTrue {
         Ok(Self { value: s.parse()? })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_command() -> Command<'static> {
+        Command::new("test")
+            .arg(
+                Arg::new("listen_addr")
+                    .long("listen-addr")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::new("zone")
+                    .long("zone")
+                    .takes_value(true)
+                    .multiple_occurrences(true),
+            )
+            .arg(
+                Arg::new("default_storage_host_size")
+                    .long("default-storage-host-size")
+                    .takes_value(true),
+            )
+            .arg(Arg::new("unsafe_mode").long("unsafe-mode"))
+    }
+
+    fn parse(text: &str) -> Result<ConfigFile, String> {
+        let lookup = |name: &str| match name {
+            "HOST" => Some("0.0.0.0".into()),
+            _ => None,
+        };
+        ConfigFile::parse(text, &test_command(), &lookup)
+    }
+
+    #[test]
+    fn test_config_file() {
+        assert_eq!(
+            parse(
+                r#"
+listen-addr = "${HOST}:6875"
+zone = ["a", "b"]
+default-storage-host-size = 2
+unsafe-mode = true
+"#
+            ),
+            Ok(ConfigFile {
+                values: vec![
+                    ("default_storage_host_size".into(), vec!["2".into()]),
+                    ("listen_addr".into(), vec!["0.0.0.0:6875".into()]),
+                    ("zone".into(), vec!["a".into(), "b".into()]),
+                ],
+                flags: vec![("unsafe_mode".into(), "unsafe-mode".into())],
+            })
+        );
+        assert_eq!(parse("unsafe-mode = false"), Ok(ConfigFile::default()));
+        assert_eq!(
+            parse("listen-addr = \"a\"\nworkers = 4"),
+            Err("unknown option `workers` at line 2 column 1".into())
+        );
+        assert_eq!(
+            parse("listen-addr = [\"a\", \"b\"]"),
+            Err("option `listen-addr` takes a single value at line 1 column 15".into())
+        );
+        assert_eq!(
+            parse("unsafe-mode = \"yes\""),
+            Err("option `unsafe-mode` must be a boolean at line 1 column 15".into())
+        );
+        assert_eq!(
+            parse("listen-addr = \"${PORT}\""),
+            Err(
+                "option `listen-addr`: environment variable `PORT` is not set \
+                 at line 1 column 15"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let lookup = |name: &str| Some(name.to_lowercase());
+        assert_eq!(interpolate("plain", &lookup), Ok("plain".into()));
+        assert_eq!(interpolate("${A}-${B}", &lookup), Ok("a-b".into()));
+        assert_eq!(interpolate("$$HOME $x", &lookup), Ok("$HOME $x".into()));
+        assert_eq!(interpolate("${A", &lookup), Err("unterminated `${`".into()));
+    }
+}
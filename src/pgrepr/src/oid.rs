@@ -21,6 +21,8 @@ pub const TYPE_ANYELEMENT_OID: u32 = 2283;
 pub const TYPE_ANYNONARRAY_OID: u32 = 2776;
 pub const TYPE_BOOL_ARRAY_OID: u32 = 1000;
 pub const TYPE_BOOL_OID: u32 = 16;
+pub const TYPE_BOX_ARRAY_OID: u32 = 1020;
+pub const TYPE_BOX_OID: u32 = 603;
 pub const TYPE_BPCHAR_ARRAY_OID: u32 = 1014;
 pub const TYPE_BPCHAR_OID: u32 = 1042;
 pub const TYPE_BYTEA_ARRAY_OID: u32 = 1001;
@@ -50,6 +52,8 @@ pub const TYPE_NUMERIC_ARRAY_OID: u32 = 1231;
 pub const TYPE_NUMERIC_OID: u32 = 1700;
 pub const TYPE_OID_ARRAY_OID: u32 = 1028;
 pub const TYPE_OID_OID: u32 = 26;
+pub const TYPE_POINT_ARRAY_OID: u32 = 1017;
+pub const TYPE_POINT_OID: u32 = 600;
 pub const TYPE_RECORD_ARRAY_OID: u32 = 2287;
 pub const TYPE_RECORD_OID: u32 = 2249;
 pub const TYPE_REGCLASS_ARRAY_OID: u32 = 2210;
@@ -274,3 +278,14 @@ pub const FUNC_MZ_NOW_OID: u32 = 16_560;
 pub const FUNC_MAX_MZ_TIMESTAMP_OID: u32 = 16_561;
 pub const FUNC_MIN_MZ_TIMESTAMP_OID: u32 = 16_562;
 pub const FUNC_DATE_FROM_TEXT: u32 = 16_563;
+pub const FUNC_TOKENIZE_OID: u32 = 16_564;
+pub const OP_TEXT_SEARCH_MATCH_OID: u32 = 16_565;
+pub const FUNC_ST_BOX_CONTAINS_OID: u32 = 16_566;
+pub const FUNC_ST_DISTANCE_OID: u32 = 16_567;
+pub const FUNC_ST_DISTANCE_SPHERE_OID: u32 = 16_568;
+pub const FUNC_SESSION_WINDOWS_TIMESTAMP_OID: u32 = 16_569;
+pub const FUNC_SESSION_WINDOWS_TIMESTAMPTZ_OID: u32 = 16_570;
+pub const FUNC_BUCKET_DELTAS_TIMESTAMP_OID: u32 = 16_571;
+pub const FUNC_BUCKET_DELTAS_TIMESTAMPTZ_OID: u32 = 16_572;
+pub const FUNC_XXHASH64_BYTES_OID: u32 = 16_573;
+pub const FUNC_XXHASH64_STRING_OID: u32 = 16_574;
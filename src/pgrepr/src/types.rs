@@ -135,6 +135,10 @@ pub enum Type {
     },
     /// A universally unique identifier.
     Uuid,
+    /// A point in two-dimensional space.
+    Point,
+    /// An axis-aligned rectangle.
+    Box,
     /// A function name.
     RegProc,
     /// A type name.
@@ -511,6 +515,8 @@ impl Type {
             postgres_types::Type::TIMESTAMP => Type::Timestamp { precision: None },
             postgres_types::Type::TIMESTAMPTZ => Type::TimestampTz { precision: None },
             postgres_types::Type::UUID => Type::Uuid,
+            postgres_types::Type::POINT => Type::Point,
+            postgres_types::Type::BOX => Type::Box,
             postgres_types::Type::REGCLASS => Type::RegClass,
             postgres_types::Type::REGPROC => Type::RegProc,
             postgres_types::Type::REGTYPE => Type::RegType,
@@ -548,6 +554,8 @@ impl Type {
                 Type::Array(Box::new(Type::TimestampTz { precision: None }))
             }
             postgres_types::Type::UUID_ARRAY => Type::Array(Box::new(Type::Uuid)),
+            postgres_types::Type::POINT_ARRAY => Type::Array(Box::new(Type::Point)),
+            postgres_types::Type::BOX_ARRAY => Type::Array(Box::new(Type::Box)),
             postgres_types::Type::VARCHAR_ARRAY => {
                 Type::Array(Box::new(Type::VarChar { max_length: None }))
             }
@@ -670,6 +678,8 @@ impl Type {
                 Type::Timestamp { .. } => &postgres_types::Type::TIMESTAMP_ARRAY,
                 Type::TimestampTz { .. } => &postgres_types::Type::TIMESTAMPTZ_ARRAY,
                 Type::Uuid => &postgres_types::Type::UUID_ARRAY,
+                Type::Point => &postgres_types::Type::POINT_ARRAY,
+                Type::Box => &postgres_types::Type::BOX_ARRAY,
                 Type::RegClass => &postgres_types::Type::REGCLASS_ARRAY,
                 Type::RegProc => &postgres_types::Type::REGPROC_ARRAY,
                 Type::RegType => &postgres_types::Type::REGTYPE_ARRAY,
@@ -712,6 +722,8 @@ impl Type {
             Type::Timestamp { .. } => &postgres_types::Type::TIMESTAMP,
             Type::TimestampTz { .. } => &postgres_types::Type::TIMESTAMPTZ,
             Type::Uuid => &postgres_types::Type::UUID,
+            Type::Point => &postgres_types::Type::POINT,
+            Type::Box => &postgres_types::Type::BOX,
             Type::RegClass => &postgres_types::Type::REGCLASS,
             Type::RegProc => &postgres_types::Type::REGPROC,
             Type::RegType => &postgres_types::Type::REGTYPE,
@@ -759,6 +771,8 @@ impl Type {
             &postgres_types::Type::TIMESTAMP_ARRAY => "timestamp[]",
             &postgres_types::Type::TIMESTAMPTZ_ARRAY => "timestamp with time zone[]",
             &postgres_types::Type::UUID_ARRAY => "uuid[]",
+            &postgres_types::Type::POINT_ARRAY => "point[]",
+            &postgres_types::Type::BOX_ARRAY => "box[]",
             &postgres_types::Type::VARCHAR_ARRAY => "character varying[]",
             &postgres_types::Type::BOOL => "boolean",
             &postgres_types::Type::BPCHAR => "character",
@@ -843,6 +857,8 @@ impl Type {
             | Type::Timestamp { precision: None }
             | Type::TimestampTz { precision: None }
             | Type::Uuid
+            | Type::Point
+            | Type::Box
             | Type::MzTimestamp
             | Type::VarChar { max_length: None }
             | Type::Range { .. } => None,
@@ -882,6 +898,8 @@ impl Type {
             Type::Timestamp { .. } => 8,
             Type::TimestampTz { .. } => 8,
             Type::Uuid => 16,
+            Type::Point => 16,
+            Type::Box => 32,
             Type::RegClass => 4,
             Type::RegProc => 4,
             Type::RegType => 4,
@@ -1005,6 +1023,8 @@ impl TryFrom<&Type> for ScalarType {
                 Err(TypeConversionError::UnsupportedType(typ.clone()))
             }
             Type::Uuid => Ok(ScalarType::Uuid),
+            Type::Point => Ok(ScalarType::Point),
+            Type::Box => Ok(ScalarType::BoundingBox),
             Type::RegClass => Ok(ScalarType::RegClass),
             Type::RegProc => Ok(ScalarType::RegProc),
             Type::RegType => Ok(ScalarType::RegType),
@@ -1147,6 +1167,8 @@ impl From<&ScalarType> for Type {
             ScalarType::Timestamp => Type::Timestamp { precision: None },
             ScalarType::TimestampTz => Type::TimestampTz { precision: None },
             ScalarType::Uuid => Type::Uuid,
+            ScalarType::Point => Type::Point,
+            ScalarType::BoundingBox => Type::Box,
             ScalarType::Numeric { max_scale } => Type::Numeric {
                 constraints: Some(NumericConstraints {
                     max_precision: i32::from(NUMERIC_DATUM_MAX_PRECISION),
@@ -21,6 +21,7 @@ use mz_ore::cast::ReinterpretCast;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::geo::{BoundingBox, Point};
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::range::Range;
 use mz_repr::adt::timestamp::CheckedTimestamp;
@@ -98,6 +99,10 @@ pub enum Value {
     VarChar(String),
     /// A universally unique identifier.
     Uuid(Uuid),
+    /// A point in two-dimensional space.
+    Point(Point),
+    /// An axis-aligned rectangle.
+    Box(BoundingBox),
     /// A small int vector.
     Int2Vector {
         /// The elements of the vector.
@@ -149,6 +154,8 @@ impl Value {
                 Some(Value::Jsonb(Jsonb(JsonbRef::from_datum(datum).to_owned())))
             }
             (Datum::Uuid(u), ScalarType::Uuid) => Some(Value::Uuid(u)),
+            (Datum::Point(p), ScalarType::Point) => Some(Value::Point(p)),
+            (Datum::BoundingBox(b), ScalarType::BoundingBox) => Some(Value::Box(b)),
             (Datum::Array(array), ScalarType::Array(elem_type)) => {
                 let dims = array.dims().into_iter().collect();
                 let elements = array
@@ -273,6 +280,8 @@ impl Value {
             Value::BpChar(s) => Datum::String(buf.push_string(s.trim_end().into())),
             Value::VarChar(s) => Datum::String(buf.push_string(s)),
             Value::Uuid(u) => Datum::Uuid(u),
+            Value::Point(p) => Datum::Point(p),
+            Value::Box(b) => Datum::BoundingBox(b),
             Value::Numeric(n) => Datum::Numeric(n.0),
             Value::MzTimestamp(t) => Datum::MzTimestamp(t),
             Value::Range(range) => {
@@ -357,6 +366,8 @@ impl Value {
             Value::Timestamp(ts) => strconv::format_timestamp(buf, ts),
             Value::TimestampTz(ts) => strconv::format_timestamptz(buf, ts),
             Value::Uuid(u) => strconv::format_uuid(buf, *u),
+            Value::Point(p) => strconv::format_point(buf, *p),
+            Value::Box(b) => strconv::format_bounding_box(buf, *b),
             Value::Numeric(d) => strconv::format_numeric(buf, &d.0),
             Value::MzTimestamp(t) => strconv::format_mz_timestamp(buf, *t),
             Value::Range(range) => strconv::format_range(buf, range, |buf, elem| match elem {
@@ -468,6 +479,16 @@ impl Value {
             Value::Timestamp(ts) => ts.to_sql(&PgType::TIMESTAMP, buf),
             Value::TimestampTz(ts) => ts.to_sql(&PgType::TIMESTAMPTZ, buf),
             Value::Uuid(u) => u.to_sql(&PgType::UUID, buf),
+            Value::Point(p) => {
+                encode_point(buf, *p);
+                Ok(postgres_types::IsNull::No)
+            }
+            Value::Box(b) => {
+                // Like PostgreSQL, send the upper-right corner first.
+                encode_point(buf, b.high());
+                encode_point(buf, b.low());
+                Ok(postgres_types::IsNull::No)
+            }
             Value::Numeric(a) => a.to_sql(&PgType::NUMERIC, buf),
             Value::MzTimestamp(t) => t.to_string().to_sql(&PgType::TEXT, buf),
             Value::Range(_) => Err("binary encodings of range types not yet implemented".into()),
@@ -562,6 +583,8 @@ impl Value {
             Type::Timestamp { .. } => Value::Timestamp(strconv::parse_timestamp(s)?),
             Type::TimestampTz { .. } => Value::TimestampTz(strconv::parse_timestamptz(s)?),
             Type::Uuid => Value::Uuid(Uuid::parse_str(s)?),
+            Type::Point => Value::Point(strconv::parse_point(s)?),
+            Type::Box => Value::Box(strconv::parse_bounding_box(s)?),
             Type::MzTimestamp => Value::MzTimestamp(strconv::parse_mz_timestamp(s)?),
             Type::Range { element_type } => Value::Range(strconv::parse_range(s, |elem_text| {
                 Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
@@ -618,6 +641,15 @@ impl Value {
                 )?))
             }
             Type::Uuid => Uuid::from_sql(ty.inner(), raw).map(Value::Uuid),
+            Type::Point => decode_point(raw).map(Value::Point),
+            Type::Box => {
+                if raw.len() != 32 {
+                    return Err("invalid message length for box".into());
+                }
+                let high = decode_point(&raw[..16])?;
+                let low = decode_point(&raw[16..])?;
+                Ok(Value::Box(BoundingBox::new(high, low)))
+            }
             Type::MzTimestamp => {
                 let s = String::from_sql(ty.inner(), raw)?;
                 let t: mz_repr::Timestamp = s.parse()?;
@@ -628,6 +660,22 @@ impl Value {
     }
 }
 
+fn encode_point(buf: &mut BytesMut, point: Point) {
+    buf.put_f64(point.x);
+    buf.put_f64(point.y);
+}
+
+fn decode_point(raw: &[u8]) -> Result<Point, Box<dyn Error + Sync + Send>> {
+    if raw.len() != 16 {
+        return Err("invalid message length for point".into());
+    }
+    let (x, y) = raw.split_at(8);
+    Ok(Point::new(
+        f64::from_be_bytes(x.try_into()?),
+        f64::from_be_bytes(y.try_into()?),
+    ))
+}
+
 fn encode_element(buf: &mut BytesMut, elem: Option<&Value>, ty: &Type) -> Result<(), io::Error> {
     match elem {
         None => buf.put_i32(-1),
@@ -12,12 +12,15 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use postgres::error::SqlState;
 
+use mz_adapter::catalog::ErrorKind as CatalogErrorKind;
 use mz_adapter::session::ClientSeverity as AdapterClientSeverity;
 use mz_adapter::session::TransactionCode;
 use mz_adapter::{AdapterError, AdapterNotice, StartupMessage};
 use mz_expr::EvalError;
 use mz_repr::{ColumnName, NotNullViolation, RelationDesc};
 use mz_sql::ast::NoticeSeverity;
+use mz_sql::catalog::CatalogError as SqlCatalogError;
+use mz_sql::plan::PlanError;
 
 // Pgwire protocol versions are represented as 32-bit integers, where the
 // high 16 bits represent the major version and the low 16 bits represent the
@@ -310,24 +313,15 @@ impl ErrorResponse {
         // those errors that are truly internal errors. At the moment we have
         // a various classes of uncategorized errors that use this error code
         // inappropriately.
-        let code = match e {
+        let code = match &e {
             // DATA_EXCEPTION to match what Postgres returns for degenerate
             // range bounds
             AdapterError::AbsurdSubscribeBounds { .. } => SqlState::DATA_EXCEPTION,
-            AdapterError::Catalog(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::Catalog(e) => catalog_error_code(e.kind()),
             AdapterError::ChangedPlan => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::ConstrainedParameter { .. } => SqlState::INVALID_PARAMETER_VALUE,
             AdapterError::DuplicateCursor(_) => SqlState::DUPLICATE_CURSOR,
-            AdapterError::Eval(EvalError::CharacterNotValidForEncoding(_)) => {
-                SqlState::PROGRAM_LIMIT_EXCEEDED
-            }
-            AdapterError::Eval(EvalError::CharacterTooLargeForEncoding(_)) => {
-                SqlState::PROGRAM_LIMIT_EXCEEDED
-            }
-            AdapterError::Eval(EvalError::NullCharacterNotPermitted) => {
-                SqlState::PROGRAM_LIMIT_EXCEEDED
-            }
-            AdapterError::Eval(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::Eval(e) => eval_error_code(e),
             AdapterError::Explain(_) => SqlState::INTERNAL_ERROR,
             AdapterError::FixedValueParameter(_) => SqlState::INVALID_PARAMETER_VALUE,
             AdapterError::IdExhaustionError => SqlState::INTERNAL_ERROR,
@@ -341,13 +335,17 @@ impl ErrorResponse {
             AdapterError::InvalidStorageHostSize { .. } => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::StorageHostSizeRequired { .. } => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::InvalidTableMutationSelection => SqlState::INVALID_TRANSACTION_STATE,
+            AdapterError::InvalidTemporalFilter(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::ConstraintViolation(NotNullViolation(_)) => SqlState::NOT_NULL_VIOLATION,
             AdapterError::NoClusterReplicasAvailable(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::OperationProhibitsTransaction(_) => SqlState::ACTIVE_SQL_TRANSACTION,
             AdapterError::OperationRequiresTransaction(_) => SqlState::NO_ACTIVE_SQL_TRANSACTION,
-            AdapterError::PlanError(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::PeekQueueTimeout { .. } => SqlState::QUERY_CANCELED,
+            AdapterError::PlanError(e) => plan_error_code(e),
             AdapterError::PreparedStatementExists(_) => SqlState::DUPLICATE_PSTATEMENT,
             AdapterError::QGM(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::QueryAdmissionDenied { .. } => SqlState::INSUFFICIENT_RESOURCES,
+            AdapterError::ReadOnlyMode => SqlState::READ_ONLY_SQL_TRANSACTION,
             AdapterError::ReadOnlyTransaction => SqlState::READ_ONLY_SQL_TRANSACTION,
             AdapterError::ReadOnlyParameter(_) => SqlState::CANT_CHANGE_RUNTIME_PARAM,
             AdapterError::ReadWriteUnavailable => SqlState::INVALID_TRANSACTION_STATE,
@@ -355,12 +353,13 @@ impl ErrorResponse {
             AdapterError::IdleInTransactionSessionTimeout => {
                 SqlState::IDLE_IN_TRANSACTION_SESSION_TIMEOUT
             }
-            AdapterError::RecursionLimit(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::RecursionLimit(_) => SqlState::STATEMENT_TOO_COMPLEX,
             AdapterError::RelationOutsideTimeDomain { .. } => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ResourceExhaustion { .. } => SqlState::INSUFFICIENT_RESOURCES,
             AdapterError::ResultSize(_) => SqlState::OUT_OF_MEMORY,
             AdapterError::SafeModeViolation(_) => SqlState::INTERNAL_ERROR,
-            AdapterError::SqlCatalog(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::SourceColumnLimit { .. } => SqlState::TOO_MANY_COLUMNS,
+            AdapterError::SqlCatalog(e) => sql_catalog_error_code(e),
             AdapterError::SubscribeOnlyTransaction => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::Transform(_) => SqlState::INTERNAL_ERROR,
             AdapterError::Unauthorized(_) => SqlState::INSUFFICIENT_PRIVILEGE,
@@ -413,6 +412,7 @@ impl ErrorResponse {
             AdapterNotice::DroppedActiveDatabase { .. } => SqlState::WARNING,
             AdapterNotice::DroppedActiveCluster { .. } => SqlState::WARNING,
             AdapterNotice::QueryTimestamp { .. } => SqlState::WARNING,
+            AdapterNotice::QueryStatistics { .. } => SqlState::WARNING,
             AdapterNotice::EqualSubscribeBounds { .. } => SqlState::WARNING,
             AdapterNotice::QueryTrace { .. } => SqlState::WARNING,
             AdapterNotice::UnimplementedIsolationLevel { .. } => SqlState::WARNING,
@@ -444,6 +444,184 @@ impl ErrorResponse {
     }
 }
 
+/// Returns the SQLSTATE code of an error that occurred during planning.
+fn plan_error_code(e: &PlanError) -> SqlState {
+    match e {
+        PlanError::Unsupported { .. } => SqlState::FEATURE_NOT_SUPPORTED,
+        PlanError::UnknownColumn { .. } => SqlState::UNDEFINED_COLUMN,
+        PlanError::UngroupedColumn { .. } => SqlState::GROUPING_ERROR,
+        PlanError::WrongJoinTypeForLateralColumn { .. } => SqlState::INVALID_COLUMN_REFERENCE,
+        PlanError::AmbiguousColumn(_) => SqlState::AMBIGUOUS_COLUMN,
+        PlanError::AmbiguousTable(_) => SqlState::DUPLICATE_ALIAS,
+        PlanError::UnknownColumnInUsingClause { .. } => SqlState::UNDEFINED_COLUMN,
+        PlanError::AmbiguousColumnInUsingClause { .. } => SqlState::AMBIGUOUS_COLUMN,
+        PlanError::MisqualifiedName(_)
+        | PlanError::OverqualifiedDatabaseName(_)
+        | PlanError::OverqualifiedSchemaName(_)
+        | PlanError::UnderqualifiedColumnName(_) => SqlState::INVALID_NAME,
+        PlanError::SubqueriesDisallowed { .. } => SqlState::FEATURE_NOT_SUPPORTED,
+        PlanError::UnknownParameter(_) => SqlState::UNDEFINED_PARAMETER,
+        PlanError::RecursionLimit(_) => SqlState::STATEMENT_TOO_COMPLEX,
+        PlanError::StrconvParse(_) => SqlState::INVALID_TEXT_REPRESENTATION,
+        PlanError::Catalog(e) => sql_catalog_error_code(e),
+        PlanError::UpsertSinkWithoutKey => SqlState::INVALID_PARAMETER_VALUE,
+        PlanError::InvalidNumericMaxScale(_)
+        | PlanError::InvalidCharLength(_)
+        | PlanError::InvalidVarCharMaxLength(_) => SqlState::INVALID_PARAMETER_VALUE,
+        PlanError::InvalidObject(_) | PlanError::InvalidSecret(_) => SqlState::WRONG_OBJECT_TYPE,
+        PlanError::InvalidTemporarySchema => SqlState::INVALID_TABLE_DEFINITION,
+        PlanError::Parser(_) => SqlState::SYNTAX_ERROR,
+        PlanError::Qgm(_) => SqlState::INTERNAL_ERROR,
+        PlanError::DropViewOnMaterializedView(_)
+        | PlanError::AlterViewOnMaterializedView(_)
+        | PlanError::ShowCreateViewOnMaterializedView(_)
+        | PlanError::ExplainViewOnMaterializedView(_) => SqlState::WRONG_OBJECT_TYPE,
+        PlanError::DropSubsource { .. } => SqlState::DEPENDENT_OBJECTS_STILL_EXIST,
+        PlanError::CannotModifyLinkedCluster { .. } => SqlState::FEATURE_NOT_SUPPORTED,
+        PlanError::UnacceptableTimelineName(_) => SqlState::INVALID_PARAMETER_VALUE,
+        PlanError::UnrecognizedTypeInPostgresSource { .. } => SqlState::FEATURE_NOT_SUPPORTED,
+        PlanError::FetchingCsrSchemaFailed { .. }
+        | PlanError::FetchingPostgresPublicationInfoFailed { .. }
+        | PlanError::FetchingPostgresTableInfoFailed { .. }
+        | PlanError::FetchingMaterializeViewInfoFailed { .. } => SqlState::CONNECTION_EXCEPTION,
+        PlanError::InvalidProtobufSchema { .. } => SqlState::INVALID_PARAMETER_VALUE,
+        PlanError::InvalidOptionValue { .. } => SqlState::INVALID_PARAMETER_VALUE,
+        PlanError::UnexpectedDuplicateReference { .. } => SqlState::DUPLICATE_OBJECT,
+        PlanError::RecursiveTypeMismatch(..) => SqlState::DATATYPE_MISMATCH,
+        PlanError::UnknownFunction { .. } | PlanError::UnknownOperator { .. } => {
+            SqlState::UNDEFINED_FUNCTION
+        }
+        PlanError::IndistinctFunction { .. } | PlanError::IndistinctOperator { .. } => {
+            SqlState::AMBIGUOUS_FUNCTION
+        }
+        PlanError::InvalidPrivatelinkAvailabilityZone { .. } => SqlState::INVALID_PARAMETER_VALUE,
+        PlanError::Unstructured(_) => SqlState::INTERNAL_ERROR,
+    }
+}
+
+/// Returns the SQLSTATE code of an error that occurred while evaluating an
+/// expression.
+fn eval_error_code(e: &EvalError) -> SqlState {
+    match e {
+        EvalError::CharacterNotValidForEncoding(_)
+        | EvalError::CharacterTooLargeForEncoding(_)
+        | EvalError::NullCharacterNotPermitted
+        | EvalError::LikePatternTooLong => SqlState::PROGRAM_LIMIT_EXCEEDED,
+        EvalError::DivisionByZero => SqlState::DIVISION_BY_ZERO,
+        EvalError::Unsupported { .. }
+        | EvalError::UnsupportedUnits(..)
+        | EvalError::MultidimensionalArrayRemovalNotSupported => SqlState::FEATURE_NOT_SUPPORTED,
+        EvalError::FloatOverflow
+        | EvalError::FloatUnderflow
+        | EvalError::NumericFieldOverflow
+        | EvalError::Float32OutOfRange
+        | EvalError::Float64OutOfRange
+        | EvalError::Int16OutOfRange
+        | EvalError::Int32OutOfRange
+        | EvalError::Int64OutOfRange
+        | EvalError::UInt16OutOfRange
+        | EvalError::UInt32OutOfRange
+        | EvalError::UInt64OutOfRange
+        | EvalError::MzTimestampOutOfRange
+        | EvalError::MzTimestampStepOverflow
+        | EvalError::OidOutOfRange
+        | EvalError::CharOutOfRange
+        | EvalError::InfinityOutOfDomain(_)
+        | EvalError::NegativeOutOfDomain(_)
+        | EvalError::ZeroOutOfDomain(_)
+        | EvalError::OutOfDomain(..)
+        | EvalError::ComplexOutOfRange(_) => SqlState::NUMERIC_VALUE_OUT_OF_RANGE,
+        EvalError::DateBinOutOfRange(_)
+        | EvalError::IntervalOutOfRange
+        | EvalError::TimestampCannotBeNan
+        | EvalError::TimestampOutOfRange
+        | EvalError::DateOutOfRange => SqlState::DATETIME_FIELD_OVERFLOW,
+        EvalError::IndexOutOfRange { .. } | EvalError::IncompatibleArrayDimensions { .. } => {
+            SqlState::ARRAY_SUBSCRIPT_ERROR
+        }
+        EvalError::InvalidBase64Equals
+        | EvalError::InvalidBase64Symbol(_)
+        | EvalError::InvalidBase64EndSequence
+        | EvalError::InvalidTimezone(_)
+        | EvalError::InvalidTimezoneInterval
+        | EvalError::InvalidTimezoneConversion
+        | EvalError::InvalidLayer { .. }
+        | EvalError::InvalidEncodingName(_)
+        | EvalError::InvalidHashAlgorithm(_)
+        | EvalError::InvalidJsonbCast { .. }
+        | EvalError::InvalidRegexFlag(_)
+        | EvalError::InvalidParameterValue(_)
+        | EvalError::UnknownUnits(_) => SqlState::INVALID_PARAMETER_VALUE,
+        EvalError::InvalidArray(_) | EvalError::InvalidRange(_) => SqlState::DATA_EXCEPTION,
+        EvalError::InvalidByteSequence { .. } => SqlState::CHARACTER_NOT_IN_REPERTOIRE,
+        EvalError::InvalidRegex(_) => SqlState::INVALID_REGULAR_EXPRESSION,
+        EvalError::NegSqrt | EvalError::Undefined(_) => {
+            SqlState::INVALID_ARGUMENT_FOR_POWER_FUNCTION
+        }
+        EvalError::UnterminatedLikeEscapeSequence | EvalError::LikeEscapeTooLong => {
+            SqlState::INVALID_ESCAPE_SEQUENCE
+        }
+        EvalError::Parse(_) | EvalError::ParseHex(_) => SqlState::INVALID_TEXT_REPRESENTATION,
+        EvalError::MultipleRowsFromSubquery => SqlState::CARDINALITY_VIOLATION,
+        EvalError::StringValueTooLong { .. } => SqlState::STRING_DATA_RIGHT_TRUNCATION,
+        EvalError::Internal(_) | EvalError::TypeFromOid(_) => SqlState::INTERNAL_ERROR,
+    }
+}
+
+/// Returns the SQLSTATE code of an error that occurred while resolving names
+/// in the catalog.
+fn sql_catalog_error_code(e: &SqlCatalogError) -> SqlState {
+    match e {
+        SqlCatalogError::UnknownDatabase(_) => SqlState::INVALID_CATALOG_NAME,
+        SqlCatalogError::UnknownSchema(_) => SqlState::INVALID_SCHEMA_NAME,
+        SqlCatalogError::UnknownRole(_)
+        | SqlCatalogError::UnknownComputeInstance(_)
+        | SqlCatalogError::UnknownComputeReplica(_)
+        | SqlCatalogError::UnknownConnection(_) => SqlState::UNDEFINED_OBJECT,
+        SqlCatalogError::UnknownItem(_) => SqlState::UNDEFINED_TABLE,
+        SqlCatalogError::UnknownFunction(_) => SqlState::UNDEFINED_FUNCTION,
+        SqlCatalogError::UnexpectedType { .. } => SqlState::WRONG_OBJECT_TYPE,
+        SqlCatalogError::InvalidDependency { .. } => SqlState::FEATURE_NOT_SUPPORTED,
+    }
+}
+
+/// Returns the SQLSTATE code of an error that occurred while changing the
+/// catalog.
+fn catalog_error_code(e: &CatalogErrorKind) -> SqlState {
+    match e {
+        CatalogErrorKind::Sql(e) => sql_catalog_error_code(e),
+        CatalogErrorKind::DatabaseAlreadyExists(_) => SqlState::DUPLICATE_DATABASE,
+        CatalogErrorKind::SchemaAlreadyExists(_) => SqlState::DUPLICATE_SCHEMA,
+        CatalogErrorKind::RoleAlreadyExists(_)
+        | CatalogErrorKind::ClusterAlreadyExists(_)
+        | CatalogErrorKind::DuplicateReplica(..)
+        | CatalogErrorKind::ItemAlreadyExists(..) => SqlState::DUPLICATE_OBJECT,
+        CatalogErrorKind::ReservedSchemaName(_)
+        | CatalogErrorKind::ReservedRoleName(_)
+        | CatalogErrorKind::ReservedClusterName(_)
+        | CatalogErrorKind::ReservedReplicaName(_) => SqlState::RESERVED_NAME,
+        CatalogErrorKind::ReadOnlyComputeInstance(_)
+        | CatalogErrorKind::ReadOnlySystemSchema(_)
+        | CatalogErrorKind::ReadOnlyItem(_) => SqlState::INSUFFICIENT_PRIVILEGE,
+        CatalogErrorKind::SchemaNotEmpty(_) => SqlState::DEPENDENT_OBJECTS_STILL_EXIST,
+        CatalogErrorKind::InvalidTemporaryDependency(_)
+        | CatalogErrorKind::InvalidTemporarySchema => SqlState::INVALID_TABLE_DEFINITION,
+        CatalogErrorKind::UnsatisfiableLoggingDependency { .. }
+        | CatalogErrorKind::AmbiguousRename(_)
+        | CatalogErrorKind::TypeRename(_) => SqlState::FEATURE_NOT_SUPPORTED,
+        CatalogErrorKind::Corruption { .. }
+        | CatalogErrorKind::IdExhaustion
+        | CatalogErrorKind::OidExhaustion
+        | CatalogErrorKind::FailedMigration { .. }
+        | CatalogErrorKind::FailedBuiltinSchemaMigration(_)
+        | CatalogErrorKind::FailpointReached(_)
+        | CatalogErrorKind::Unstructured(_)
+        | CatalogErrorKind::Stash(_)
+        | CatalogErrorKind::UnexpectedStashState
+        | CatalogErrorKind::Uuid(_) => SqlState::INTERNAL_ERROR,
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Severity {
     Panic,
@@ -568,6 +746,7 @@ impl Severity {
             AdapterNotice::DroppedActiveDatabase { .. } => Severity::Notice,
             AdapterNotice::DroppedActiveCluster { .. } => Severity::Notice,
             AdapterNotice::QueryTimestamp { .. } => Severity::Notice,
+            AdapterNotice::QueryStatistics { .. } => Severity::Notice,
             AdapterNotice::EqualSubscribeBounds { .. } => Severity::Notice,
             AdapterNotice::QueryTrace { .. } => Severity::Notice,
             AdapterNotice::UnimplementedIsolationLevel { .. } => Severity::Notice,
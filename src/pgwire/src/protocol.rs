@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::convert::TryFrom;
 use std::future::Future;
 use std::iter;
@@ -30,7 +30,7 @@ use mz_adapter::session::{
     EndTransactionAction, ExternalUserMetadata, InProgressRows, Portal, PortalState,
     RowBatchStream, TransactionStatus,
 };
-use mz_adapter::{ExecuteResponse, PeekResponseUnary, RowsFuture};
+use mz_adapter::{AdapterError, ExecuteResponse, PeekResponseUnary, RowsFuture};
 use mz_frontegg_auth::FronteggAuthentication;
 use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
@@ -206,8 +206,7 @@ where
         external_metadata,
     });
     for (name, value) in params {
-        let local = false;
-        let _ = session.vars_mut().set(&name, &value, local);
+        let _ = session.vars_mut().set_default(&name, &value);
     }
 
     let mut buf = vec![BackendMessage::AuthenticationOk];
@@ -254,6 +253,8 @@ where
     let machine = StateMachine {
         conn,
         adapter_client,
+        pipeline: None,
+        pending_message: None,
     };
 
     select! {
@@ -277,6 +278,36 @@ enum State {
 struct StateMachine<'a, A> {
     conn: &'a mut FramedConn<A>,
     adapter_client: mz_adapter::SessionClient,
+    /// The responses that are waiting to be sent to the client while a
+    /// pipeline is active, in the order they must be sent. While this is
+    /// `Some`, `send` appends to it rather than writing to the connection. See
+    /// `advance_ready` for details.
+    pipeline: Option<VecDeque<Pipelined>>,
+    /// A message that was received while a pipeline was active but that could
+    /// not be pipelined. It is handled once the pipeline has been drained.
+    pending_message: Option<FrontendMessage>,
+}
+
+/// An entry in the pipeline of responses waiting to be sent to the client.
+enum Pipelined {
+    /// A message that was sent while the pipeline was active.
+    Message(BackendMessage),
+    /// The response to a pipelined `SELECT`, whose peek runs concurrently with
+    /// the execution of later statements.
+    ///
+    /// The response is queued when the `Execute` is handled, and the
+    /// statement's implicit transaction is committed right after, before the
+    /// next message is read. Its rows are only sent later, when the pipeline
+    /// is drained because the client sent a message that can't be pipelined
+    /// or has no more messages waiting. Committing the transaction clears the
+    /// session's portals, so the portal is taken out of the session and held
+    /// here until its rows have been sent.
+    Response {
+        response: ExecuteResponse,
+        row_desc: Option<RelationDesc>,
+        portal_name: String,
+        portal: Portal,
+    },
 }
 
 impl<'a, A> StateMachine<'a, A>
@@ -305,22 +336,52 @@ where
     }
 
     async fn advance_ready(&mut self) -> Result<State, io::Error> {
-        // Handle timeouts first so we don't execute any statements when there's a pending timeout.
-        let message = select! {
-            biased;
-
-            // `recv_timeout()` is cancel-safe as per it's docs.
-            Some(timeout) = self.adapter_client.recv_timeout() => {
-                let error_response = ErrorResponse::from_adapter_error(Severity::Fatal, timeout.into());
-                self.adapter_client.terminate().await;
-                // We must wait for the client to send a request before we can send the error response.
-                // Due to the PG wire protocol, we can't send an ErrorResponse unless it is in response
-                // to a client message.
-                let _ = self.conn.recv().await?;
-                return self.error(error_response).await;
-            },
-            // `recv()` is cancel-safe as per it's docs.
-            message = self.conn.recv() => message?,
+        // Clients that pipeline their requests send several statements before
+        // waiting for any results. When such a client executes a `SELECT` in
+        // its own implicit transaction, we issue the peek but defer sending its
+        // results, and keep executing any further `SELECT`s the client has
+        // already sent, so that their peeks run concurrently instead of one
+        // after another. Responses are still sent in order, as soon as the
+        // client runs out of statements that can be pipelined.
+        //
+        // Only messages that have already arrived are considered, as waiting
+        // for the client could deadlock if it is waiting for our results.
+        if self.pipeline.is_some() {
+            match self.conn.recv().now_or_never() {
+                Some(Ok(Some(message))) if self.is_pipelinable(&message) => {
+                    self.pending_message = Some(message);
+                }
+                Some(Ok(Some(message))) => {
+                    self.pending_message = Some(message);
+                    return self.drain_pipeline().await;
+                }
+                Some(Ok(None)) => return Ok(State::Done),
+                Some(Err(e)) => return Err(e),
+                None => return self.drain_pipeline().await,
+            }
+        }
+
+        let message = if let Some(message) = self.pending_message.take() {
+            Some(message)
+        } else {
+            // Handle timeouts first so we don't execute any statements when there's a pending
+            // timeout.
+            select! {
+                biased;
+
+                // `recv_timeout()` is cancel-safe as per it's docs.
+                Some(timeout) = self.adapter_client.recv_timeout() => {
+                    let error_response = ErrorResponse::from_adapter_error(Severity::Fatal, timeout.into());
+                    self.adapter_client.terminate().await;
+                    // We must wait for the client to send a request before we can send the error response.
+                    // Due to the PG wire protocol, we can't send an ErrorResponse unless it is in response
+                    // to a client message.
+                    let _ = self.conn.recv().await?;
+                    return self.error(error_response).await;
+                },
+                // `recv()` is cancel-safe as per it's docs.
+                message = self.conn.recv() => message?,
+            }
         };
 
         self.adapter_client
@@ -363,6 +424,9 @@ where
                 portal_name,
                 max_rows,
             }) => {
+                if self.pipeline.is_none() && self.can_pipeline_execute(&portal_name, max_rows) {
+                    self.pipeline = Some(VecDeque::new());
+                }
                 let max_rows = match usize::try_from(max_rows) {
                     Ok(0) | Err(_) => ExecuteCount::All, // If `max_rows < 0`, no limit.
                     Ok(n) => ExecuteCount::Count(n),
@@ -415,7 +479,15 @@ where
     }
 
     async fn advance_drain(&mut self) -> Result<State, io::Error> {
-        let message = self.conn.recv().await?;
+        if self.pipeline.is_some() {
+            if let State::Done = self.drain_pipeline().await? {
+                return Ok(State::Done);
+            }
+        }
+        let message = match self.pending_message.take() {
+            Some(message) => Some(message),
+            None => self.conn.recv().await?,
+        };
         if message.is_some() {
             self.adapter_client
                 .remove_idle_in_transaction_session_timeout();
@@ -427,6 +499,93 @@ where
         }
     }
 
+    /// Reports whether `message` can be handled while a pipeline is active.
+    fn is_pipelinable(&mut self, message: &FrontendMessage) -> bool {
+        match message {
+            // Named statements and portals are excluded, as they would outlive
+            // an error in an earlier statement, after which PostgreSQL would
+            // have skipped creating them.
+            FrontendMessage::Parse { name, .. } => name.is_empty(),
+            FrontendMessage::Bind { portal_name, .. } => portal_name.is_empty(),
+            FrontendMessage::DescribeStatement { .. } | FrontendMessage::DescribePortal { .. } => {
+                true
+            }
+            FrontendMessage::Execute {
+                portal_name,
+                max_rows,
+            } => self.can_pipeline_execute(portal_name, *max_rows),
+            _ => false,
+        }
+    }
+
+    /// Reports whether executing the named portal can start or continue a
+    /// pipeline. The portal must be a `SELECT` in its own implicit transaction
+    /// whose results are requested all at once.
+    fn can_pipeline_execute(&mut self, portal_name: &str, max_rows: i32) -> bool {
+        if max_rows > 0 {
+            return false;
+        }
+        let session = self.adapter_client.session();
+        if !matches!(session.transaction(), TransactionStatus::Started(_)) {
+            return false;
+        }
+        match session.get_portal_unverified(portal_name) {
+            Some(portal) => {
+                matches!(portal.state, PortalState::NotStarted)
+                    && matches!(portal.stmt, Some(Statement::Select(_)))
+            }
+            None => false,
+        }
+    }
+
+    /// Sends the responses in the pipeline to the client and ends the
+    /// pipeline.
+    async fn drain_pipeline(&mut self) -> Result<State, io::Error> {
+        let pipeline = self.pipeline.take().expect("pipeline must be active");
+        for pipelined in pipeline {
+            match pipelined {
+                Pipelined::Message(message) => self.conn.send(message).await?,
+                Pipelined::Response {
+                    response,
+                    row_desc,
+                    portal_name,
+                    portal,
+                } => {
+                    // Temporarily reinstate the portal, to which `send_rows`
+                    // records its progress.
+                    let displaced = self
+                        .adapter_client
+                        .session()
+                        .insert_portal(portal_name.clone(), portal);
+                    let state = self
+                        .send_execute_response(
+                            response,
+                            row_desc,
+                            portal_name.clone(),
+                            ExecuteCount::All,
+                            portal_exec_message,
+                            None,
+                            ExecuteTimeout::None,
+                        )
+                        .await?;
+                    let session = self.adapter_client.session();
+                    session.remove_portal(&portal_name);
+                    if let Some(displaced) = displaced {
+                        session.insert_portal(portal_name, displaced);
+                    }
+                    match state {
+                        State::Ready => (),
+                        // PostgreSQL skips all messages after an error until
+                        // the next Sync, so the remaining responses must not
+                        // be sent.
+                        state => return Ok(state),
+                    }
+                }
+            }
+        }
+        Ok(State::Ready)
+    }
+
     async fn one_query(&mut self, stmt: Statement<Raw>) -> Result<State, io::Error> {
         // Bind the portal. Note that this does not set the empty string prepared
         // statement.
@@ -468,7 +627,7 @@ where
             }
         }
 
-        let result = match self.adapter_client.execute(EMPTY_PORTAL.to_string()).await {
+        let result = match self.execute_portal(EMPTY_PORTAL.to_string()).await? {
             Ok(response) => {
                 self.send_pending_notices().await?;
                 self.send_execute_response(
@@ -818,7 +977,27 @@ where
                     // Postgres).
                     self.start_transaction(Some(1)).await;
 
-                    match self.adapter_client.execute(portal_name.clone()).await {
+                    match self.execute_portal(portal_name.clone()).await? {
+                        Ok(response @ ExecuteResponse::SendingRows { .. })
+                            if self.pipeline.is_some() =>
+                        {
+                            self.send_pending_notices().await?;
+                            let portal = self
+                                .adapter_client
+                                .session()
+                                .take_portal(&portal_name)
+                                .expect("executed portal must exist");
+                            self.pipeline
+                                .as_mut()
+                                .expect("pipeline must be active")
+                                .push_back(Pipelined::Response {
+                                    response,
+                                    row_desc,
+                                    portal_name,
+                                    portal,
+                                });
+                            Ok(State::Ready)
+                        }
                         Ok(response) => {
                             self.send_pending_notices().await?;
                             self.send_execute_response(
@@ -878,6 +1057,21 @@ where
         .boxed()
     }
 
+    /// Executes the portal `portal_name`, unless the client hangs up first.
+    ///
+    /// Giving up on the response lets the coordinator notice that the client
+    /// has gone away, e.g. so that it does not run peeks that are still
+    /// waiting in a queue.
+    async fn execute_portal(
+        &mut self,
+        portal_name: String,
+    ) -> Result<Result<ExecuteResponse, AdapterError>, io::Error> {
+        tokio::select! {
+            err = self.conn.wait_closed() => Err(err),
+            response = self.adapter_client.execute(portal_name) => Ok(response),
+        }
+    }
+
     async fn describe_statement(&mut self, name: &str) -> Result<State, io::Error> {
         // Start a transaction if we aren't in one.
         self.start_transaction(Some(1)).await;
@@ -1028,20 +1222,22 @@ where
         M: Into<BackendMessage>,
     {
         let message: BackendMessage = message.into();
-        match message {
-            BackendMessage::ErrorResponse(ref err) => {
-                let minimum_client_severity =
-                    self.adapter_client.session().vars().client_min_messages();
-                if err
-                    .severity
-                    .should_output_to_client(minimum_client_severity)
-                {
-                    self.conn.send(message).await
-                } else {
-                    Ok(())
-                }
+        if let BackendMessage::ErrorResponse(ref err) = message {
+            let minimum_client_severity =
+                self.adapter_client.session().vars().client_min_messages();
+            if !err
+                .severity
+                .should_output_to_client(minimum_client_severity)
+            {
+                return Ok(());
+            }
+        }
+        match &mut self.pipeline {
+            Some(pipeline) => {
+                pipeline.push_back(Pipelined::Message(message));
+                Ok(())
             }
-            _ => self.conn.send(message).await,
+            None => self.conn.send(message).await,
         }
     }
 
@@ -1185,18 +1381,31 @@ where
                 // This code is somewhat awkwardly structured because we
                 // can't hold `var` across an await point.
                 let qn = name.to_string();
-                let msg = if let Some(var) = self
+                let reset_all = qn.eq_ignore_ascii_case("all");
+                let msgs: Vec<_> = self
                     .adapter_client
                     .session()
                     .vars_mut()
                     .notify_set()
-                    .find(|v| v.name() == qn)
-                {
-                    Some(BackendMessage::ParameterStatus(var.name(), var.value()))
-                } else {
-                    None
-                };
-                if let Some(msg) = msg {
+                    .filter(|v| reset_all || v.name() == qn)
+                    .map(|var| BackendMessage::ParameterStatus(var.name(), var.value()))
+                    .collect();
+                for msg in msgs {
+                    self.send(msg).await?;
+                }
+                command_complete!()
+            }
+            ExecuteResponse::DiscardedAll => {
+                // Report the reset values of all parameters, as they may have
+                // changed.
+                let msgs: Vec<_> = self
+                    .adapter_client
+                    .session()
+                    .vars()
+                    .notify_set()
+                    .map(|var| BackendMessage::ParameterStatus(var.name(), var.value()))
+                    .collect();
+                for msg in msgs {
                     self.send(msg).await?;
                 }
                 command_complete!()
@@ -1280,7 +1489,6 @@ where
             | ExecuteResponse::CreatedViews { .. }
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::Deleted(..)
-            | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DiscardedTemp
             | ExecuteResponse::DroppedComputeInstance
             | ExecuteResponse::DroppedComputeReplica
@@ -226,55 +226,149 @@ pub async fn publication_info(
     let mut table_infos = vec![];
     for row in tables {
         let oid = row.get("oid");
-
-        let columns = client
-            .query(
-                "SELECT
-                        a.attname AS name,
-                        a.atttypid AS typoid,
-                        a.atttypmod AS typmod,
-                        a.attnotnull AS not_null,
-                        b.oid IS NOT NULL AS primary_key
-                    FROM pg_catalog.pg_attribute a
-                    LEFT JOIN pg_catalog.pg_constraint b
-                        ON a.attrelid = b.conrelid
-                        AND b.contype = 'p'
-                        AND a.attnum = ANY (b.conkey)
-                    WHERE a.attnum > 0::pg_catalog.int2
-                        AND NOT a.attisdropped
-                        AND a.attrelid = $1
-                    ORDER BY a.attnum",
-                &[&oid],
-            )
-            .await?
-            .into_iter()
-            .map(|row| {
-                let name: String = row.get("name");
-                let type_oid = row.get("typoid");
-                let type_mod: i32 = row.get("typmod");
-                let not_null: bool = row.get("not_null");
-                let primary_key = row.get("primary_key");
-                Ok(PostgresColumnDesc {
-                    name,
-                    type_oid,
-                    type_mod,
-                    nullable: !not_null,
-                    primary_key,
-                })
-            })
-            .collect::<Result<Vec<_>, PostgresError>>()?;
-
         table_infos.push(PostgresTableDesc {
             oid,
             namespace: row.get("schemaname"),
             name: row.get("tablename"),
-            columns,
+            columns: table_columns(&client, oid).await?,
         });
     }
 
     Ok(table_infos)
 }
 
+/// Fetches table schema information from an upstream Postgres database for a
+/// single table, given a connection string and the table's schema and name.
+///
+/// # Errors
+///
+/// - Invalid connection string, user information, or user permissions.
+/// - Upstream table does not exist, or is not an ordinary or partitioned
+///   table.
+pub async fn table_info(
+    config: &Config,
+    namespace: &str,
+    name: &str,
+) -> Result<PostgresTableDesc, PostgresError> {
+    let client = config.connect("postgres_table_info").await?;
+
+    let row = client
+        .query(
+            "SELECT
+                c.oid
+            FROM
+                pg_catalog.pg_class AS c
+                JOIN pg_namespace AS n ON c.relnamespace = n.oid
+            WHERE
+                n.nspname = $1 AND c.relname = $2 AND c.relkind IN ('r', 'p')",
+            &[&namespace, &name],
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "table {:?}.{:?} does not exist or is not a table",
+                namespace,
+                name
+            )
+        })?;
+    let oid = row.get("oid");
+
+    Ok(PostgresTableDesc {
+        oid,
+        namespace: namespace.to_string(),
+        name: name.to_string(),
+        columns: table_columns(&client, oid).await?,
+    })
+}
+
+/// Fetches the schema of a relation in an upstream Materialize instance, given
+/// a connection string and the relation's schema and name.
+///
+/// Materialize does not describe the columns of views in `pg_attribute`, so
+/// the columns are instead taken from a prepared statement that selects from
+/// the relation. Upstream Materialize instances report neither the
+/// nullability nor the type modifiers of these columns, so all columns are
+/// described as nullable and without a type modifier.
+///
+/// # Errors
+///
+/// - Invalid connection string, user information, or user permissions.
+/// - Upstream relation does not exist.
+pub async fn view_info(
+    config: &Config,
+    namespace: &str,
+    name: &str,
+) -> Result<PostgresTableDesc, PostgresError> {
+    let client = config.connect("materialize_view_info").await?;
+
+    let statement = client
+        .prepare(&format!("SELECT * FROM {:?}.{:?}", namespace, name))
+        .await?;
+    let columns = statement
+        .columns()
+        .iter()
+        .map(|column| PostgresColumnDesc {
+            name: column.name().to_string(),
+            type_oid: column.type_().oid(),
+            type_mod: -1,
+            nullable: true,
+            primary_key: false,
+        })
+        .collect();
+
+    Ok(PostgresTableDesc {
+        oid: 0,
+        namespace: namespace.to_string(),
+        name: name.to_string(),
+        columns,
+    })
+}
+
+/// Fetches the description of each column of the table with the given OID.
+async fn table_columns(
+    client: &Client,
+    oid: u32,
+) -> Result<Vec<PostgresColumnDesc>, PostgresError> {
+    client
+        .query(
+            "SELECT
+                    a.attname AS name,
+                    a.atttypid AS typoid,
+                    a.atttypmod AS typmod,
+                    a.attnotnull AS not_null,
+                    b.oid IS NOT NULL AS primary_key
+                FROM pg_catalog.pg_attribute a
+                LEFT JOIN pg_catalog.pg_constraint b
+                    ON a.attrelid = b.conrelid
+                    AND b.contype = 'p'
+                    AND a.attnum = ANY (b.conkey)
+                WHERE a.attnum > 0::pg_catalog.int2
+                    AND NOT a.attisdropped
+                    AND a.attrelid = $1
+                ORDER BY a.attnum",
+            &[&oid],
+        )
+        .await?
+        .into_iter()
+        .map(|row| {
+            let name: String = row.get("name");
+            let type_oid = row.get("typoid");
+            let type_mod: i32 = row.get("typmod");
+            let not_null: bool = row.get("not_null");
+            let primary_key = row.get("primary_key");
+            Ok(PostgresColumnDesc {
+                name,
+                type_oid,
+                type_mod,
+                nullable: !not_null,
+                primary_key,
+            })
+        })
+        .collect()
+}
+
 pub async fn drop_replication_slots(config: Config, slots: &[&str]) -> Result<(), PostgresError> {
     let client = config.connect("postgres_drop_replication_slots").await?;
     let replication_client = config.connect_replication().await?;
@@ -0,0 +1,232 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Geometric types.
+//!
+//! [`Point`] and [`BoundingBox`] back the SQL types `point` and `box`, which
+//! the `st_*` functions use to cover the common streaming use case of testing
+//! whether moving objects are near each other or inside a rectangular region.
+//! There is no notion of a coordinate reference system: coordinates are either
+//! planar, or longitude/latitude pairs in degrees when using the spherical
+//! functions.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use ordered_float::OrderedFloat;
+
+/// The mean radius of the Earth in meters, as used by PostGIS for spherical
+/// distance calculations.
+pub const EARTH_MEAN_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// A point in two-dimensional space.
+///
+/// Points compare and hash their coordinates like [`OrderedFloat`], so that
+/// they can be used as datums: `NaN` is equal to itself, and greater than all
+/// other values.
+#[derive(Debug, Clone, Copy)]
+pub struct Point {
+    /// The horizontal coordinate, or longitude in degrees.
+    pub x: f64,
+    /// The vertical coordinate, or latitude in degrees.
+    pub y: f64,
+}
+
+impl Point {
+    /// Constructs a new point.
+    pub fn new(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+
+    /// Returns the planar (Euclidean) distance between two points.
+    pub fn distance(&self, other: &Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    /// Returns the great-circle distance in meters between two points whose
+    /// coordinates are longitude/latitude pairs in degrees, using the
+    /// haversine formula on a sphere of radius [`EARTH_MEAN_RADIUS_METERS`].
+    ///
+    /// Returns an error if either point's coordinates are not valid
+    /// longitude/latitude values.
+    pub fn distance_sphere(&self, other: &Point) -> Result<f64, InvalidCoordinateError> {
+        self.validate_lon_lat()?;
+        other.validate_lon_lat()?;
+        let (lat1, lat2) = (self.y.to_radians(), other.y.to_radians());
+        let dlat = (other.y - self.y).to_radians();
+        let dlon = (other.x - self.x).to_radians();
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        let c = 2.0 * a.sqrt().min(1.0).asin();
+        Ok(EARTH_MEAN_RADIUS_METERS * c)
+    }
+
+    fn validate_lon_lat(&self) -> Result<(), InvalidCoordinateError> {
+        if !(-180.0..=180.0).contains(&self.x) {
+            return Err(InvalidCoordinateError::Longitude(self.x));
+        }
+        if !(-90.0..=90.0).contains(&self.y) {
+            return Err(InvalidCoordinateError::Latitude(self.y));
+        }
+        Ok(())
+    }
+
+    fn ordered(&self) -> (OrderedFloat<f64>, OrderedFloat<f64>) {
+        (OrderedFloat(self.x), OrderedFloat(self.y))
+    }
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Point) -> bool {
+        self.ordered() == other.ordered()
+    }
+}
+
+impl Eq for Point {}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Point) -> Ordering {
+        self.ordered().cmp(&other.ordered())
+    }
+}
+
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ordered().hash(state)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// An axis-aligned rectangle.
+///
+/// A bounding box is normalized on construction so that `low` is the
+/// lower-left corner and `high` is the upper-right corner, regardless of the
+/// order in which the corners were specified, as PostgreSQL does for its `box`
+/// type. Boxes are ordered by their lower-left, then their upper-right corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundingBox {
+    low: Point,
+    high: Point,
+}
+
+impl BoundingBox {
+    /// Constructs the bounding box with the given opposite corners.
+    pub fn new(a: Point, b: Point) -> BoundingBox {
+        BoundingBox {
+            low: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            high: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// Returns the lower-left corner of the box.
+    pub fn low(&self) -> Point {
+        self.low
+    }
+
+    /// Returns the upper-right corner of the box.
+    pub fn high(&self) -> Point {
+        self.high
+    }
+
+    /// Reports whether `point` lies within the box. Points on the boundary of
+    /// the box are considered to be within it.
+    pub fn contains(&self, point: &Point) -> bool {
+        self.low.x <= point.x
+            && point.x <= self.high.x
+            && self.low.y <= point.y
+            && point.y <= self.high.y
+    }
+}
+
+impl fmt::Display for BoundingBox {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Match the output of PostgreSQL's `box` type, which prints the
+        // upper-right corner first.
+        write!(f, "{},{}", self.high, self.low)
+    }
+}
+
+/// An error indicating that a coordinate is not a valid longitude or
+/// latitude.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidCoordinateError {
+    /// The longitude was outside of the range [-180, 180].
+    Longitude(f64),
+    /// The latitude was outside of the range [-90, 90].
+    Latitude(f64),
+}
+
+impl fmt::Display for InvalidCoordinateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidCoordinateError::Longitude(x) => {
+                write!(f, "longitude {} is out of range [-180, 180]", x)
+            }
+            InvalidCoordinateError::Latitude(y) => {
+                write!(f, "latitude {} is out of range [-90, 90]", y)
+            }
+        }
+    }
+}
+
+impl Error for InvalidCoordinateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(Point::new(0.0, 0.0).distance(&Point::new(3.0, 4.0)), 5.0);
+        assert_eq!(Point::new(1.0, 1.0).distance(&Point::new(1.0, 1.0)), 0.0);
+    }
+
+    #[test]
+    fn test_distance_sphere() {
+        // Paris to London is roughly 343.5km.
+        let paris = Point::new(2.3522, 48.8566);
+        let london = Point::new(-0.1276, 51.5072);
+        let d = paris.distance_sphere(&london).unwrap();
+        assert!((d - 343_500.0).abs() < 1_000.0, "{}", d);
+        assert_eq!(paris.distance_sphere(&paris).unwrap(), 0.0);
+
+        assert_eq!(
+            Point::new(181.0, 0.0).distance_sphere(&paris),
+            Err(InvalidCoordinateError::Longitude(181.0))
+        );
+        assert_eq!(
+            paris.distance_sphere(&Point::new(0.0, -90.5)),
+            Err(InvalidCoordinateError::Latitude(-90.5))
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let b = BoundingBox::new(Point::new(10.0, 0.0), Point::new(0.0, 10.0));
+        assert_eq!(b.low(), Point::new(0.0, 0.0));
+        assert_eq!(b.high(), Point::new(10.0, 10.0));
+        assert_eq!(b.to_string(), "(10,10),(0,0)");
+        assert!(b.contains(&Point::new(5.0, 5.0)));
+        assert!(b.contains(&Point::new(0.0, 10.0)));
+        assert!(!b.contains(&Point::new(-0.1, 5.0)));
+        assert!(!b.contains(&Point::new(5.0, f64::NAN)));
+    }
+}
@@ -33,6 +33,7 @@ use crate::adt::array::{
     Array, ArrayDimension, ArrayDimensions, InvalidArrayError, MAX_ARRAY_DIMENSIONS,
 };
 use crate::adt::date::Date;
+use crate::adt::geo::{BoundingBox, Point};
 use crate::adt::interval::Interval;
 use crate::adt::numeric;
 use crate::adt::numeric::Numeric;
@@ -400,6 +401,8 @@ enum Tag {
     UInt64,
     MzTimestamp,
     Range,
+    Point,
+    BoundingBox,
 }
 
 // --------------------------------------------------------------------------------
@@ -479,6 +482,12 @@ fn read_time(data: &[u8], offset: &mut usize) -> NaiveTime {
     NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos).unwrap()
 }
 
+fn read_point(data: &[u8], offset: &mut usize) -> Point {
+    let x = f64::from_bits(u64::from_le_bytes(read_byte_array(data, offset)));
+    let y = f64::from_bits(u64::from_le_bytes(read_byte_array(data, offset)));
+    Point::new(x, y)
+}
+
 /// Read a datum starting at byte `offset`.
 ///
 /// Updates `offset` to point to the first byte after the end of the read region.
@@ -566,6 +575,14 @@ unsafe fn read_datum<'a>(data: &'a [u8], offset: &mut usize) -> Datum<'a> {
         | Tag::StringLong
         | Tag::StringHuge => read_lengthed_datum(data, offset, tag),
         Tag::Uuid => Datum::Uuid(Uuid::from_bytes(read_byte_array(data, offset))),
+        Tag::Point => Datum::Point(read_point(data, offset)),
+        Tag::BoundingBox => {
+            // Bounding boxes are written already normalized, so constructing
+            // them from their corners again is lossless.
+            let low = read_point(data, offset);
+            let high = read_point(data, offset);
+            Datum::BoundingBox(BoundingBox::new(low, high))
+        }
         Tag::Array => {
             // See the comment in `Row::push_array` for details on the encoding
             // of arrays.
@@ -719,6 +736,14 @@ where
     data.extend_from_slice(&u32::to_le_bytes(time.nanosecond()));
 }
 
+fn push_point<D>(data: &mut D, point: Point)
+where
+    D: Vector<u8>,
+{
+    data.extend_from_slice(&point.x.to_bits().to_le_bytes());
+    data.extend_from_slice(&point.y.to_bits().to_le_bytes());
+}
+
 fn push_datum<D>(data: &mut D, datum: Datum)
 where
     D: Vector<u8>,
@@ -813,6 +838,15 @@ where
             data.push(Tag::Uuid.into());
             data.extend_from_slice(u.as_bytes());
         }
+        Datum::Point(p) => {
+            data.push(Tag::Point.into());
+            push_point(data, p);
+        }
+        Datum::BoundingBox(b) => {
+            data.push(Tag::BoundingBox.into());
+            push_point(data, b.low());
+            push_point(data, b.high());
+        }
         Datum::Array(array) => {
             // See the comment in `Row::push_array` for details on the encoding
             // of arrays.
@@ -971,6 +1005,8 @@ pub fn datum_size(datum: &Datum) -> usize {
             1 + bytes_for_length + string.len()
         }
         Datum::Uuid(_) => 1 + size_of::<uuid::Bytes>(),
+        Datum::Point(_) => 1 + 2 * size_of::<f64>(),
+        Datum::BoundingBox(_) => 1 + 4 * size_of::<f64>(),
         Datum::Array(array) => {
             1 + size_of::<u8>()
                 + array.dims.data.len()
@@ -21,13 +21,14 @@ use mz_ore::cast::CastFrom;
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
 
 use crate::adt::array::ArrayDimension;
+use crate::adt::geo::{BoundingBox, Point};
 use crate::adt::numeric::Numeric;
 use crate::adt::range::{Range, RangeInner, RangeLowerBound, RangeUpperBound};
 use crate::chrono::ProtoNaiveTime;
 use crate::row::proto_datum::DatumType;
 use crate::row::{
-    ProtoArray, ProtoArrayDimension, ProtoDatum, ProtoDatumOther, ProtoDict, ProtoDictElement,
-    ProtoNumeric, ProtoRange, ProtoRangeInner, ProtoRow,
+    ProtoArray, ProtoArrayDimension, ProtoBoundingBox, ProtoDatum, ProtoDatumOther, ProtoDict,
+    ProtoDictElement, ProtoNumeric, ProtoPoint, ProtoRange, ProtoRangeInner, ProtoRow,
 };
 use crate::{Datum, Row, RowPacker};
 
@@ -130,6 +131,11 @@ impl<'a> From<Datum<'a>> for ProtoDatum {
             }
             Datum::JsonNull => DatumType::Other(ProtoDatumOther::JsonNull.into()),
             Datum::Uuid(x) => DatumType::Uuid(x.as_bytes().to_vec()),
+            Datum::Point(x) => DatumType::Point(x.into()),
+            Datum::BoundingBox(x) => DatumType::BoundingBox(ProtoBoundingBox {
+                low: Some(x.low().into()),
+                high: Some(x.high().into()),
+            }),
             Datum::MzTimestamp(x) => DatumType::MzTimestamp(x.into()),
             Datum::Dummy => DatumType::Other(ProtoDatumOther::Dummy.into()),
             Datum::Null => DatumType::Other(ProtoDatumOther::Null.into()),
@@ -198,6 +204,15 @@ impl RowPacker<'_> {
                 let u = Uuid::from_slice(x).map_err(|err| err.to_string())?;
                 self.push(Datum::Uuid(u));
             }
+            Some(DatumType::Point(x)) => self.push(Datum::Point(x.into())),
+            Some(DatumType::BoundingBox(x)) => {
+                let low = x.low.as_ref().ok_or("missing low for bounding box")?;
+                let high = x.high.as_ref().ok_or("missing high for bounding box")?;
+                self.push(Datum::BoundingBox(BoundingBox::new(
+                    low.into(),
+                    high.into(),
+                )))
+            }
             Some(DatumType::Date(x)) => self.push(Datum::Date(x.clone().into_rust()?)),
             Some(DatumType::Time(x)) => self.push(Datum::Time(x.clone().into_rust()?)),
             Some(DatumType::Timestamp(x)) => self.push(Datum::Timestamp(x.clone().into_rust()?)),
@@ -289,6 +304,18 @@ impl RowPacker<'_> {
     }
 }
 
+impl From<Point> for ProtoPoint {
+    fn from(x: Point) -> Self {
+        ProtoPoint { x: x.x, y: x.y }
+    }
+}
+
+impl From<&ProtoPoint> for Point {
+    fn from(x: &ProtoPoint) -> Self {
+        Point::new(x.x, x.y)
+    }
+}
+
 /// TODO: remove this in favor of [`RustType::from_proto`].
 impl TryFrom<&ProtoRow> for Row {
     type Error = String;
@@ -331,6 +358,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::adt::array::ArrayDimension;
+    use crate::adt::geo::{BoundingBox, Point};
     use crate::adt::interval::Interval;
     use crate::adt::numeric::Numeric;
     use crate::adt::timestamp::CheckedTimestamp;
@@ -388,6 +416,11 @@ mod tests {
             Datum::from(Numeric::nan()),
             Datum::JsonNull,
             Datum::Uuid(Uuid::from_u128(30)),
+            Datum::Point(Point::new(0.5, -0.5)),
+            Datum::BoundingBox(BoundingBox::new(
+                Point::new(1.0, 2.0),
+                Point::new(-1.0, -2.0),
+            )),
             Datum::Dummy,
             Datum::Null,
         ]);
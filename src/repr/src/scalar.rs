@@ -33,6 +33,7 @@ use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use crate::adt::array::{Array, ArrayDimension};
 use crate::adt::char::{Char, CharLength};
 use crate::adt::date::Date;
+use crate::adt::geo::{BoundingBox, Point};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::numeric::{Numeric, NumericMaxScale};
@@ -123,6 +124,10 @@ pub enum Datum<'a> {
     JsonNull,
     /// A universally unique identifier.
     Uuid(Uuid),
+    /// A point in two-dimensional space.
+    Point(Point),
+    /// An axis-aligned rectangle.
+    BoundingBox(BoundingBox),
     MzTimestamp(crate::Timestamp),
     /// A range of values, e.g. [-1, 1).
     Range(Range<DatumNested<'a>>),
@@ -658,6 +663,32 @@ impl<'a> Datum<'a> {
         }
     }
 
+    /// Unwraps the point value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::Point`].
+    #[track_caller]
+    pub fn unwrap_point(&self) -> Point {
+        match self {
+            Datum::Point(p) => *p,
+            _ => panic!("Datum::unwrap_point called on {:?}", self),
+        }
+    }
+
+    /// Unwraps the bounding box value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::BoundingBox`].
+    #[track_caller]
+    pub fn unwrap_bounding_box(&self) -> BoundingBox {
+        match self {
+            Datum::BoundingBox(b) => *b,
+            _ => panic!("Datum::unwrap_bounding_box called on {:?}", self),
+        }
+    }
+
     /// Unwraps the array value within this datum.
     ///
     /// # Panics
@@ -809,6 +840,10 @@ impl<'a> Datum<'a> {
                     (Datum::String(_), _) => false,
                     (Datum::Uuid(_), ScalarType::Uuid) => true,
                     (Datum::Uuid(_), _) => false,
+                    (Datum::Point(_), ScalarType::Point) => true,
+                    (Datum::Point(_), _) => false,
+                    (Datum::BoundingBox(_), ScalarType::BoundingBox) => true,
+                    (Datum::BoundingBox(_), _) => false,
                     (Datum::Array(array), ScalarType::Array(t)) => {
                         array.elements.iter().all(|e| match e {
                             Datum::Null => true,
@@ -1034,6 +1069,19 @@ impl<'a> From<Uuid> for Datum<'a> {
         Datum::Uuid(uuid)
     }
 }
+
+impl<'a> From<Point> for Datum<'a> {
+    fn from(point: Point) -> Datum<'a> {
+        Datum::Point(point)
+    }
+}
+
+impl<'a> From<BoundingBox> for Datum<'a> {
+    fn from(bounding_box: BoundingBox) -> Datum<'a> {
+        Datum::BoundingBox(bounding_box)
+    }
+}
+
 impl<'a> From<crate::Timestamp> for Datum<'a> {
     fn from(ts: crate::Timestamp) -> Datum<'a> {
         Datum::MzTimestamp(ts)
@@ -1111,6 +1159,8 @@ impl fmt::Display for Datum<'_> {
                 f.write_str("\"")
             }
             Datum::Uuid(u) => write!(f, "{}", u),
+            Datum::Point(p) => write!(f, "{}", p),
+            Datum::BoundingBox(b) => write!(f, "{}", b),
             Datum::Array(array) => {
                 f.write_str("{")?;
                 write_delimited(f, ", ", &array.elements, |f, e| write!(f, "{}", e))?;
@@ -1220,6 +1270,10 @@ pub enum ScalarType {
     Jsonb,
     /// The type of [`Datum::Uuid`].
     Uuid,
+    /// The type of [`Datum::Point`].
+    Point,
+    /// The type of [`Datum::BoundingBox`].
+    BoundingBox,
     /// The type of [`Datum::Array`].
     ///
     /// Elements within the array are of the specified type. It is illegal for
@@ -1314,6 +1368,8 @@ impl RustType<ProtoScalarType> for ScalarType {
                 ScalarType::String => String(()),
                 ScalarType::Jsonb => Jsonb(()),
                 ScalarType::Uuid => Uuid(()),
+                ScalarType::Point => Point(()),
+                ScalarType::BoundingBox => BoundingBox(()),
                 ScalarType::Oid => Oid(()),
                 ScalarType::RegProc => RegProc(()),
                 ScalarType::RegType => RegType(()),
@@ -1382,6 +1438,8 @@ impl RustType<ProtoScalarType> for ScalarType {
             String(()) => Ok(ScalarType::String),
             Jsonb(()) => Ok(ScalarType::Jsonb),
             Uuid(()) => Ok(ScalarType::Uuid),
+            Point(()) => Ok(ScalarType::Point),
+            BoundingBox(()) => Ok(ScalarType::BoundingBox),
             Oid(()) => Ok(ScalarType::Oid),
             RegProc(()) => Ok(ScalarType::RegProc),
             RegType(()) => Ok(ScalarType::RegType),
@@ -1541,6 +1599,8 @@ impl_datum_type_copy!(NaiveTime, Time);
 impl_datum_type_copy!(CheckedTimestamp<NaiveDateTime>, Timestamp);
 impl_datum_type_copy!(CheckedTimestamp<DateTime<Utc>>, TimestampTz);
 impl_datum_type_copy!(Uuid, Uuid);
+impl_datum_type_copy!(Point, Point);
+impl_datum_type_copy!(BoundingBox, BoundingBox);
 impl_datum_type_copy!('a, &'a str, String);
 impl_datum_type_copy!('a, &'a [u8], Bytes);
 impl_datum_type_copy!(crate::Timestamp, MzTimestamp);
@@ -2542,6 +2602,22 @@ impl<'a> ScalarType {
                 Datum::Uuid(Uuid::from_u128(u128::MAX)),
             ])
         });
+        static POINT: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::Point(Point::new(0.0, 0.0)),
+                Datum::Point(Point::new(f64::MIN, f64::MAX)),
+                Datum::Point(Point::new(f64::NAN, f64::INFINITY)),
+            ])
+        });
+        static BOUNDINGBOX: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::BoundingBox(BoundingBox::new(Point::new(0.0, 0.0), Point::new(0.0, 0.0))),
+                Datum::BoundingBox(BoundingBox::new(
+                    Point::new(f64::MIN, f64::MIN),
+                    Point::new(f64::MAX, f64::MAX),
+                )),
+            ])
+        });
         static ARRAY: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
         static LIST: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
         static RECORD: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
@@ -2582,6 +2658,8 @@ impl<'a> ScalarType {
             ScalarType::VarChar { .. } => (*STRING).iter(),
             ScalarType::Jsonb => (*JSONB).iter(),
             ScalarType::Uuid => (*UUID).iter(),
+            ScalarType::Point => (*POINT).iter(),
+            ScalarType::BoundingBox => (*BOUNDINGBOX).iter(),
             ScalarType::Array(_) => (*ARRAY).iter(),
             ScalarType::List { .. } => (*LIST).iter(),
             ScalarType::Record { .. } => (*RECORD).iter(),
@@ -2630,6 +2708,8 @@ impl<'a> ScalarType {
             ScalarType::VarChar { max_length: None },
             ScalarType::Jsonb,
             ScalarType::Uuid,
+            ScalarType::Point,
+            ScalarType::BoundingBox,
             ScalarType::Oid,
             ScalarType::RegProc,
             ScalarType::RegType,
@@ -2693,6 +2773,8 @@ impl Arbitrary for ScalarType {
                 .boxed(),
             Just(ScalarType::Jsonb).boxed(),
             Just(ScalarType::Uuid).boxed(),
+            Just(ScalarType::Point).boxed(),
+            Just(ScalarType::BoundingBox).boxed(),
             Just(ScalarType::Oid).boxed(),
             Just(ScalarType::RegProc).boxed(),
             Just(ScalarType::RegType).boxed(),
@@ -2814,6 +2896,8 @@ pub enum PropDatum {
 
     JsonNull,
     Uuid(Uuid),
+    Point(Point),
+    BoundingBox(BoundingBox),
     Dummy,
 }
 
@@ -2848,6 +2932,10 @@ pub fn arb_datum() -> BoxedStrategy<PropDatum> {
         ".*".prop_map(PropDatum::String).boxed(),
         Just(PropDatum::JsonNull).boxed(),
         Just(PropDatum::Uuid(Uuid::nil())).boxed(),
+        arb_point().prop_map(PropDatum::Point).boxed(),
+        (arb_point(), arb_point())
+            .prop_map(|(a, b)| PropDatum::BoundingBox(BoundingBox::new(a, b)))
+            .boxed(),
         arb_range().prop_map(PropDatum::Range).boxed(),
         Just(PropDatum::Dummy).boxed(),
     ]);
@@ -3080,6 +3168,12 @@ fn arb_interval() -> BoxedStrategy<Interval> {
         .boxed()
 }
 
+fn arb_point() -> BoxedStrategy<Point> {
+    (any::<f64>(), any::<f64>())
+        .prop_map(|(x, y)| Point::new(x, y))
+        .boxed()
+}
+
 fn add_arb_duration<T: 'static + Copy + Add<chrono::Duration> + std::fmt::Debug>(
     to: T,
 ) -> BoxedStrategy<T::Output>
@@ -3135,6 +3229,8 @@ impl<'a> From<&'a PropDatum> for Datum<'a> {
             }
             JsonNull => Datum::JsonNull,
             Uuid(u) => Datum::from(*u),
+            Point(p) => Datum::from(*p),
+            BoundingBox(b) => Datum::from(*b),
             Dummy => Datum::Dummy,
         }
     }
@@ -53,6 +53,7 @@ use mz_proto::{RustType, TryFromProtoError};
 use crate::adt::array::ArrayDimension;
 use crate::adt::date::Date;
 use crate::adt::datetime::{self, DateTimeField, ParsedDateTime};
+use crate::adt::geo::{BoundingBox, Point};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::numeric::{self, Numeric, NUMERIC_DATUM_MAX_PRECISION};
@@ -723,6 +724,99 @@ where
     Nestable::Yes
 }
 
+/// Splits the textual representation of a geometric type into its
+/// comma-separated elements, ignoring commas within parentheses.
+///
+/// Returns `None` if the parentheses in `s` are unbalanced.
+fn split_geometric_elements(s: &str) -> Option<Vec<&str>> {
+    let mut elems = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                elems.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    if depth != 0 {
+        return None;
+    }
+    elems.push(s[start..].trim());
+    Some(elems)
+}
+
+/// Like [`split_geometric_elements`], but additionally strips one pair of
+/// parentheses that encloses the entirety of `s`.
+fn split_enclosed_geometric_elements(s: &str) -> Option<Vec<&str>> {
+    let elems = split_geometric_elements(s)?;
+    if let [elem] = elems[..] {
+        return split_geometric_elements(elem.strip_prefix('(')?.strip_suffix(')')?);
+    }
+    Some(elems)
+}
+
+fn parse_point_inner(s: &str) -> Option<Point> {
+    match split_enclosed_geometric_elements(s)?[..] {
+        [x, y] => Some(Point::new(parse_float64(x).ok()?, parse_float64(y).ok()?)),
+        _ => None,
+    }
+}
+
+/// Parses a [`Point`] from `s`, which must be in the form `(x,y)` or `x,y`.
+pub fn parse_point(s: &str) -> Result<Point, ParseError> {
+    parse_point_inner(s).ok_or_else(|| ParseError::invalid_input_syntax("point", s))
+}
+
+/// Writes a [`Point`] to `buf` in the form `(x,y)`.
+pub fn format_point<F>(buf: &mut F, point: Point) -> Nestable
+where
+    F: FormatBuffer,
+{
+    buf.write_char('(');
+    format_float64(buf, point.x);
+    buf.write_char(',');
+    format_float64(buf, point.y);
+    buf.write_char(')');
+    Nestable::MayNeedEscaping
+}
+
+fn parse_bounding_box_inner(s: &str) -> Option<BoundingBox> {
+    let (a, b) = match split_enclosed_geometric_elements(s)?[..] {
+        [a, b] => (parse_point_inner(a)?, parse_point_inner(b)?),
+        [x1, y1, x2, y2] => (
+            Point::new(parse_float64(x1).ok()?, parse_float64(y1).ok()?),
+            Point::new(parse_float64(x2).ok()?, parse_float64(y2).ok()?),
+        ),
+        _ => return None,
+    };
+    Some(BoundingBox::new(a, b))
+}
+
+/// Parses a [`BoundingBox`] from `s`, which must be in the form
+/// `(x1,y1),(x2,y2)`, `((x1,y1),(x2,y2))`, or `x1,y1,x2,y2`, where the two
+/// points are any pair of opposite corners of the box.
+pub fn parse_bounding_box(s: &str) -> Result<BoundingBox, ParseError> {
+    parse_bounding_box_inner(s).ok_or_else(|| ParseError::invalid_input_syntax("box", s))
+}
+
+/// Writes a [`BoundingBox`] to `buf` in the form `(x1,y1),(x2,y2)`, where
+/// the first point is the upper-right corner of the box and the second point
+/// is the lower-left corner, as PostgreSQL does.
+pub fn format_bounding_box<F>(buf: &mut F, bounding_box: BoundingBox) -> Nestable
+where
+    F: FormatBuffer,
+{
+    format_point(buf, bounding_box.high());
+    buf.write_char(',');
+    format_point(buf, bounding_box.low());
+    Nestable::MayNeedEscaping
+}
+
 fn format_nanos_to_micros<F>(buf: &mut F, nanos: u32)
 where
     F: FormatBuffer,
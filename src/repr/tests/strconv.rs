@@ -78,6 +78,7 @@ use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZon
 
 use mz_repr::adt::date::Date;
 use mz_repr::adt::datetime::DateTimeField;
+use mz_repr::adt::geo::{BoundingBox, Point};
 use mz_repr::adt::interval::Interval;
 use mz_repr::strconv;
 
@@ -843,3 +844,59 @@ fn test_format_timestamptz() {
         assert_eq!(buf, e);
     }
 }
+
+#[test]
+fn test_parse_format_geometric() {
+    run_test_point("(1,2)", Point::new(1.0, 2.0), "(1,2)");
+    run_test_point(
+        " ( -1.5 , 2e10 ) ",
+        Point::new(-1.5, 2e10),
+        "(-1.5,20000000000)",
+    );
+    run_test_point("3,4", Point::new(3.0, 4.0), "(3,4)");
+    run_test_point(
+        "(NaN,-inf)",
+        Point::new(f64::NAN, f64::NEG_INFINITY),
+        "(NaN,-Infinity)",
+    );
+    for s in [
+        "", "()", "(1)", "(1,2,3)", "((1,2))", "(1,2", "1,2)", "(a,b)",
+    ] {
+        assert_eq!(
+            strconv::parse_point(s).unwrap_err().to_string(),
+            format!("invalid input syntax for type point: \"{}\"", s)
+        );
+    }
+
+    let b = BoundingBox::new(Point::new(0.0, 0.0), Point::new(1.0, 2.0));
+    run_test_box("(0,0),(1,2)", b, "(1,2),(0,0)");
+    run_test_box("((1,0),(0,2))", b, "(1,2),(0,0)");
+    run_test_box(" 1 , 2 , 0 , 0 ", b, "(1,2),(0,0)");
+    for s in [
+        "",
+        "(1,2)",
+        "(1,2),(3)",
+        "(1,2),(3,4),(5,6)",
+        "1,2,3",
+        "((1,2),(3,4)",
+    ] {
+        assert_eq!(
+            strconv::parse_bounding_box(s).unwrap_err().to_string(),
+            format!("invalid input syntax for type box: \"{}\"", s)
+        );
+    }
+
+    fn run_test_point(s: &str, p: Point, e: &str) {
+        assert_eq!(strconv::parse_point(s).unwrap(), p);
+        let mut buf = String::new();
+        strconv::format_point(&mut buf, p);
+        assert_eq!(buf, e);
+    }
+
+    fn run_test_box(s: &str, b: BoundingBox, e: &str) {
+        assert_eq!(strconv::parse_bounding_box(s).unwrap(), b);
+        let mut buf = String::new();
+        strconv::format_bounding_box(&mut buf, b);
+        assert_eq!(buf, e);
+    }
+}
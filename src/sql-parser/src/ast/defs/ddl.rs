@@ -139,6 +139,7 @@ impl_display_t!(ProtobufSchema);
 pub enum CsrConfigOptionName {
     AvroKeyFullname,
     AvroValueFullname,
+    QuarantineUnresolvableSchemas,
 }
 
 impl AstDisplay for CsrConfigOptionName {
@@ -146,6 +147,7 @@ impl AstDisplay for CsrConfigOptionName {
         f.write_str(match self {
             CsrConfigOptionName::AvroKeyFullname => "AVRO KEY FULLNAME",
             CsrConfigOptionName::AvroValueFullname => "AVRO VALUE FULLNAME",
+            CsrConfigOptionName::QuarantineUnresolvableSchemas => "QUARANTINE UNRESOLVABLE SCHEMAS",
         })
     }
 }
@@ -426,7 +428,7 @@ impl Envelope {
             Envelope::None => false,
             // TODO[btv] - Adjust this if we change Dbz semantics
             // (why is this a parser-level concept, anyway? Should it be moved?)
-            Envelope::Debezium(DbzMode::Plain) => false,
+            Envelope::Debezium(DbzMode::Plain | DbzMode::Full) => false,
             Envelope::Upsert => false,
             Envelope::CdcV2 => true,
         }
@@ -507,18 +509,21 @@ impl_display!(Compression);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DbzMode {
-    /// There is now only one `DEBEZIUM` envelope,
-    /// which has upsert semantics in sources and classic
-    /// semantics in sinks.
+    /// The bare `DEBEZIUM` envelope, which has upsert semantics in sources
+    /// and classic semantics in sinks.
     Plain,
+    /// `DEBEZIUM FULL`, which is only supported in sinks and additionally
+    /// emits the `source`, `op` and `ts_ms` fields of Debezium's envelope.
+    Full,
 }
 
 impl AstDisplay for DbzMode {
-    fn fmt<W: fmt::Write>(&self, _f: &mut AstFormatter<W>) {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             // We interpret the bare keyword `DEBEZIUM` as debezium upsert, so don't
             // display anything here.
             Self::Plain => {}
+            Self::Full => f.write_str(" FULL"),
         }
     }
 }
@@ -870,31 +875,47 @@ impl_display_t!(CreateConnection);
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum KafkaConfigOptionName {
     Acks,
+    BatchSize,
+    CleanupPolicy,
     ClientId,
+    ClientRack,
+    CompressionType,
+    CreateTopic,
     EnableIdempotence,
     FetchMessageMaxBytes,
     GroupIdPrefix,
     IsolationLevel,
+    LingerMs,
+    MaxInFlight,
     Topic,
     TopicMetadataRefreshIntervalMs,
     TransactionTimeoutMs,
     StartTimestamp,
     StartOffset,
     PartitionCount,
+    PartitionStrategy,
     ReplicationFactor,
     RetentionMs,
     RetentionBytes,
+    UpdateOrder,
 }
 
 impl AstDisplay for KafkaConfigOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
             KafkaConfigOptionName::Acks => "ACKS",
+            KafkaConfigOptionName::BatchSize => "BATCH SIZE",
+            KafkaConfigOptionName::CleanupPolicy => "CLEANUP POLICY",
             KafkaConfigOptionName::ClientId => "CLIENT ID",
+            KafkaConfigOptionName::ClientRack => "CLIENT RACK",
+            KafkaConfigOptionName::CompressionType => "COMPRESSION TYPE",
+            KafkaConfigOptionName::CreateTopic => "CREATE TOPIC",
             KafkaConfigOptionName::EnableIdempotence => "ENABLE IDEMPOTENCE",
             KafkaConfigOptionName::FetchMessageMaxBytes => "FETCH MESSAGE MAX BYTES",
             KafkaConfigOptionName::GroupIdPrefix => "GROUP ID PREFIX",
             KafkaConfigOptionName::IsolationLevel => "ISOLATION LEVEL",
+            KafkaConfigOptionName::LingerMs => "LINGER MS",
+            KafkaConfigOptionName::MaxInFlight => "MAX IN FLIGHT",
             KafkaConfigOptionName::Topic => "TOPIC",
             KafkaConfigOptionName::TopicMetadataRefreshIntervalMs => {
                 "TOPIC METADATA REFRESH INTERVAL MS"
@@ -903,9 +924,11 @@ impl AstDisplay for KafkaConfigOptionName {
             KafkaConfigOptionName::StartOffset => "START OFFSET",
             KafkaConfigOptionName::StartTimestamp => "START TIMESTAMP",
             KafkaConfigOptionName::PartitionCount => "PARTITION COUNT",
+            KafkaConfigOptionName::PartitionStrategy => "PARTITION STRATEGY",
             KafkaConfigOptionName::ReplicationFactor => "REPLICATION FACTOR",
             KafkaConfigOptionName::RetentionBytes => "RETENTION BYTES",
             KafkaConfigOptionName::RetentionMs => "RETENTION MS",
+            KafkaConfigOptionName::UpdateOrder => "UPDATE ORDER",
         })
     }
 }
@@ -1015,10 +1038,29 @@ pub enum CreateSourceConnection<T: AstInfo> {
         connection: T::ObjectName,
         options: Vec<PgConfigOption<T>>,
     },
+    PostgresTable {
+        /// The postgres connection.
+        connection: T::ObjectName,
+        /// The upstream table to snapshot.
+        table: UnresolvedObjectName,
+        options: Vec<PgConfigOption<T>>,
+    },
+    Materialize {
+        /// The connection to the upstream Materialize instance.
+        connection: T::ObjectName,
+        /// The upstream relation to subscribe to.
+        view: UnresolvedObjectName,
+        options: Vec<PgConfigOption<T>>,
+    },
     LoadGenerator {
         generator: LoadGenerator,
         options: Vec<LoadGeneratorOption<T>>,
     },
+    Http {
+        /// The URL to poll.
+        url: String,
+        options: Vec<HttpSourceOption<T>>,
+    },
     TestScript {
         desc_json: String,
     },
@@ -1074,6 +1116,36 @@ impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
                     f.write_str(")");
                 }
             }
+            CreateSourceConnection::PostgresTable {
+                connection,
+                table,
+                options,
+            } => {
+                f.write_str("POSTGRES CONNECTION ");
+                f.write_node(connection);
+                f.write_str(" TABLE ");
+                f.write_node(table);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSourceConnection::Materialize {
+                connection,
+                view,
+                options,
+            } => {
+                f.write_str("MATERIALIZE CONNECTION ");
+                f.write_node(connection);
+                f.write_str(" VIEW ");
+                f.write_node(view);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
             CreateSourceConnection::LoadGenerator { generator, options } => {
                 f.write_str("LOAD GENERATOR ");
                 f.write_node(generator);
@@ -1083,6 +1155,16 @@ impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
                     f.write_str(")");
                 }
             }
+            CreateSourceConnection::Http { url, options } => {
+                f.write_str("HTTP URL '");
+                f.write_str(&display::escape_single_quote_string(url));
+                f.write_str("'");
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
             CreateSourceConnection::TestScript { desc_json } => {
                 f.write_str("TEST SCRIPT ");
                 f.write_str("'");
@@ -1148,29 +1230,125 @@ impl<T: AstInfo> AstDisplay for LoadGeneratorOption<T> {
 }
 impl_display_t!(LoadGeneratorOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HttpSourceOptionName {
+    KeyField,
+    PollInterval,
+}
+
+impl AstDisplay for HttpSourceOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            HttpSourceOptionName::KeyField => "KEY FIELD",
+            HttpSourceOptionName::PollInterval => "POLL INTERVAL",
+        })
+    }
+}
+impl_display!(HttpSourceOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An option in a `CREATE SOURCE...FROM HTTP`.
+pub struct HttpSourceOption<T: AstInfo> {
+    pub name: HttpSourceOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for HttpSourceOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(HttpSourceOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSinkConnection<T: AstInfo> {
     Kafka {
         connection: KafkaConnection<T>,
         key: Option<KafkaSinkKey>,
+        /// An expression over the key columns that determines the partition
+        /// of each record.
+        partition_by: Option<Expr<T>>,
+    },
+    Elasticsearch {
+        /// The base URL of the cluster.
+        url: String,
+        options: Vec<ElasticsearchSinkOption<T>>,
+        key: Option<KafkaSinkKey>,
     },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
-            CreateSinkConnection::Kafka { connection, key } => {
+            CreateSinkConnection::Kafka {
+                connection,
+                key,
+                partition_by,
+            } => {
                 f.write_str("KAFKA ");
                 f.write_node(connection);
                 if let Some(key) = key.as_ref() {
                     f.write_node(key);
                 }
+                if let Some(partition_by) = partition_by.as_ref() {
+                    f.write_str(" PARTITION BY ");
+                    f.write_node(partition_by);
+                }
+            }
+            CreateSinkConnection::Elasticsearch { url, options, key } => {
+                f.write_str("ELASTICSEARCH URL '");
+                f.write_str(&display::escape_single_quote_string(url));
+                f.write_str("'");
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
             }
         }
     }
 }
 impl_display_t!(CreateSinkConnection);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ElasticsearchSinkOptionName {
+    Index,
+}
+
+impl AstDisplay for ElasticsearchSinkOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            ElasticsearchSinkOptionName::Index => "INDEX",
+        })
+    }
+}
+impl_display!(ElasticsearchSinkOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An option in a `CREATE SINK...INTO ELASTICSEARCH`.
+pub struct ElasticsearchSinkOption<T: AstInfo> {
+    pub name: ElasticsearchSinkOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for ElasticsearchSinkOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(ElasticsearchSinkOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KafkaSinkKey {
     pub key_columns: Vec<Ident>,
@@ -1334,8 +1512,11 @@ impl_display!(KeyConstraint);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSourceOptionName {
+    Fields,
     IgnoreKeys,
+    RefreshInterval,
     Remote,
+    Retention,
     Size,
     Timeline,
     TimestampInterval,
@@ -1344,8 +1525,11 @@ pub enum CreateSourceOptionName {
 impl AstDisplay for CreateSourceOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
+            CreateSourceOptionName::Fields => "FIELDS",
             CreateSourceOptionName::IgnoreKeys => "IGNORE KEYS",
+            CreateSourceOptionName::RefreshInterval => "REFRESH INTERVAL",
             CreateSourceOptionName::Remote => "REMOTE",
+            CreateSourceOptionName::Retention => "RETENTION",
             CreateSourceOptionName::Size => "SIZE",
             CreateSourceOptionName::Timeline => "TIMELINE",
             CreateSourceOptionName::TimestampInterval => "TIMESTAMP INTERVAL",
@@ -1372,6 +1556,26 @@ impl<T: AstInfo> AstDisplay for CreateSourceOption<T> {
 }
 impl_display_t!(CreateSourceOption);
 
+/// The value of the `RETENTION` option of a source, e.g. `'30 days' ON
+/// COLUMN ts`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceRetention {
+    /// The interval for which rows remain visible.
+    pub interval: String,
+    /// The column whose value the interval is added to.
+    pub column: Ident,
+}
+
+impl AstDisplay for SourceRetention {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("'");
+        f.write_node(&display::escape_single_quote_string(&self.interval));
+        f.write_str("' ON COLUMN ");
+        f.write_node(&self.column);
+    }
+}
+impl_display!(SourceRetention);
+
 /// SQL column definition
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ColumnDef<T: AstInfo> {
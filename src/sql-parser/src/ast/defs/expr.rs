@@ -63,10 +63,10 @@ pub enum Expr<T: AstInfo> {
         left: Box<Expr<T>>,
         right: Box<Expr<T>>,
     },
-    /// `IS {NULL, TRUE, FALSE, UNKNOWN}` expression
+    /// `IS {NULL, TRUE, FALSE, UNKNOWN, DISTINCT FROM <expr>}` expression
     IsExpr {
         expr: Box<Expr<T>>,
-        construct: IsExprConstruct,
+        construct: IsExprConstruct<T>,
         negated: bool,
     },
     /// `[ NOT ] IN (val1, val2, ...)`
@@ -562,6 +562,10 @@ impl<T: AstInfo> Expr<T> {
         self.binop(Op::bare("="), right)
     }
 
+    pub fn plus(self, right: Expr<T>) -> Expr<T> {
+        self.binop(Op::bare("+"), right)
+    }
+
     pub fn minus(self, right: Expr<T>) -> Expr<T> {
         self.binop(Op::bare("-"), right)
     }
@@ -858,31 +862,36 @@ impl<T: AstInfo> AstDisplay for FunctionArgs<T> {
 }
 impl_display_t!(FunctionArgs);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum IsExprConstruct {
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IsExprConstruct<T: AstInfo> {
     Null,
     True,
     False,
     Unknown,
+    DistinctFrom(Box<Expr<T>>),
 }
 
-impl IsExprConstruct {
+impl<T: AstInfo> IsExprConstruct<T> {
     pub fn requires_boolean_expr(&self) -> bool {
         match self {
-            IsExprConstruct::Null => false,
+            IsExprConstruct::Null | IsExprConstruct::DistinctFrom(_) => false,
             IsExprConstruct::True | IsExprConstruct::False | IsExprConstruct::Unknown => true,
         }
     }
 }
 
-impl AstDisplay for IsExprConstruct {
+impl<T: AstInfo> AstDisplay for IsExprConstruct<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             IsExprConstruct::Null => f.write_str("NULL"),
             IsExprConstruct::True => f.write_str("TRUE"),
             IsExprConstruct::False => f.write_str("FALSE"),
             IsExprConstruct::Unknown => f.write_str("UNKNOWN"),
+            IsExprConstruct::DistinctFrom(e) => {
+                f.write_str("DISTINCT FROM ");
+                f.write_node(e);
+            }
         }
     }
 }
-impl_display!(IsExprConstruct);
+impl_display_t!(IsExprConstruct);
@@ -126,6 +126,9 @@ pub enum SetExpr<T: AstInfo> {
     SetOperation {
         op: SetOperator,
         all: bool,
+        /// Whether the columns of the queries are matched by name rather than
+        /// by position.
+        by_name: bool,
         left: Box<SetExpr<T>>,
         right: Box<SetExpr<T>>,
     },
@@ -150,6 +153,7 @@ impl<T: AstInfo> AstDisplay for SetExpr<T> {
                 right,
                 op,
                 all,
+                by_name,
             } => {
                 f.write_node(left);
                 f.write_str(" ");
@@ -158,6 +162,9 @@ impl<T: AstInfo> AstDisplay for SetExpr<T> {
                 if *all {
                     f.write_str("ALL ");
                 }
+                if *by_name {
+                    f.write_str("BY NAME ");
+                }
                 f.write_node(right);
             }
         }
@@ -226,7 +233,7 @@ pub struct Select<T: AstInfo> {
     /// WHERE
     pub selection: Option<Expr<T>>,
     /// GROUP BY
-    pub group_by: Vec<Expr<T>>,
+    pub group_by: Vec<GroupByExpr<T>>,
     /// HAVING
     pub having: Option<Expr<T>>,
     /// OPTION
@@ -306,6 +313,80 @@ impl<T: AstInfo> AstDisplay for Distinct<T> {
     }
 }
 
+/// An item in a `GROUP BY` clause.
+///
+/// The items of a `GROUP BY` clause are combined by taking the cross product
+/// of the grouping sets that each item describes. A plain expression describes
+/// exactly one grouping set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupByExpr<T: AstInfo> {
+    /// A single expression, as in `GROUP BY a`.
+    Expr(Expr<T>),
+    /// The empty grouping set, `()`.
+    Empty,
+    /// `ROLLUP (a, b, ...)`. An element that is a row constructor, as in
+    /// `ROLLUP ((a, b), c)`, is treated as a single unit.
+    Rollup(Vec<Expr<T>>),
+    /// `CUBE (a, b, ...)`. An element that is a row constructor is treated as
+    /// a single unit.
+    Cube(Vec<Expr<T>>),
+    /// `GROUPING SETS (...)`. An element that is a row constructor, as in
+    /// `GROUPING SETS ((a, b), a)`, describes the grouping set containing each
+    /// of its expressions.
+    GroupingSets(Vec<GroupByExpr<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for GroupByExpr<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            GroupByExpr::Expr(expr) => f.write_node(expr),
+            GroupByExpr::Empty => f.write_str("()"),
+            GroupByExpr::Rollup(exprs) | GroupByExpr::Cube(exprs) => {
+                if let GroupByExpr::Rollup(_) = self {
+                    f.write_str("ROLLUP (");
+                } else {
+                    f.write_str("CUBE (");
+                }
+                for (i, expr) in exprs.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ");
+                    }
+                    fmt_grouping_element(f, expr);
+                }
+                f.write_str(")");
+            }
+            GroupByExpr::GroupingSets(items) => {
+                f.write_str("GROUPING SETS (");
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ");
+                    }
+                    match item {
+                        GroupByExpr::Expr(expr) => fmt_grouping_element(f, expr),
+                        item => f.write_node(item),
+                    }
+                }
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(GroupByExpr);
+
+/// Writes an element of a grouping set construct. Row constructors are
+/// printed as bare parenthesized lists, as in `ROLLUP ((a, b), c)`, rather than
+/// as `ROW(a, b)`.
+fn fmt_grouping_element<W: fmt::Write, T: AstInfo>(f: &mut AstFormatter<W>, expr: &Expr<T>) {
+    match expr {
+        Expr::Row { exprs } if exprs.len() > 1 => {
+            f.write_str("(");
+            f.write_node(&display::comma_separated(exprs));
+            f.write_str(")");
+        }
+        expr => f.write_node(expr),
+    }
+}
+
 /// A block of common table expressions (CTEs).
 ///
 /// The block can either be entirely "simple" (traditional SQL `WITH` block),
@@ -486,6 +567,7 @@ pub enum TableFactor<T: AstInfo> {
     Table {
         name: T::ObjectName,
         alias: Option<TableAlias>,
+        sample: Option<TableSample<T>>,
     },
     Function {
         function: TableFunction<T>,
@@ -515,12 +597,20 @@ pub enum TableFactor<T: AstInfo> {
 impl<T: AstInfo> AstDisplay for TableFactor<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
-            TableFactor::Table { name, alias } => {
+            TableFactor::Table {
+                name,
+                alias,
+                sample,
+            } => {
                 f.write_node(name);
                 if let Some(alias) = alias {
                     f.write_str(" AS ");
                     f.write_node(alias);
                 }
+                if let Some(sample) = sample {
+                    f.write_str(" ");
+                    f.write_node(sample);
+                }
             }
             TableFactor::Function {
                 function,
@@ -582,6 +672,48 @@ impl<T: AstInfo> AstDisplay for TableFactor<T> {
 }
 impl_display_t!(TableFactor);
 
+/// A `TABLESAMPLE` clause, such as `TABLESAMPLE BERNOULLI (10) REPEATABLE (42)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableSample<T: AstInfo> {
+    pub method: TableSampleMethod,
+    /// The percentage of rows to sample.
+    pub percentage: Expr<T>,
+    /// The seed of the sample.
+    pub repeatable: Option<Expr<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for TableSample<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("TABLESAMPLE ");
+        f.write_node(&self.method);
+        f.write_str(" (");
+        f.write_node(&self.percentage);
+        f.write_str(")");
+        if let Some(repeatable) = &self.repeatable {
+            f.write_str(" REPEATABLE (");
+            f.write_node(repeatable);
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(TableSample);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableSampleMethod {
+    Bernoulli,
+    System,
+}
+
+impl AstDisplay for TableSampleMethod {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            TableSampleMethod::Bernoulli => "BERNOULLI",
+            TableSampleMethod::System => "SYSTEM",
+        })
+    }
+}
+impl_display!(TableSampleMethod);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TableFunction<T: AstInfo> {
     pub name: UnresolvedObjectName,
@@ -687,6 +819,20 @@ impl<T: AstInfo> AstDisplay for Join<T> {
                 f.write_node(&self.relation);
                 f.write_node(&suffix(constraint));
             }
+            JoinOperator::AsOf(constraint) => {
+                f.write_str(" ");
+                f.write_str(prefix(constraint));
+                f.write_str("ASOF JOIN ");
+                f.write_node(&self.relation);
+                f.write_node(&suffix(constraint));
+            }
+            JoinOperator::AsOfLeftOuter(constraint) => {
+                f.write_str(" ");
+                f.write_str(prefix(constraint));
+                f.write_str("ASOF LEFT JOIN ");
+                f.write_node(&self.relation);
+                f.write_node(&suffix(constraint));
+            }
             JoinOperator::CrossJoin => {
                 f.write_str(" CROSS JOIN ");
                 f.write_node(&self.relation);
@@ -703,6 +849,11 @@ pub enum JoinOperator<T: AstInfo> {
     RightOuter(JoinConstraint<T>),
     FullOuter(JoinConstraint<T>),
     CrossJoin,
+    /// Joins each row of the left relation to the latest matching row of the
+    /// right relation, as ordered by the inequality in the `ON` clause.
+    AsOf(JoinConstraint<T>),
+    /// Like `AsOf`, but retains the left rows that match no right row.
+    AsOfLeftOuter(JoinConstraint<T>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
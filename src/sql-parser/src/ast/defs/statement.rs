@@ -30,8 +30,8 @@ use crate::ast::display::{self, AstDisplay, AstFormatter};
 use crate::ast::{
     AstInfo, ColumnDef, CreateConnection, CreateSinkConnection, CreateSourceConnection,
     CreateSourceFormat, CreateSourceOption, CreateSourceOptionName, DeferredObjectName, Envelope,
-    Expr, Format, Ident, KeyConstraint, Query, SelectItem, SourceIncludeMetadata, TableAlias,
-    TableConstraint, TableWithJoins, UnresolvedDatabaseName, UnresolvedObjectName,
+    Expr, Format, Ident, KeyConstraint, Query, SelectItem, SourceIncludeMetadata, SourceRetention,
+    TableAlias, TableConstraint, TableWithJoins, UnresolvedDatabaseName, UnresolvedObjectName,
     UnresolvedSchemaName, Value,
 };
 
@@ -62,6 +62,7 @@ pub enum Statement<T: AstInfo> {
     CreateSecret(CreateSecretStatement<T>),
     AlterObjectRename(AlterObjectRenameStatement),
     AlterIndex(AlterIndexStatement<T>),
+    AlterSchema(AlterSchemaStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
     AlterSink(AlterSinkStatement<T>),
     AlterSource(AlterSourceStatement<T>),
@@ -92,6 +93,7 @@ pub enum Statement<T: AstInfo> {
     Execute(ExecuteStatement<T>),
     Deallocate(DeallocateStatement),
     Raise(RaiseStatement),
+    ValidateView(ValidateViewStatement<T>),
 }
 
 impl<T: AstInfo> AstDisplay for Statement<T> {
@@ -119,6 +121,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
             Statement::AlterObjectRename(stmt) => f.write_node(stmt),
             Statement::AlterIndex(stmt) => f.write_node(stmt),
+            Statement::AlterSchema(stmt) => f.write_node(stmt),
             Statement::AlterSecret(stmt) => f.write_node(stmt),
             Statement::AlterSink(stmt) => f.write_node(stmt),
             Statement::AlterSource(stmt) => f.write_node(stmt),
@@ -149,6 +152,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Execute(stmt) => f.write_node(stmt),
             Statement::Deallocate(stmt) => f.write_node(stmt),
             Statement::Raise(stmt) => f.write_node(stmt),
+            Statement::ValidateView(stmt) => f.write_node(stmt),
         }
     }
 }
@@ -679,6 +683,7 @@ impl_display_t!(CreateSubsourceStatement);
 /// An option in a `CREATE SINK` statement.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSinkOptionName {
+    LatencyTracking,
     Remote,
     Size,
     Snapshot,
@@ -687,6 +692,9 @@ pub enum CreateSinkOptionName {
 impl AstDisplay for CreateSinkOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
+            CreateSinkOptionName::LatencyTracking => {
+                f.write_str("LATENCY TRACKING");
+            }
             CreateSinkOptionName::Remote => {
                 f.write_str("REMOTE");
             }
@@ -716,12 +724,34 @@ impl<T: AstInfo> AstDisplay for CreateSinkOption<T> {
     }
 }
 
+/// A column of the relation a sink is created from, optionally renamed in
+/// the sink's output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SinkColumn {
+    pub name: Ident,
+    pub alias: Option<Ident>,
+}
+
+impl AstDisplay for SinkColumn {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(alias) = &self.alias {
+            f.write_str(" AS ");
+            f.write_node(alias);
+        }
+    }
+}
+impl_display!(SinkColumn);
+
 /// `CREATE SINK`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateSinkStatement<T: AstInfo> {
     pub name: UnresolvedObjectName,
     pub if_not_exists: bool,
     pub from: T::ObjectName,
+    /// The columns of `from` to emit, in order. Empty if all columns are
+    /// emitted under their own names.
+    pub columns: Vec<SinkColumn>,
     pub connection: CreateSinkConnection<T>,
     pub format: Option<Format<T>>,
     pub envelope: Option<Envelope>,
@@ -737,6 +767,11 @@ impl<T: AstInfo> AstDisplay for CreateSinkStatement<T> {
         f.write_node(&self.name);
         f.write_str(" FROM ");
         f.write_node(&self.from);
+        if !self.columns.is_empty() {
+            f.write_str(" (");
+            f.write_node(&display::comma_separated(&self.columns));
+            f.write_str(")");
+        }
         f.write_str(" INTO ");
         f.write_node(&self.connection);
         if let Some(format) = &self.format {
@@ -943,11 +978,16 @@ impl_display_t!(CreateIndexStatement);
 pub enum IndexOptionName {
     // The `LOGICAL COMPACTION WINDOW` option
     LogicalCompactionWindow,
+    // The `LAZY` option
+    Lazy,
 }
 
 impl AstDisplay for IndexOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
+            IndexOptionName::Lazy => {
+                f.write_str("LAZY");
+            }
             IndexOptionName::LogicalCompactionWindow => {
                 f.write_str("LOGICAL COMPACTION WINDOW");
             }
@@ -1384,6 +1424,83 @@ impl<T: AstInfo> AstDisplay for AlterIndexStatement<T> {
 
 impl_display_t!(AlterIndexStatement);
 
+/// An option in an `ALTER SCHEMA` statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaOptionName {
+    /// The `MAX SOURCES` option.
+    MaxSources,
+    /// The `MAX INDEXES` option.
+    MaxIndexes,
+    /// The `MAX MATERIALIZED VIEWS` option.
+    MaxMaterializedViews,
+}
+
+impl AstDisplay for SchemaOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            SchemaOptionName::MaxSources => "MAX SOURCES",
+            SchemaOptionName::MaxIndexes => "MAX INDEXES",
+            SchemaOptionName::MaxMaterializedViews => "MAX MATERIALIZED VIEWS",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchemaOption<T: AstInfo> {
+    pub name: SchemaOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for SchemaOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterSchemaAction<T: AstInfo> {
+    SetOptions(Vec<SchemaOption<T>>),
+    ResetOptions(Vec<SchemaOptionName>),
+}
+
+/// `ALTER SCHEMA ... {RESET, SET}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterSchemaStatement<T: AstInfo> {
+    pub name: UnresolvedSchemaName,
+    pub if_exists: bool,
+    pub action: AlterSchemaAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterSchemaStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER SCHEMA ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ");
+
+        match &self.action {
+            AlterSchemaAction::SetOptions(options) => {
+                f.write_str("SET (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+            }
+            AlterSchemaAction::ResetOptions(options) => {
+                f.write_str("RESET (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+            }
+        }
+    }
+}
+
+impl_display_t!(AlterSchemaStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AlterSinkAction<T: AstInfo> {
     SetOptions(Vec<CreateSinkOption<T>>),
@@ -1813,6 +1930,7 @@ pub enum ShowObjectType<T: AstInfo> {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ShowObjectsStatement<T: AstInfo> {
     pub object_type: ShowObjectType<T>,
+    pub full: bool,
     pub from: Option<T::SchemaName>,
     pub filter: Option<ShowStatementFilter<T>>,
 }
@@ -1821,6 +1939,9 @@ impl<T: AstInfo> AstDisplay for ShowObjectsStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str("SHOW");
         f.write_str(" ");
+        if self.full {
+            f.write_str("FULL ");
+        }
         f.write_str(match &self.object_type {
             ShowObjectType::Table => "TABLES",
             ShowObjectType::View => "VIEWS",
@@ -2056,6 +2177,9 @@ impl_display!(RollbackStatement);
 pub enum SubscribeOptionName {
     Snapshot,
     Progress,
+    PartitionKey,
+    PartitionCount,
+    PartitionIndex,
 }
 
 impl AstDisplay for SubscribeOptionName {
@@ -2063,6 +2187,9 @@ impl AstDisplay for SubscribeOptionName {
         match self {
             SubscribeOptionName::Snapshot => f.write_str("SNAPSHOT"),
             SubscribeOptionName::Progress => f.write_str("PROGRESS"),
+            SubscribeOptionName::PartitionKey => f.write_str("PARTITION KEY"),
+            SubscribeOptionName::PartitionCount => f.write_str("PARTITION COUNT"),
+            SubscribeOptionName::PartitionIndex => f.write_str("PARTITION INDEX"),
         }
     }
 }
@@ -2250,6 +2377,7 @@ pub enum WithOptionValue<T: AstInfo> {
     // Special cases.
     ClusterReplicas(Vec<ReplicaDefinition<T>>),
     ConnectionKafkaBroker(KafkaBroker<T>),
+    SourceRetention(SourceRetention),
 }
 
 impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
@@ -2277,6 +2405,9 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
             WithOptionValue::ConnectionKafkaBroker(broker) => {
                 f.write_node(broker);
             }
+            WithOptionValue::SourceRetention(retention) => {
+                f.write_node(retention);
+            }
         }
     }
 }
@@ -2659,6 +2790,20 @@ impl AstDisplay for RaiseStatement {
 }
 impl_display!(RaiseStatement);
 
+/// `VALIDATE VIEW <name>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidateViewStatement<T: AstInfo> {
+    pub name: T::ObjectName,
+}
+
+impl<T: AstInfo> AstDisplay for ValidateViewStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("VALIDATE VIEW ");
+        f.write_node(&self.name);
+    }
+}
+impl_display_t!(ValidateViewStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NoticeSeverity {
     Debug,
@@ -2756,6 +2901,7 @@ pub enum ShowStatement<T: AstInfo> {
     ShowCreateSink(ShowCreateSinkStatement<T>),
     ShowCreateIndex(ShowCreateIndexStatement<T>),
     ShowCreateConnection(ShowCreateConnectionStatement<T>),
+    ShowCreateCatalog,
     ShowVariable(ShowVariableStatement),
 }
 
@@ -2773,6 +2919,7 @@ impl<T: AstInfo> AstDisplay for ShowStatement<T> {
             ShowStatement::ShowCreateSink(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateIndex(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateConnection(stmt) => f.write_node(stmt),
+            ShowStatement::ShowCreateCatalog => f.write_str("SHOW CREATE CATALOG"),
             ShowStatement::ShowVariable(stmt) => f.write_node(stmt),
         }
     }
@@ -90,11 +90,13 @@ impl Keyword {
             self,
             // These keywords are ambiguous when used as a table alias, as they
             // conflict with the syntax for joins.
-            ON | JOIN | INNER | CROSS | FULL | LEFT | RIGHT | NATURAL | USING |
+            ON | JOIN | INNER | CROSS | FULL | LEFT | RIGHT | NATURAL | USING | ASOF |
             // `OUTER` is not strictly ambiguous, but it prevents `a OUTER JOIN
             // b` from parsing as `a AS outer JOIN b`, instead producing a nice
             // syntax error.
-            OUTER
+            OUTER |
+            // `TABLESAMPLE` follows the table alias.
+            TABLESAMPLE
         ) || self.is_reserved()
     }
 
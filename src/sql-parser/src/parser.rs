@@ -280,6 +280,7 @@ impl<'a> Parser<'a> {
                 Token::Keyword(EXECUTE) => Ok(self.parse_execute()?),
                 Token::Keyword(DEALLOCATE) => Ok(self.parse_deallocate()?),
                 Token::Keyword(RAISE) => Ok(self.parse_raise()?),
+                Token::Keyword(VALIDATE) => Ok(self.parse_validate()?),
                 Token::Keyword(kw) => parser_err!(
                     self,
                     self.peek_prev_pos(),
@@ -1024,10 +1025,17 @@ impl<'a> Parser<'a> {
                                 _ => unreachable!(),
                             },
                         })
+                    } else if self.parse_keywords(&[DISTINCT, FROM]) {
+                        let other = self.parse_subexpr(Precedence::Is)?;
+                        Ok(Expr::IsExpr {
+                            expr: Box::new(expr),
+                            negated,
+                            construct: IsExprConstruct::DistinctFrom(Box::new(other)),
+                        })
                     } else {
                         self.expected(
                             self.peek_pos(),
-                            "NULL, NOT NULL, TRUE, NOT TRUE, FALSE, NOT FALSE, UNKNOWN, NOT UNKNOWN after IS",
+                            "NULL, NOT NULL, TRUE, NOT TRUE, FALSE, NOT FALSE, UNKNOWN, NOT UNKNOWN, DISTINCT FROM, NOT DISTINCT FROM after IS",
                             self.peek_token(),
                         )
                     }
@@ -1810,7 +1818,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_csr_config_option(&mut self) -> Result<CsrConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[AVRO])? {
+        let name = match self.expect_one_of_keywords(&[AVRO, QUARANTINE])? {
             AVRO => {
                 let name = match self.expect_one_of_keywords(&[KEY, VALUE])? {
                     KEY => CsrConfigOptionName::AvroKeyFullname,
@@ -1820,6 +1828,10 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(FULLNAME)?;
                 name
             }
+            QUARANTINE => {
+                self.expect_keywords(&[UNRESOLVABLE, SCHEMAS])?;
+                CsrConfigOptionName::QuarantineUnresolvableSchemas
+            }
             _ => unreachable!(),
         };
         Ok(CsrConfigOption {
@@ -1921,9 +1933,13 @@ impl<'a> Parser<'a> {
         let envelope = if self.parse_keyword(NONE) {
             Envelope::None
         } else if self.parse_keyword(DEBEZIUM) {
-            // In Platform, `DEBEZIUM UPSERT` is the only available option.
-            // Revisit this if we ever change that.
-            let debezium_mode = DbzMode::Plain;
+            // In Platform, `DEBEZIUM UPSERT` is the only available option for
+            // sources. `DEBEZIUM FULL` is only supported by sinks.
+            let debezium_mode = if self.parse_keyword(FULL) {
+                DbzMode::Full
+            } else {
+                DbzMode::Plain
+            };
             Envelope::Debezium(debezium_mode)
         } else if self.parse_keyword(UPSERT) {
             Envelope::Upsert
@@ -2155,11 +2171,17 @@ impl<'a> Parser<'a> {
     fn parse_kafka_config_option(&mut self) -> Result<KafkaConfigOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[
             ACKS,
+            BATCH,
+            CLEANUP,
             CLIENT,
+            COMPRESSION,
+            CREATE,
             ENABLE,
             FETCH,
             GROUP,
             ISOLATION,
+            LINGER,
+            crate::keywords::MAX,
             PARTITION,
             REPLICATION,
             RETENTION,
@@ -2167,11 +2189,29 @@ impl<'a> Parser<'a> {
             START,
             TOPIC,
             TRANSACTION,
+            UPDATE,
         ])? {
             ACKS => KafkaConfigOptionName::Acks,
-            CLIENT => {
-                self.expect_keyword(ID)?;
-                KafkaConfigOptionName::ClientId
+            BATCH => {
+                self.expect_keyword(SIZE)?;
+                KafkaConfigOptionName::BatchSize
+            }
+            CLEANUP => {
+                self.expect_keyword(POLICY)?;
+                KafkaConfigOptionName::CleanupPolicy
+            }
+            CLIENT => match self.expect_one_of_keywords(&[ID, RACK])? {
+                ID => KafkaConfigOptionName::ClientId,
+                RACK => KafkaConfigOptionName::ClientRack,
+                _ => unreachable!(),
+            },
+            COMPRESSION => {
+                self.expect_keyword(TYPE)?;
+                KafkaConfigOptionName::CompressionType
+            }
+            CREATE => {
+                self.expect_keyword(TOPIC)?;
+                KafkaConfigOptionName::CreateTopic
             }
             ENABLE => {
                 self.expect_keyword(IDEMPOTENCE)?;
@@ -2189,10 +2229,19 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(LEVEL)?;
                 KafkaConfigOptionName::IsolationLevel
             }
-            PARTITION => {
-                self.expect_keyword(COUNT)?;
-                KafkaConfigOptionName::PartitionCount
+            LINGER => {
+                self.expect_keyword(MS)?;
+                KafkaConfigOptionName::LingerMs
+            }
+            crate::keywords::MAX => {
+                self.expect_keywords(&[IN, FLIGHT])?;
+                KafkaConfigOptionName::MaxInFlight
             }
+            PARTITION => match self.expect_one_of_keywords(&[COUNT, STRATEGY])? {
+                COUNT => KafkaConfigOptionName::PartitionCount,
+                STRATEGY => KafkaConfigOptionName::PartitionStrategy,
+                _ => unreachable!(),
+            },
             REPLICATION => {
                 self.expect_keyword(FACTOR)?;
                 KafkaConfigOptionName::ReplicationFactor
@@ -2219,6 +2268,10 @@ impl<'a> Parser<'a> {
                 TIMESTAMP => KafkaConfigOptionName::StartTimestamp,
                 _ => unreachable!(),
             },
+            UPDATE => {
+                self.expect_keyword(ORDER)?;
+                KafkaConfigOptionName::UpdateOrder
+            }
             _ => unreachable!(),
         };
         Ok(KafkaConfigOption {
@@ -2505,31 +2558,50 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_source_option_name(&mut self) -> Result<CreateSourceOptionName, ParserError> {
-        let name =
-            match self.expect_one_of_keywords(&[IGNORE, REMOTE, SIZE, TIMELINE, TIMESTAMP])? {
-                IGNORE => {
-                    self.expect_keyword(KEYS)?;
-                    CreateSourceOptionName::IgnoreKeys
-                }
-                REMOTE => CreateSourceOptionName::Remote,
-                SIZE => CreateSourceOptionName::Size,
-                TIMELINE => CreateSourceOptionName::Timeline,
-                TIMESTAMP => {
-                    self.expect_keyword(INTERVAL)?;
-                    CreateSourceOptionName::TimestampInterval
-                }
-                _ => unreachable!(),
-            };
+        let name = match self.expect_one_of_keywords(&[
+            FIELDS, IGNORE, REFRESH, REMOTE, RETENTION, SIZE, TIMELINE, TIMESTAMP,
+        ])? {
+            FIELDS => CreateSourceOptionName::Fields,
+            IGNORE => {
+                self.expect_keyword(KEYS)?;
+                CreateSourceOptionName::IgnoreKeys
+            }
+            REFRESH => {
+                self.expect_keyword(INTERVAL)?;
+                CreateSourceOptionName::RefreshInterval
+            }
+            REMOTE => CreateSourceOptionName::Remote,
+            RETENTION => CreateSourceOptionName::Retention,
+            SIZE => CreateSourceOptionName::Size,
+            TIMELINE => CreateSourceOptionName::Timeline,
+            TIMESTAMP => {
+                self.expect_keyword(INTERVAL)?;
+                CreateSourceOptionName::TimestampInterval
+            }
+            _ => unreachable!(),
+        };
         Ok(name)
     }
 
     /// Parses a single valid option in the WITH block of a create source
     fn parse_source_option(&mut self) -> Result<CreateSourceOption<Raw>, ParserError> {
         let name = self.parse_source_option_name()?;
-        Ok(CreateSourceOption {
-            name,
-            value: self.parse_optional_option_value()?,
-        })
+        let value = match name {
+            CreateSourceOptionName::Retention => Some(self.parse_source_retention()?),
+            _ => self.parse_optional_option_value()?,
+        };
+        Ok(CreateSourceOption { name, value })
+    }
+
+    fn parse_source_retention(&mut self) -> Result<WithOptionValue<Raw>, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+        let interval = self.parse_literal_string()?;
+        self.expect_keywords(&[ON, COLUMN])?;
+        let column = self.parse_identifier()?;
+        Ok(WithOptionValue::SourceRetention(SourceRetention {
+            interval,
+            column,
+        }))
     }
 
     fn parse_create_sink(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -2538,6 +2610,13 @@ impl<'a> Parser<'a> {
         let name = self.parse_object_name()?;
         self.expect_keyword(FROM)?;
         let from = self.parse_raw_name()?;
+        let columns = if self.consume_token(&Token::LParen) {
+            let columns = self.parse_comma_separated(Parser::parse_sink_column)?;
+            self.expect_token(&Token::RParen)?;
+            columns
+        } else {
+            vec![]
+        };
         self.expect_keyword(INTO)?;
         let connection = self.parse_create_sink_connection()?;
         let format = if self.parse_keyword(FORMAT) {
@@ -2563,6 +2642,7 @@ impl<'a> Parser<'a> {
         Ok(Statement::CreateSink(CreateSinkStatement {
             name,
             from,
+            columns,
             connection,
             format,
             envelope,
@@ -2571,9 +2651,25 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse a column of the relation a sink is created from, with an optional
+    /// `AS` alias.
+    fn parse_sink_column(&mut self) -> Result<SinkColumn, ParserError> {
+        let name = self.parse_identifier()?;
+        let alias = if self.parse_keyword(AS) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+        Ok(SinkColumn { name, alias })
+    }
+
     /// Parse the name of a CREATE SINK optional parameter
     fn parse_create_sink_option_name(&mut self) -> Result<CreateSinkOptionName, ParserError> {
-        let name = match self.expect_one_of_keywords(&[REMOTE, SIZE, SNAPSHOT])? {
+        let name = match self.expect_one_of_keywords(&[LATENCY, REMOTE, SIZE, SNAPSHOT])? {
+            LATENCY => {
+                self.expect_keyword(TRACKING)?;
+                CreateSinkOptionName::LatencyTracking
+            }
             SIZE => CreateSinkOptionName::Size,
             SNAPSHOT => CreateSinkOptionName::Snapshot,
             REMOTE => CreateSinkOptionName::Remote,
@@ -2593,11 +2689,52 @@ impl<'a> Parser<'a> {
     fn parse_create_source_connection(
         &mut self,
     ) -> Result<CreateSourceConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, KINESIS, S3, POSTGRES, LOAD, TEST])? {
+        match self.expect_one_of_keywords(&[
+            KAFKA,
+            KINESIS,
+            S3,
+            POSTGRES,
+            MATERIALIZE,
+            LOAD,
+            HTTP,
+            TEST,
+        ])? {
             POSTGRES => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_raw_name()?;
 
+                let table = if self.parse_keyword(TABLE) {
+                    Some(self.parse_object_name()?)
+                } else {
+                    None
+                };
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_pg_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                match table {
+                    Some(table) => Ok(CreateSourceConnection::PostgresTable {
+                        connection,
+                        table,
+                        options,
+                    }),
+                    None => Ok(CreateSourceConnection::Postgres {
+                        connection,
+                        options,
+                    }),
+                }
+            }
+            MATERIALIZE => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+                self.expect_keyword(VIEW)?;
+                let view = self.parse_object_name()?;
+
                 let options = if self.consume_token(&Token::LParen) {
                     let options = self.parse_comma_separated(Parser::parse_pg_connection_option)?;
                     self.expect_token(&Token::RParen)?;
@@ -2606,8 +2743,9 @@ impl<'a> Parser<'a> {
                     vec![]
                 };
 
-                Ok(CreateSourceConnection::Postgres {
+                Ok(CreateSourceConnection::Materialize {
                     connection,
+                    view,
                     options,
                 })
             }
@@ -2707,6 +2845,18 @@ impl<'a> Parser<'a> {
                 };
                 Ok(CreateSourceConnection::LoadGenerator { generator, options })
             }
+            HTTP => {
+                self.expect_keyword(URL)?;
+                let url = self.parse_literal_string()?;
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_http_source_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+                Ok(CreateSourceConnection::Http { url, options })
+            }
             TEST => {
                 self.expect_keyword(SCRIPT)?;
                 Ok(CreateSourceConnection::TestScript {
@@ -2770,34 +2920,99 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_http_source_option(&mut self) -> Result<HttpSourceOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[KEY, POLL])? {
+            KEY => {
+                self.expect_keyword(FIELD)?;
+                HttpSourceOptionName::KeyField
+            }
+            POLL => {
+                self.expect_keyword(INTERVAL)?;
+                HttpSourceOptionName::PollInterval
+            }
+            _ => unreachable!(),
+        };
+
+        let _ = self.consume_token(&Token::Eq);
+        Ok(HttpSourceOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_create_sink_connection(&mut self) -> Result<CreateSinkConnection<Raw>, ParserError> {
-        self.expect_keyword(KAFKA)?;
-        self.expect_keyword(CONNECTION)?;
+        match self.expect_one_of_keywords(&[KAFKA, ELASTICSEARCH])? {
+            KAFKA => {
+                self.expect_keyword(CONNECTION)?;
 
-        let connection = self.parse_kafka_connection_reference()?;
+                let connection = self.parse_kafka_connection_reference()?;
 
-        // one token of lookahead:
-        // * `KEY (` means we're parsing a list of columns for the key
-        // * `KEY FORMAT` means there is no key, we'll parse a KeyValueFormat later
-        let key =
-            if self.peek_keyword(KEY) && self.peek_nth_token(1) != Some(Token::Keyword(FORMAT)) {
-                let _ = self.expect_keyword(KEY);
-                let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
-
-                let not_enforced = if self.peek_keywords(&[NOT, ENFORCED]) {
-                    let _ = self.expect_keywords(&[NOT, ENFORCED])?;
-                    true
+                let key = self.parse_sink_key()?;
+                let partition_by = if self.parse_keywords(&[PARTITION, BY]) {
+                    Some(self.parse_expr()?)
                 } else {
-                    false
+                    None
                 };
-                Some(KafkaSinkKey {
-                    key_columns,
-                    not_enforced,
+                Ok(CreateSinkConnection::Kafka {
+                    connection,
+                    key,
+                    partition_by,
                 })
+            }
+            ELASTICSEARCH => {
+                self.expect_keyword(URL)?;
+                let url = self.parse_literal_string()?;
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_elasticsearch_sink_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+                let key = self.parse_sink_key()?;
+                Ok(CreateSinkConnection::Elasticsearch { url, options, key })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_sink_key(&mut self) -> Result<Option<KafkaSinkKey>, ParserError> {
+        // one token of lookahead:
+        // * `KEY (` means we're parsing a list of columns for the key
+        // * `KEY FORMAT` means there is no key, we'll parse a KeyValueFormat later
+        if self.peek_keyword(KEY) && self.peek_nth_token(1) != Some(Token::Keyword(FORMAT)) {
+            let _ = self.expect_keyword(KEY);
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = if self.peek_keywords(&[NOT, ENFORCED]) {
+                let _ = self.expect_keywords(&[NOT, ENFORCED])?;
+                true
             } else {
-                None
+                false
             };
-        Ok(CreateSinkConnection::Kafka { connection, key })
+            Ok(Some(KafkaSinkKey {
+                key_columns,
+                not_enforced,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_elasticsearch_sink_option(
+        &mut self,
+    ) -> Result<ElasticsearchSinkOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[INDEX])? {
+            INDEX => ElasticsearchSinkOptionName::Index,
+            _ => unreachable!(),
+        };
+
+        let _ = self.consume_token(&Token::Eq);
+        Ok(ElasticsearchSinkOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
     }
 
     fn parse_create_view(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -2929,13 +3144,19 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_index_option_name(&mut self) -> Result<IndexOptionName, ParserError> {
-        self.expect_keywords(&[LOGICAL, COMPACTION, WINDOW])?;
-        Ok(IndexOptionName::LogicalCompactionWindow)
+        let name = match self.expect_one_of_keywords(&[LAZY, LOGICAL])? {
+            LAZY => IndexOptionName::Lazy,
+            LOGICAL => {
+                self.expect_keywords(&[COMPACTION, WINDOW])?;
+                IndexOptionName::LogicalCompactionWindow
+            }
+            _ => unreachable!(),
+        };
+        Ok(name)
     }
 
     fn parse_index_option(&mut self) -> Result<IndexOption<Raw>, ParserError> {
-        self.expect_keywords(&[LOGICAL, COMPACTION, WINDOW])?;
-        let name = IndexOptionName::LogicalCompactionWindow;
+        let name = self.parse_index_option_name()?;
         let value = self.parse_optional_option_value()?;
         Ok(IndexOption { name, value })
     }
@@ -3570,6 +3791,7 @@ impl<'a> Parser<'a> {
             MATERIALIZED,
             TABLE,
             INDEX,
+            SCHEMA,
             SECRET,
             SYSTEM,
             CONNECTION,
@@ -3583,6 +3805,7 @@ impl<'a> Parser<'a> {
             }
             TABLE => ObjectType::Table,
             INDEX => return self.parse_alter_index(),
+            SCHEMA => return self.parse_alter_schema(),
             SECRET => return self.parse_alter_secret(),
             SYSTEM => return self.parse_alter_system(),
             CONNECTION => return self.parse_alter_connection(),
@@ -3685,6 +3908,56 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_alter_schema(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_schema_name()?;
+
+        Ok(match self.expect_one_of_keywords(&[RESET, SET])? {
+            RESET => {
+                self.expect_token(&Token::LParen)?;
+                let reset_options = self.parse_comma_separated(Parser::parse_schema_option_name)?;
+                self.expect_token(&Token::RParen)?;
+
+                Statement::AlterSchema(AlterSchemaStatement {
+                    name,
+                    if_exists,
+                    action: AlterSchemaAction::ResetOptions(reset_options),
+                })
+            }
+            SET => {
+                self.expect_token(&Token::LParen)?;
+                let set_options = self.parse_comma_separated(Parser::parse_schema_option)?;
+                self.expect_token(&Token::RParen)?;
+                Statement::AlterSchema(AlterSchemaStatement {
+                    name,
+                    if_exists,
+                    action: AlterSchemaAction::SetOptions(set_options),
+                })
+            }
+            _ => unreachable!(),
+        })
+    }
+
+    fn parse_schema_option_name(&mut self) -> Result<SchemaOptionName, ParserError> {
+        self.expect_keyword(MAX)?;
+        let name = match self.expect_one_of_keywords(&[SOURCES, INDEXES, MATERIALIZED])? {
+            SOURCES => SchemaOptionName::MaxSources,
+            INDEXES => SchemaOptionName::MaxIndexes,
+            MATERIALIZED => {
+                self.expect_keyword(VIEWS)?;
+                SchemaOptionName::MaxMaterializedViews
+            }
+            _ => unreachable!(),
+        };
+        Ok(name)
+    }
+
+    fn parse_schema_option(&mut self) -> Result<SchemaOption<Raw>, ParserError> {
+        let name = self.parse_schema_option_name()?;
+        let value = self.parse_optional_option_value()?;
+        Ok(SchemaOption { name, value })
+    }
+
     fn parse_alter_secret(&mut self) -> Result<Statement<Raw>, ParserError> {
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_object_name()?;
@@ -4635,10 +4908,17 @@ impl<'a> Parser<'a> {
                     "Cannot specify both ALL and DISTINCT in set operation"
                 );
             }
+            let by_name = if self.parse_keyword(BY) {
+                self.expect_keyword(NAME)?;
+                true
+            } else {
+                false
+            };
             expr = SetExpr::SetOperation {
                 left: Box::new(expr),
                 op: op.unwrap(),
                 all,
+                by_name,
                 right: Box::new(self.parse_query_body(next_precedence)?),
             };
         }
@@ -4704,7 +4984,7 @@ impl<'a> Parser<'a> {
         };
 
         let group_by = if self.parse_keywords(&[GROUP, BY]) {
-            self.parse_comma_separated(Parser::parse_expr)?
+            self.parse_comma_separated(Parser::parse_group_by_expr)?
         } else {
             vec![]
         };
@@ -4735,6 +5015,38 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses a single item of a `GROUP BY` clause, which may be an ordinary
+    /// expression or one of the grouping set constructs.
+    fn parse_group_by_expr(&mut self) -> Result<GroupByExpr<Raw>, ParserError> {
+        if self.peek_token() == Some(Token::LParen) && self.peek_nth_token(1) == Some(Token::RParen)
+        {
+            self.expect_token(&Token::LParen)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(GroupByExpr::Empty)
+        } else if self.peek_keyword(ROLLUP) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.expect_keyword(ROLLUP)?;
+            self.expect_token(&Token::LParen)?;
+            let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(GroupByExpr::Rollup(exprs))
+        } else if self.peek_keyword(CUBE) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.expect_keyword(CUBE)?;
+            self.expect_token(&Token::LParen)?;
+            let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(GroupByExpr::Cube(exprs))
+        } else if self.parse_keywords(&[GROUPING, SETS]) {
+            self.expect_token(&Token::LParen)?;
+            let items = self.parse_comma_separated(|parser| {
+                parser.checked_recur_mut(Parser::parse_group_by_expr)
+            })?;
+            self.expect_token(&Token::RParen)?;
+            Ok(GroupByExpr::GroupingSets(items))
+        } else {
+            Ok(GroupByExpr::Expr(self.parse_expr()?))
+        }
+    }
+
     fn parse_select_option(&mut self) -> Result<SelectOption<Raw>, ParserError> {
         self.expect_keywords(&[EXPECTED, GROUP, SIZE])?;
         let name = SelectOptionName::ExpectedGroupSize;
@@ -4849,6 +5161,14 @@ impl<'a> Parser<'a> {
             }));
         }
 
+        // `FULL` adds columns that describe the state of each object, and is
+        // only supported for the objects below that have such state.
+        let full = self.parse_keyword(FULL);
+        if full {
+            self.expect_one_of_keywords(&[VIEWS, SOURCES, MATERIALIZED])?;
+            self.prev_token();
+        }
+
         if self.parse_one_of_keywords(&[COLUMNS, FIELDS]).is_some() {
             self.parse_show_columns()
         } else if self.parse_keyword(SCHEMAS) {
@@ -4952,6 +5272,7 @@ impl<'a> Parser<'a> {
             // Only Materialized Views and Indexes (handled separately below) are associated with clusters.
             Ok(ShowStatement::ShowObjects(ShowObjectsStatement {
                 object_type: show_object_type,
+                full,
                 from,
                 filter: self.parse_show_statement_filter()?,
             }))
@@ -4987,6 +5308,8 @@ impl<'a> Parser<'a> {
                     connection_name: self.parse_raw_name()?,
                 },
             ))
+        } else if self.parse_keywords(&[CREATE, CATALOG]) {
+            Ok(ShowStatement::ShowCreateCatalog)
         } else {
             let variable = if self.parse_keywords(&[TRANSACTION, ISOLATION, LEVEL]) {
                 Ident::new("transaction_isolation")
@@ -5067,6 +5390,17 @@ impl<'a> Parser<'a> {
                             _ => unreachable!(),
                         }
                     }
+                    Some(ASOF) => {
+                        let _ = self.next_token();
+                        if self.parse_keyword(LEFT) {
+                            let _ = self.parse_keyword(OUTER);
+                            self.expect_keyword(JOIN)?;
+                            JoinOperator::AsOfLeftOuter
+                        } else {
+                            self.expect_keyword(JOIN)?;
+                            JoinOperator::AsOf
+                        }
+                    }
                     Some(OUTER) => {
                         return self.expected(
                             self.peek_pos(),
@@ -5185,11 +5519,39 @@ impl<'a> Parser<'a> {
                 _ => Ok(TableFactor::Table {
                     name,
                     alias: self.parse_optional_table_alias()?,
+                    sample: self.parse_optional_table_sample()?,
                 }),
             }
         }
     }
 
+    fn parse_optional_table_sample(&mut self) -> Result<Option<TableSample<Raw>>, ParserError> {
+        if !self.parse_keyword(TABLESAMPLE) {
+            return Ok(None);
+        }
+        let method = match self.expect_one_of_keywords(&[BERNOULLI, SYSTEM])? {
+            BERNOULLI => TableSampleMethod::Bernoulli,
+            SYSTEM => TableSampleMethod::System,
+            _ => unreachable!(),
+        };
+        self.expect_token(&Token::LParen)?;
+        let percentage = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        let repeatable = if self.parse_keyword(REPEATABLE) {
+            self.expect_token(&Token::LParen)?;
+            let seed = self.parse_expr()?;
+            self.expect_token(&Token::RParen)?;
+            Some(seed)
+        } else {
+            None
+        };
+        Ok(Some(TableSample {
+            method,
+            percentage,
+            repeatable,
+        }))
+    }
+
     fn parse_rows_from(&mut self) -> Result<TableFactor<Raw>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let functions = self.parse_comma_separated(Parser::parse_table_function)?;
@@ -5517,7 +5879,13 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_subscribe_option(&mut self) -> Result<SubscribeOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[PROGRESS, SNAPSHOT])? {
+        let name = match self.expect_one_of_keywords(&[PARTITION, PROGRESS, SNAPSHOT])? {
+            PARTITION => match self.expect_one_of_keywords(&[COUNT, INDEX, KEY])? {
+                COUNT => SubscribeOptionName::PartitionCount,
+                INDEX => SubscribeOptionName::PartitionIndex,
+                KEY => SubscribeOptionName::PartitionKey,
+                _ => unreachable!(),
+            },
             PROGRESS => SubscribeOptionName::Progress,
             SNAPSHOT => SubscribeOptionName::Snapshot,
             _ => unreachable!(),
@@ -5741,6 +6109,15 @@ impl<'a> Parser<'a> {
 
         Ok(Statement::Raise(RaiseStatement { severity }))
     }
+
+    /// Parse a `VALIDATE VIEW` statement, assuming that the `VALIDATE` token
+    /// has already been consumed.
+    fn parse_validate(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(VIEW)?;
+        Ok(Statement::ValidateView(ValidateViewStatement {
+            name: self.parse_raw_name()?,
+        }))
+    }
 }
 
 impl CheckedRecursion for Parser<'_> {
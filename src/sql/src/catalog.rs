@@ -197,6 +197,12 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync {
     /// Check if window functions are supported by the current system configuration.
     fn window_functions(&self) -> bool;
 
+    /// Returns the `TIMESTAMP INTERVAL` of sources that do not specify one.
+    fn default_timestamp_interval(&self) -> Duration;
+
+    /// Returns the `INTROSPECTION INTERVAL` of cluster replicas that do not specify one.
+    fn default_introspection_interval(&self) -> Duration;
+
     /// Returns the number of milliseconds since the system epoch. For normal use
     /// this means the Unix epoch. This can safely be mocked in tests and start
     /// at 0.
@@ -495,6 +501,8 @@ pub enum CatalogType<T: TypeReference> {
     Uuid,
     VarChar,
     Int2Vector,
+    Point,
+    BoundingBox,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -926,6 +934,14 @@ impl SessionCatalog for DummyCatalog {
         true
     }
 
+    fn default_timestamp_interval(&self) -> Duration {
+        self.config().timestamp_interval
+    }
+
+    fn default_introspection_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
     fn now(&self) -> EpochMillis {
         (self.config().now)()
     }
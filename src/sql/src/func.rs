@@ -106,6 +106,7 @@ impl TypeCategory {
             ScalarType::Map { .. } => Self::Pseudo,
             ScalarType::MzTimestamp => Self::Numeric,
             ScalarType::Range { .. } => Self::Range,
+            ScalarType::Point | ScalarType::BoundingBox => Self::Geometric,
         }
     }
 
@@ -167,6 +168,7 @@ impl TypeCategory {
             CatalogType::Map { .. } | CatalogType::Pseudo => Self::Pseudo,
             CatalogType::MzTimestamp => Self::String,
             CatalogType::Range { .. } => Self::Range,
+            CatalogType::Point | CatalogType::BoundingBox => Self::Geometric,
         }
     }
 
@@ -877,6 +879,8 @@ impl From<ScalarBaseType> for ParamType {
             RegType => ScalarType::RegType,
             Int2Vector => ScalarType::Int2Vector,
             MzTimestamp => ScalarType::MzTimestamp,
+            Point => ScalarType::Point,
+            BoundingBox => ScalarType::BoundingBox,
         };
         ParamType::Plain(s)
     }
@@ -1709,7 +1713,7 @@ macro_rules! impl_def {
     // Return type can be automatically determined as a function of the
     // parameters.
     ($params:expr, $op:expr, $oid:expr) => {{
-        let pcx = crate::plan::PlanContext::new(DateTime::<Utc>::MIN_UTC, false);
+        let pcx = crate::plan::PlanContext::new(DateTime::<Utc>::MIN_UTC, false, false);
         let scx = StatementContext::new(None, &crate::catalog::DummyCatalog);
         // This lifetime is compatible with more functions.
         let qcx = QueryContext::root(&scx, QueryLifetime::OneShot(&pcx));
@@ -1827,6 +1831,18 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
             params!(Float32) => UnaryFunc::AbsFloat32(func::AbsFloat32), 1394;
             params!(Float64) => UnaryFunc::AbsFloat64(func::AbsFloat64), 1395;
         },
+        "age" => Scalar {
+            params!(Timestamp, Timestamp) => BinaryFunc::AgeTimestamp, 2058;
+            params!(TimestampTz, TimestampTz) => BinaryFunc::AgeTimestampTz, 1199;
+            // PostgreSQL measures the age of a single timestamp from midnight of
+            // the current date.
+            params!(Timestamp) => sql_impl_func(
+                "pg_catalog.age(pg_catalog.date_trunc('day', pg_catalog.now())::pg_catalog.timestamp, $1)"
+            ) => Interval, 2059;
+            params!(TimestampTz) => sql_impl_func(
+                "pg_catalog.age(pg_catalog.date_trunc('day', pg_catalog.now()), $1)"
+            ) => Interval, 1386;
+        },
         "array_cat" => Scalar {
             params!(ArrayAnyCompatible, ArrayAnyCompatible) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(lhs.call_binary(rhs, BinaryFunc::ArrayArrayConcat))
@@ -1870,6 +1886,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
             params!(Bytes) => UnaryFunc::BitLengthBytes(func::BitLengthBytes), 1810;
             params!(String) => UnaryFunc::BitLengthString(func::BitLengthString), 1811;
         },
+        "box" => Scalar {
+            params!(Point, Point) => VariadicFunc::GeoBoxCreate, 1421;
+        },
         "btrim" => Scalar {
             params!(String) => UnaryFunc::TrimWhitespace(func::TrimWhitespace), 885;
             params!(String, String) => BinaryFunc::Trim, 884;
@@ -2023,6 +2042,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
                     END"
             ) => String, 1081;
         },
+        "get_bit" => Scalar {
+            params!(Bytes, Int64) => BinaryFunc::GetBit, 723;
+        },
         "get_byte" => Scalar {
             params!(Bytes, Int32) => BinaryFunc::GetByte, 721;
         },
@@ -2351,8 +2373,12 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
                 Ok(HirScalarExpr::literal(Datum::String(&name), ScalarType::String))
             }) => String, 1619;
         },
+        "point" => Scalar {
+            params!(Float64, Float64) => VariadicFunc::GeoPointCreate, 1440;
+        },
         "position" => Scalar {
             params!(String, String) => BinaryFunc::Position, 849;
+            params!(Bytes, Bytes) => BinaryFunc::PositionBytes, 2014;
         },
         "pow" => Scalar {
             params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("pow")) => Float64, 1346;
@@ -2387,6 +2413,12 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
             params!(String) => UnaryFunc::TrimTrailingWhitespace(func::TrimTrailingWhitespace), 882;
             params!(String, String) => BinaryFunc::TrimTrailing, 876;
         },
+        "set_bit" => Scalar {
+            params!(Bytes, Int64, Int32) => VariadicFunc::SetBit, 724;
+        },
+        "set_byte" => Scalar {
+            params!(Bytes, Int32, Int32) => VariadicFunc::SetByte, 722;
+        },
         "sha224" => Scalar {
             params!(Bytes) => digest("sha224") => Bytes, 3419;
         },
@@ -2447,10 +2479,14 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
         "substr" => Scalar {
             params!(String, Int64) => VariadicFunc::Substr, 883;
             params!(String, Int64, Int64) => VariadicFunc::Substr, 877;
+            params!(Bytes, Int64) => VariadicFunc::SubstrBytes, 2086;
+            params!(Bytes, Int64, Int64) => VariadicFunc::SubstrBytes, 2085;
         },
         "substring" => Scalar {
             params!(String, Int64) => VariadicFunc::Substr, 937;
             params!(String, Int64, Int64) => VariadicFunc::Substr, 936;
+            params!(Bytes, Int64) => VariadicFunc::SubstrBytes, 2013;
+            params!(Bytes, Int64, Int64) => VariadicFunc::SubstrBytes, 2012;
         },
         "sqrt" => Scalar {
             params!(Float64) => UnaryFunc::SqrtFloat64(func::SqrtFloat64), 1344;
@@ -2973,6 +3009,34 @@ pub static MZ_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
     use ParamType::*;
     use ScalarType::*;
     builtins! {
+        "bucket_deltas" => Aggregate {
+            params!(Timestamp, Float64, Interval) => Operation::variadic(|_ecx, exprs| {
+                let e = HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::RecordCreate {
+                        field_names: vec![
+                            ColumnName::from("bucket"),
+                            ColumnName::from("value"),
+                            ColumnName::from("width"),
+                        ],
+                    },
+                    exprs,
+                };
+                Ok((e, AggregateFunc::BucketDeltas))
+            }) => ListAny, oid::FUNC_BUCKET_DELTAS_TIMESTAMP_OID;
+            params!(TimestampTz, Float64, Interval) => Operation::variadic(|_ecx, exprs| {
+                let e = HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::RecordCreate {
+                        field_names: vec![
+                            ColumnName::from("bucket"),
+                            ColumnName::from("value"),
+                            ColumnName::from("width"),
+                        ],
+                    },
+                    exprs,
+                };
+                Ok((e, AggregateFunc::BucketDeltas))
+            }) => ListAny, oid::FUNC_BUCKET_DELTAS_TIMESTAMPTZ_OID;
+        },
         "csv_extract" => Table {
             params!(Int64, String) => Operation::binary(move |_ecx, ncols, input| {
                 let ncols = match ncols.into_literal_int64() {
@@ -3101,6 +3165,46 @@ pub static MZ_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
                 })
             }), oid::FUNC_REPEAT_OID;
         },
+        "session_windows" => Aggregate {
+            params!(Timestamp, Interval) => Operation::binary(|_ecx, ts, gap| {
+                let e = HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::RecordCreate {
+                        field_names: vec![ColumnName::from("ts"), ColumnName::from("gap")],
+                    },
+                    exprs: vec![ts, gap],
+                };
+                Ok((e, AggregateFunc::SessionWindows))
+            }) => ListAny, oid::FUNC_SESSION_WINDOWS_TIMESTAMP_OID;
+            params!(TimestampTz, Interval) => Operation::binary(|_ecx, ts, gap| {
+                let e = HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::RecordCreate {
+                        field_names: vec![ColumnName::from("ts"), ColumnName::from("gap")],
+                    },
+                    exprs: vec![ts, gap],
+                };
+                Ok((e, AggregateFunc::SessionWindows))
+            }) => ListAny, oid::FUNC_SESSION_WINDOWS_TIMESTAMPTZ_OID;
+        },
+        "st_box_contains" => Scalar {
+            params!(BoundingBox, Point) => VariadicFunc::GeoBoxContains => Bool, oid::FUNC_ST_BOX_CONTAINS_OID;
+        },
+        "st_distance" => Scalar {
+            params!(Point, Point) => VariadicFunc::GeoDistance => Float64, oid::FUNC_ST_DISTANCE_OID;
+        },
+        "st_distance_sphere" => Scalar {
+            params!(Point, Point) => VariadicFunc::GeoDistanceSphere => Float64, oid::FUNC_ST_DISTANCE_SPHERE_OID;
+        },
+        "tokenize" => Table {
+            params!(String) => Operation::unary(move |_ecx, e| {
+                Ok(TableFuncPlan {
+                    expr: HirRelationExpr::CallTable {
+                        func: TableFunc::Tokenize,
+                        exprs: vec![e],
+                    },
+                    column_names: vec!["token".into()],
+                })
+            }) => ReturnType::set_of(String.into()), oid::FUNC_TOKENIZE_OID;
+        },
         "unnest" => Table {
             vec![ArrayAny] => Operation::unary(move |ecx, e| {
                 let el_typ = ecx.scalar_type(&e).unwrap_array_element_type().clone();
@@ -3126,6 +3230,10 @@ pub static MZ_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
             }) =>
                 // This return type should be equivalent to "ListElementAny", but this would be its sole use.
                 ReturnType::set_of(Any), oid::FUNC_UNNEST_LIST_OID;
+        },
+        "xxhash64" => Scalar {
+            params!(Bytes) => UnaryFunc::Xxhash64Bytes(func::Xxhash64Bytes), oid::FUNC_XXHASH64_BYTES_OID;
+            params!(String) => UnaryFunc::Xxhash64String(func::Xxhash64String), oid::FUNC_XXHASH64_STRING_OID;
         }
     }
 });
@@ -3528,6 +3636,7 @@ static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
                 Ok(lhs.call_binary(rhs, TextConcat))
             }) => String, 2780;
             params!(String, String) => TextConcat, 654;
+            params!(Bytes, Bytes) => ByteaConcat, 2018;
             params!(Jsonb, Jsonb) => JsonbConcat, 3284;
             params!(ArrayAnyCompatible, ArrayAnyCompatible) => ArrayArrayConcat => ArrayAnyCompatible, 375;
             params!(ListAnyCompatible, ListAnyCompatible) => ListListConcat => ListAnyCompatible, oid::OP_CONCAT_LIST_LIST_OID;
@@ -3605,6 +3714,10 @@ static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
         "?|" => Scalar {
             params!(MapAny, ScalarType::Array(Box::new(ScalarType::String))) => MapContainsAnyKeys => Bool, oid::OP_CONTAINS_ANY_KEYS_MAP_OID;
         },
+        // TEXT SEARCH
+        "@@" => Scalar {
+            params!(String, String) => TextSearchMatch => Bool, oid::OP_TEXT_SEARCH_MATCH_OID;
+        },
         // COMPARISON OPS
         "<" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Lt, 1754;
@@ -40,28 +40,10 @@ pub fn validate_options_for_context<T: AstInfo>(
     options: &[KafkaConfigOption<T>],
     context: KafkaOptionCheckContext,
 ) -> Result<(), anyhow::Error> {
-    use KafkaConfigOptionName::*;
     use KafkaOptionCheckContext::*;
 
     for KafkaConfigOption { name, .. } in options {
-        let limited_to_context = match name {
-            Acks => None,
-            ClientId => None,
-            EnableIdempotence => None,
-            FetchMessageMaxBytes => None,
-            GroupIdPrefix => None,
-            IsolationLevel => None,
-            Topic => None,
-            TopicMetadataRefreshIntervalMs => None,
-            TransactionTimeoutMs => None,
-            StartTimestamp => Some(Source),
-            StartOffset => Some(Source),
-            PartitionCount => Some(Sink),
-            ReplicationFactor => Some(Sink),
-            RetentionBytes => Some(Sink),
-            RetentionMs => Some(Sink),
-        };
-        if limited_to_context.is_some() && limited_to_context != Some(context) {
+        if !is_option_for_context(name, context) {
             bail!(
                 "cannot set {} for {}",
                 name.to_ast_string(),
@@ -76,27 +58,74 @@ pub fn validate_options_for_context<T: AstInfo>(
     Ok(())
 }
 
+/// Reports whether the option `name` can be used in `context`.
+pub fn is_option_for_context(
+    name: &KafkaConfigOptionName,
+    context: KafkaOptionCheckContext,
+) -> bool {
+    use KafkaConfigOptionName::*;
+    use KafkaOptionCheckContext::*;
+
+    let limited_to_context = match name {
+        Acks => None,
+        BatchSize => Some(Sink),
+        CleanupPolicy => Some(Sink),
+        ClientId => None,
+        ClientRack => Some(Source),
+        CompressionType => Some(Sink),
+        CreateTopic => Some(Sink),
+        EnableIdempotence => None,
+        FetchMessageMaxBytes => None,
+        GroupIdPrefix => None,
+        IsolationLevel => None,
+        LingerMs => Some(Sink),
+        MaxInFlight => Some(Sink),
+        Topic => None,
+        TopicMetadataRefreshIntervalMs => None,
+        TransactionTimeoutMs => None,
+        StartTimestamp => Some(Source),
+        StartOffset => Some(Source),
+        PartitionCount => Some(Sink),
+        PartitionStrategy => Some(Sink),
+        ReplicationFactor => Some(Sink),
+        RetentionBytes => Some(Sink),
+        RetentionMs => Some(Sink),
+        UpdateOrder => Some(Sink),
+    };
+    limited_to_context.map_or(true, |limited_to| limited_to == context)
+}
+
 generate_extracted_config!(
     KafkaConfigOption,
     (Acks, String),
+    (BatchSize, i32),
+    (CleanupPolicy, String),
     (ClientId, String),
+    (ClientRack, String),
+    (CompressionType, String),
+    (CreateTopic, bool, Default(true)),
     (EnableIdempotence, bool),
     (FetchMessageMaxBytes, i32),
     (GroupIdPrefix, String),
     (
         IsolationLevel,
         String,
-        Default(String::from("read_committed"))
+        Default(String::from("read_committed")),
+        NotRecorded
     ),
+    (LingerMs, i32),
+    (MaxInFlight, i32),
     (Topic, String),
     (TopicMetadataRefreshIntervalMs, i32),
     (TransactionTimeoutMs, i32),
     (StartTimestamp, i64),
     (StartOffset, Vec<i64>),
-    (PartitionCount, i32, Default(-1)),
-    (ReplicationFactor, i32, Default(-1)),
+    (PartitionCount, i32),
+    (PartitionStrategy, String),
+    (ReplicationFactor, i32),
     (RetentionBytes, i64),
-    (RetentionMs, i64)
+    (RetentionMs, i64),
+    (UpdateOrder, String)
 );
 
 /// The config options we expect to pass along when connecting to librdkafka
@@ -108,10 +137,15 @@ impl TryFrom<&KafkaConfigOptionExtracted> for LibRdKafkaConfig {
     fn try_from(
         KafkaConfigOptionExtracted {
             acks,
+            batch_size,
             client_id,
+            client_rack,
+            compression_type,
             enable_idempotence,
             fetch_message_max_bytes,
             isolation_level,
+            linger_ms,
+            max_in_flight,
             topic_metadata_refresh_interval_ms,
             transaction_timeout_ms,
             ..
@@ -138,6 +172,7 @@ impl TryFrom<&KafkaConfigOptionExtracted> for LibRdKafkaConfig {
 
         fill_options!(acks, "acks");
         fill_options!(client_id, "client.id");
+        fill_options!(client_rack, "client.rack");
         fill_options!(
             topic_metadata_refresh_interval_ms,
             "topic.metadata.refresh.interval.ms",
@@ -160,6 +195,34 @@ impl TryFrom<&KafkaConfigOptionExtracted> for LibRdKafkaConfig {
             |i: &i32| { 0 <= *i && *i <= 1_000_000_000 },
             "FETCH MESSAGE MAX BYTES must be within [0, 1,000,000,000]"
         );
+        // The ranges of the producer options come from
+        // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
+        fill_options!(
+            batch_size,
+            "batch.size",
+            |i: &i32| 1 <= *i,
+            "BATCH SIZE must be within [1, 2,147,483,647]"
+        );
+        fill_options!(
+            linger_ms,
+            "linger.ms",
+            |i: &i32| { 0 <= *i && *i <= 900_000 },
+            "LINGER MS must be within [0, 900,000]"
+        );
+        fill_options!(
+            compression_type,
+            "compression.type",
+            |s: &String| ["none", "gzip", "snappy", "lz4", "zstd"].contains(&s.as_str()),
+            "COMPRESSION TYPE must be one of 'none', 'gzip', 'snappy', 'lz4', or 'zstd'"
+        );
+        fill_options!(
+            max_in_flight,
+            "max.in.flight.requests.per.connection",
+            // Kafka sinks use idempotent producers, which only guarantee
+            // ordering with at most 5 in-flight requests.
+            |i: &i32| { 1 <= *i && *i <= 5 },
+            "MAX IN FLIGHT must be within [1, 5]"
+        );
 
         Ok(LibRdKafkaConfig(o))
     }
@@ -1156,6 +1156,7 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                     .collect(),
             ),
             ConnectionKafkaBroker(broker) => ConnectionKafkaBroker(self.fold_kafka_broker(broker)),
+            SourceRetention(retention) => SourceRetention(self.fold_source_retention(retention)),
         }
     }
 }
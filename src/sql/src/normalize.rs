@@ -474,29 +474,44 @@ pub fn create_statement(
 ///   you can also define a default value.
 /// - `Default($v)` is an optional parameter that sets the default value of the
 ///   field to `$v`. `$v` must be convertible to `$t` using `.into`. This also
-///   converts the struct's type from `Option<$t>` to `<$t>`.
+///   converts the struct's type from `Option<$t>` to `<$t>`. `$t` must
+///   implement `IntoValue`, so that `defaulted_options` can report the
+///   options that were left at their defaults.
+/// - `Default($v), NotRecorded` sets a default value like `Default($v)`, but
+///   `defaulted_options` does not report the option when it is left at its
+///   default, e.g. because specifying the option requires unsafe mode.
 macro_rules! generate_extracted_config {
     // No default specified, have remaining options.
-    ($option_ty:ty, [$($processed:tt)*], ($option_name:path, $t:ty), $($tail:tt),*) => {
-        generate_extracted_config!($option_ty, [$($processed)* ($option_name, Option::<$t>, None)], $(
+    ($option_ty:ty, [$($processed:tt)*], [$($defaulted:tt)*], ($option_name:path, $t:ty), $($tail:tt),*) => {
+        generate_extracted_config!($option_ty, [$($processed)* ($option_name, Option::<$t>, None)], [$($defaulted)*], $(
             $tail
         ),*);
     };
     // No default specified, no remaining options.
-    ($option_ty:ty, [$($processed:tt)*], ($option_name:path, $t:ty)) => {
-        generate_extracted_config!($option_ty, [$($processed)* ($option_name, Option::<$t>, None)]);
+    ($option_ty:ty, [$($processed:tt)*], [$($defaulted:tt)*], ($option_name:path, $t:ty)) => {
+        generate_extracted_config!($option_ty, [$($processed)* ($option_name, Option::<$t>, None)], [$($defaulted)*]);
+    };
+    // Default specified but not recorded, have remaining options.
+    ($option_ty:ty, [$($processed:tt)*], [$($defaulted:tt)*], ($option_name:path, $t:ty, Default($v:expr), NotRecorded), $($tail:tt),*) => {
+        generate_extracted_config!($option_ty, [$($processed)* ($option_name, $t, $v)], [$($defaulted)*], $(
+            $tail
+        ),*);
+    };
+    // Default specified but not recorded, no remaining options.
+    ($option_ty:ty, [$($processed:tt)*], [$($defaulted:tt)*], ($option_name:path, $t:ty, Default($v:expr), NotRecorded)) => {
+        generate_extracted_config!($option_ty, [$($processed)* ($option_name, $t, $v)], [$($defaulted)*]);
     };
     // Default specified, have remaining options.
-    ($option_ty:ty, [$($processed:tt)*], ($option_name:path, $t:ty, Default($v:expr)), $($tail:tt),*) => {
-        generate_extracted_config!($option_ty, [$($processed)* ($option_name, $t, $v)], $(
+    ($option_ty:ty, [$($processed:tt)*], [$($defaulted:tt)*], ($option_name:path, $t:ty, Default($v:expr)), $($tail:tt),*) => {
+        generate_extracted_config!($option_ty, [$($processed)* ($option_name, $t, $v)], [$($defaulted)* ($option_name)], $(
             $tail
         ),*);
     };
     // Default specified, no remaining options.
-    ($option_ty:ty, [$($processed:tt)*], ($option_name:path, $t:ty, Default($v:expr))) => {
-        generate_extracted_config!($option_ty, [$($processed)* ($option_name, $t, $v)]);
+    ($option_ty:ty, [$($processed:tt)*], [$($defaulted:tt)*], ($option_name:path, $t:ty, Default($v:expr))) => {
+        generate_extracted_config!($option_ty, [$($processed)* ($option_name, $t, $v)], [$($defaulted)* ($option_name)]);
     };
-    ($option_ty:ty, [$(($option_name:path, $t:ty, $v:expr))+]) => {
+    ($option_ty:ty, [$(($option_name:path, $t:ty, $v:expr))+], [$(($defaulted_name:path))*]) => {
         paste::paste! {
             #[derive(Debug)]
             pub struct [<$option_ty Extracted>] {
@@ -539,10 +554,34 @@ macro_rules! generate_extracted_config {
                     Ok(extracted)
                 }
             }
+
+            impl [<$option_ty Extracted>] {
+                /// Returns the options that have a default, but were not
+                /// specified, set to their default values.
+                #[allow(dead_code)]
+                pub(crate) fn defaulted_options(&self) -> Vec<$option_ty<Aug>> {
+                    #[allow(unused_mut)]
+                    let mut options = vec![];
+                    $(
+                        {
+                            use [<$option_ty Name>]::*;
+                            if !self.seen.contains(&$defaulted_name) {
+                                options.push([<$option_ty>] {
+                                    name: $defaulted_name,
+                                    value: Some($crate::plan::with_options::IntoValue::into_value(
+                                        self.[<$defaulted_name:snake>].clone(),
+                                    )),
+                                });
+                            }
+                        }
+                    )*
+                    options
+                }
+            }
         }
     };
     ($option_ty:ty, $($h:tt),+) => {
-        generate_extracted_config!{$option_ty, [], $($h),+}
+        generate_extracted_config!{$option_ty, [], [], $($h),+}
     };
 }
 
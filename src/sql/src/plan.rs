@@ -39,9 +39,11 @@ use enum_kinds::EnumKind;
 use serde::{Deserialize, Serialize};
 
 use mz_compute_client::controller::ComputeInstanceId;
+use mz_compute_client::types::sinks::SubscribePartition;
 use mz_expr::{MirRelationExpr, MirScalarExpr, RowSetFinishing};
 use mz_ore::now::{self, NOW_ZERO};
 use mz_pgcopy::CopyFormatParams;
+use mz_repr::adt::interval::Interval;
 use mz_repr::explain_new::{ExplainConfig, ExplainFormat};
 use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType};
 use mz_storage_client::types::sinks::{SinkEnvelope, StorageSinkConnectionBuilder};
@@ -109,6 +111,7 @@ pub enum Plan {
     EmptyQuery,
     ShowAllVariables,
     ShowVariable(ShowVariablePlan),
+    ShowCreateCatalog,
     SetVariable(SetVariablePlan),
     ResetVariable(ResetVariablePlan),
     StartTransaction(StartTransactionPlan),
@@ -124,6 +127,7 @@ pub enum Plan {
     AlterNoop(AlterNoopPlan),
     AlterIndexSetOptions(AlterIndexSetOptionsPlan),
     AlterIndexResetOptions(AlterIndexResetOptionsPlan),
+    AlterSchema(AlterSchemaPlan),
     AlterSink(AlterSinkPlan),
     AlterSource(AlterSourcePlan),
     AlterItemRename(AlterItemRenamePlan),
@@ -156,6 +160,7 @@ impl Plan {
             StatementKind::AlterObjectRename => {
                 vec![PlanKind::AlterItemRename, PlanKind::AlterNoop]
             }
+            StatementKind::AlterSchema => vec![PlanKind::AlterNoop, PlanKind::AlterSchema],
             StatementKind::AlterSecret => vec![PlanKind::AlterNoop, PlanKind::AlterSecret],
             StatementKind::AlterSink => vec![PlanKind::AlterNoop, PlanKind::AlterSink],
             StatementKind::AlterSource => vec![PlanKind::AlterNoop, PlanKind::AlterSource],
@@ -216,10 +221,12 @@ impl Plan {
                 PlanKind::SendRows,
                 PlanKind::ShowVariable,
                 PlanKind::ShowAllVariables,
+                PlanKind::ShowCreateCatalog,
             ],
             StatementKind::StartTransaction => vec![PlanKind::StartTransaction],
             StatementKind::Subscribe => vec![PlanKind::Subscribe],
             StatementKind::Update => vec![PlanKind::ReadThenWrite, PlanKind::SendRows],
+            StatementKind::ValidateView => vec![PlanKind::Peek],
         }
     }
 }
@@ -367,6 +374,7 @@ pub struct CreateSinkPlan {
     pub name: QualifiedObjectName,
     pub sink: Sink,
     pub with_snapshot: bool,
+    pub track_latency: bool,
     pub if_not_exists: bool,
     pub host_config: StorageHostConfig,
 }
@@ -474,6 +482,7 @@ pub struct SubscribePlan {
     pub up_to: Option<MirScalarExpr>,
     pub copy_to: Option<CopyFormat>,
     pub emit_progress: bool,
+    pub partition: Option<SubscribePartition>,
 }
 
 #[derive(Debug)]
@@ -552,12 +561,22 @@ pub struct AlterIndexResetOptionsPlan {
 
 #[derive(Debug, Clone)]
 
-pub enum AlterOptionParameter {
-    Set(String),
+pub enum AlterOptionParameter<T = String> {
+    Set(T),
     Reset,
     Unchanged,
 }
 
+/// Changes the quotas of a schema.
+#[derive(Debug)]
+pub struct AlterSchemaPlan {
+    pub database_id: DatabaseId,
+    pub schema_id: SchemaId,
+    pub max_sources: AlterOptionParameter<u32>,
+    pub max_indexes: AlterOptionParameter<u32>,
+    pub max_materialized_views: AlterOptionParameter<u32>,
+}
+
 #[derive(Debug)]
 pub struct AlterSinkPlan {
     pub id: GlobalId,
@@ -570,6 +589,7 @@ pub struct AlterSourcePlan {
     pub id: GlobalId,
     pub size: AlterOptionParameter,
     pub remote: AlterOptionParameter,
+    pub timestamp_interval: AlterOptionParameter<Interval>,
 }
 
 #[derive(Debug)]
@@ -659,6 +679,9 @@ pub struct Source {
     pub create_sql: String,
     pub ingestion: Option<Ingestion>,
     pub desc: RelationDesc,
+    /// A temporal predicate that every read of the source applies, which
+    /// retracts rows once they are older than the source's `RETENTION`.
+    pub retention_filter: Option<MirScalarExpr>,
 }
 
 #[derive(Clone, Debug)]
@@ -684,6 +707,8 @@ pub struct Secret {
 pub struct Sink {
     pub create_sql: String,
     pub from: GlobalId,
+    /// The columns of `from` to emit, in order, if not all of them.
+    pub from_projection: Option<Vec<usize>>,
     pub connection_builder: StorageSinkConnectionBuilder,
     pub envelope: SinkEnvelope,
 }
@@ -710,6 +735,8 @@ pub struct Index {
     pub on: GlobalId,
     pub keys: Vec<mz_expr::MirScalarExpr>,
     pub compute_instance: ComputeInstanceId,
+    /// Whether the dataflow of the index is only built once a query uses it.
+    pub lazy: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -836,13 +863,20 @@ impl Params {
 pub struct PlanContext {
     pub wall_time: DateTime<Utc>,
     pub qgm_optimizations: bool,
+    /// Whether to reject wildcards in the queries of materialized views.
+    pub forbid_materialized_view_wildcards: bool,
 }
 
 impl PlanContext {
-    pub fn new(wall_time: DateTime<Utc>, qgm_optimizations: bool) -> Self {
+    pub fn new(
+        wall_time: DateTime<Utc>,
+        qgm_optimizations: bool,
+        forbid_materialized_view_wildcards: bool,
+    ) -> Self {
         Self {
             wall_time,
             qgm_optimizations,
+            forbid_materialized_view_wildcards,
         }
     }
 
@@ -853,6 +887,7 @@ impl PlanContext {
         PlanContext {
             wall_time: now::to_datetime(NOW_ZERO()),
             qgm_optimizations: false,
+            forbid_materialized_view_wildcards: false,
         }
     }
 }
@@ -107,6 +107,12 @@ pub enum PlanError {
     FetchingPostgresPublicationInfoFailed {
         cause: Arc<mz_postgres_util::PostgresError>,
     },
+    FetchingPostgresTableInfoFailed {
+        cause: Arc<mz_postgres_util::PostgresError>,
+    },
+    FetchingMaterializeViewInfoFailed {
+        cause: Arc<mz_postgres_util::PostgresError>,
+    },
     InvalidProtobufSchema {
         cause: protobuf_native::OperationFailedError,
     },
@@ -158,6 +164,8 @@ impl PlanError {
         match self {
             Self::FetchingCsrSchemaFailed { cause, .. } => Some(cause.to_string()),
             Self::FetchingPostgresPublicationInfoFailed { cause } => Some(cause.to_string()),
+            Self::FetchingPostgresTableInfoFailed { cause } => Some(cause.to_string()),
+            Self::FetchingMaterializeViewInfoFailed { cause } => Some(cause.to_string()),
             Self::InvalidProtobufSchema { cause } => Some(cause.to_string()),
             Self::InvalidOptionValue { err, .. } => err.detail(),
             _ => None,
@@ -194,7 +202,8 @@ impl PlanError {
                 as text."
                     .into(),
             ),
-            Self::FetchingPostgresPublicationInfoFailed { cause } => {
+            Self::FetchingPostgresPublicationInfoFailed { cause }
+            | Self::FetchingPostgresTableInfoFailed { cause } => {
                 if let Some(cause) = cause.source() {
                     if let Some(cause) = cause.downcast_ref::<io::Error>() {
                         if cause.kind() == io::ErrorKind::TimedOut {
@@ -208,6 +217,20 @@ impl PlanError {
                 }
                 None
             }
+            Self::FetchingMaterializeViewInfoFailed { cause } => {
+                if let Some(cause) = cause.source() {
+                    if let Some(cause) = cause.downcast_ref::<io::Error>() {
+                        if cause.kind() == io::ErrorKind::TimedOut {
+                            return Some(
+                                "Do you have a firewall or security group that is \
+                                preventing Materialize from connecting to the upstream Materialize instance?"
+                                    .into(),
+                            );
+                        }
+                    }
+                }
+                None
+            }
             Self::InvalidOptionValue { err, .. } => err.hint(),
             Self::UnknownFunction { alternative_hint, ..} => {
                 match alternative_hint {
@@ -349,6 +372,12 @@ impl fmt::Display for PlanError {
             Self::FetchingPostgresPublicationInfoFailed { .. } => {
                 write!(f, "failed to fetch publication information from PostgreSQL database")
             }
+            Self::FetchingPostgresTableInfoFailed { .. } => {
+                write!(f, "failed to fetch table information from PostgreSQL database")
+            }
+            Self::FetchingMaterializeViewInfoFailed { .. } => {
+                write!(f, "failed to fetch view information from Materialize")
+            }
             Self::InvalidProtobufSchema { .. } => {
                 write!(f, "invalid protobuf schema")
             }
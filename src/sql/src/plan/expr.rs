@@ -892,6 +892,13 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Groups `Datum::List`s of `(timestamp, gap)` records into sessions
+    /// separated by gaps of inactivity.
+    SessionWindows,
+    /// Compares the sum of the values of `Datum::List`s of
+    /// `(bucket, value, width)` records per bucket to the sum of the
+    /// previous bucket.
+    BucketDeltas,
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -956,6 +963,8 @@ impl AggregateFunc {
                 mz_expr::AggregateFunc::ListConcat { order_by }
             }
             AggregateFunc::StringAgg { order_by } => mz_expr::AggregateFunc::StringAgg { order_by },
+            AggregateFunc::SessionWindows => mz_expr::AggregateFunc::SessionWindows,
+            AggregateFunc::BucketDeltas => mz_expr::AggregateFunc::BucketDeltas,
             AggregateFunc::Dummy => mz_expr::AggregateFunc::Dummy,
         }
     }
@@ -1001,6 +1010,16 @@ impl AggregateFunc {
                     _ => unreachable!(),
                 }
             }
+            AggregateFunc::SessionWindows => {
+                mz_expr::AggregateFunc::SessionWindows
+                    .output_type(input_type)
+                    .scalar_type
+            }
+            AggregateFunc::BucketDeltas => {
+                mz_expr::AggregateFunc::BucketDeltas
+                    .output_type(input_type)
+                    .scalar_type
+            }
             _ => input_type.scalar_type,
         };
         // max/min/sum return null on empty sets
@@ -1212,7 +1231,6 @@ impl HirRelationExpr {
         }
     }
 
-    #[allow(dead_code)]
     pub fn top_k(
         self,
         group_key: Vec<usize>,
@@ -2281,7 +2299,7 @@ impl HirScalarExpr {
         self.visit_recursively_mut(0, &mut |_: usize, e: &mut HirScalarExpr| {
             if let HirScalarExpr::Parameter(n) = e {
                 let datum = match params.datums.iter().nth(*n - 1) {
-                    None => sql_bail!("there is no parameter ${}", n),
+                    None => return Err(PlanError::UnknownParameter(*n)),
                     Some(datum) => datum,
                 };
                 let scalar_type = &params.types[*n - 1];
@@ -1807,25 +1807,29 @@ pub(crate) fn derive_equijoin_cols(
     }
 
     // We restrict ourselves to predicates that test column equality between left and right.
+    // `IS NOT DISTINCT FROM` counts as equality, as the join matches nulls to nulls, and
+    // the predicate itself is applied again to the joined rows.
     let mut l_keys = Vec::new();
     let mut r_keys = Vec::new();
-    for predicate in predicates.iter_mut() {
-        if let mz_expr::MirScalarExpr::CallBinary {
-            expr1,
-            expr2,
-            func: BinaryFunc::Eq,
-        } = predicate
-        {
-            if let (mz_expr::MirScalarExpr::Column(c1), mz_expr::MirScalarExpr::Column(c2)) =
-                (&mut **expr1, &mut **expr2)
-            {
-                if *c1 > *c2 {
-                    std::mem::swap(c1, c2);
-                }
-                if (oa <= *c1 && *c1 < oa + la) && (oa + la <= *c2 && *c2 < oa + la + ra) {
-                    l_keys.push(*c1);
-                    r_keys.push(*c2 - la);
+    for predicate in predicates.iter() {
+        let columns = match predicate {
+            mz_expr::MirScalarExpr::CallBinary {
+                expr1,
+                expr2,
+                func: BinaryFunc::Eq,
+            } => match (&**expr1, &**expr2) {
+                (mz_expr::MirScalarExpr::Column(c1), mz_expr::MirScalarExpr::Column(c2)) => {
+                    Some((*c1, *c2))
                 }
+                _ => None,
+            },
+            _ => not_distinct_columns(predicate),
+        };
+        if let Some((c1, c2)) = columns {
+            let (c1, c2) = if c1 > c2 { (c2, c1) } else { (c1, c2) };
+            if (oa <= c1 && c1 < oa + la) && (oa + la <= c2 && c2 < oa + la + ra) {
+                l_keys.push(c1);
+                r_keys.push(c2 - la);
             }
         }
     }
@@ -1837,6 +1841,100 @@ pub(crate) fn derive_equijoin_cols(
     }
 }
 
+/// If `expr` is `c1 IS NOT DISTINCT FROM c2` for two columns `c1` and `c2`,
+/// returns the columns.
+///
+/// This recognizes the reduced form of the expression that the planner produces,
+/// `(c1 = c2 AND NOT isnull(c1) AND NOT isnull(c2)) OR (isnull(c1) AND isnull(c2))`,
+/// as well as the form without the `NOT isnull` guards.
+fn not_distinct_columns(expr: &mz_expr::MirScalarExpr) -> Option<(usize, usize)> {
+    use mz_expr::{BinaryFunc, MirScalarExpr, UnaryFunc, VariadicFunc};
+
+    fn is_null_column(expr: &MirScalarExpr) -> Option<usize> {
+        match expr {
+            MirScalarExpr::CallUnary {
+                func: UnaryFunc::IsNull(_),
+                expr,
+            } => match &**expr {
+                MirScalarExpr::Column(c) => Some(*c),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn conjuncts(expr: &MirScalarExpr) -> &[MirScalarExpr] {
+        match expr {
+            MirScalarExpr::CallVariadic {
+                func: VariadicFunc::And,
+                exprs,
+            } => exprs,
+            _ => std::slice::from_ref(expr),
+        }
+    }
+
+    // Returns the columns of `c1 = c2`, guarded by any of `NOT isnull(c1)`
+    // and `NOT isnull(c2)`. Any other term means the expression is not an
+    // equality on the columns alone, and returns `None`.
+    fn guarded_equality(expr: &MirScalarExpr) -> Option<(usize, usize)> {
+        let mut equality = None;
+        let mut guards = Vec::new();
+        for term in conjuncts(expr) {
+            match term {
+                MirScalarExpr::CallBinary {
+                    func: BinaryFunc::Eq,
+                    expr1,
+                    expr2,
+                } if equality.is_none() => match (&**expr1, &**expr2) {
+                    (MirScalarExpr::Column(c1), MirScalarExpr::Column(c2)) => {
+                        equality = Some((*c1, *c2));
+                    }
+                    _ => return None,
+                },
+                MirScalarExpr::CallUnary {
+                    func: UnaryFunc::Not(_),
+                    expr,
+                } => guards.push(is_null_column(expr)?),
+                _ => return None,
+            }
+        }
+        let (c1, c2) = equality?;
+        guards
+            .iter()
+            .all(|c| *c == c1 || *c == c2)
+            .then_some((c1, c2))
+    }
+
+    // Returns the columns of `isnull(c1) AND isnull(c2)`.
+    fn both_null(expr: &MirScalarExpr) -> Option<(usize, usize)> {
+        match conjuncts(expr) {
+            [a, b] => Some((is_null_column(a)?, is_null_column(b)?)),
+            _ => None,
+        }
+    }
+
+    if let MirScalarExpr::CallVariadic {
+        func: VariadicFunc::Or,
+        exprs,
+    } = expr
+    {
+        if let [a, b] = &exprs[..] {
+            let (equal, null) = match (guarded_equality(a), guarded_equality(b)) {
+                (Some(equal), _) => (equal, both_null(b)?),
+                (None, Some(equal)) => (equal, both_null(a)?),
+                (None, None) => return None,
+            };
+            let same = |(a, b): (usize, usize), (c, d): (usize, usize)| {
+                (a == c && b == d) || (a == d && b == c)
+            };
+            if same(equal, null) {
+                return Some(equal);
+            }
+        }
+    }
+    None
+}
+
 /// Attempts an efficient outer join, if `on` has equijoin structure.
 fn attempt_outer_join(
     left: mz_expr::MirRelationExpr,
@@ -1884,7 +1982,12 @@ fn attempt_outer_join(
             // TODO: we know that we can re-use the arrangements of left and right
             // needed for the inner join with each of the conditional outer joins.
             // It is not clear whether we should hint that, or just let the planner
-            // and optimizer run and see what happens.
+            // and optimizer run and see what happens. Note that a let-bound
+            // `ArrangeBy` of `get_left` or `get_right` would not be shared as
+            // things stand: once the filter below is pushed down, the inner
+            // join arranges the inputs with their keys filtered to be non-null,
+            // while the joins that find the matched rows arrange the unfiltered
+            // inputs.
 
             // We'll want the inner join (minus repeated columns)
             let join = mz_expr::MirRelationExpr::join(
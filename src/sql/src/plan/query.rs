@@ -59,10 +59,10 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     AsOf, Assignment, AstInfo, CteBlock, DeleteStatement, Distinct, Expr, Function, FunctionArgs,
-    HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join, JoinConstraint, JoinOperator,
-    Limit, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName, SetExpr,
-    SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor, TableFunction,
-    TableWithJoins, UnresolvedObjectName, UpdateStatement, Value, Values, WindowFrame,
+    GroupByExpr, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join, JoinConstraint,
+    JoinOperator, Limit, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName,
+    SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor, TableFunction,
+    TableSample, TableWithJoins, UnresolvedObjectName, UpdateStatement, Value, Values, WindowFrame,
     WindowFrameBound, WindowFrameUnits, WindowSpec,
 };
 
@@ -988,6 +988,32 @@ pub fn plan_index_exprs<'a>(
     Ok(out)
 }
 
+/// Plans the `PARTITION BY` expression of a Kafka sink, which may only refer to
+/// the columns of the sink's key.
+pub fn plan_sink_partition_expr(
+    scx: &StatementContext,
+    key_desc: &RelationDesc,
+    mut expr: Expr<Aug>,
+) -> Result<mz_expr::MirScalarExpr, PlanError> {
+    let scope = Scope::from_source(None, key_desc.iter_names());
+    let qcx = QueryContext::root(scx, QueryLifetime::Static);
+
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "PARTITION BY",
+        scope: &scope,
+        relation_type: key_desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_windows: false,
+    };
+    transform_ast::transform_expr(scx, &mut expr)?;
+    let expr = plan_expr(ecx, &expr)?.cast_to(ecx, CastContext::Implicit, &ScalarType::Int64)?;
+    let mut expr = expr.lower_uncorrelated()?;
+    expr.reduce(&key_desc.typ().column_types);
+    Ok(expr)
+}
+
 fn plan_expr_or_col_index(ecx: &ExprContext, e: &Expr<Aug>) -> Result<HirScalarExpr, PlanError> {
     match check_col_index(ecx.name, e, ecx.relation_type.column_types.len())? {
         Some(column) => Ok(HirScalarExpr::column(column)),
@@ -1241,6 +1267,74 @@ pub fn plan_nested_query(
     Ok((expr.project(finishing.project), scope))
 }
 
+/// Reorders the columns of the inputs of a set operation with `BY NAME`, so
+/// that the columns with the same name are at the same position in both
+/// inputs. The output has the columns of the LHS followed by the columns that
+/// only the RHS has, and columns that only one input has are `NULL` in the
+/// other.
+fn align_set_operation_by_name(
+    qcx: &QueryContext,
+    op: &SetOperator,
+    (left_expr, left_scope): (HirRelationExpr, Scope),
+    (right_expr, right_scope): (HirRelationExpr, Scope),
+) -> Result<((HirRelationExpr, Scope), (HirRelationExpr, Scope)), PlanError> {
+    let left_names: Vec<_> = left_scope.column_names().cloned().collect();
+    let right_names: Vec<_> = right_scope.column_names().cloned().collect();
+    for names in [&left_names, &right_names] {
+        let mut seen = HashSet::new();
+        for name in names {
+            if !seen.insert(name) {
+                sql_bail!(
+                    "column name {} appears more than once in a {} BY NAME query",
+                    name.as_str().quoted(),
+                    op
+                );
+            }
+        }
+    }
+
+    let mut names = left_names.clone();
+    names.extend(
+        right_names
+            .iter()
+            .filter(|name| !left_names.contains(name))
+            .cloned(),
+    );
+
+    let left_type = qcx.relation_type(&left_expr);
+    let right_type = qcx.relation_type(&right_expr);
+    let align = |expr: HirRelationExpr,
+                 own_names: &[ColumnName],
+                 other_names: &[ColumnName],
+                 other_type: &RelationType| {
+        let exprs = names
+            .iter()
+            .map(|name| match own_names.iter().position(|n| n == name) {
+                Some(i) => HirScalarExpr::column(i),
+                None => {
+                    // Missing columns take the type of the other input's
+                    // column, which the set operation then casts as usual.
+                    let i = other_names
+                        .iter()
+                        .position(|n| n == name)
+                        .expect("name taken from one of the inputs");
+                    HirScalarExpr::literal_null(other_type.column_types[i].scalar_type.clone())
+                }
+            })
+            .collect();
+        let arity = own_names.len();
+        expr.map(exprs)
+            .project((arity..arity + names.len()).collect())
+    };
+    let left_expr = align(left_expr, &left_names, &right_names, &right_type);
+    let right_expr = align(right_expr, &right_names, &left_names, &left_type);
+
+    Ok((
+        (left_expr, Scope::from_source(None, names.clone())),
+        (right_expr, Scope::from_source(None, names)),
+    ))
+}
+
 fn plan_set_expr(
     qcx: &mut QueryContext,
     q: &SetExpr<Aug>,
@@ -1257,12 +1351,21 @@ fn plan_set_expr(
         SetExpr::SetOperation {
             op,
             all,
+            by_name,
             left,
             right,
         } => {
             // Plan the LHS and RHS.
-            let (left_expr, left_scope) = plan_set_expr(qcx, left)?;
-            let (right_expr, right_scope) = plan_set_expr(qcx, right)?;
+            let left = plan_set_expr(qcx, left)?;
+            let right = plan_set_expr(qcx, right)?;
+            let ((left_expr, left_scope), (right_expr, right_scope)) = if *by_name {
+                if *op != SetOperator::Union {
+                    bail_unsupported!(format!("{} BY NAME", op));
+                }
+                align_set_operation_by_name(qcx, op, left, right)?
+            } else {
+                (left, right)
+            };
 
             // Validate that the LHS and RHS are the same width.
             let left_type = qcx.relation_type(&left_expr);
@@ -1459,7 +1562,9 @@ fn plan_set_expr(
                 ShowStatement::ShowSchemas(stmt) => {
                     show::show_schemas(qcx.scx, stmt)?.plan_hir(qcx)
                 }
-                ShowStatement::ShowVariable(_) => sql_bail!("unsupported SHOW statement"),
+                ShowStatement::ShowCreateCatalog | ShowStatement::ShowVariable(_) => {
+                    sql_bail!("unsupported SHOW statement")
+                }
             }
         }
     }
@@ -1699,7 +1804,7 @@ fn plan_view_select(
     }
 
     // Step 3. Gather aggregates and table functions.
-    let (aggregates, table_funcs) = {
+    let (aggregates, groupings, table_funcs) = {
         let mut visitor = AggregateTableFuncVisitor::new(qcx.scx);
         visitor.visit_select_mut(&mut s);
         for o in order_by_exprs.iter_mut() {
@@ -1759,7 +1864,16 @@ fn plan_view_select(
         let mut group_scope = Scope::empty();
         let mut select_all_mapping = BTreeMap::new();
 
-        for group_expr in &s.group_by {
+        // Each grouping set is described by the indices of its expressions in
+        // `group_hir_exprs`.
+        let grouping_sets = expand_grouping_sets(&s.group_by)?;
+        let mut grouping_set_columns = vec![vec![]; grouping_sets.len()];
+
+        for (group_expr, set) in grouping_sets
+            .iter()
+            .enumerate()
+            .flat_map(|(set, exprs)| exprs.iter().map(move |expr| (*expr, set)))
+        {
             let (group_expr, expr) = plan_group_by_expr(ecx, group_expr, &projection)?;
             let new_column = group_key.len();
 
@@ -1769,6 +1883,13 @@ fn plan_view_select(
                 // next AST expression to its set
                 if let Some(existing_scope_item) = group_exprs.get_mut(&expr) {
                     existing_scope_item.exprs.insert(group_expr.clone());
+                    let column = group_hir_exprs
+                        .iter()
+                        .position(|e| *e == expr)
+                        .expect("group expression is known");
+                    if !grouping_set_columns[set].contains(&column) {
+                        grouping_set_columns[set].push(column);
+                    }
                     continue;
                 }
             }
@@ -1794,6 +1915,7 @@ fn plan_view_select(
                 scope_item.exprs.insert(group_expr);
             }
 
+            grouping_set_columns[set].push(new_column);
             group_key.push(from_scope.len() + group_exprs.len());
             group_hir_exprs.push(expr.clone());
             group_exprs.insert(expr, scope_item);
@@ -1806,6 +1928,13 @@ fn plan_view_select(
             }
         }
 
+        // Plan GROUPING operations. Each one is described by the indices of
+        // its arguments in `group_hir_exprs`.
+        let grouping_args = groupings
+            .iter()
+            .map(|grouping| plan_grouping(ecx, grouping, &group_hir_exprs))
+            .collect::<Result<Vec<_>, _>>()?;
+
         // Plan aggregates.
         let ecx = &ExprContext {
             qcx,
@@ -1823,14 +1952,40 @@ fn plan_view_select(
                 .items
                 .push(ScopeItem::from_expr(Expr::Function(sql_function.clone())));
         }
-        if !agg_exprs.is_empty() || !group_key.is_empty() || s.having.is_some() {
-            // apply GROUP BY / aggregates
-            relation_expr = relation_expr.map(group_hir_exprs).reduce(
-                group_key,
+        for grouping in groupings {
+            group_scope
+                .items
+                .push(ScopeItem::from_expr(Expr::Function(grouping)));
+        }
+        if grouping_set_columns.len() > 1 {
+            // apply GROUPING SETS / ROLLUP / CUBE
+            relation_expr = plan_grouping_sets(
+                qcx,
+                relation_expr,
+                from_scope.len(),
+                group_hir_exprs,
+                grouping_set_columns,
                 agg_exprs,
+                &grouping_args,
                 expected_group_size.map(usize::cast_from),
             );
             (group_scope, select_all_mapping)
+        } else if !agg_exprs.is_empty() || !s.group_by.is_empty() || s.having.is_some() {
+            // apply GROUP BY / aggregates
+            let group_set: Vec<_> = (0..group_hir_exprs.len()).collect();
+            let grouping_values = grouping_args
+                .iter()
+                .map(|args| grouping_value(args, &group_set))
+                .collect();
+            relation_expr = relation_expr
+                .map(group_hir_exprs)
+                .reduce(
+                    group_key,
+                    agg_exprs,
+                    expected_group_size.map(usize::cast_from),
+                )
+                .map(grouping_values);
+            (group_scope, select_all_mapping)
         } else {
             // if no GROUP BY, aggregates or having then all columns remain in scope
             (
@@ -2118,6 +2273,212 @@ fn plan_scalar_table_funcs(
     Ok((expr, scope))
 }
 
+/// The maximum number of grouping sets that a `GROUP BY` clause may expand
+/// to. This matches PostgreSQL.
+const MAX_GROUPING_SETS: usize = 4096;
+
+/// The maximum number of elements in a `CUBE`, which is the largest number
+/// whose power set does not exceed [`MAX_GROUPING_SETS`].
+const MAX_CUBE_ELEMENTS: usize = 12;
+
+/// Expands the items of a `GROUP BY` clause into the list of grouping sets
+/// they describe.
+///
+/// A plain `GROUP BY a, b` describes the single grouping set `(a, b)`. Each
+/// `ROLLUP`, `CUBE`, or `GROUPING SETS` item describes several grouping sets,
+/// and a clause with several items describes the cross product of its items'
+/// grouping sets, concatenating the expressions of each combination.
+fn expand_grouping_sets<'a>(
+    group_by: &'a [GroupByExpr<Aug>],
+) -> Result<Vec<Vec<&'a Expr<Aug>>>, PlanError> {
+    // The expressions that an element of a grouping set construct contributes.
+    // Row constructors, as in `ROLLUP ((a, b), c)`, are treated as a unit.
+    fn element(expr: &Expr<Aug>) -> Vec<&Expr<Aug>> {
+        match expr {
+            Expr::Row { exprs } => exprs.iter().collect(),
+            expr => vec![expr],
+        }
+    }
+
+    fn expand(item: &GroupByExpr<Aug>) -> Result<Vec<Vec<&Expr<Aug>>>, PlanError> {
+        let sets = match item {
+            GroupByExpr::Expr(expr) => vec![vec![expr]],
+            GroupByExpr::Empty => vec![vec![]],
+            // `ROLLUP (a, b)` is `GROUPING SETS ((a, b), (a), ())`.
+            GroupByExpr::Rollup(exprs) => (0..=exprs.len())
+                .rev()
+                .map(|n| exprs[..n].iter().flat_map(element).collect())
+                .collect(),
+            // `CUBE (a, b)` is `GROUPING SETS ((a, b), (a), (b), ())`.
+            GroupByExpr::Cube(exprs) => {
+                if exprs.len() > MAX_CUBE_ELEMENTS {
+                    sql_bail!("CUBE is limited to {} elements", MAX_CUBE_ELEMENTS);
+                }
+                (0..1usize << exprs.len())
+                    .rev()
+                    .map(|mask| {
+                        exprs
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| mask & (1 << (exprs.len() - 1 - i)) != 0)
+                            .flat_map(|(_, expr)| element(expr))
+                            .collect()
+                    })
+                    .collect()
+            }
+            GroupByExpr::GroupingSets(items) => {
+                let mut sets = vec![];
+                for item in items {
+                    match item {
+                        GroupByExpr::Expr(expr) => sets.push(element(expr)),
+                        item => sets.extend(expand(item)?),
+                    }
+                }
+                sets
+            }
+        };
+        if sets.len() > MAX_GROUPING_SETS {
+            sql_bail!("too many grouping sets present");
+        }
+        Ok(sets)
+    }
+
+    let mut sets = vec![vec![]];
+    for item in group_by {
+        let item_sets = expand(item)?;
+        if sets.len() * item_sets.len() > MAX_GROUPING_SETS {
+            sql_bail!("too many grouping sets present");
+        }
+        sets = sets
+            .iter()
+            .cartesian_product(&item_sets)
+            .map(|(l, r)| l.iter().chain(r).copied().collect())
+            .collect();
+    }
+    Ok(sets)
+}
+
+/// Plans the aggregation of `input` over several grouping sets.
+///
+/// Each grouping set is planned as its own reduction of the input, keyed by
+/// the grouping set's expressions. The group expressions that are not part of
+/// a grouping set are filled in with `NULL`, so that every reduction produces
+/// one column for each of `group_exprs` followed by one column for each of
+/// `aggregates`, and the reductions are then combined with a `UNION ALL`. The
+/// branches of the union all reduce the same input, which the optimizer
+/// arranges to compute only once.
+///
+/// Each reduction also produces one column for each of the `GROUPING`
+/// operations described by `groupings`, which holds the constant value of the
+/// operation for the reduction's grouping set. These columns identify the
+/// grouping set that produced a row.
+fn plan_grouping_sets(
+    qcx: &QueryContext,
+    input: HirRelationExpr,
+    input_arity: usize,
+    group_exprs: Vec<HirScalarExpr>,
+    grouping_sets: Vec<Vec<usize>>,
+    aggregates: Vec<AggregateExpr>,
+    groupings: &[Vec<usize>],
+    expected_group_size: Option<usize>,
+) -> HirRelationExpr {
+    let group_arity = group_exprs.len();
+    let input = input.map(group_exprs);
+    let input_type = qcx.relation_type(&input);
+    let mut branches = grouping_sets
+        .into_iter()
+        .map(|set| {
+            let key = set.iter().map(|column| input_arity + column).collect();
+            let mut nulls = vec![];
+            let mut outputs = vec![];
+            for column in 0..group_arity {
+                match set.iter().position(|c| *c == column) {
+                    Some(i) => outputs.push(i),
+                    None => {
+                        let typ = &input_type.column_types[input_arity + column];
+                        outputs.push(set.len() + aggregates.len() + nulls.len());
+                        nulls.push(HirScalarExpr::literal_null(typ.scalar_type.clone()));
+                    }
+                }
+            }
+            outputs.extend(set.len()..set.len() + aggregates.len());
+            let grouping_start = set.len() + aggregates.len() + nulls.len();
+            outputs.extend(grouping_start..grouping_start + groupings.len());
+            let mut values = nulls;
+            values.extend(groupings.iter().map(|args| grouping_value(args, &set)));
+            input
+                .clone()
+                .reduce(key, aggregates.clone(), expected_group_size)
+                .map(values)
+                .project(outputs)
+        })
+        .collect::<Vec<_>>();
+    let base = branches.remove(0);
+    HirRelationExpr::Union {
+        base: Box::new(base),
+        inputs: branches,
+    }
+}
+
+/// The maximum number of arguments of a `GROUPING` operation, so that its
+/// result fits in an `integer`. This matches PostgreSQL.
+const MAX_GROUPING_ARGS: usize = 31;
+
+/// Reports whether `func` is a call to the `GROUPING` operation.
+fn is_grouping_call(func: &Function<Aug>) -> bool {
+    match normalize::unresolved_object_name(func.name.clone()) {
+        Ok(name) => name.database.is_none() && name.schema.is_none() && name.item == "grouping",
+        Err(_) => false,
+    }
+}
+
+/// Plans the arguments of the `GROUPING` operation `grouping`, returning the
+/// index of each argument in `group_exprs`.
+fn plan_grouping(
+    ecx: &ExprContext,
+    grouping: &Function<Aug>,
+    group_exprs: &[HirScalarExpr],
+) -> Result<Vec<usize>, PlanError> {
+    let args = match grouping {
+        Function {
+            name: _,
+            args: FunctionArgs::Args { args, order_by },
+            filter: None,
+            over: None,
+            distinct: false,
+        } if !args.is_empty() && order_by.is_empty() => args,
+        _ => sql_bail!("GROUPING only accepts a list of grouping expressions"),
+    };
+    if args.len() > MAX_GROUPING_ARGS {
+        sql_bail!(
+            "GROUPING must have fewer than {} arguments",
+            MAX_GROUPING_ARGS + 1
+        );
+    }
+    args.iter()
+        .map(|arg| {
+            let expr = plan_expr(ecx, arg)?.type_as_any(ecx)?;
+            group_exprs.iter().position(|e| *e == expr).ok_or_else(|| {
+                sql_err!(
+                    "arguments to GROUPING must be grouping expressions of the associated query level"
+                )
+            })
+        })
+        .collect()
+}
+
+/// Computes the value of a `GROUPING` operation whose arguments are the group
+/// expressions `args` for the rows of the grouping set `set`.
+///
+/// The value has one bit for each argument, with the last argument in the least
+/// significant bit. A bit is set if its argument is not part of the grouping set.
+fn grouping_value(args: &[usize], set: &[usize]) -> HirScalarExpr {
+    let value = args.iter().fold(0, |value, column| {
+        (value << 1) | i32::from(!set.contains(column))
+    });
+    HirScalarExpr::literal(Datum::Int32(value), ScalarType::Int32)
+}
+
 /// Plans an expression in a `GROUP BY` clause.
 ///
 /// For historical reasons, PostgreSQL allows `GROUP BY` expressions to refer to
@@ -2271,9 +2632,16 @@ fn plan_table_factor(
     table_factor: &TableFactor<Aug>,
 ) -> Result<(HirRelationExpr, Scope), PlanError> {
     match table_factor {
-        TableFactor::Table { name, alias } => {
-            let (expr, scope) = qcx.resolve_table_name(name.clone())?;
+        TableFactor::Table {
+            name,
+            alias,
+            sample,
+        } => {
+            let (mut expr, scope) = qcx.resolve_table_name(name.clone())?;
             let scope = plan_table_alias(scope, alias.as_ref())?;
+            if let Some(sample) = sample {
+                expr = plan_table_sample(qcx, expr, &scope, sample)?;
+            }
             Ok((expr, scope))
         }
 
@@ -2320,6 +2688,70 @@ fn plan_table_factor(
     }
 }
 
+/// Plans a `TABLESAMPLE` clause as a filter on the table it samples.
+///
+/// A row is sampled if the hash of its text representation, salted with the
+/// seed, falls within the requested percentage of the range of the hash. The
+/// filter still reads the whole relation, and `SYSTEM` samples individual rows,
+/// like `BERNOULLI`, rather than skipping parts of the relation. Because the
+/// hash only depends on the row and the seed, copies of the same row are either
+/// all sampled or not at all, which is unlike a true `BERNOULLI` sample.
+fn plan_table_sample(
+    qcx: &QueryContext,
+    expr: HirRelationExpr,
+    scope: &Scope,
+    sample: &TableSample<Aug>,
+) -> Result<HirRelationExpr, PlanError> {
+    let pcx = match qcx.lifetime {
+        QueryLifetime::OneShot(pcx) => pcx,
+        QueryLifetime::Static => sql_bail!("TABLESAMPLE cannot be used in static queries"),
+    };
+    let percentage: f64 = match &sample.percentage {
+        Expr::Value(Value::Number(x)) => x.parse()?,
+        _ => sql_bail!("TABLESAMPLE percentage must be a numeric constant"),
+    };
+    if !(0.0..=100.0).contains(&percentage) {
+        sql_bail!("sample percentage must be between 0 and 100");
+    }
+    // Without `REPEATABLE`, every query draws a different sample.
+    let seed = match &sample.repeatable {
+        None => pcx.wall_time.timestamp_nanos().to_string(),
+        Some(Expr::Value(Value::Number(x))) => x.clone(),
+        Some(_) => sql_bail!("REPEATABLE seed must be a numeric constant"),
+    };
+    if percentage == 100.0 {
+        return Ok(expr);
+    }
+
+    let ecx = &ExprContext {
+        qcx,
+        name: "TABLESAMPLE clause",
+        scope,
+        relation_type: &qcx.relation_type(&expr),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_windows: false,
+    };
+    let arity = ecx.relation_type.arity();
+    let row = HirScalarExpr::CallVariadic {
+        func: VariadicFunc::RecordCreate {
+            field_names: iter::repeat(ColumnName::from("")).take(arity).collect(),
+        },
+        exprs: (0..arity).map(HirScalarExpr::column).collect(),
+    };
+    let row = typeconv::plan_cast(ecx, CastContext::Explicit, row, &ScalarType::String)?;
+    let hash = HirScalarExpr::literal(Datum::String(&seed), ScalarType::String)
+        .call_binary(row, BinaryFunc::TextConcat)
+        .call_unary(UnaryFunc::Xxhash64String(expr_func::Xxhash64String));
+    // The hash is uniformly distributed over the range of `i64`, so the
+    // threshold is `percentage` percent of the way through that range. The
+    // cast saturates at `i64::MIN` for a percentage of zero.
+    #[allow(clippy::as_conversions)]
+    let threshold = (percentage / 100.0 * 2f64.powi(64) + i64::MIN as f64) as i64;
+    let threshold = HirScalarExpr::literal(Datum::Int64(threshold), ScalarType::Int64);
+    Ok(expr.filter(vec![hash.call_binary(threshold, BinaryFunc::Lt)]))
+}
+
 /// Plans a `ROWS FROM` expression.
 ///
 /// `ROWS FROM` concatenates table functions into a single table, filling in
@@ -2921,16 +3353,38 @@ fn plan_join(
         JoinOperator::LeftOuter(constraint) => (JoinKind::LeftOuter, constraint),
         JoinOperator::RightOuter(constraint) => (JoinKind::RightOuter, constraint),
         JoinOperator::FullOuter(constraint) => (JoinKind::FullOuter, constraint),
+        JoinOperator::AsOf(constraint) => (JoinKind::Inner, constraint),
+        JoinOperator::AsOfLeftOuter(constraint) => (JoinKind::LeftOuter, constraint),
     };
+    let asof = matches!(
+        join.join_operator,
+        JoinOperator::AsOf(_) | JoinOperator::AsOfLeftOuter(_)
+    );
 
     let mut right_qcx = left_qcx.derived_context(left_scope.clone(), left_qcx.relation_type(&left));
-    if !kind.can_be_correlated() {
+    // The right side of an ASOF join is joined with an expression derived from
+    // the left side rather than with the left side itself, so it cannot be
+    // lateral.
+    if !kind.can_be_correlated() || asof {
         for item in &mut right_qcx.outer_scopes[0].items {
             item.lateral_error_if_referenced = true;
         }
     }
     let (right, right_scope) = plan_table_factor(&right_qcx, &join.relation)?;
 
+    if asof {
+        return plan_asof_join(
+            left_qcx,
+            left,
+            left_scope,
+            &right_qcx,
+            right,
+            right_scope,
+            constraint,
+            kind,
+        );
+    }
+
     let (expr, scope) = match constraint {
         JoinConstraint::On(expr) => {
             let product_scope = left_scope.product(right_scope)?;
@@ -2989,6 +3443,224 @@ fn plan_join(
     Ok((expr, scope))
 }
 
+/// Plans an `ASOF JOIN`, which joins each row of `left` to the latest row of
+/// `right` that matches it.
+///
+/// The `ON` clause must be a conjunction of equalities, which form the join
+/// key, and of exactly one inequality, which orders the matching rows. Each
+/// must compare an expression of the left side with one of the right side.
+/// When the inequality bounds the right expression from above (e.g. `l.ts >=
+/// r.ts`), the match is the right row with the greatest value of it; when it
+/// bounds it from below, the match is the right row with the least value.
+///
+/// The join is planned as
+///
+/// ```text
+/// keys    = Distinct(left key and ordering expressions)
+/// matches = TopK(keys JOIN right ON <ON clause>,
+///                group by keys, order by the right ordering expression, limit 1)
+/// result  = left JOIN matches ON the left key and ordering expressions
+/// ```
+///
+/// so that the matches are maintained once per distinct key and timestamp of
+/// the left side, rather than once per left row.
+///
+/// If several right rows tie for the match of a left row, one of them is chosen
+/// arbitrarily, but deterministically.
+#[allow(clippy::too_many_arguments)]
+fn plan_asof_join(
+    left_qcx: &QueryContext,
+    left: HirRelationExpr,
+    left_scope: Scope,
+    right_qcx: &QueryContext,
+    right: HirRelationExpr,
+    right_scope: Scope,
+    constraint: &JoinConstraint<Aug>,
+    kind: JoinKind,
+) -> Result<(HirRelationExpr, Scope), PlanError> {
+    let on = match constraint {
+        JoinConstraint::On(on) => on,
+        JoinConstraint::Using(_) | JoinConstraint::Natural => {
+            sql_bail!("ASOF JOIN requires an ON clause")
+        }
+    };
+    let left_arity = left_scope.len();
+    let right_arity = right_scope.len();
+    let product_scope = left_scope.product(right_scope)?;
+    let ecx = &ExprContext {
+        qcx: left_qcx,
+        name: "ASOF JOIN ON clause",
+        scope: &product_scope,
+        relation_type: &RelationType::new(
+            left_qcx
+                .relation_type(&left)
+                .column_types
+                .into_iter()
+                .chain(right_qcx.relation_type(&right).column_types)
+                .collect(),
+        ),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_windows: false,
+    };
+    let on = plan_expr(ecx, on)?.type_as(ecx, &ScalarType::Bool)?;
+
+    // Reports which side of the join the columns of `expr` belong to, if they
+    // all belong to the same side.
+    let side = |expr: &HirScalarExpr| {
+        let (mut in_left, mut in_right) = (false, false);
+        #[allow(deprecated)]
+        expr.visit_columns(0, &mut |depth, col| {
+            if col.level == depth {
+                if col.column < left_arity {
+                    in_left = true;
+                } else {
+                    in_right = true;
+                }
+            }
+        });
+        match (in_left, in_right) {
+            (true, false) => Some(JoinSide::Left),
+            (false, true) => Some(JoinSide::Right),
+            _ => None,
+        }
+    };
+
+    // Split the ON clause into the key equalities and the ordering inequality,
+    // with the expression of the left side first.
+    let mut left_keys = vec![];
+    let mut right_keys = vec![];
+    let mut ordering = None;
+    let mut conjuncts = vec![on];
+    while let Some(conjunct) = conjuncts.pop() {
+        let (func, expr1, expr2) = match conjunct {
+            HirScalarExpr::CallVariadic {
+                func: VariadicFunc::And,
+                exprs,
+            } => {
+                conjuncts.extend(exprs.into_iter().rev());
+                continue;
+            }
+            HirScalarExpr::CallBinary { func, expr1, expr2 } => (func, *expr1, *expr2),
+            _ => sql_bail!(
+                "ASOF JOIN ON clause must consist of equalities and one inequality \
+                between the two sides of the join"
+            ),
+        };
+        let (func, left_expr, right_expr) = match (side(&expr1), side(&expr2)) {
+            (Some(JoinSide::Left), Some(JoinSide::Right)) => (func, expr1, expr2),
+            (Some(JoinSide::Right), Some(JoinSide::Left)) => {
+                let func = match func {
+                    BinaryFunc::Lt => BinaryFunc::Gt,
+                    BinaryFunc::Lte => BinaryFunc::Gte,
+                    BinaryFunc::Gt => BinaryFunc::Lt,
+                    BinaryFunc::Gte => BinaryFunc::Lte,
+                    func => func,
+                };
+                (func, expr2, expr1)
+            }
+            _ => sql_bail!(
+                "ASOF JOIN ON clause must compare an expression of the left side of the join \
+                with one of the right side"
+            ),
+        };
+        match func {
+            BinaryFunc::Eq => {
+                left_keys.push(left_expr);
+                right_keys.push(right_expr);
+            }
+            BinaryFunc::Lt | BinaryFunc::Lte | BinaryFunc::Gt | BinaryFunc::Gte => {
+                if ordering.is_some() {
+                    sql_bail!("ASOF JOIN ON clause must contain exactly one inequality");
+                }
+                ordering = Some((func, left_expr, right_expr));
+            }
+            _ => sql_bail!(
+                "ASOF JOIN ON clause must consist of equalities and one inequality \
+                between the two sides of the join"
+            ),
+        }
+    }
+    let (func, left_order, right_order) = match ordering {
+        Some(ordering) => ordering,
+        None => sql_bail!("ASOF JOIN ON clause must contain exactly one inequality"),
+    };
+    // An upper bound on the right expression asks for its greatest value.
+    let desc = matches!(func, BinaryFunc::Gt | BinaryFunc::Gte);
+
+    // The distinct keys of the left side, followed by its ordering expression.
+    let key_arity = left_keys.len() + 1;
+    let left_key_exprs: Vec<_> = left_keys
+        .into_iter()
+        .chain(iter::once(left_order))
+        .collect();
+    let keys = left
+        .clone()
+        .map(left_key_exprs.clone())
+        .project((left_arity..left_arity + key_arity).collect())
+        .distinct();
+
+    // Moves the references to the columns of the right side of the product
+    // to their positions after the keys.
+    let after_keys = |mut expr: HirScalarExpr| {
+        #[allow(deprecated)]
+        expr.visit_columns_mut(0, &mut |depth, col| {
+            if col.level == depth {
+                col.column = col.column + key_arity - left_arity;
+            }
+        });
+        expr
+    };
+    let mut candidates_on: Vec<_> = right_keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, right_key)| {
+            HirScalarExpr::column(i).call_binary(after_keys(right_key), BinaryFunc::Eq)
+        })
+        .collect();
+    let right_order = after_keys(right_order);
+    candidates_on.push(HirScalarExpr::column(key_arity - 1).call_binary(right_order.clone(), func));
+    let mut matches = keys
+        .join(
+            right,
+            HirScalarExpr::variadic_and(candidates_on),
+            JoinKind::Inner,
+        )
+        .map(vec![right_order])
+        .top_k(
+            (0..key_arity).collect(),
+            vec![ColumnOrder {
+                column: key_arity + right_arity,
+                desc,
+                nulls_last: true,
+            }],
+            Some(1),
+            0,
+        );
+
+    // The matches become the right side of a join with the left side, which
+    // puts them in a scope of their own.
+    #[allow(deprecated)]
+    matches.visit_columns_mut(0, &mut |depth, col| {
+        if col.level > depth {
+            col.level += 1;
+        }
+    });
+    let on = HirScalarExpr::variadic_and(
+        left_key_exprs
+            .into_iter()
+            .enumerate()
+            .map(|(i, key)| key.call_binary(HirScalarExpr::column(left_arity + i), BinaryFunc::Eq))
+            .collect(),
+    );
+    let joined = left.join(matches, on, kind).project(
+        (0..left_arity)
+            .chain(left_arity + key_arity..left_arity + key_arity + right_arity)
+            .collect(),
+    );
+    Ok((joined, product_scope))
+}
+
 // See page 440 of ANSI SQL 2016 spec for details on scoping of using/natural joins
 #[allow(clippy::too_many_arguments)]
 fn plan_using_constraint(
@@ -3179,7 +3851,7 @@ fn plan_expr_inner<'a>(
             expr,
             construct,
             negated,
-        } => Ok(plan_is_expr(ecx, expr, *construct, *negated)?.into()),
+        } => Ok(plan_is_expr(ecx, expr, construct, *negated)?.into()),
         Expr::Case {
             operand,
             conditions,
@@ -4136,6 +4808,15 @@ fn plan_aggregate(
                     name
                 );
             }
+            // Distinctness is determined over the aggregate's input, which for
+            // order-sensitive aggregates includes the ORDER BY keys. Like
+            // PostgreSQL, we require those keys to be arguments, so that they
+            // cannot introduce spurious duplicates.
+            if *distinct && order_by.iter().any(|obe| !args.contains(&obe.expr)) {
+                sql_bail!(
+                    "in an aggregate with DISTINCT, ORDER BY expressions must appear in argument list"
+                );
+            }
             let args = plan_exprs(ecx, args)?;
             (args, order_by.clone())
         }
@@ -4310,6 +4991,17 @@ fn plan_function<'a>(
 ) -> Result<HirScalarExpr, PlanError> {
     let unresolved_name = normalize::unresolved_object_name(name.clone())?;
 
+    if is_grouping_call(f) {
+        // Valid `GROUPING` operations have already been planned as columns of
+        // the grouping and are caught by `scope.resolve_expr` in `plan_expr`.
+        if ecx.allow_aggregates {
+            sql_bail!(
+                "arguments to GROUPING must be grouping expressions of the associated query level"
+            );
+        }
+        sql_bail!("grouping operations are not allowed in {}", ecx.name);
+    }
+
     let impls = match resolve_func(ecx, name, args)? {
         Func::Aggregate(_) if ecx.allow_aggregates => {
             // should already have been caught by `scope.resolve_expr` in `plan_expr`
@@ -4476,9 +5168,12 @@ pub fn resolve_func(
 fn plan_is_expr<'a>(
     ecx: &ExprContext,
     inner: &'a Expr<Aug>,
-    construct: IsExprConstruct,
+    construct: &'a IsExprConstruct<Aug>,
     not: bool,
 ) -> Result<HirScalarExpr, PlanError> {
+    if let IsExprConstruct::DistinctFrom(other) = construct {
+        return plan_is_distinct_from(ecx, inner, other, not);
+    }
     let planned_expr = plan_expr(ecx, inner)?;
     let expr = if construct.requires_boolean_expr() {
         planned_expr.type_as(ecx, &ScalarType::Bool)?
@@ -4493,6 +5188,7 @@ fn plan_is_expr<'a>(
         IsExprConstruct::Null | IsExprConstruct::Unknown => UnaryFunc::IsNull(expr_func::IsNull),
         IsExprConstruct::True => UnaryFunc::IsTrue(expr_func::IsTrue),
         IsExprConstruct::False => UnaryFunc::IsFalse(expr_func::IsFalse),
+        IsExprConstruct::DistinctFrom(_) => unreachable!("handled above"),
     };
     let expr = HirScalarExpr::CallUnary {
         func,
@@ -4509,6 +5205,43 @@ fn plan_is_expr<'a>(
     }
 }
 
+/// Plans `left IS [NOT] DISTINCT FROM right`.
+///
+/// `left IS NOT DISTINCT FROM right` is planned as
+/// `(left = right AND left IS NOT NULL AND right IS NOT NULL) OR (left IS NULL
+/// AND right IS NULL)`, which is never `NULL`. The optimizer recognizes this
+/// form, and plans it as an equijoin in join conditions, where equality
+/// already matches `NULL` to `NULL`.
+fn plan_is_distinct_from<'a>(
+    ecx: &ExprContext,
+    left: &'a Expr<Aug>,
+    right: &'a Expr<Aug>,
+    not: bool,
+) -> Result<HirScalarExpr, PlanError> {
+    // Plan the comparison as `=`, so that both sides are coerced to a common
+    // type exactly as they would be for an equality.
+    let (left, right) = match plan_op(ecx, "=", left, Some(right))? {
+        HirScalarExpr::CallBinary {
+            func: BinaryFunc::Eq,
+            expr1,
+            expr2,
+        } => (*expr1, *expr2),
+        _ => sql_bail!("IS DISTINCT FROM is not supported for these types"),
+    };
+    let equal = HirScalarExpr::variadic_and(vec![
+        left.clone().call_binary(right.clone(), BinaryFunc::Eq),
+        left.clone().call_is_null().not(),
+        right.clone().call_is_null().not(),
+    ]);
+    let both_null = left.call_is_null().and(right.call_is_null());
+    let not_distinct = equal.or(both_null);
+    if not {
+        Ok(not_distinct)
+    } else {
+        Ok(not_distinct.not())
+    }
+}
+
 fn plan_case<'a>(
     ecx: &ExprContext,
     operand: &'a Option<Box<Expr<Aug>>>,
@@ -4949,6 +5682,8 @@ fn scalar_type_from_catalog(
                 CatalogType::TimestampTz => Ok(ScalarType::TimestampTz),
                 CatalogType::Uuid => Ok(ScalarType::Uuid),
                 CatalogType::Int2Vector => Ok(ScalarType::Int2Vector),
+                CatalogType::Point => Ok(ScalarType::Point),
+                CatalogType::BoundingBox => Ok(ScalarType::BoundingBox),
                 CatalogType::Numeric => unreachable!("handled above"),
                 CatalogType::Char => unreachable!("handled above"),
                 CatalogType::VarChar => unreachable!("handled above"),
@@ -4962,6 +5697,7 @@ fn scalar_type_from_catalog(
 struct AggregateTableFuncVisitor<'a> {
     scx: &'a StatementContext<'a>,
     aggs: Vec<Function<Aug>>,
+    groupings: Vec<Function<Aug>>,
     within_aggregate: bool,
     tables: HashMap<TableFunction<Aug>, String>,
     table_disallowed_context: Vec<&'static str>,
@@ -4974,6 +5710,7 @@ impl<'a> AggregateTableFuncVisitor<'a> {
         AggregateTableFuncVisitor {
             scx,
             aggs: Vec::new(),
+            groupings: Vec::new(),
             within_aggregate: false,
             tables: HashMap::new(),
             table_disallowed_context: Vec::new(),
@@ -4984,7 +5721,14 @@ impl<'a> AggregateTableFuncVisitor<'a> {
 
     fn into_result(
         self,
-    ) -> Result<(Vec<Function<Aug>>, HashMap<TableFunction<Aug>, String>), PlanError> {
+    ) -> Result<
+        (
+            Vec<Function<Aug>>,
+            Vec<Function<Aug>>,
+            HashMap<TableFunction<Aug>, String>,
+        ),
+        PlanError,
+    > {
         match self.err {
             Some(err) => Err(err),
             None => {
@@ -4996,7 +5740,13 @@ impl<'a> AggregateTableFuncVisitor<'a> {
                     .into_iter()
                     .filter(move |agg| seen.insert(agg.clone()))
                     .collect();
-                Ok((aggs, self.tables))
+                let mut seen = HashSet::new();
+                let groupings = self
+                    .groupings
+                    .into_iter()
+                    .filter(move |grouping| seen.insert(grouping.clone()))
+                    .collect();
+                Ok((aggs, groupings, self.tables))
             }
         }
     }
@@ -5004,6 +5754,19 @@ impl<'a> AggregateTableFuncVisitor<'a> {
 
 impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
     fn visit_function_mut(&mut self, func: &mut Function<Aug>) {
+        if is_grouping_call(func) {
+            if self.within_aggregate {
+                self.err = Some(sql_err!(
+                    "aggregate function calls cannot contain grouping operations"
+                ));
+                return;
+            }
+            // The arguments must be grouping expressions, which are checked
+            // when the call is planned.
+            self.groupings.push(func.clone());
+            return;
+        }
+
         let item = match self.scx.resolve_function(func.name.clone()) {
             Ok(i) => i,
             // Catching missing functions later in planning improves error messages.
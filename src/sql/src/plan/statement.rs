@@ -112,6 +112,7 @@ pub fn describe(
         Statement::AlterConnection(stmt) => ddl::describe_alter_connection(&scx, stmt)?,
         Statement::AlterIndex(stmt) => ddl::describe_alter_index_options(&scx, stmt)?,
         Statement::AlterObjectRename(stmt) => ddl::describe_alter_object_rename(&scx, stmt)?,
+        Statement::AlterSchema(stmt) => ddl::describe_alter_schema(&scx, stmt)?,
         Statement::AlterSecret(stmt) => ddl::describe_alter_secret_options(&scx, stmt)?,
         Statement::AlterSink(stmt) => ddl::describe_alter_sink(&scx, stmt)?,
         Statement::AlterSource(stmt) => ddl::describe_alter_source(&scx, stmt)?,
@@ -167,6 +168,9 @@ pub fn describe(
         Statement::Show(ShowStatement::ShowCreateMaterializedView(stmt)) => {
             show::describe_show_create_materialized_view(&scx, stmt)?
         }
+        Statement::Show(ShowStatement::ShowCreateCatalog) => {
+            show::describe_show_create_catalog(&scx)?
+        }
         Statement::Show(ShowStatement::ShowDatabases(stmt)) => {
             show::show_databases(&scx, stmt)?.describe()?
         }
@@ -199,6 +203,7 @@ pub fn describe(
         Statement::Select(stmt) => dml::describe_select(&scx, stmt)?,
         Statement::Subscribe(stmt) => dml::describe_subscribe(&scx, stmt)?,
         Statement::Update(stmt) => dml::describe_update(&scx, stmt)?,
+        Statement::ValidateView(stmt) => dml::describe_validate_view(&scx, stmt)?,
 
         // TCL statements.
         Statement::Commit(stmt) => tcl::describe_commit(&scx, stmt)?,
@@ -250,6 +255,7 @@ pub fn plan(
         Statement::AlterConnection(stmt) => ddl::plan_alter_connection(scx, stmt),
         Statement::AlterIndex(stmt) => ddl::plan_alter_index_options(scx, stmt),
         Statement::AlterObjectRename(stmt) => ddl::plan_alter_object_rename(scx, stmt),
+        Statement::AlterSchema(stmt) => ddl::plan_alter_schema(scx, stmt),
         Statement::AlterSecret(stmt) => ddl::plan_alter_secret(scx, stmt),
         Statement::AlterSink(stmt) => ddl::plan_alter_sink(scx, stmt),
         Statement::AlterSource(stmt) => ddl::plan_alter_source(scx, stmt),
@@ -288,6 +294,7 @@ pub fn plan(
         Statement::Select(stmt) => dml::plan_select(scx, stmt, params, None),
         Statement::Subscribe(stmt) => dml::plan_subscribe(scx, stmt, None),
         Statement::Update(stmt) => dml::plan_update(scx, stmt, params),
+        Statement::ValidateView(stmt) => dml::plan_validate_view(scx, stmt),
 
         // `SHOW` statements.
         Statement::Show(ShowStatement::ShowColumns(stmt)) => show::show_columns(scx, stmt)?.plan(),
@@ -312,6 +319,7 @@ pub fn plan(
         Statement::Show(ShowStatement::ShowCreateMaterializedView(stmt)) => {
             show::plan_show_create_materialized_view(scx, stmt).map(Plan::SendRows)
         }
+        Statement::Show(ShowStatement::ShowCreateCatalog) => show::plan_show_create_catalog(scx),
         Statement::Show(ShowStatement::ShowDatabases(stmt)) => {
             show::show_databases(scx, stmt)?.plan()
         }
@@ -16,6 +16,7 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Write;
 use std::num::NonZeroUsize;
 use std::str::FromStr;
+use std::time::Duration;
 
 use aws_arn::ResourceName as AmazonResourceName;
 use globset::GlobBuilder;
@@ -24,23 +25,24 @@ use prost::Message;
 use regex::Regex;
 use tracing::warn;
 
-use mz_compute_client::controller::DEFAULT_COMPUTE_REPLICA_LOGGING_INTERVAL_MICROS;
-use mz_expr::CollectionPlan;
+use mz_expr::{func, BinaryFunc, CollectionPlan, MirScalarExpr, UnaryFunc, UnmaterializableFunc};
 use mz_interchange::avro::AvroSchemaGenerator;
-use mz_ore::cast::{self, TryCastFrom};
+use mz_ore::cast::TryCastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_ore::str::StrExt;
+use mz_postgres_util::desc::{PostgresTableDesc, ProtoPostgresTableDesc};
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::system::Oid;
 use mz_repr::strconv;
-use mz_repr::{ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType};
+use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, RelationType, ScalarType};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
     AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceStatement,
     AlterSystemResetAllStatement, AlterSystemResetStatement, AlterSystemSetStatement,
     CreateTypeListOption, CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName,
-    DeferredObjectName, SetVariableValue, SshConnectionOption, UnresolvedObjectName,
+    DeferredObjectName, SetVariableValue, SourceRetention, SshConnectionOption,
+    UnresolvedObjectName,
 };
 use mz_storage_client::types::connections::aws::{
     AwsAssumeRole, AwsConfig, AwsCredentials, SerdeUri,
@@ -50,27 +52,31 @@ use mz_storage_client::types::connections::{
     KafkaSecurity, KafkaTlsConfig, SaslConfig, SshTunnel, StringOrSecret, TlsIdentity, Tunnel,
 };
 use mz_storage_client::types::sinks::{
-    KafkaConsistencyConfig, KafkaSinkConnectionBuilder, KafkaSinkConnectionRetention,
-    KafkaSinkFormat, SinkEnvelope, StorageSinkConnectionBuilder,
+    DbzSourceInfo, ElasticsearchSinkConnection, KafkaConsistencyConfig, KafkaSinkConnectionBuilder,
+    KafkaSinkConnectionRetention, KafkaSinkFormat, KafkaSinkPartitioning, SinkEnvelope,
+    StorageSinkConnectionBuilder,
 };
 use mz_storage_client::types::sources::encoding::{
     included_column_desc, AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, DataEncodingInner,
     ProtobufEncoding, RegexEncoding, SourceDataEncoding, SourceDataEncodingInner,
 };
 use mz_storage_client::types::sources::{
-    GenericSourceConnection, IncludedColumnPos, KafkaSourceConnection, KeyEnvelope,
-    KinesisSourceConnection, LoadGenerator, LoadGeneratorSourceConnection,
-    PostgresSourceConnection, PostgresSourcePublicationDetails,
-    ProtoPostgresSourcePublicationDetails, S3SourceConnection, SourceDesc, SourceEnvelope,
-    TestScriptSourceConnection, Timeline, UnplannedSourceEnvelope, UpsertStyle,
+    GenericSourceConnection, HttpSourceConnection, IncludedColumnPos, KafkaSourceConnection,
+    KeyEnvelope, KinesisSourceConnection, LoadGenerator, LoadGeneratorSourceConnection,
+    MaterializeSourceConnection, PostgresSourceConnection, PostgresSourcePublicationDetails,
+    PostgresTableSourceConnection, ProtoPostgresSourcePublicationDetails, S3SourceConnection,
+    SourceDesc, SourceEnvelope, TestScriptSourceConnection, Timeline, UnplannedSourceEnvelope,
+    UpsertStyle,
 };
 
 use crate::ast::display::AstDisplay;
+use crate::ast::visit::{self, Visit};
 use crate::ast::{
     AlterConnectionStatement, AlterIndexAction, AlterIndexStatement, AlterObjectRenameStatement,
-    AlterSecretStatement, AvroSchema, AvroSchemaOption, AvroSchemaOptionName, AwsConnectionOption,
-    AwsConnectionOptionName, AwsPrivatelinkConnectionOption, AwsPrivatelinkConnectionOptionName,
-    ClusterOption, ClusterOptionName, ColumnOption, Compression, CreateClusterReplicaStatement,
+    AlterSchemaAction, AlterSchemaStatement, AlterSecretStatement, AvroSchema, AvroSchemaOption,
+    AvroSchemaOptionName, AwsConnectionOption, AwsConnectionOptionName,
+    AwsPrivatelinkConnectionOption, AwsPrivatelinkConnectionOptionName, ClusterOption,
+    ClusterOptionName, ColumnOption, Compression, CreateClusterReplicaStatement,
     CreateClusterStatement, CreateConnection, CreateConnectionStatement, CreateDatabaseStatement,
     CreateIndexStatement, CreateMaterializedViewStatement, CreateReferencedSubsources,
     CreateRoleOption, CreateRoleStatement, CreateSchemaStatement, CreateSecretStatement,
@@ -80,15 +86,17 @@ use crate::ast::{
     CreateTypeStatement, CreateViewStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
     CsrConnectionAvro, CsrConnectionOption, CsrConnectionOptionName, CsrConnectionProtobuf,
     CsrSeedProtobuf, CsvColumns, DbzMode, DropClusterReplicasStatement, DropClustersStatement,
-    DropDatabaseStatement, DropObjectsStatement, DropRolesStatement, DropSchemaStatement, Envelope,
-    Expr, Format, Ident, IfExistsBehavior, IndexOption, IndexOptionName, KafkaBroker,
+    DropDatabaseStatement, DropObjectsStatement, DropRolesStatement, DropSchemaStatement,
+    ElasticsearchSinkOption, ElasticsearchSinkOptionName, Envelope, Expr, Format, HttpSourceOption,
+    HttpSourceOptionName, Ident, IfExistsBehavior, IndexOption, IndexOptionName, KafkaBroker,
     KafkaBrokerAwsPrivatelinkOption, KafkaBrokerAwsPrivatelinkOptionName, KafkaBrokerTunnel,
-    KafkaConfigOptionName, KafkaConnectionOption, KafkaConnectionOptionName, KeyConstraint,
-    LoadGeneratorOption, LoadGeneratorOptionName, ObjectType, PgConfigOption, PgConfigOptionName,
-    PostgresConnectionOption, PostgresConnectionOptionName, ProtobufSchema, QualifiedReplica,
-    ReplicaDefinition, ReplicaOption, ReplicaOptionName, SourceIncludeMetadata,
-    SourceIncludeMetadataType, SshConnectionOptionName, Statement, TableConstraint,
-    UnresolvedDatabaseName, Value, ViewDefinition,
+    KafkaConfigOption, KafkaConfigOptionName, KafkaConnectionOption, KafkaConnectionOptionName,
+    KeyConstraint, LoadGeneratorOption, LoadGeneratorOptionName, ObjectType, PgConfigOption,
+    PgConfigOptionName, PostgresConnectionOption, PostgresConnectionOptionName, ProtobufSchema,
+    QualifiedReplica, ReplicaDefinition, ReplicaOption, ReplicaOptionName, SchemaOption,
+    SchemaOptionName, SelectItem, SinkColumn, SourceIncludeMetadata, SourceIncludeMetadataType,
+    SshConnectionOptionName, Statement, TableConstraint, UnresolvedDatabaseName, Value,
+    ViewDefinition,
 };
 use crate::catalog::{
     CatalogComputeInstance, CatalogItem, CatalogItemType, CatalogType, CatalogTypeDetails,
@@ -108,12 +116,12 @@ use crate::plan::typeconv::{plan_cast, CastContext};
 use crate::plan::with_options::{self, OptionalInterval, TryFromValue};
 use crate::plan::{
     plan_utils, query, AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan,
-    AlterNoopPlan, AlterOptionParameter, AlterSecretPlan, AlterSinkPlan, AlterSourcePlan,
-    AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, CreateComputeInstancePlan, CreateComputeReplicaPlan,
-    CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
-    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
-    CreateTablePlan, CreateTypePlan, CreateViewPlan, DropComputeInstancesPlan,
+    AlterNoopPlan, AlterOptionParameter, AlterSchemaPlan, AlterSecretPlan, AlterSinkPlan,
+    AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan,
+    ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, CreateComputeInstancePlan,
+    CreateComputeReplicaPlan, CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan,
+    CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan,
+    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, DropComputeInstancesPlan,
     DropComputeReplicasPlan, DropDatabasePlan, DropItemsPlan, DropRolesPlan, DropSchemaPlan,
     FullObjectName, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan, QueryContext,
     RotateKeysPlan, Secret, Sink, Source, StorageHostConfig, Table, Type, View,
@@ -322,13 +330,70 @@ pub fn describe_create_subsource(
 
 generate_extracted_config!(
     CreateSourceOption,
+    (Fields, Vec<String>),
     (IgnoreKeys, bool),
+    (RefreshInterval, Interval),
     (Remote, String),
+    (Retention, SourceRetention),
     (Size, String),
     (Timeline, String),
     (TimestampInterval, Interval)
 );
 
+/// Restricts the value encoding of a source to the named fields of its Avro
+/// records, so that the remaining fields are skipped rather than decoded.
+fn plan_avro_fields(
+    encoding: &mut SourceDataEncoding,
+    fields: Vec<String>,
+) -> Result<(), PlanError> {
+    let value = match encoding {
+        SourceDataEncoding::Single(value) | SourceDataEncoding::KeyValue { value, .. } => value,
+    };
+    let avro = match &mut value.inner {
+        DataEncodingInner::Avro(avro) => avro,
+        _ => sql_bail!("FIELDS is only supported for sources with FORMAT AVRO"),
+    };
+    if let Some(dup) = fields.iter().duplicates().next() {
+        sql_bail!("field {} specified more than once", dup.as_str().quoted());
+    }
+    let projected = mz_interchange::avro::parse_schema(&avro.schema)
+        .and_then(|schema| Ok(schema.project_fields(&fields)?))
+        .map_err(|e| sql_err!("invalid FIELDS: {:#}", e))?;
+    // Records that are not resolved against the schemas in a schema registry
+    // were written with the full schema, which the decoder needs in order to
+    // skip the fields that are not selected.
+    if avro.csr_connection.is_none() {
+        avro.writer_schema = Some(avro.schema.clone());
+    }
+    avro.schema = projected.to_string();
+    Ok(())
+}
+
+/// Converts the `TIMESTAMP INTERVAL` of a source into the granularity with
+/// which the source mints timestamps.
+fn plan_timestamp_interval(interval: Interval) -> Result<Duration, PlanError> {
+    let duration = interval.duration()?;
+    if duration < Duration::from_millis(1) {
+        sql_bail!("TIMESTAMP INTERVAL must be at least 1ms");
+    }
+    Ok(duration)
+}
+
+generate_extracted_config!(
+    HttpSourceOption,
+    (KeyField, String),
+    (PollInterval, Interval)
+);
+
+/// The interval at which HTTP sources poll their endpoint by default.
+const DEFAULT_HTTP_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The shortest interval at which HTTP sources may poll their endpoint.
+const MIN_HTTP_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The shortest interval at which Postgres table sources may be refreshed.
+const MIN_POSTGRES_TABLE_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
 generate_extracted_config!(
     PgConfigOption,
     (Details, String),
@@ -336,9 +401,147 @@ generate_extracted_config!(
     (TextColumns, Vec::<UnresolvedObjectName>, Default(vec![]))
 );
 
+/// Decodes the description of a single upstream relation that purification
+/// stored in the `DETAILS` of a source, and plans the casts of its text encoded
+/// columns along with the description of the source that results from them.
+fn plan_upstream_relation(
+    scx: &StatementContext,
+    details: Option<&str>,
+    text_columns: Vec<UnresolvedObjectName>,
+) -> Result<(PostgresTableDesc, Vec<MirScalarExpr>, RelationDesc), PlanError> {
+    let details =
+        details.ok_or_else(|| sql_err!("internal error: Postgres source missing details"))?;
+    let details = hex::decode(details).map_err(|e| sql_err!("{}", e))?;
+    let table = ProtoPostgresTableDesc::decode(&*details).map_err(|e| sql_err!("{}", e))?;
+    let table = PostgresTableDesc::from_proto(table).map_err(|e| sql_err!("{}", e))?;
+
+    // Purification validated that the text columns are unqualified.
+    let text_columns: HashSet<_> = text_columns
+        .into_iter()
+        .flat_map(|name| name.0)
+        .map(Ident::into_string)
+        .collect();
+    let casts = plan_postgres_table_casts(scx, &table, Some(&text_columns))?;
+
+    // The relation is described by the types that its text encoded columns
+    // are cast to.
+    let text_types: Vec<_> = table
+        .columns
+        .iter()
+        .map(|column| ColumnType {
+            nullable: column.nullable,
+            scalar_type: ScalarType::String,
+        })
+        .collect();
+    let desc = RelationDesc::new(
+        RelationType::new(casts.iter().map(|cast| cast.typ(&text_types)).collect()),
+        table.columns.iter().map(|column| column.name.as_str()),
+    );
+    Ok((table, casts, desc))
+}
+
+/// Plans the expressions that cast each text encoded column of an upstream
+/// Postgres table to its target type. Columns named in `text_columns` are
+/// ingested as text.
+fn plan_postgres_table_casts(
+    scx: &StatementContext,
+    table: &PostgresTableDesc,
+    text_columns: Option<&HashSet<String>>,
+) -> Result<Vec<MirScalarExpr>, PlanError> {
+    // First, construct an expression context where the expression is evaluated on an
+    // imaginary row which has the same number of columns as the upstream table but all
+    // of the types are text
+    let mut cast_scx = scx.clone();
+    cast_scx.param_types = Default::default();
+    let cast_qcx = QueryContext::root(&cast_scx, QueryLifetime::Static);
+    let mut column_types = vec![];
+    for column in table.columns.iter() {
+        column_types.push(ColumnType {
+            nullable: column.nullable,
+            scalar_type: ScalarType::String,
+        });
+    }
+
+    let cast_ecx = ExprContext {
+        qcx: &cast_qcx,
+        name: "plan_postgres_source_cast",
+        scope: &Scope::empty(),
+        relation_type: &RelationType {
+            column_types,
+            keys: vec![],
+        },
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_windows: false,
+    };
+
+    // Then, for each column we will generate a MirRelationExpr that extracts the nth
+    // column and casts it to the appropriate target type
+    let mut column_casts = vec![];
+    for (i, column) in table.columns.iter().enumerate() {
+        let ty = match text_columns {
+            // Treat the column as text if it was referenced in
+            // `TEXT COLUMNS`. This is the only place we need to
+            // perform this logic; even if the type is unsupported,
+            // we'll be able to ingest its values as text in
+            // storage.
+            Some(names) if names.contains(&column.name) => mz_pgrepr::Type::Text,
+            _ => {
+                match mz_pgrepr::Type::from_oid_and_typmod(column.type_oid, column.type_mod) {
+                    Ok(t) => t,
+                    // If this reference survived purification, we
+                    // do not expect it to be from a table that the
+                    // user will consume., i.e. expect this table to
+                    // be filtered out of table casts.
+                    Err(_) => {
+                        column_casts.push(
+                            HirScalarExpr::CallVariadic {
+                                func: mz_expr::VariadicFunc::ErrorIfNull,
+                                exprs: vec![
+                                    HirScalarExpr::literal_null(ScalarType::String),
+                                    HirScalarExpr::literal(
+                                        mz_repr::Datum::from(
+                                            format!(
+                                                "Unsupported type with OID {}",
+                                                column.type_oid
+                                            )
+                                            .as_str(),
+                                        ),
+                                        ScalarType::String,
+                                    ),
+                                ],
+                            }
+                            .lower_uncorrelated()
+                            .expect("no correlation"),
+                        );
+                        continue;
+                    }
+                }
+            }
+        };
+
+        let data_type = scx.resolve_type(ty)?;
+        let scalar_type = query::scalar_type_from_sql(scx, &data_type)?;
+
+        let col_expr = HirScalarExpr::Column(ColumnRef {
+            level: 0,
+            column: i,
+        });
+
+        let cast_expr = plan_cast(&cast_ecx, CastContext::Explicit, col_expr, &scalar_type)?
+            .lower_uncorrelated()
+            .expect(
+                "lower_uncorrelated should not fail given that there is no correlation \
+                in the input col_expr",
+            );
+        column_casts.push(cast_expr);
+    }
+    Ok(column_casts)
+}
+
 pub fn plan_create_source(
     scx: &StatementContext,
-    stmt: CreateSourceStatement<Aug>,
+    mut stmt: CreateSourceStatement<Aug>,
 ) -> Result<Plan, PlanError> {
     let CreateSourceStatement {
         name,
@@ -355,7 +558,11 @@ pub fn plan_create_source(
 
     let envelope = envelope.clone().unwrap_or(Envelope::None);
 
-    const SAFE_WITH_OPTIONS: &[CreateSourceOptionName] = &[CreateSourceOptionName::Size];
+    const SAFE_WITH_OPTIONS: &[CreateSourceOptionName] = &[
+        CreateSourceOptionName::Fields,
+        CreateSourceOptionName::Retention,
+        CreateSourceOptionName::Size,
+    ];
 
     if with_options
         .iter()
@@ -379,7 +586,7 @@ pub fn plan_create_source(
         bail_unsupported!("INCLUDE metadata with non-Kafka sources");
     }
 
-    let (mut external_connection, encoding, available_subsources) = match connection {
+    let (mut external_connection, mut encoding, available_subsources) = match connection {
         CreateSourceConnection::Kafka(mz_sql_parser::ast::KafkaSourceConnection {
             connection:
                 mz_sql_parser::ast::KafkaConnection {
@@ -395,9 +602,12 @@ pub fn plan_create_source(
             };
 
             // Starting offsets are allowed out unsafe mode, as they are a simple,
-            // useful way to specify where to start reading a topic.
+            // useful way to specify where to start reading a topic. The client
+            // rack is allowed as well, as it is needed to fetch from the
+            // closest replica of each partition in multi-AZ deployments.
             if let Some(opt) = options.iter().find(|opt| {
-                opt.name != KafkaConfigOptionName::StartOffset
+                opt.name != KafkaConfigOptionName::ClientRack
+                    && opt.name != KafkaConfigOptionName::StartOffset
                     && opt.name != KafkaConfigOptionName::StartTimestamp
                     && opt.name != KafkaConfigOptionName::Topic
             }) {
@@ -663,109 +873,18 @@ pub fn plan_create_source(
             // Register the available subsources
             let mut available_subsources = BTreeMap::new();
 
+            let publication_details = PostgresSourcePublicationDetails::from_proto(details)
+                .map_err(|e| sql_err!("{}", e))?;
+
             // Here we will generate the cast expressions required to convert the text encoded
             // columns into the appropriate target types, creating a Vec<MirScalarExpr> per table.
             // The postgres source reader will then eval each of those on the incoming rows based
             // on the target table
             let mut table_casts = HashMap::new();
 
-            for (i, table) in details.tables.iter().enumerate() {
-                // First, construct an expression context where the expression is evaluated on an
-                // imaginary row which has the same number of columns as the upstream table but all
-                // of the types are text
-                let mut cast_scx = scx.clone();
-                cast_scx.param_types = Default::default();
-                let cast_qcx = QueryContext::root(&cast_scx, QueryLifetime::Static);
-                let mut column_types = vec![];
-                for column in table.columns.iter() {
-                    column_types.push(ColumnType {
-                        nullable: column.nullable,
-                        scalar_type: ScalarType::String,
-                    });
-                }
-
-                let cast_ecx = ExprContext {
-                    qcx: &cast_qcx,
-                    name: "plan_postgres_source_cast",
-                    scope: &Scope::empty(),
-                    relation_type: &RelationType {
-                        column_types,
-                        keys: vec![],
-                    },
-                    allow_aggregates: false,
-                    allow_subqueries: false,
-                    allow_windows: false,
-                };
-
-                // Then, for each column we will generate a MirRelationExpr that extracts the nth
-                // column and casts it to the appropriate target type
-                let mut column_casts = vec![];
-                for (i, column) in table.columns.iter().enumerate() {
-                    let ty = match text_cols.get(&Oid(table.oid)) {
-                        // Treat the column as text if it was referenced in
-                        // `TEXT COLUMNS`. This is the only place we need to
-                        // perform this logic; even if the type is unsupported,
-                        // we'll be able to ingest its values as text in
-                        // storage.
-                        Some(names) if names.contains(&column.name) => mz_pgrepr::Type::Text,
-                        _ => {
-                            match mz_pgrepr::Type::from_oid_and_typmod(
-                                column.type_oid,
-                                column.type_mod,
-                            ) {
-                                Ok(t) => t,
-                                // If this reference survived purification, we
-                                // do not expect it to be from a table that the
-                                // user will consume., i.e. expect this table to
-                                // be filtered out of table casts.
-                                Err(_) => {
-                                    column_casts.push(
-                                        HirScalarExpr::CallVariadic {
-                                            func: mz_expr::VariadicFunc::ErrorIfNull,
-                                            exprs: vec![
-                                                HirScalarExpr::literal_null(ScalarType::String),
-                                                HirScalarExpr::literal(
-                                                    mz_repr::Datum::from(
-                                                        format!(
-                                                            "Unsupported type with OID {}",
-                                                            column.type_oid
-                                                        )
-                                                        .as_str(),
-                                                    ),
-                                                    ScalarType::String,
-                                                ),
-                                            ],
-                                        }
-                                        .lower_uncorrelated()
-                                        .expect("no correlation"),
-                                    );
-                                    continue;
-                                }
-                            }
-                        }
-                    };
-
-                    let data_type = scx.resolve_type(ty)?;
-                    let scalar_type = query::scalar_type_from_sql(scx, &data_type)?;
-
-                    let col_expr = HirScalarExpr::Column(ColumnRef {
-                        level: 0,
-                        column: i,
-                    });
-
-                    let cast_expr = plan_cast(
-                        &cast_ecx,
-                        CastContext::Explicit,
-                        col_expr,
-                        &scalar_type,
-                    )?
-                    .lower_uncorrelated()
-                    .expect(
-                        "lower_uncorrelated should not fail given that there is no correlation \
-                            in the input col_expr",
-                    );
-                    column_casts.push(cast_expr);
-                }
+            for (i, table) in publication_details.tables.iter().enumerate() {
+                let column_casts =
+                    plan_postgres_table_casts(scx, table, text_cols.get(&Oid(table.oid)))?;
                 let r = table_casts.insert(i + 1, column_casts);
                 assert!(r.is_none(), "cannot have table defined multiple times");
 
@@ -781,9 +900,6 @@ pub fn plan_create_source(
                 available_subsources.insert(name, i + 1);
             }
 
-            let publication_details = PostgresSourcePublicationDetails::from_proto(details)
-                .map_err(|e| sql_err!("{}", e))?;
-
             let connection = GenericSourceConnection::Postgres(PostgresSourceConnection {
                 connection,
                 connection_id: connection_item.id(),
@@ -798,6 +914,104 @@ pub fn plan_create_source(
             ));
             (connection, encoding, Some(available_subsources))
         }
+        CreateSourceConnection::PostgresTable {
+            connection,
+            table: _,
+            options,
+        } => {
+            scx.require_unsafe_mode("CREATE SOURCE ... FROM POSTGRES CONNECTION ... TABLE")?;
+
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::Postgres(connection) => connection.clone(),
+                _ => sql_bail!("{} is not a postgres connection", connection_item.name()),
+            };
+            let PgConfigOptionExtracted {
+                details,
+                publication,
+                text_columns,
+                seen: _,
+            } = options.clone().try_into()?;
+            if publication.is_some() {
+                sql_bail!("POSTGRES TABLE sources do not support PUBLICATION");
+            }
+            if !matches!(format, CreateSourceFormat::None) {
+                sql_bail!("POSTGRES TABLE sources do not support FORMAT");
+            }
+            if !matches!(envelope, Envelope::None) {
+                bail_unsupported!(format!("ENVELOPE {} for POSTGRES TABLE sources", envelope));
+            }
+
+            let (table, casts, desc) =
+                plan_upstream_relation(scx, details.as_deref(), text_columns)?;
+
+            let CreateSourceOptionExtracted {
+                refresh_interval, ..
+            } = with_options.clone().try_into()?;
+            let refresh_interval = match refresh_interval {
+                Some(interval) => {
+                    let duration = interval.duration()?;
+                    if duration < MIN_POSTGRES_TABLE_REFRESH_INTERVAL {
+                        sql_bail!("REFRESH INTERVAL must be at least 1s");
+                    }
+                    Some(duration)
+                }
+                None => None,
+            };
+
+            let connection =
+                GenericSourceConnection::PostgresTable(PostgresTableSourceConnection {
+                    connection_id: connection_item.id(),
+                    connection,
+                    table,
+                    casts,
+                    refresh_interval,
+                });
+            let encoding =
+                SourceDataEncoding::Single(DataEncoding::new(DataEncodingInner::RowCodec(desc)));
+            (connection, encoding, None)
+        }
+        CreateSourceConnection::Materialize {
+            connection,
+            view: _,
+            options,
+        } => {
+            scx.require_unsafe_mode("CREATE SOURCE ... FROM MATERIALIZE CONNECTION")?;
+
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::Postgres(connection) => connection.clone(),
+                _ => sql_bail!("{} is not a postgres connection", connection_item.name()),
+            };
+            let PgConfigOptionExtracted {
+                details,
+                publication,
+                text_columns,
+                seen: _,
+            } = options.clone().try_into()?;
+            if publication.is_some() {
+                sql_bail!("MATERIALIZE sources do not support PUBLICATION");
+            }
+            if !matches!(format, CreateSourceFormat::None) {
+                sql_bail!("MATERIALIZE sources do not support FORMAT");
+            }
+            if !matches!(envelope, Envelope::None) {
+                bail_unsupported!(format!("ENVELOPE {} for MATERIALIZE sources", envelope));
+            }
+
+            let (view, casts, desc) =
+                plan_upstream_relation(scx, details.as_deref(), text_columns)?;
+
+            let connection = GenericSourceConnection::Materialize(MaterializeSourceConnection {
+                connection_id: connection_item.id(),
+                connection,
+                view,
+                casts,
+            });
+            let encoding =
+                SourceDataEncoding::Single(DataEncoding::new(DataEncodingInner::RowCodec(desc)));
+            (connection, encoding, None)
+        }
         CreateSourceConnection::LoadGenerator { generator, options } => {
             let (load_generator, available_subsources) =
                 load_generator_ast_to_generator(generator, options)?;
@@ -823,6 +1037,68 @@ pub fn plan_create_source(
 
             (connection, encoding, available_subsources)
         }
+        CreateSourceConnection::Http { url, options } => {
+            scx.require_unsafe_mode("CREATE SOURCE ... FROM HTTP")?;
+
+            let HttpSourceOptionExtracted {
+                key_field,
+                poll_interval,
+                seen: _,
+            } = options.clone().try_into()?;
+
+            let parsed_url: reqwest::Url = url
+                .parse()
+                .map_err(|e| sql_err!("parsing HTTP source url: {e}"))?;
+            if !matches!(parsed_url.scheme(), "http" | "https") {
+                sql_bail!("HTTP source URL must use the http or https scheme");
+            }
+
+            let poll_interval = match poll_interval {
+                Some(interval) => interval.duration()?,
+                None => DEFAULT_HTTP_POLL_INTERVAL,
+            };
+            if poll_interval < MIN_HTTP_POLL_INTERVAL {
+                sql_bail!("POLL INTERVAL must be at least 1s");
+            }
+
+            match (&envelope, &key_field) {
+                (Envelope::None, None) | (Envelope::Upsert, Some(_)) => (),
+                (Envelope::None, Some(_)) => {
+                    sql_bail!("KEY FIELD requires ENVELOPE UPSERT")
+                }
+                (Envelope::Upsert, None) => {
+                    sql_bail!("ENVELOPE UPSERT for HTTP sources requires KEY FIELD")
+                }
+                (envelope, _) => bail_unsupported!(format!("{} with HTTP sources", envelope)),
+            }
+
+            let formats = match format {
+                CreateSourceFormat::None => vec![],
+                CreateSourceFormat::Bare(format) => vec![format],
+                CreateSourceFormat::KeyValue { key, value } => vec![key, value],
+            };
+            for format in formats {
+                match format {
+                    Format::Bytes | Format::Text | Format::Csv { .. } | Format::Regex(_) => (),
+                    _ => sql_bail!("HTTP sources support only BYTES, TEXT, CSV and REGEX formats"),
+                }
+            }
+            // The source reads back the keys it emitted before a restart,
+            // which it can only do when they decode to the raw key.
+            if let CreateSourceFormat::KeyValue { key, .. } = format {
+                if !matches!(key, Format::Bytes | Format::Text) {
+                    sql_bail!("KEY FIELD requires KEY FORMAT BYTES or TEXT");
+                }
+            }
+
+            let encoding = get_encoding(scx, format, &envelope, Some(connection))?;
+            let connection = GenericSourceConnection::Http(HttpSourceConnection {
+                url: url.clone(),
+                poll_interval,
+                key_field,
+            });
+            (connection, encoding, None)
+        }
         CreateSourceConnection::TestScript { desc_json } => {
             scx.require_unsafe_mode("CREATE SOURCE ... FROM TEST SCRIPT")?;
             let connection = GenericSourceConnection::TestScript(TestScriptSourceConnection {
@@ -914,6 +1190,25 @@ pub fn plan_create_source(
         table_casts.retain(|pos, _| used_pos.contains(pos));
     }
 
+    let CreateSourceOptionExtracted {
+        fields,
+        refresh_interval,
+        remote,
+        retention,
+        size,
+        timeline,
+        timestamp_interval,
+        ignore_keys,
+        seen: _,
+    } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
+
+    if let Some(fields) = fields {
+        if matches!(envelope, Envelope::CdcV2) {
+            sql_bail!("FIELDS is not supported with ENVELOPE MATERIALIZE");
+        }
+        plan_avro_fields(&mut encoding, fields)?;
+    }
+
     let (key_desc, value_desc) = encoding.desc()?;
 
     let mut key_envelope = get_key_envelope(include_metadata, &envelope, &encoding)?;
@@ -929,16 +1224,14 @@ pub fn plan_create_source(
     let envelope = match &envelope {
         // TODO: fixup key envelope
         mz_sql_parser::ast::Envelope::None => UnplannedSourceEnvelope::None(key_envelope),
-        mz_sql_parser::ast::Envelope::Debezium(mode) => {
-            //TODO check that key envelope is not set
-            let (_before_idx, after_idx) = typecheck_debezium(&value_desc)?;
-
-            match mode {
-                DbzMode::Plain => {
-                    UnplannedSourceEnvelope::Upsert(UpsertStyle::Debezium { after_idx })
-                }
+        mz_sql_parser::ast::Envelope::Debezium(mode) => match mode {
+            DbzMode::Plain => {
+                //TODO check that key envelope is not set
+                let (_before_idx, after_idx) = typecheck_debezium(&value_desc)?;
+                UnplannedSourceEnvelope::Upsert(UpsertStyle::Debezium { after_idx })
             }
-        }
+            DbzMode::Full => bail_unsupported!("ENVELOPE DEBEZIUM FULL in sources"),
+        },
         mz_sql_parser::ast::Envelope::Upsert => {
             let key_encoding = match encoding.key_ref() {
                 None => {
@@ -969,14 +1262,11 @@ pub fn plan_create_source(
     let metadata_desc = included_column_desc(metadata_columns.clone());
     let (envelope, mut desc) = envelope.desc(key_desc, value_desc, metadata_desc)?;
 
-    let CreateSourceOptionExtracted {
-        remote,
-        size,
-        timeline,
-        timestamp_interval,
-        ignore_keys,
-        seen: _,
-    } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
+    if refresh_interval.is_some()
+        && !matches!(connection, CreateSourceConnection::PostgresTable { .. })
+    {
+        sql_bail!("REFRESH INTERVAL is only supported for POSTGRES TABLE sources");
+    }
 
     if ignore_keys.unwrap_or(false) {
         desc = desc.without_keys();
@@ -1028,11 +1318,24 @@ pub fn plan_create_source(
         }
     }
 
+    let retention_filter = match retention {
+        Some(retention) => {
+            if !matches!(envelope, SourceEnvelope::None(_)) {
+                sql_bail!("RETENTION is only supported for sources with ENVELOPE NONE");
+            }
+            if timeline.is_some() {
+                sql_bail!("RETENTION cannot be combined with TIMELINE");
+            }
+            Some(plan_source_retention(scx, &desc, retention)?)
+        }
+        None => None,
+    };
+
     let host_config = host_config(remote, size)?;
 
     let timestamp_interval = match timestamp_interval {
-        Some(timestamp_interval) => timestamp_interval.duration()?,
-        None => scx.catalog.config().timestamp_interval,
+        Some(timestamp_interval) => plan_timestamp_interval(timestamp_interval)?,
+        None => scx.catalog.default_timestamp_interval(),
     };
 
     let source_desc = SourceDesc {
@@ -1045,6 +1348,21 @@ pub fn plan_create_source(
 
     let if_not_exists = *if_not_exists;
     let name = scx.allocate_qualified_name(normalize::unresolved_object_name(name.clone())?)?;
+    if let CreateSourceConnection::Kafka(mz_sql_parser::ast::KafkaSourceConnection {
+        connection: mz_sql_parser::ast::KafkaConnection { options, .. },
+        ..
+    }) = &mut stmt.connection
+    {
+        add_defaulted_kafka_options(options, kafka_util::KafkaOptionCheckContext::Source)?;
+    }
+    match &mut stmt.format {
+        CreateSourceFormat::None => {}
+        CreateSourceFormat::Bare(format) => add_defaulted_format_options(format)?,
+        CreateSourceFormat::KeyValue { key, value } => {
+            add_defaulted_format_options(key)?;
+            add_defaulted_format_options(value)?;
+        }
+    }
     let create_sql = normalize::create_statement(scx, Statement::CreateSource(stmt))?;
 
     // Allow users to specify a timeline. If they do not, determine a default
@@ -1072,6 +1390,7 @@ pub fn plan_create_source(
             subsource_exports,
         }),
         desc,
+        retention_filter,
     };
 
     Ok(Plan::CreateSource(CreateSourcePlan {
@@ -1177,6 +1496,7 @@ pub fn plan_create_subsource(
         create_sql,
         ingestion: None,
         desc,
+        retention_filter: None,
     };
 
     Ok(Plan::CreateSource(CreateSourcePlan {
@@ -1327,6 +1647,93 @@ fn get_encoding(
     Ok(encoding)
 }
 
+/// Records the defaulted Kafka options that are valid in `context`, so that the
+/// object's definition does not change if the defaults change.
+fn add_defaulted_kafka_options(
+    options: &mut Vec<KafkaConfigOption<Aug>>,
+    context: kafka_util::KafkaOptionCheckContext,
+) -> Result<(), PlanError> {
+    let extracted: KafkaConfigOptionExtracted = options.clone().try_into()?;
+    options.extend(
+        extracted
+            .defaulted_options()
+            .into_iter()
+            .filter(|o| kafka_util::is_option_for_context(&o.name, context)),
+    );
+    Ok(())
+}
+
+/// Records the Avro options of `format` that were left at their defaults in
+/// `format`, so that the definition of the source reports them.
+fn add_defaulted_format_options(format: &mut Format<Aug>) -> Result<(), PlanError> {
+    match format {
+        Format::Avro(AvroSchema::InlineSchema { with_options, .. }) => {
+            let extracted: AvroSchemaOptionExtracted = with_options.clone().try_into()?;
+            with_options.extend(extracted.defaulted_options());
+        }
+        Format::Avro(AvroSchema::Csr {
+            csr_connection: CsrConnectionAvro { connection, .. },
+        }) => {
+            let extracted: CsrConfigOptionExtracted = connection.options.clone().try_into()?;
+            connection.options.extend(extracted.defaulted_options());
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Plans the `RETENTION` option of a source into a temporal predicate over the
+/// columns of `desc`, which holds for a row until its retention column plus the
+/// retention interval.
+fn plan_source_retention(
+    scx: &StatementContext,
+    desc: &RelationDesc,
+    SourceRetention { interval, column }: SourceRetention,
+) -> Result<MirScalarExpr, PlanError> {
+    let column = normalize::column_name(column);
+    let (idx, typ) = desc
+        .get_by_name(&column)
+        .ok_or_else(|| sql_err!("No such column in source retention: {}", column))?;
+    if desc.get_unambiguous_name(idx).is_none() {
+        sql_bail!("Ambiguous column in source retention: {}", column);
+    }
+    let (add, cast) = match typ.scalar_type {
+        ScalarType::Timestamp => (
+            BinaryFunc::AddTimestampInterval,
+            UnaryFunc::CastTimestampToMzTimestamp(func::CastTimestampToMzTimestamp),
+        ),
+        ScalarType::TimestampTz => (
+            BinaryFunc::AddTimestampTzInterval,
+            UnaryFunc::CastTimestampTzToMzTimestamp(func::CastTimestampTzToMzTimestamp),
+        ),
+        _ => sql_bail!(
+            "RETENTION column {} must be of type timestamp or timestamp with time zone, not {}",
+            column.as_str().quoted(),
+            scx.humanize_scalar_type(&typ.scalar_type),
+        ),
+    };
+
+    let interval = strconv::parse_interval(&interval)?;
+    if interval.months != 0 {
+        sql_bail!("RETENTION interval must not contain months or years");
+    }
+    match interval.duration() {
+        Ok(duration) if duration > Duration::ZERO => {}
+        _ => sql_bail!("RETENTION interval must be positive"),
+    }
+
+    let expires_at = MirScalarExpr::column(idx)
+        .call_binary(
+            MirScalarExpr::literal_ok(Datum::Interval(interval), ScalarType::Interval),
+            add,
+        )
+        .call_unary(cast);
+    Ok(
+        MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::MzNow)
+            .call_binary(expires_at, BinaryFunc::Lte),
+    )
+}
+
 fn host_config(
     remote: Option<String>,
     size: Option<String>,
@@ -1347,6 +1754,7 @@ pub struct Schema {
     pub value_schema: String,
     pub csr_connection: Option<mz_storage_client::types::connections::CsrConnection>,
     pub confluent_wire_format: bool,
+    pub quarantine_unresolvable_schemas: bool,
 }
 
 fn get_encoding_inner(
@@ -1362,6 +1770,7 @@ fn get_encoding_inner(
                 value_schema,
                 csr_connection,
                 confluent_wire_format,
+                quarantine_unresolvable_schemas,
             } = match schema {
                 // TODO(jldlaughlin): we need a way to pass in primary key information
                 // when building a source from a string or file.
@@ -1379,6 +1788,7 @@ fn get_encoding_inner(
                         value_schema: schema.clone(),
                         csr_connection: None,
                         confluent_wire_format,
+                        quarantine_unresolvable_schemas: false,
                     }
                 }
                 AvroSchema::Csr {
@@ -1397,6 +1807,10 @@ fn get_encoding_inner(
                             sql_bail!("{} is not a schema registry connection", item.name())
                         }
                     };
+                    let CsrConfigOptionExtracted {
+                        quarantine_unresolvable_schemas,
+                        ..
+                    } = connection.options.clone().try_into()?;
 
                     if let Some(seed) = seed {
                         Schema {
@@ -1404,6 +1818,7 @@ fn get_encoding_inner(
                             value_schema: seed.value_schema.clone(),
                             csr_connection: Some(csr_connection),
                             confluent_wire_format: true,
+                            quarantine_unresolvable_schemas,
                         }
                     } else {
                         unreachable!("CSR seed resolution should already have been called: Avro")
@@ -1415,20 +1830,26 @@ fn get_encoding_inner(
                 return Ok(SourceDataEncodingInner::KeyValue {
                     key: DataEncodingInner::Avro(AvroEncoding {
                         schema: key_schema,
+                        writer_schema: None,
                         csr_connection: csr_connection.clone(),
                         confluent_wire_format,
+                        quarantine_unresolvable_schemas,
                     }),
                     value: DataEncodingInner::Avro(AvroEncoding {
                         schema: value_schema,
+                        writer_schema: None,
                         csr_connection,
                         confluent_wire_format,
+                        quarantine_unresolvable_schemas,
                     }),
                 });
             } else {
                 DataEncodingInner::Avro(AvroEncoding {
                     schema: value_schema,
+                    writer_schema: None,
                     csr_connection,
                     confluent_wire_format,
+                    quarantine_unresolvable_schemas,
                 })
             }
         }
@@ -1698,6 +2119,20 @@ pub fn plan_create_materialized_view(
         print_name: None,
     });
 
+    if scx
+        .pcx
+        .map_or(false, |pcx| pcx.forbid_materialized_view_wildcards)
+    {
+        let mut visitor = WildcardVisitor::default();
+        visitor.visit_query(&stmt.query);
+        if visitor.found {
+            sql_bail!(
+                "materialized view queries must list their columns instead of using wildcards \
+                when forbid_materialized_view_wildcards is enabled"
+            );
+        }
+    }
+
     let create_sql =
         normalize::create_statement(scx, Statement::CreateMaterializedView(stmt.clone()))?;
 
@@ -1757,6 +2192,29 @@ pub fn plan_create_materialized_view(
     }))
 }
 
+/// Finds the wildcards in a query, whose columns depend on those of the
+/// relations the query selects from.
+#[derive(Debug, Default)]
+struct WildcardVisitor {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast, Aug> for WildcardVisitor {
+    fn visit_select_item(&mut self, item: &'ast SelectItem<Aug>) {
+        match item {
+            SelectItem::Wildcard => self.found = true,
+            _ => visit::visit_select_item(self, item),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'ast Expr<Aug>) {
+        match expr {
+            Expr::QualifiedWildcard(_) | Expr::WildcardAccess(_) => self.found = true,
+            _ => visit::visit_expr(self, expr),
+        }
+    }
+}
+
 pub fn describe_create_sink(
     _: &StatementContext,
     _: CreateSinkStatement<Aug>,
@@ -1766,6 +2224,7 @@ pub fn describe_create_sink(
 
 generate_extracted_config!(
     CreateSinkOption,
+    (LatencyTracking, bool),
     (Remote, String),
     (Size, String),
     (Snapshot, bool)
@@ -1773,12 +2232,19 @@ generate_extracted_config!(
 
 pub fn plan_create_sink(
     scx: &StatementContext,
-    stmt: CreateSinkStatement<Aug>,
+    mut stmt: CreateSinkStatement<Aug>,
 ) -> Result<Plan, PlanError> {
+    if let CreateSinkConnection::Kafka { connection, .. } = &mut stmt.connection {
+        add_defaulted_kafka_options(
+            &mut connection.options,
+            kafka_util::KafkaOptionCheckContext::Sink,
+        )?;
+    }
     let create_sql = normalize::create_statement(scx, Statement::CreateSink(stmt.clone()))?;
     let CreateSinkStatement {
         name,
         from,
+        columns,
         connection,
         format,
         envelope,
@@ -1786,8 +2252,11 @@ pub fn plan_create_sink(
         with_options,
     } = stmt;
 
-    const SAFE_WITH_OPTIONS: &[CreateSinkOptionName] =
-        &[CreateSinkOptionName::Size, CreateSinkOptionName::Snapshot];
+    const SAFE_WITH_OPTIONS: &[CreateSinkOptionName] = &[
+        CreateSinkOptionName::LatencyTracking,
+        CreateSinkOptionName::Size,
+        CreateSinkOptionName::Snapshot,
+    ];
 
     if with_options
         .iter()
@@ -1799,19 +2268,72 @@ pub fn plan_create_sink(
         ))?;
     }
 
+    let name = scx.allocate_qualified_name(normalize::unresolved_object_name(name)?)?;
+    let from = scx.get_item_by_resolved_name(&from)?;
+    let from_name = scx.catalog.resolve_full_name(from.name());
+
     let envelope = match envelope {
         None => sql_bail!("ENVELOPE clause is required"),
-        Some(Envelope::Debezium(mz_sql_parser::ast::DbzMode::Plain)) => SinkEnvelope::Debezium,
+        Some(Envelope::Debezium(DbzMode::Plain)) => SinkEnvelope::Debezium,
+        Some(Envelope::Debezium(DbzMode::Full)) => SinkEnvelope::DebeziumFull(DbzSourceInfo {
+            version: scx.catalog.config().build_info.version.to_string(),
+            name: scx.catalog.resolve_full_name(&name).to_string(),
+            database: match &from_name.database {
+                RawDatabaseSpecifier::Name(database) => database.clone(),
+                RawDatabaseSpecifier::Ambient => String::new(),
+            },
+            schema: from_name.schema.clone(),
+            table: from_name.item.clone(),
+        }),
         Some(Envelope::Upsert) => SinkEnvelope::Upsert,
         Some(Envelope::CdcV2) => bail_unsupported!("CDCv2 sinks"),
         Some(Envelope::None) => bail_unsupported!("\"ENVELOPE NONE\" sinks"),
     };
-    let name = scx.allocate_qualified_name(normalize::unresolved_object_name(name)?)?;
-    let from = scx.get_item_by_resolved_name(&from)?;
 
-    let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
+    let desc = from.desc(&from_name)?;
+    // If the sink lists the columns to emit, select, reorder and rename the
+    // columns of `from` accordingly. Keys refer to the emitted columns.
+    let (desc, from_projection) = if columns.is_empty() {
+        (desc.into_owned(), None)
+    } else {
+        let mut seen = HashSet::new();
+        let mut projection = Vec::with_capacity(columns.len());
+        let mut names = Vec::with_capacity(columns.len());
+        let mut types = Vec::with_capacity(columns.len());
+        for SinkColumn { name, alias } in columns {
+            let col = normalize::column_name(name);
+            let idx = desc
+                .get_by_name(&col)
+                .map(|(idx, _type)| idx)
+                .ok_or_else(|| sql_err!("No such column: {}", col))?;
+            if desc.get_unambiguous_name(idx).is_none() {
+                sql_bail!("Ambiguous column: {}", col);
+            }
+            let output_name = alias.map(normalize::column_name).unwrap_or(col);
+            if !seen.insert(output_name.clone()) {
+                sql_bail!("Repeated column name in sink: {}", output_name);
+            }
+            projection.push(idx);
+            names.push(output_name);
+            types.push(desc.typ().column_types[idx].clone());
+        }
+        // Only the keys of `from` whose columns are all emitted remain keys.
+        let keys = desc
+            .typ()
+            .keys
+            .iter()
+            .filter_map(|key| {
+                key.iter()
+                    .map(|col| projection.iter().position(|idx| idx == col))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect();
+        let typ = RelationType::new(types).with_keys(keys);
+        (RelationDesc::new(typ, names), Some(projection))
+    };
     let key_indices = match &connection {
-        CreateSinkConnection::Kafka { key, .. } => {
+        CreateSinkConnection::Kafka { key, .. }
+        | CreateSinkConnection::Elasticsearch { key, .. } => {
             if let Some(key) = key.clone() {
                 let key_columns = key
                     .key_columns
@@ -1877,18 +2399,34 @@ pub fn plan_create_sink(
     }
 
     let connection_builder = match connection {
-        CreateSinkConnection::Kafka { connection, .. } => kafka_sink_builder(
+        CreateSinkConnection::Kafka {
+            connection,
+            partition_by,
+            ..
+        } => kafka_sink_builder(
             scx,
             connection,
+            partition_by,
             format,
             relation_key_indices,
             key_desc_and_indices,
-            desc.into_owned(),
-            envelope,
+            desc,
+            envelope.clone(),
+        )?,
+        CreateSinkConnection::Elasticsearch { url, options, .. } => elasticsearch_sink_builder(
+            scx,
+            url,
+            options,
+            format,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc,
+            &envelope,
         )?,
     };
 
     let CreateSinkOptionExtracted {
+        latency_tracking,
         remote,
         size,
         snapshot,
@@ -1899,16 +2437,19 @@ pub fn plan_create_sink(
 
     // WITH SNAPSHOT defaults to true
     let with_snapshot = snapshot.unwrap_or(true);
+    let track_latency = latency_tracking.unwrap_or(false);
 
     Ok(Plan::CreateSink(CreateSinkPlan {
         name,
         sink: Sink {
             create_sql,
             from: from.id(),
+            from_projection,
             connection_builder,
             envelope,
         },
         with_snapshot,
+        track_latency,
         if_not_exists,
         host_config,
     }))
@@ -1965,7 +2506,8 @@ fn key_constraint_err(desc: &RelationDesc, user_keys: &[ColumnName]) -> PlanErro
 generate_extracted_config!(
     CsrConfigOption,
     (AvroKeyFullname, String),
-    (AvroValueFullname, String)
+    (AvroValueFullname, String),
+    (QuarantineUnresolvableSchemas, bool, Default(false))
 );
 
 fn kafka_sink_builder(
@@ -1974,6 +2516,7 @@ fn kafka_sink_builder(
         connection,
         options: with_options,
     }: mz_sql_parser::ast::KafkaConnection<Aug>,
+    partition_by: Option<Expr<Aug>>,
     format: Option<Format<Aug>>,
     relation_key_indices: Option<Vec<usize>>,
     key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
@@ -1987,13 +2530,30 @@ fn kafka_sink_builder(
         _ => sql_bail!("{} is not a kafka connection", item.name()),
     };
 
-    if with_options
-        .iter()
-        .any(|mz_sql_parser::ast::KafkaConfigOption { name, .. }| {
-            !matches!(name, KafkaConfigOptionName::Topic)
-        })
-    {
-        scx.require_unsafe_mode("KAFKA CONNECTION options besides TOPIC")?;
+    // The producer tuning options are allowed out of unsafe mode, as they
+    // are needed to get good throughput out of high-volume sinks, and so are
+    // the partition strategy and update order, which downstream consumers
+    // may depend on, and the configuration of the topic that the sink
+    // creates.
+    if let Some(opt) = with_options.iter().find(|opt| {
+        !matches!(
+            opt.name,
+            KafkaConfigOptionName::Topic
+                | KafkaConfigOptionName::BatchSize
+                | KafkaConfigOptionName::CompressionType
+                | KafkaConfigOptionName::LingerMs
+                | KafkaConfigOptionName::MaxInFlight
+                | KafkaConfigOptionName::PartitionStrategy
+                | KafkaConfigOptionName::UpdateOrder
+                | KafkaConfigOptionName::CreateTopic
+                | KafkaConfigOptionName::PartitionCount
+                | KafkaConfigOptionName::ReplicationFactor
+                | KafkaConfigOptionName::RetentionMs
+                | KafkaConfigOptionName::RetentionBytes
+                | KafkaConfigOptionName::CleanupPolicy
+        )
+    }) {
+        scx.require_unsafe_mode(&format!("KAFKA CONNECTION option {}", opt.name))?;
     }
 
     kafka_util::validate_options_for_context(
@@ -2010,15 +2570,62 @@ fn kafka_sink_builder(
     let connection_id = item.id();
     let KafkaConfigOptionExtracted {
         topic,
+        create_topic,
         partition_count,
+        partition_strategy,
         replication_factor,
         retention_ms,
         retention_bytes,
+        cleanup_policy,
+        update_order,
         ..
     } = extracted_options;
 
     let topic_name = topic.ok_or_else(|| sql_err!("KAFKA CONNECTION must specify TOPIC"))?;
 
+    let partitioning = match (partition_strategy.as_deref(), partition_by) {
+        (Some(_), Some(_)) => {
+            sql_bail!("cannot specify PARTITION STRATEGY and PARTITION BY at the same time")
+        }
+        (None | Some("consistent"), None) => None,
+        (Some("murmur2"), None) => {
+            // Matches the default partitioner of the Java Kafka client.
+            connection.options.insert(
+                "partitioner".into(),
+                StringOrSecret::String("murmur2_random".into()),
+            );
+            None
+        }
+        (Some("round_robin"), None) => {
+            // Upsert sinks rely on all updates for a key landing in the same
+            // partition.
+            if envelope == SinkEnvelope::Upsert {
+                sql_bail!("PARTITION STRATEGY 'round_robin' is not supported with ENVELOPE UPSERT");
+            }
+            Some(KafkaSinkPartitioning::RoundRobin)
+        }
+        (Some(strategy), None) => sql_bail!(
+            "PARTITION STRATEGY must be one of 'consistent', 'murmur2', or 'round_robin', not {}",
+            strategy.quoted()
+        ),
+        (None, Some(expr)) => {
+            let (key_desc, _indices) = key_desc_and_indices
+                .as_ref()
+                .ok_or_else(|| sql_err!("PARTITION BY requires a KEY"))?;
+            let expr = query::plan_sink_partition_expr(scx, key_desc, expr)?;
+            Some(KafkaSinkPartitioning::Expr(expr))
+        }
+    };
+
+    let sort_by_key = match update_order.as_deref() {
+        None | Some("none") => false,
+        Some("key") => true,
+        Some(order) => sql_bail!(
+            "UPDATE ORDER must be one of 'none' or 'key', not {}",
+            order.quoted()
+        ),
+    };
+
     let format = match format {
         Some(Format::Avro(AvroSchema::Csr {
             csr_connection:
@@ -2053,9 +2660,14 @@ fn kafka_sink_builder(
             let CsrConfigOptionExtracted {
                 avro_key_fullname,
                 avro_value_fullname,
+                quarantine_unresolvable_schemas,
                 ..
             } = options.try_into()?;
 
+            if quarantine_unresolvable_schemas {
+                sql_bail!("QUARANTINE UNRESOLVABLE SCHEMAS is only supported for sources");
+            }
+
             if key_desc_and_indices.is_none() && avro_key_fullname.is_some() {
                 sql_bail!("Cannot specify AVRO KEY FULLNAME without a corresponding KEY field");
             }
@@ -2073,7 +2685,7 @@ fn kafka_sink_builder(
                     .as_ref()
                     .map(|(desc, _indices)| desc.clone()),
                 value_desc.clone(),
-                matches!(envelope, SinkEnvelope::Debezium),
+                envelope.dbz_envelope(),
             )?;
             let value_schema = schema_generator.value_writer_schema().to_string();
             let key_schema = schema_generator
@@ -2099,13 +2711,13 @@ fn kafka_sink_builder(
         }),
     };
 
-    if partition_count == 0 || partition_count < -1 {
+    if matches!(partition_count, Some(n) if n == 0 || n < -1) {
         sql_bail!(
             "PARTION COUNT for sink topics must be a positive integer or -1 for broker default"
         );
     }
 
-    if replication_factor == 0 || replication_factor < -1 {
+    if matches!(replication_factor, Some(n) if n == 0 || n < -1) {
         sql_bail!(
             "REPLICATION FACTOR for sink topics must be a positive integer or -1 for broker default"
         );
@@ -2124,6 +2736,32 @@ fn kafka_sink_builder(
         bytes: retention_bytes,
     };
 
+    if let Some(cleanup_policy) = &cleanup_policy {
+        if !["delete", "compact", "compact,delete"].contains(&cleanup_policy.as_str()) {
+            sql_bail!("CLEANUP POLICY must be one of 'delete', 'compact', or 'compact,delete'");
+        }
+        // Compacted topics reject messages without keys.
+        if cleanup_policy.contains("compact") && key_desc_and_indices.is_none() {
+            sql_bail!(
+                "CLEANUP POLICY '{}' requires the sink to have a KEY",
+                cleanup_policy
+            );
+        }
+    }
+
+    if !create_topic
+        && (partition_count.unwrap_or(-1) != -1
+            || replication_factor.unwrap_or(-1) != -1
+            || retention_ms.is_some()
+            || retention_bytes.is_some()
+            || cleanup_policy.is_some())
+    {
+        sql_bail!(
+            "PARTITION COUNT, REPLICATION FACTOR, RETENTION MS, RETENTION BYTES and \
+            CLEANUP POLICY cannot be specified when CREATE TOPIC is false"
+        );
+    }
+
     Ok(StorageSinkConnectionBuilder::Kafka(
         KafkaSinkConnectionBuilder {
             connection_id,
@@ -2131,13 +2769,82 @@ fn kafka_sink_builder(
             format,
             topic_name,
             consistency_config,
-            partition_count,
-            replication_factor,
+            // -1 uses the broker's default.
+            partition_count: partition_count.unwrap_or(-1),
+            replication_factor: replication_factor.unwrap_or(-1),
             fuel: 10000,
             relation_key_indices,
             key_desc_and_indices,
             value_desc,
             retention,
+            cleanup_policy,
+            create_topic,
+            partitioning,
+            sort_by_key,
+        },
+    ))
+}
+
+generate_extracted_config!(ElasticsearchSinkOption, (Index, String));
+
+fn elasticsearch_sink_builder(
+    scx: &StatementContext,
+    url: String,
+    options: Vec<ElasticsearchSinkOption<Aug>>,
+    format: Option<Format<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+    envelope: &SinkEnvelope,
+) -> Result<StorageSinkConnectionBuilder, PlanError> {
+    scx.require_unsafe_mode("CREATE SINK ... INTO ELASTICSEARCH")?;
+
+    let ElasticsearchSinkOptionExtracted { index, seen: _ } = options.try_into()?;
+
+    let parsed_url: reqwest::Url = url
+        .parse()
+        .map_err(|e| sql_err!("parsing Elasticsearch sink url: {e}"))?;
+    if !matches!(parsed_url.scheme(), "http" | "https") {
+        sql_bail!("Elasticsearch sink URL must use the http or https scheme");
+    }
+
+    let index = match index {
+        Some(index) => index,
+        None => sql_bail!("INDEX option is required for Elasticsearch sinks"),
+    };
+    // Elasticsearch rejects index names with upper case letters, and names
+    // that could be confused with paths or index patterns.
+    if index.is_empty()
+        || index.starts_with(['-', '_', '+'])
+        || index
+            .chars()
+            .any(|c| c.is_uppercase() || c.is_whitespace() || r#"\/*?"<>|,#:"#.contains(c))
+        || index == "."
+        || index == ".."
+    {
+        sql_bail!("invalid Elasticsearch index name: {}", index.quoted());
+    }
+
+    // Documents are replaced and deleted by their key, which only makes sense
+    // for upserts.
+    if *envelope != SinkEnvelope::Upsert {
+        sql_bail!("Elasticsearch sinks require ENVELOPE UPSERT");
+    }
+    let key_desc_and_indices = key_desc_and_indices.ok_or(PlanError::UpsertSinkWithoutKey)?;
+
+    match format {
+        Some(Format::Json) => (),
+        Some(format) => bail_unsupported!(format!("Elasticsearch sink format {:?}", format)),
+        None => bail_unsupported!("sink without format"),
+    }
+
+    Ok(StorageSinkConnectionBuilder::Elasticsearch(
+        ElasticsearchSinkConnection {
+            url,
+            index,
+            key_desc_and_indices,
+            relation_key_indices,
+            value_desc,
         },
     ))
 }
@@ -2232,6 +2939,8 @@ pub fn plan_create_index(
         }
     };
 
+    let index_options: IndexOptionExtracted = with_options.clone().try_into()?;
+    let lazy = index_options.lazy;
     let options = plan_index_options(scx, with_options.clone())?;
     let compute_instance = match in_cluster {
         None => scx.resolve_compute_instance(None)?.id(),
@@ -2246,6 +2955,7 @@ pub fn plan_create_index(
     // Normalize `stmt`.
     *name = Some(Ident::new(index_name.item.clone()));
     *key_parts = Some(filled_key_parts);
+    with_options.extend(index_options.defaulted_options());
     let if_not_exists = *if_not_exists;
     if let ResolvedObjectName::Object { print_id, .. } = &mut stmt.on_name {
         *print_id = false;
@@ -2259,6 +2969,7 @@ pub fn plan_create_index(
             on: on.id(),
             keys,
             compute_instance,
+            lazy,
         },
         options,
         if_not_exists,
@@ -2461,12 +3172,6 @@ pub fn plan_create_cluster(
     }))
 }
 
-const DEFAULT_COMPUTE_REPLICA_INTROSPECTION_INTERVAL: Interval = Interval {
-    micros: cast::u32_to_i64(DEFAULT_COMPUTE_REPLICA_LOGGING_INTERVAL_MICROS),
-    months: 0,
-    days: 0,
-};
-
 generate_extracted_config!(
     ReplicaOption,
     (AvailabilityZone, String),
@@ -2505,12 +3210,13 @@ fn plan_replica_config(
         scx.require_unsafe_mode("WORKERS cluster replica option")?;
     }
 
-    let introspection_interval = introspection_interval
-        .map(|OptionalInterval(i)| i)
-        .unwrap_or(Some(DEFAULT_COMPUTE_REPLICA_INTROSPECTION_INTERVAL));
+    let introspection_interval = match introspection_interval {
+        Some(OptionalInterval(interval)) => interval.map(|i| i.duration()).transpose()?,
+        None => Some(scx.catalog.default_introspection_interval()),
+    };
     let introspection = match introspection_interval {
         Some(interval) => Some(ComputeReplicaIntrospectionConfig {
-            interval: interval.duration()?,
+            interval,
             debugging: introspection_debugging,
         }),
         None if introspection_debugging => {
@@ -3507,17 +4213,18 @@ pub fn describe_alter_index_options(
     Ok(StatementDesc::new(None))
 }
 
-generate_extracted_config!(IndexOption, (LogicalCompactionWindow, OptionalInterval));
+generate_extracted_config!(
+    IndexOption,
+    (LogicalCompactionWindow, OptionalInterval),
+    (Lazy, bool, Default(false))
+);
 
+/// Plans the options of an index that are applied to its dataflow, as opposed
+/// to the `LAZY` option, which is recorded in its catalog item.
 fn plan_index_options(
     scx: &StatementContext,
     with_opts: Vec<IndexOption<Aug>>,
 ) -> Result<Vec<crate::plan::IndexOption>, PlanError> {
-    if !with_opts.is_empty() {
-        // Index options are not durable.
-        scx.require_unsafe_mode("INDEX OPTIONS")?;
-    }
-
     let IndexOptionExtracted {
         logical_compaction_window,
         ..
@@ -3526,6 +4233,7 @@ fn plan_index_options(
     let mut out = Vec::with_capacity(1);
 
     if let Some(OptionalInterval(lcw)) = logical_compaction_window {
+        // The logical compaction window of an index is not durable.
         scx.require_unsafe_mode("LOGICAL COMPACTION WINDOW")?;
         out.push(crate::plan::IndexOption::LogicalCompactionWindow(
             lcw.map(|interval| interval.duration()).transpose()?,
@@ -3564,6 +4272,16 @@ pub fn plan_alter_index_options(
     }
     let id = entry.id();
 
+    let alters_lazy = match &actions {
+        AlterIndexAction::ResetOptions(options) => options.contains(&IndexOptionName::Lazy),
+        AlterIndexAction::SetOptions(options) => {
+            options.iter().any(|o| o.name == IndexOptionName::Lazy)
+        }
+    };
+    if alters_lazy {
+        sql_bail!("cannot change the LAZY option of an existing index");
+    }
+
     match actions {
         AlterIndexAction::ResetOptions(options) => {
             Ok(Plan::AlterIndexResetOptions(AlterIndexResetOptionsPlan {
@@ -3580,6 +4298,97 @@ pub fn plan_alter_index_options(
     }
 }
 
+pub fn describe_alter_schema(
+    _: &StatementContext,
+    _: AlterSchemaStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+generate_extracted_config!(
+    SchemaOption,
+    (MaxSources, u32),
+    (MaxIndexes, u32),
+    (MaxMaterializedViews, u32)
+);
+
+pub fn plan_alter_schema(
+    scx: &StatementContext,
+    AlterSchemaStatement {
+        name,
+        if_exists,
+        action,
+    }: AlterSchemaStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let schema = match scx.resolve_schema(name) {
+        Ok(schema) => schema,
+        Err(_) if if_exists => {
+            // TODO(benesch/jkosh44): generate a notice indicating that the
+            // schema does not exist.
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Schema,
+            }));
+        }
+        Err(e) => return Err(e),
+    };
+    let (database_id, schema_id) = match (schema.database(), schema.id()) {
+        (ResolvedDatabaseSpecifier::Id(database_id), SchemaSpecifier::Id(schema_id)) => {
+            (database_id.clone(), schema_id.clone())
+        }
+        _ => sql_bail!(
+            "cannot alter schema {} because it is required by the database system",
+            schema.name().schema
+        ),
+    };
+
+    let mut max_sources = AlterOptionParameter::Unchanged;
+    let mut max_indexes = AlterOptionParameter::Unchanged;
+    let mut max_materialized_views = AlterOptionParameter::Unchanged;
+    match action {
+        AlterSchemaAction::SetOptions(options) => {
+            let SchemaOptionExtracted {
+                seen: _,
+                max_sources: max_sources_opt,
+                max_indexes: max_indexes_opt,
+                max_materialized_views: max_materialized_views_opt,
+            } = SchemaOptionExtracted::try_from(options)?;
+
+            if let Some(value) = max_sources_opt {
+                max_sources = AlterOptionParameter::Set(value);
+            }
+            if let Some(value) = max_indexes_opt {
+                max_indexes = AlterOptionParameter::Set(value);
+            }
+            if let Some(value) = max_materialized_views_opt {
+                max_materialized_views = AlterOptionParameter::Set(value);
+            }
+        }
+        AlterSchemaAction::ResetOptions(reset) => {
+            for name in reset {
+                match name {
+                    SchemaOptionName::MaxSources => {
+                        max_sources = AlterOptionParameter::Reset;
+                    }
+                    SchemaOptionName::MaxIndexes => {
+                        max_indexes = AlterOptionParameter::Reset;
+                    }
+                    SchemaOptionName::MaxMaterializedViews => {
+                        max_materialized_views = AlterOptionParameter::Reset;
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(Plan::AlterSchema(AlterSchemaPlan {
+        database_id,
+        schema_id,
+        max_sources,
+        max_indexes,
+        max_materialized_views,
+    }))
+}
+
 pub fn describe_alter_object_rename(
     _: &StatementContext,
     _: AlterObjectRenameStatement,
@@ -3720,6 +4529,7 @@ pub fn plan_alter_sink(
     match action {
         AlterSinkAction::SetOptions(options) => {
             let CreateSinkOptionExtracted {
+                latency_tracking,
                 remote: remote_opt,
                 size: size_opt,
                 snapshot,
@@ -3735,10 +4545,16 @@ pub fn plan_alter_sink(
             if let Some(_) = snapshot {
                 sql_bail!("Cannot modify the SNAPSHOT of a SINK.");
             }
+            if let Some(_) = latency_tracking {
+                sql_bail!("Cannot modify the LATENCY TRACKING of a SINK.");
+            }
         }
         AlterSinkAction::ResetOptions(reset) => {
             for name in reset {
                 match name {
+                    CreateSinkOptionName::LatencyTracking => {
+                        sql_bail!("Cannot modify the LATENCY TRACKING of a SINK.");
+                    }
                     CreateSinkOptionName::Remote => {
                         remote = AlterOptionParameter::Reset;
                     }
@@ -3801,11 +4617,15 @@ pub fn plan_alter_source(
 
     let mut size = AlterOptionParameter::Unchanged;
     let mut remote = AlterOptionParameter::Unchanged;
+    let mut timestamp_interval = AlterOptionParameter::Unchanged;
     match action {
         AlterSourceAction::SetOptions(options) => {
             let CreateSourceOptionExtracted {
                 seen: _,
+                fields: fields_opt,
+                refresh_interval: refresh_interval_opt,
                 remote: remote_opt,
+                retention: retention_opt,
                 size: size_opt,
                 timeline: timeline_opt,
                 timestamp_interval: timestamp_interval_opt,
@@ -3821,12 +4641,22 @@ pub fn plan_alter_source(
             if let Some(_) = timeline_opt {
                 sql_bail!("Cannot modify the TIMELINE of a SOURCE.");
             }
-            if let Some(_) = timestamp_interval_opt {
-                sql_bail!("Cannot modify the TIMESTAMP INTERVAL of a SOURCE.");
+            if let Some(_) = refresh_interval_opt {
+                sql_bail!("Cannot modify the REFRESH INTERVAL of a SOURCE.");
+            }
+            if let Some(_) = retention_opt {
+                sql_bail!("Cannot modify the RETENTION of a SOURCE.");
+            }
+            if let Some(value) = timestamp_interval_opt {
+                plan_timestamp_interval(value)?;
+                timestamp_interval = AlterOptionParameter::Set(value);
             }
             if let Some(_) = ignore_keys_opt {
                 sql_bail!("Cannot modify the IGNORE KEYS property of a SOURCE.");
             }
+            if let Some(_) = fields_opt {
+                sql_bail!("Cannot modify the FIELDS of a SOURCE.");
+            }
         }
         AlterSourceAction::ResetOptions(reset) => {
             for name in reset {
@@ -3841,17 +4671,31 @@ pub fn plan_alter_source(
                         sql_bail!("Cannot modify the TIMELINE of a SOURCE.");
                     }
                     CreateSourceOptionName::TimestampInterval => {
-                        sql_bail!("Cannot modify the TIMESTAMP INTERVAL of a SOURCE.");
+                        timestamp_interval = AlterOptionParameter::Reset;
                     }
                     CreateSourceOptionName::IgnoreKeys => {
                         sql_bail!("Cannot modify the IGNORE KEYS property of a SOURCE.");
                     }
+                    CreateSourceOptionName::RefreshInterval => {
+                        sql_bail!("Cannot modify the REFRESH INTERVAL of a SOURCE.");
+                    }
+                    CreateSourceOptionName::Retention => {
+                        sql_bail!("Cannot modify the RETENTION of a SOURCE.");
+                    }
+                    CreateSourceOptionName::Fields => {
+                        sql_bail!("Cannot modify the FIELDS of a SOURCE.");
+                    }
                 }
             }
         }
     };
 
-    Ok(Plan::AlterSource(AlterSourcePlan { id, size, remote }))
+    Ok(Plan::AlterSource(AlterSourcePlan {
+        id,
+        size,
+        remote,
+        timestamp_interval,
+    }))
 }
 
 pub fn describe_alter_system_set(
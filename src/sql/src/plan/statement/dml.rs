@@ -15,19 +15,21 @@
 use std::borrow::Cow;
 use std::collections::{BTreeSet, HashMap, HashSet};
 
+use mz_compute_client::types::sinks::SubscribePartition;
 use mz_expr::MirRelationExpr;
 use mz_ore::collections::CollectionExt;
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::explain_new::{ExplainConfig, ExplainFormat};
-use mz_repr::{RelationDesc, ScalarType};
+use mz_repr::{ColumnName, RelationDesc, ScalarType};
 
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
     CreateMaterializedViewStatement, CreateViewStatement, DeleteStatement, ExplainStage,
-    ExplainStatement, Explainee, Ident, InsertStatement, Query, SelectStatement, Statement,
-    SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement, UpdateStatement,
+    ExplainStatement, Explainee, Expr, Ident, InsertStatement, Query, Select, SelectItem,
+    SelectStatement, Statement, SubscribeOption, SubscribeOptionName, SubscribeRelation,
+    SubscribeStatement, TableFactor, TableWithJoins, UpdateStatement, ValidateViewStatement,
     ViewDefinition,
 };
 use crate::catalog::CatalogItemType;
@@ -355,6 +357,86 @@ pub fn plan_explain(
     }))
 }
 
+pub fn describe_validate_view(
+    scx: &StatementContext,
+    stmt: ValidateViewStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    describe_select(scx, validate_view_query(scx, stmt)?)
+}
+
+pub fn plan_validate_view(
+    scx: &StatementContext,
+    stmt: ValidateViewStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    plan_select(scx, validate_view_query(scx, stmt)?, &Params::empty(), None)
+}
+
+/// Rewrites `VALIDATE VIEW` into a query that compares the maintained contents
+/// of the view with a recomputation of its definition. Both sides are read by
+/// the same peek, and therefore at the same timestamp.
+///
+/// The query returns one row per discrepancy, with a leading `side` column
+/// that reports whether the row only occurs in the `maintained` or in the
+/// `recomputed` contents of the view. If the view is correct, the query
+/// returns no rows.
+fn validate_view_query(
+    scx: &StatementContext,
+    ValidateViewStatement { name }: ValidateViewStatement<Aug>,
+) -> Result<SelectStatement<Aug>, PlanError> {
+    let item = scx.get_item_by_resolved_name(&name)?;
+    let parsed =
+        crate::parse::parse(item.create_sql()).expect("Sql for existing item should be valid sql");
+    let query = match (item.item_type(), parsed.into_last()) {
+        (
+            CatalogItemType::View,
+            Statement::CreateView(CreateViewStatement {
+                definition: ViewDefinition { query, .. },
+                ..
+            }),
+        ) => {
+            let indexed = item.used_by().iter().any(|id| {
+                matches!(
+                    scx.catalog.get_item(id).index_details(),
+                    Some((_, on)) if on == item.id()
+                )
+            });
+            if !indexed {
+                sql_bail!(
+                    "cannot validate {}: views are only maintained if they are indexed",
+                    name.full_name_str()
+                );
+            }
+            query
+        }
+        (
+            CatalogItemType::MaterializedView,
+            Statement::CreateMaterializedView(CreateMaterializedViewStatement { query, .. }),
+        ) => query,
+        (item_type, _) => sql_bail!(
+            "Expected {} to be a view or materialized view, not a {}",
+            name.full_name_str(),
+            item_type
+        ),
+    };
+
+    let name = name.to_ast_string();
+    let query = query.to_ast_string();
+    let sql = format!(
+        "SELECT 'maintained' AS side, * FROM (SELECT * FROM {name} EXCEPT ALL ({query})) AS m
+        UNION ALL
+        SELECT 'recomputed', * FROM (({query}) EXCEPT ALL SELECT * FROM {name}) AS r"
+    );
+    let stmt = match crate::parse::parse(&sql)
+        .expect("rewritten VALIDATE VIEW should be valid sql")
+        .into_element()
+    {
+        Statement::Select(select) => select,
+        _ => unreachable!("rewritten VALIDATE VIEW should parse as SELECT"),
+    };
+    let (stmt, _) = names::resolve(scx.catalog, stmt)?;
+    Ok(stmt)
+}
+
 /// Plans and decorrelates a `Query`. Like `query::plan_root_query`, but returns
 /// an `mz_expr::MirRelationExpr`, which cannot include correlated expressions.
 pub fn plan_query(
@@ -376,7 +458,14 @@ pub fn plan_query(
     })
 }
 
-generate_extracted_config!(SubscribeOption, (Snapshot, bool), (Progress, bool));
+generate_extracted_config!(
+    SubscribeOption,
+    (Snapshot, bool),
+    (Progress, bool),
+    (PartitionKey, Vec<String>),
+    (PartitionCount, u64),
+    (PartitionIndex, u64)
+);
 
 pub fn describe_subscribe(
     scx: &StatementContext,
@@ -426,14 +515,19 @@ pub fn plan_subscribe(
     }: SubscribeStatement<Aug>,
     copy_to: Option<CopyFormat>,
 ) -> Result<Plan, PlanError> {
-    let from = match relation {
+    let (from, desc) = match relation {
         SubscribeRelation::Name(name) => {
             let entry = scx.get_item_by_resolved_name(&name)?;
             match entry.item_type() {
                 CatalogItemType::Table
                 | CatalogItemType::Source
                 | CatalogItemType::View
-                | CatalogItemType::MaterializedView => SubscribeFrom::Id(entry.id()),
+                | CatalogItemType::MaterializedView => {
+                    let desc = entry
+                        .desc(&scx.catalog.resolve_full_name(entry.name()))?
+                        .into_owned();
+                    (SubscribeFrom::Id(entry.id()), desc)
+                }
                 CatalogItemType::Func
                 | CatalogItemType::Index
                 | CatalogItemType::Sink
@@ -459,10 +553,14 @@ pub fn plan_subscribe(
                 QueryLifetime::OneShot(scx.pcx()?),
             )?;
             assert!(query.finishing.is_trivial(query.desc.arity()));
-            SubscribeFrom::Query {
-                expr: query.expr,
-                desc: query.desc,
-            }
+            let desc = query.desc.clone();
+            (
+                SubscribeFrom::Query {
+                    expr: query.expr,
+                    desc: query.desc,
+                },
+                desc,
+            )
         }
     };
 
@@ -470,8 +568,22 @@ pub fn plan_subscribe(
     let up_to = up_to.map(|up_to| plan_up_to(scx, up_to)).transpose()?;
 
     let SubscribeOptionExtracted {
-        progress, snapshot, ..
+        progress,
+        snapshot,
+        partition_key,
+        partition_count,
+        partition_index,
+        ..
     } = options.try_into()?;
+    let partition = match (partition_key, partition_count, partition_index) {
+        (None, None, None) => None,
+        (Some(key), Some(count), Some(index)) => {
+            Some(plan_subscribe_partition(&desc, key, count, index)?)
+        }
+        _ => sql_bail!(
+            "PARTITION KEY, PARTITION COUNT and PARTITION INDEX must be specified together"
+        ),
+    };
     Ok(Plan::Subscribe(SubscribePlan {
         from,
         when,
@@ -479,9 +591,44 @@ pub fn plan_subscribe(
         with_snapshot: snapshot.unwrap_or(true),
         copy_to,
         emit_progress: progress.unwrap_or(false),
+        partition,
     }))
 }
 
+fn plan_subscribe_partition(
+    desc: &RelationDesc,
+    key: Vec<String>,
+    count: u64,
+    index: u64,
+) -> Result<SubscribePartition, PlanError> {
+    if key.is_empty() {
+        sql_bail!("PARTITION KEY must contain at least one column");
+    }
+    if count == 0 {
+        sql_bail!("PARTITION COUNT must be greater than zero");
+    }
+    if index >= count {
+        sql_bail!(
+            "PARTITION INDEX must be less than PARTITION COUNT ({})",
+            count
+        );
+    }
+    let key = key
+        .into_iter()
+        .map(|name| {
+            let column = ColumnName::from(name);
+            match desc.get_by_name(&column) {
+                Some((i, _)) => Ok(i),
+                None => Err(PlanError::UnknownColumn {
+                    table: None,
+                    column,
+                }),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(SubscribePartition { key, count, index })
+}
+
 pub fn describe_table(
     scx: &StatementContext,
     table_name: <Aug as AstInfo>::ObjectName,
@@ -493,16 +640,49 @@ pub fn describe_table(
 
 pub fn describe_copy(
     scx: &StatementContext,
-    CopyStatement { relation, .. }: CopyStatement<Aug>,
+    CopyStatement {
+        relation,
+        direction,
+        ..
+    }: CopyStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
     Ok(match relation {
-        CopyRelation::Table { name, columns } => describe_table(scx, name, columns)?,
+        CopyRelation::Table { name, columns } => match direction {
+            CopyDirection::To => describe_select(scx, copy_to_relation_select(name, columns))?,
+            CopyDirection::From => describe_table(scx, name, columns)?,
+        },
         CopyRelation::Select(stmt) => describe_select(scx, stmt)?,
         CopyRelation::Subscribe(stmt) => describe_subscribe(scx, stmt)?,
     }
     .with_is_copy())
 }
 
+/// Rewrites `COPY <relation> [(<columns>)] TO ...` into a copy of
+/// `SELECT <columns> FROM <relation>`, which sends a snapshot of the relation.
+fn copy_to_relation_select(name: ResolvedObjectName, columns: Vec<Ident>) -> SelectStatement<Aug> {
+    let mut select = Select::default().from(TableWithJoins {
+        relation: TableFactor::Table {
+            name,
+            alias: None,
+            sample: None,
+        },
+        joins: vec![],
+    });
+    if columns.is_empty() {
+        select = select.project(SelectItem::Wildcard);
+    }
+    for column in columns {
+        select = select.project(SelectItem::Expr {
+            expr: Expr::Identifier(vec![column]),
+            alias: None,
+        });
+    }
+    SelectStatement {
+        query: Query::select(select),
+        as_of: None,
+    }
+}
+
 fn plan_copy_from(
     scx: &StatementContext,
     table_name: ResolvedObjectName,
@@ -614,7 +794,12 @@ pub fn plan_copy(
     }
     match (&direction, &target) {
         (CopyDirection::To, CopyTarget::Stdout) => match relation {
-            CopyRelation::Table { .. } => sql_bail!("table with COPY TO unsupported"),
+            CopyRelation::Table { name, columns } => Ok(plan_select(
+                scx,
+                copy_to_relation_select(name, columns),
+                &Params::empty(),
+                Some(format),
+            )?),
             CopyRelation::Select(stmt) => {
                 Ok(plan_select(scx, stmt, &Params::empty(), Some(format))?)
             }
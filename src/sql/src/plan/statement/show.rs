@@ -267,6 +267,20 @@ pub fn plan_show_create_connection(
     }
 }
 
+pub fn describe_show_create_catalog(_: &StatementContext) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(Some(
+        RelationDesc::empty()
+            .with_column("name", ScalarType::String.nullable(false))
+            .with_column("create_sql", ScalarType::String.nullable(false)),
+    )))
+}
+
+pub fn plan_show_create_catalog(_: &StatementContext) -> Result<Plan, PlanError> {
+    // The statements are generated by the coordinator, which has access to
+    // all objects in the catalog.
+    Ok(Plan::ShowCreateCatalog)
+}
+
 pub fn show_databases<'a>(
     scx: &'a StatementContext<'a>,
     ShowDatabasesStatement { filter }: ShowDatabasesStatement<Aug>,
@@ -301,14 +315,15 @@ pub fn show_objects<'a>(
     scx: &'a StatementContext<'a>,
     ShowObjectsStatement {
         object_type,
+        full,
         from,
         filter,
     }: ShowObjectsStatement<Aug>,
 ) -> Result<ShowSelect<'a>, PlanError> {
     match object_type {
         ShowObjectType::Table => show_tables(scx, from, filter),
-        ShowObjectType::Source => show_sources(scx, from, filter),
-        ShowObjectType::View => show_views(scx, from, filter),
+        ShowObjectType::Source => show_sources(scx, from, full, filter),
+        ShowObjectType::View => show_views(scx, from, full, filter),
         ShowObjectType::Sink => show_sinks(scx, from, filter),
         ShowObjectType::Type => show_types(scx, from, filter),
         ShowObjectType::Object => show_all_objects(scx, from, filter),
@@ -318,7 +333,7 @@ pub fn show_objects<'a>(
         ShowObjectType::Secret => show_secrets(scx, from, filter),
         ShowObjectType::Connection => show_connections(scx, from, filter),
         ShowObjectType::MaterializedView { in_cluster } => {
-            show_materialized_views(scx, from, in_cluster, filter)
+            show_materialized_views(scx, from, in_cluster, full, filter)
         }
         ShowObjectType::Index {
             in_cluster,
@@ -358,28 +373,46 @@ fn show_tables<'a>(
 fn show_sources<'a>(
     scx: &'a StatementContext<'a>,
     from: Option<ResolvedSchemaName>,
+    full: bool,
     filter: Option<ShowStatementFilter<Aug>>,
 ) -> Result<ShowSelect<'a>, PlanError> {
     let schema_spec = scx.resolve_optional_schema(&from)?;
-    let query = format!(
-        "SELECT name, type, size
-        FROM mz_catalog.mz_sources
-        WHERE schema_id = {schema_spec}"
-    );
+    let query = if full {
+        format!(
+            "SELECT name, type, size, storage_bytes
+            FROM mz_internal.mz_show_full_sources
+            WHERE schema_id = {schema_spec}"
+        )
+    } else {
+        format!(
+            "SELECT name, type, size
+            FROM mz_catalog.mz_sources
+            WHERE schema_id = {schema_spec}"
+        )
+    };
     ShowSelect::new(scx, query, filter, None, None)
 }
 
 fn show_views<'a>(
     scx: &'a StatementContext<'a>,
     from: Option<ResolvedSchemaName>,
+    full: bool,
     filter: Option<ShowStatementFilter<Aug>>,
 ) -> Result<ShowSelect<'a>, PlanError> {
     let schema_spec = scx.resolve_optional_schema(&from)?;
-    let query = format!(
-        "SELECT name
-        FROM mz_catalog.mz_views
-        WHERE schema_id = {schema_spec}"
-    );
+    let query = if full {
+        format!(
+            "SELECT name, indexed, frontier
+            FROM mz_internal.mz_show_full_views
+            WHERE schema_id = {schema_spec}"
+        )
+    } else {
+        format!(
+            "SELECT name
+            FROM mz_catalog.mz_views
+            WHERE schema_id = {schema_spec}"
+        )
+    };
     ShowSelect::new(scx, query, filter, None, None)
 }
 
@@ -387,6 +420,7 @@ fn show_materialized_views<'a>(
     scx: &'a StatementContext<'a>,
     from: Option<ResolvedSchemaName>,
     in_cluster: Option<ResolvedClusterName>,
+    full: bool,
     filter: Option<ShowStatementFilter<Aug>>,
 ) -> Result<ShowSelect<'a>, PlanError> {
     let schema_spec = scx.resolve_optional_schema(&from)?;
@@ -397,11 +431,19 @@ fn show_materialized_views<'a>(
             .expect("write on string cannot fail");
     }
 
-    let query = format!(
-        "SELECT name, cluster
-         FROM mz_internal.mz_show_materialized_views
-         WHERE {where_clause}"
-    );
+    let query = if full {
+        format!(
+            "SELECT name, cluster, frontier, storage_bytes
+             FROM mz_internal.mz_show_full_materialized_views
+             WHERE {where_clause}"
+        )
+    } else {
+        format!(
+            "SELECT name, cluster
+             FROM mz_internal.mz_show_materialized_views
+             WHERE {where_clause}"
+        )
+    };
 
     ShowSelect::new(scx, query, filter, None, None)
 }
@@ -19,8 +19,8 @@ use crate::names::Aug;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
-    Expr, Function, FunctionArgs, Ident, Op, OrderByExpr, Query, Select, SelectItem, TableAlias,
-    TableFactor, TableFunction, TableWithJoins, UnresolvedObjectName,
+    Expr, Function, FunctionArgs, HomogenizingFunction, Ident, Op, OrderByExpr, Query, Select,
+    SelectItem, TableAlias, TableFactor, TableFunction, TableWithJoins, UnresolvedObjectName,
 };
 
 use crate::normalize;
@@ -158,13 +158,44 @@ impl<'a> FuncRewriter<'a> {
         //
         let expr = expr.call_unary(vec!["mz_internal", "mz_avg_promotion"]);
         let expr_squared = expr.clone().multiply(expr.clone());
-        let sum_squares = Self::plan_agg(
-            UnresolvedObjectName::qualified(&["pg_catalog", "sum"]),
-            expr_squared,
-            vec![],
-            filter.clone(),
-            distinct,
-        );
+        let sum_squares = if distinct {
+            // Squaring is not injective, so `sum(DISTINCT x²)` would count
+            // `x` and `-x` only once. Squaring is injective over values of the
+            // same sign, though, so we instead compute
+            //
+            //     coalesce(sum(DISTINCT x²) FILTER (WHERE x >= 0), 0) +
+            //     coalesce(sum(DISTINCT x²) FILTER (WHERE x < 0), 0)
+            //
+            let sum_squares_where = |cond: Expr<Aug>| {
+                let filter = match &filter {
+                    Some(filter) => (**filter).clone().and(cond),
+                    None => cond,
+                };
+                Expr::HomogenizingFunction {
+                    function: HomogenizingFunction::Coalesce,
+                    exprs: vec![
+                        Self::plan_agg(
+                            UnresolvedObjectName::qualified(&["pg_catalog", "sum"]),
+                            expr_squared.clone(),
+                            vec![],
+                            Some(Box::new(filter)),
+                            true,
+                        ),
+                        Expr::number("0"),
+                    ],
+                }
+            };
+            sum_squares_where(expr.clone().gt_eq(Expr::number("0")))
+                .plus(sum_squares_where(expr.clone().lt(Expr::number("0"))))
+        } else {
+            Self::plan_agg(
+                UnresolvedObjectName::qualified(&["pg_catalog", "sum"]),
+                expr_squared,
+                vec![],
+                filter.clone(),
+                distinct,
+            )
+        };
         let sum = Self::plan_agg(
             UnresolvedObjectName::qualified(&["pg_catalog", "sum"]),
             expr.clone(),
@@ -456,6 +456,8 @@ static VALID_CASTS: Lazy<HashMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         (String, Bytes) => Explicit: CastStringToBytes(func::CastStringToBytes),
         (String, Jsonb) => Explicit: CastStringToJsonb(func::CastStringToJsonb),
         (String, Uuid) => Explicit: CastStringToUuid(func::CastStringToUuid),
+        (String, Point) => Explicit: CastStringToPoint(func::CastStringToPoint),
+        (String, BoundingBox) => Explicit: CastStringToBoundingBox(func::CastStringToBoundingBox),
         (String, Array) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
             let return_ty = to_type.clone();
             let to_el_type = to_type.unwrap_array_element_type();
@@ -623,6 +625,12 @@ static VALID_CASTS: Lazy<HashMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         // UUID
         (Uuid, String) => Assignment: CastUuidToString(func::CastUuidToString),
 
+        // POINT
+        (Point, String) => Assignment: CastPointToString(func::CastPointToString),
+
+        // BOX
+        (BoundingBox, String) => Assignment: CastBoundingBoxToString(func::CastBoundingBoxToString),
+
         // Numeric
         (Numeric, Numeric) => Assignment: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
             let scale = to_type.unwrap_numeric_max_scale();
@@ -11,6 +11,7 @@
 
 use mz_sql_parser::ast::KafkaBroker;
 use mz_sql_parser::ast::ReplicaDefinition;
+use mz_sql_parser::ast::SourceRetention;
 use serde::{Deserialize, Serialize};
 
 use mz_repr::adt::interval::Interval;
@@ -31,6 +32,12 @@ pub trait ImpliedValue: Sized {
     fn implied_value() -> Result<Self, PlanError>;
 }
 
+/// Converts a planned option back into a value that plans to it, so that the
+/// defaulted options of a statement can be recorded in its definition.
+pub trait IntoValue {
+    fn into_value(self) -> WithOptionValue<Aug>;
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct Secret(GlobalId);
 
@@ -109,6 +116,12 @@ impl ImpliedValue for UnresolvedObjectName {
     }
 }
 
+impl IntoValue for UnresolvedObjectName {
+    fn into_value(self) -> WithOptionValue<Aug> {
+        WithOptionValue::UnresolvedObjectName(self)
+    }
+}
+
 impl TryFromValue<WithOptionValue<Aug>> for ResolvedDataType {
     fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
         Ok(match v {
@@ -218,6 +231,12 @@ impl ImpliedValue for String {
     }
 }
 
+impl IntoValue for String {
+    fn into_value(self) -> WithOptionValue<Aug> {
+        WithOptionValue::Value(Value::String(self))
+    }
+}
+
 impl TryFromValue<Value> for bool {
     fn try_from_value(v: Value) -> Result<Self, PlanError> {
         match v {
@@ -236,6 +255,12 @@ impl ImpliedValue for bool {
     }
 }
 
+impl IntoValue for bool {
+    fn into_value(self) -> WithOptionValue<Aug> {
+        WithOptionValue::Value(Value::Boolean(self))
+    }
+}
+
 impl TryFromValue<Value> for f64 {
     fn try_from_value(v: Value) -> Result<Self, PlanError> {
         match v {
@@ -277,6 +302,12 @@ impl ImpliedValue for i32 {
     }
 }
 
+impl IntoValue for i32 {
+    fn into_value(self) -> WithOptionValue<Aug> {
+        WithOptionValue::Value(Value::Number(self.to_string()))
+    }
+}
+
 impl TryFromValue<Value> for i64 {
     fn try_from_value(v: Value) -> Result<Self, PlanError> {
         match v {
@@ -317,6 +348,12 @@ impl ImpliedValue for u16 {
     }
 }
 
+impl IntoValue for u16 {
+    fn into_value(self) -> WithOptionValue<Aug> {
+        WithOptionValue::Value(Value::Number(self.to_string()))
+    }
+}
+
 impl TryFromValue<Value> for u32 {
     fn try_from_value(v: Value) -> Result<Self, PlanError> {
         match v {
@@ -384,6 +421,12 @@ impl<V: ImpliedValue> ImpliedValue for Vec<V> {
     }
 }
 
+impl<V: IntoValue> IntoValue for Vec<V> {
+    fn into_value(self) -> WithOptionValue<Aug> {
+        WithOptionValue::Sequence(self.into_iter().map(V::into_value).collect())
+    }
+}
+
 impl<T: AstInfo, V: TryFromValue<WithOptionValue<T>>> TryFromValue<WithOptionValue<T>>
     for Option<V>
 {
@@ -413,7 +456,8 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
             | WithOptionValue::Secret(_)
             | WithOptionValue::DataType(_)
             | WithOptionValue::ClusterReplicas(_)
-            | WithOptionValue::ConnectionKafkaBroker(_) => sql_bail!(
+            | WithOptionValue::ConnectionKafkaBroker(_)
+            | WithOptionValue::SourceRetention(_) => sql_bail!(
                 "incompatible value types: cannot convert {} to {}",
                 match v {
                     WithOptionValue::Sequence(_) => "sequences",
@@ -423,6 +467,7 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
                     WithOptionValue::DataType(_) => "data types",
                     WithOptionValue::ClusterReplicas(_) => "cluster replicas",
                     WithOptionValue::ConnectionKafkaBroker(_) => "connection kafka brokers",
+                    WithOptionValue::SourceRetention(_) => "source retentions",
                     _ => unreachable!(),
                 },
                 V::name()
@@ -490,3 +535,21 @@ impl ImpliedValue for Vec<KafkaBroker<Aug>> {
         sql_bail!("must provide a kafka broker")
     }
 }
+
+impl TryFromValue<WithOptionValue<Aug>> for SourceRetention {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::SourceRetention(retention) => Ok(retention),
+            _ => sql_bail!("cannot use value as a source retention"),
+        }
+    }
+    fn name() -> String {
+        "source retention".to_string()
+    }
+}
+
+impl ImpliedValue for SourceRetention {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a source retention")
+    }
+}
@@ -205,6 +205,10 @@ pub async fn purify_create_source(
         CreateSourceConnection::TestScript { desc_json: _ } => {
             // TODO: verify valid json and valid schema
         }
+        CreateSourceConnection::Http { .. } => {
+            // The endpoint is not contacted during purification, as it may
+            // be unavailable at the time the source is created.
+        }
         CreateSourceConnection::S3 { connection, .. } => {
             let scx = StatementContext::new(None, &*catalog);
             let aws = {
@@ -483,6 +487,124 @@ pub async fn purify_create_source(
                 )))),
             })
         }
+        CreateSourceConnection::PostgresTable {
+            connection,
+            table,
+            options,
+        } => {
+            if requested_subsources.is_some() {
+                sql_bail!(
+                    "FOR TABLES (..) and FOR ALL TABLES are not valid for POSTGRES TABLE sources"
+                );
+            }
+
+            let scx = StatementContext::new(None, &*catalog);
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                match item.connection()? {
+                    Connection::Postgres(connection) => connection.clone(),
+                    _ => sql_bail!("{} is not a postgres connection", item.name()),
+                }
+            };
+            let crate::plan::statement::PgConfigOptionExtracted {
+                publication,
+                text_columns,
+                ..
+            } = options.clone().try_into()?;
+            if publication.is_some() {
+                sql_bail!("POSTGRES TABLE sources do not support PUBLICATION");
+            }
+
+            let (namespace, name) = resolve_upstream_name("table", table, &connection.database)?;
+
+            // verify that we can connect upstream and snapshot the table's metadata
+            let config = connection
+                .config(&*connection_context.secrets_reader)
+                .await?;
+            let table_desc = mz_postgres_util::table_info(&config, &namespace, &name)
+                .await
+                .map_err(|cause| PlanError::FetchingPostgresTableInfoFailed {
+                    cause: Arc::new(cause),
+                })?;
+
+            validate_upstream_columns(
+                "POSTGRES TABLE",
+                &connection.database,
+                &table_desc,
+                text_columns,
+            )?;
+
+            // Normalize the table name to be fully qualified.
+            *table = UnresolvedObjectName::qualified(&[&table_desc.namespace, &table_desc.name]);
+
+            // Remove any old detail references
+            options.retain(|PgConfigOption { name, .. }| name != &PgConfigOptionName::Details);
+            options.push(PgConfigOption {
+                name: PgConfigOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    table_desc.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
+        CreateSourceConnection::Materialize {
+            connection,
+            view,
+            options,
+        } => {
+            if requested_subsources.is_some() {
+                sql_bail!(
+                    "FOR TABLES (..) and FOR ALL TABLES are not valid for MATERIALIZE sources"
+                );
+            }
+
+            let scx = StatementContext::new(None, &*catalog);
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                match item.connection()? {
+                    Connection::Postgres(connection) => connection.clone(),
+                    _ => sql_bail!("{} is not a postgres connection", item.name()),
+                }
+            };
+            let crate::plan::statement::PgConfigOptionExtracted {
+                publication,
+                text_columns,
+                ..
+            } = options.clone().try_into()?;
+            if publication.is_some() {
+                sql_bail!("MATERIALIZE sources do not support PUBLICATION");
+            }
+
+            let (namespace, name) = resolve_upstream_name("view", view, &connection.database)?;
+
+            // verify that we can connect upstream and describe the view
+            let config = connection
+                .config(&*connection_context.secrets_reader)
+                .await?;
+            let view_desc = mz_postgres_util::view_info(&config, &namespace, &name)
+                .await
+                .map_err(|cause| PlanError::FetchingMaterializeViewInfoFailed {
+                    cause: Arc::new(cause),
+                })?;
+
+            validate_upstream_columns(
+                "MATERIALIZE",
+                &connection.database,
+                &view_desc,
+                text_columns,
+            )?;
+
+            // Normalize the view name to be fully qualified.
+            *view = UnresolvedObjectName::qualified(&[&view_desc.namespace, &view_desc.name]);
+
+            // Remove any old detail references
+            options.retain(|PgConfigOption { name, .. }| name != &PgConfigOptionName::Details);
+            options.push(PgConfigOption {
+                name: PgConfigOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    view_desc.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
         CreateSourceConnection::LoadGenerator { generator, options } => {
             let scx = StatementContext::new(None, &*catalog);
 
@@ -628,6 +750,99 @@ pub async fn purify_create_source(
     Ok((subsources, stmt))
 }
 
+/// Resolves the name of a relation in an upstream database into its schema
+/// and name. Unqualified names are resolved in the upstream `public` schema.
+fn resolve_upstream_name(
+    object_type: &str,
+    name: &UnresolvedObjectName,
+    database: &str,
+) -> Result<(String, String), PlanError> {
+    match &name.0[..] {
+        [item] => Ok(("public".to_string(), item.as_str().to_string())),
+        [namespace, item] => Ok((namespace.as_str().to_string(), item.as_str().to_string())),
+        [db, namespace, item] if db.as_str() == database => {
+            Ok((namespace.as_str().to_string(), item.as_str().to_string()))
+        }
+        [_, _, _] => sql_bail!(
+            "{} {} is not in the connection's database {}",
+            object_type,
+            name,
+            database.quoted()
+        ),
+        _ => sql_bail!("{} name {} has too many components", object_type, name),
+    }
+}
+
+/// Validates the `TEXT COLUMNS` of a source that ingests a single upstream
+/// relation, and that all of the relation's other columns have types that
+/// Materialize supports.
+fn validate_upstream_columns(
+    source_type: &str,
+    database: &str,
+    desc: &mz_postgres_util::desc::PostgresTableDesc,
+    text_columns: Vec<UnresolvedObjectName>,
+) -> Result<(), PlanError> {
+    let upstream_name = UnresolvedObjectName::qualified(&[database, &desc.namespace, &desc.name]);
+
+    let mut text_cols = HashSet::new();
+    for name in text_columns {
+        let col = match &name.0[..] {
+            [col] => col.as_str().to_string(),
+            _ => {
+                return Err(PlanError::InvalidOptionValue {
+                    option_name: PgConfigOptionName::TextColumns.to_ast_string(),
+                    err: Box::new(sql_err!(
+                        "{} sources require unqualified column names, got {}",
+                        source_type,
+                        name
+                    )),
+                });
+            }
+        };
+        if !desc.columns.iter().any(|column| column.name == col) {
+            return Err(PlanError::InvalidOptionValue {
+                option_name: PgConfigOptionName::TextColumns.to_ast_string(),
+                err: Box::new(PlanError::UnknownColumn {
+                    table: Some(
+                        normalize::unresolved_object_name(upstream_name.clone())
+                            .expect("known to be of valid len"),
+                    ),
+                    column: mz_repr::ColumnName::from(col),
+                }),
+            });
+        }
+        if !text_cols.insert(col) {
+            return Err(PlanError::InvalidOptionValue {
+                option_name: PgConfigOptionName::TextColumns.to_ast_string(),
+                err: Box::new(PlanError::UnexpectedDuplicateReference { name }),
+            });
+        }
+    }
+
+    let unsupported_cols: Vec<_> = desc
+        .columns
+        .iter()
+        .filter(|c| {
+            !text_cols.contains(&c.name)
+                && mz_pgrepr::Type::from_oid_and_typmod(c.type_oid, c.type_mod).is_err()
+        })
+        .map(|c| {
+            let mut full_name = upstream_name.0.clone();
+            full_name.push(Ident::new(c.name.clone()));
+            (
+                UnresolvedObjectName(full_name).to_ast_string(),
+                Oid(c.type_oid),
+            )
+        })
+        .collect();
+    if !unsupported_cols.is_empty() {
+        return Err(PlanError::UnrecognizedTypeInPostgresSource {
+            cols: unsupported_cols,
+        });
+    }
+    Ok(())
+}
+
 async fn purify_source_format(
     catalog: &dyn SessionCatalog,
     format: &mut CreateSourceFormat<Aug>,
@@ -638,11 +853,15 @@ async fn purify_source_format(
     if matches!(format, CreateSourceFormat::KeyValue { .. })
         && !matches!(
             connection,
-            CreateSourceConnection::Kafka { .. } | CreateSourceConnection::TestScript { .. }
+            CreateSourceConnection::Kafka { .. }
+                | CreateSourceConnection::Http { .. }
+                | CreateSourceConnection::TestScript { .. }
         )
     {
         // We don't mention `TestScript` to users here
-        sql_bail!("Kafka sources are the only source type that can provide KEY/VALUE formats")
+        sql_bail!(
+            "Kafka and HTTP sources are the only source types that can provide KEY/VALUE formats"
+        )
     }
 
     match format {
@@ -913,18 +1132,24 @@ async fn compile_proto(
         .build_file_descriptor_set(&[Path::new(&primary_subject.name)])
         .map_err(|cause| PlanError::InvalidProtobufSchema { cause })?;
 
-    // Ensure there is exactly one message in the file.
-    let primary_fd = fds.file(0);
-    let message_name = match primary_fd.message_type_size() {
-        1 => String::from_utf8_lossy(primary_fd.message_type(0).name()).into_owned(),
-        0 => bail_unsupported!(9598, "Protobuf schemas with no messages"),
+    let bytes = &fds
+        .serialize()
+        .map_err(|cause| PlanError::InvalidProtobufSchema { cause })?;
+
+    // Ensure there is exactly one message in the file that is not merely used
+    // as the type of a field of another message.
+    if fds.file(0).message_type_size() == 0 {
+        bail_unsupported!(9598, "Protobuf schemas with no messages");
+    }
+    let mut root_message_names =
+        mz_interchange::protobuf::root_message_names(bytes, &primary_subject.name)
+            .map_err(|e| sql_err!("{:#}", e))?;
+    let message_name = match root_message_names.len() {
+        1 => root_message_names.remove(0),
         _ => bail_unsupported!(9598, "Protobuf schemas with multiple messages"),
     };
 
     // Encode the file descriptor set into a SQL byte string.
-    let bytes = &fds
-        .serialize()
-        .map_err(|cause| PlanError::InvalidProtobufSchema { cause })?;
     let mut schema = String::new();
     strconv::format_bytes(&mut schema, bytes);
 
@@ -303,6 +303,14 @@ impl SessionCatalog for TestCatalog {
         true
     }
 
+    fn default_timestamp_interval(&self) -> Duration {
+        self.config().timestamp_interval
+    }
+
+    fn default_introspection_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
     fn now(&self) -> EpochMillis {
         (self.config().now)()
     }
@@ -388,6 +388,8 @@ impl<'a> FromSql<'a> for Slt {
                 DateTime::<Utc>::from_sql(ty, raw)?.try_into()?,
             )),
             PgType::UUID => Self(Value::Uuid(Uuid::from_sql(ty, raw)?)),
+            PgType::POINT => Self(Value::decode_binary(&mz_pgrepr::Type::Point, raw)?),
+            PgType::BOX => Self(Value::decode_binary(&mz_pgrepr::Type::Box, raw)?),
             PgType::RECORD => {
                 let num_fields = read_be_i32(&mut raw)?;
                 let mut tuple = vec![];
@@ -484,6 +486,8 @@ impl<'a> FromSql<'a> for Slt {
                 | PgType::TIMESTAMP
                 | PgType::TIMESTAMPTZ
                 | PgType::UUID
+                | PgType::POINT
+                | PgType::BOX
         )
     }
 }
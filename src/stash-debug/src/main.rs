@@ -144,6 +144,7 @@ async fn main() {
     let args = cli::parse_args(CliConfig {
         env_prefix: Some("MZ_STASH_DEBUG_"),
         enable_version_flag: true,
+        enable_config_file: false,
     });
     if let Err(err) = run(args).await {
         eprintln!("stash: {:#}", err);
@@ -299,6 +300,7 @@ impl Usage {
                 dump_col!(catalog::COLLECTION_SYSTEM_CONFIGURATION);
                 dump_col!(catalog::COLLECTION_AUDIT_LOG);
                 dump_col!(catalog::COLLECTION_STORAGE_USAGE);
+                dump_col!(catalog::COLLECTION_REGISTRY_SCHEMA);
             }
             Usage::Storage => {
                 dump_col!(storage::METADATA_COLLECTION);
@@ -356,6 +358,7 @@ impl Usage {
                 edit_col!(catalog::COLLECTION_SYSTEM_CONFIGURATION);
                 edit_col!(catalog::COLLECTION_AUDIT_LOG);
                 edit_col!(catalog::COLLECTION_STORAGE_USAGE);
+                edit_col!(catalog::COLLECTION_REGISTRY_SCHEMA);
             }
             Usage::Storage => {
                 edit_col!(storage::METADATA_COLLECTION);
@@ -17,6 +17,7 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::iter;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use differential_dataflow::lattice::Lattice;
@@ -114,6 +115,9 @@ pub enum StorageCommand<T = mz_repr::Timestamp> {
     /// accumulations must be correct.
     AllowCompaction(Vec<(GlobalId, Antichain<T>)>),
     CreateSinks(Vec<CreateSinkCommand<T>>),
+    /// Update the granularity with which the enumerated ingestions mint new
+    /// timestamps, without restarting them.
+    UpdateTimestampIntervals(Vec<(GlobalId, Duration)>),
 }
 
 /// A command that starts ingesting the given ingestion description
@@ -228,6 +232,11 @@ impl RustType<ProtoStorageCommand> for StorageCommand<mz_repr::Timestamp> {
                 StorageCommand::CreateSinks(sinks) => CreateSinks(ProtoCreateSinks {
                     sinks: sinks.into_proto(),
                 }),
+                StorageCommand::UpdateTimestampIntervals(intervals) => {
+                    UpdateTimestampIntervals(ProtoUpdateTimestampIntervals {
+                        intervals: intervals.into_proto(),
+                    })
+                }
             }),
         }
     }
@@ -248,6 +257,9 @@ impl RustType<ProtoStorageCommand> for StorageCommand<mz_repr::Timestamp> {
             Some(CreateSinks(ProtoCreateSinks { sinks })) => {
                 Ok(StorageCommand::CreateSinks(sinks.into_rust()?))
             }
+            Some(UpdateTimestampIntervals(ProtoUpdateTimestampIntervals { intervals })) => Ok(
+                StorageCommand::UpdateTimestampIntervals(intervals.into_rust()?),
+            ),
             None => Err(TryFromProtoError::missing_field(
                 "ProtoStorageCommand::kind",
             )),
@@ -283,6 +295,9 @@ impl Arbitrary for StorageCommand<mz_repr::Timestamp> {
                 )
             })
             .boxed(),
+            proptest::collection::vec((any::<GlobalId>(), any::<Duration>()), 1..4)
+                .prop_map(StorageCommand::UpdateTimestampIntervals)
+                .boxed(),
         ])
     }
 }
@@ -301,6 +316,40 @@ pub struct SourceStatisticsUpdate {
     pub updates_staged: u64,
     pub updates_committed: u64,
     pub bytes_received: u64,
+    /// The number of messages that were skipped because their schema could
+    /// not be resolved.
+    pub messages_quarantined: u64,
+    /// The error of the most recently quarantined message, if any.
+    pub last_quarantine_error: Option<String>,
+    /// The statistics that librdkafka last reported for the consumer of a
+    /// Kafka source, if the source is a Kafka source.
+    pub kafka_consumer: Option<KafkaConsumerStatistics>,
+}
+
+/// Statistics of the consumer of a Kafka source, as reported by librdkafka,
+/// for the `mz_kafka_consumer_statistics` table.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct KafkaConsumerStatistics {
+    /// The number of consumer group rebalances.
+    pub rebalances: u64,
+    /// The statistics of each broker that the consumer is connected to.
+    pub brokers: Vec<KafkaBrokerStatistics>,
+}
+
+/// Statistics of a broker that the consumer of a Kafka source is connected to.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct KafkaBrokerStatistics {
+    /// The name of the broker, as `host:port/node_id`.
+    pub broker: String,
+    /// The state of the connection to the broker, e.g. `UP` or `DOWN`.
+    pub state: String,
+    /// The number of errors receiving from the broker.
+    pub rx_errors: u64,
+    /// The number of errors sending to the broker.
+    pub tx_errors: u64,
+    /// The average time that the broker throttled requests, in milliseconds,
+    /// over the last statistics interval.
+    pub throttle_time_avg_ms: u64,
 }
 
 /// Responses that the storage nature of a worker/dataflow can provide back to the coordinator.
@@ -321,7 +370,8 @@ pub enum StorageResponse<T = mz_repr::Timestamp> {
 impl RustType<ProtoStorageResponse> for StorageResponse<mz_repr::Timestamp> {
     fn into_proto(&self) -> ProtoStorageResponse {
         use proto_storage_response::{
-            Kind::*, ProtoDroppedIds, ProtoSourceStatisticsUpdate, ProtoStatisticsUpdates,
+            Kind::*, ProtoDroppedIds, ProtoKafkaBrokerStatistics, ProtoKafkaConsumerStatistics,
+            ProtoSourceStatisticsUpdate, ProtoStatisticsUpdates,
         };
         ProtoStorageResponse {
             kind: Some(match self {
@@ -340,6 +390,24 @@ impl RustType<ProtoStorageResponse> for StorageResponse<mz_repr::Timestamp> {
                             updates_staged: update.updates_staged,
                             updates_committed: update.updates_committed,
                             bytes_received: update.bytes_received,
+                            messages_quarantined: update.messages_quarantined,
+                            last_quarantine_error: update.last_quarantine_error.clone(),
+                            kafka_consumer: update.kafka_consumer.as_ref().map(|consumer| {
+                                ProtoKafkaConsumerStatistics {
+                                    rebalances: consumer.rebalances,
+                                    brokers: consumer
+                                        .brokers
+                                        .iter()
+                                        .map(|broker| ProtoKafkaBrokerStatistics {
+                                            broker: broker.broker.clone(),
+                                            state: broker.state.clone(),
+                                            rx_errors: broker.rx_errors,
+                                            tx_errors: broker.tx_errors,
+                                            throttle_time_avg_ms: broker.throttle_time_avg_ms,
+                                        })
+                                        .collect(),
+                                }
+                            }),
                         })
                         .collect(),
                 }),
@@ -371,6 +439,24 @@ impl RustType<ProtoStorageResponse> for StorageResponse<mz_repr::Timestamp> {
                             updates_staged: update.updates_staged,
                             updates_committed: update.updates_committed,
                             bytes_received: update.bytes_received,
+                            messages_quarantined: update.messages_quarantined,
+                            last_quarantine_error: update.last_quarantine_error,
+                            kafka_consumer: update.kafka_consumer.map(|consumer| {
+                                KafkaConsumerStatistics {
+                                    rebalances: consumer.rebalances,
+                                    brokers: consumer
+                                        .brokers
+                                        .into_iter()
+                                        .map(|broker| KafkaBrokerStatistics {
+                                            broker: broker.broker,
+                                            state: broker.state,
+                                            rx_errors: broker.rx_errors,
+                                            tx_errors: broker.tx_errors,
+                                            throttle_time_avg_ms: broker.throttle_time_avg_ms,
+                                        })
+                                        .collect(),
+                                }
+                            }),
                         })
                     })
                     .collect::<Result<Vec<_>, TryFromProtoError>>()?,
@@ -459,7 +545,8 @@ where
             }
             StorageCommand::InitializationComplete
             | StorageCommand::UpdateConfiguration(_)
-            | StorageCommand::AllowCompaction(_) => {
+            | StorageCommand::AllowCompaction(_)
+            | StorageCommand::UpdateTimestampIntervals(_) => {
                 // Other commands have no known impact on frontier tracking.
             }
         }
@@ -610,6 +697,24 @@ impl RustType<ProtoCompaction> for (GlobalId, Antichain<mz_repr::Timestamp>) {
     }
 }
 
+impl RustType<ProtoTimestampInterval> for (GlobalId, Duration) {
+    fn into_proto(&self) -> ProtoTimestampInterval {
+        ProtoTimestampInterval {
+            id: Some(self.0.into_proto()),
+            interval: Some(self.1.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoTimestampInterval) -> Result<Self, TryFromProtoError> {
+        Ok((
+            proto.id.into_rust_if_some("ProtoTimestampInterval::id")?,
+            proto
+                .interval
+                .into_rust_if_some("ProtoTimestampInterval::interval")?,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mz_proto::protobuf_roundtrip;
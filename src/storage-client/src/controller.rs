@@ -25,6 +25,7 @@ use std::fmt::{self, Debug};
 use std::num::NonZeroI64;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use bytes::BufMut;
@@ -114,6 +115,8 @@ pub enum IntrospectionType {
     // Note that this single-shard introspection source will be changed to per-replica,
     // once we allow multiplexing multiple sources/sinks on a single cluster.
     StorageSourceStatistics,
+    StorageSinkFrontierLagHistogram,
+    StorageKafkaConsumerStatistics,
 }
 
 /// Describes how data is written to the collection.
@@ -219,6 +222,16 @@ pub trait StorageController: Debug + Send {
         collections: Vec<(GlobalId, StorageHostConfig)>,
     ) -> Result<(), StorageError>;
 
+    /// Changes the granularity with which the identified ingestions mint new
+    /// timestamps.
+    ///
+    /// The change is applied to running ingestions in place, and is used by
+    /// any future instantiation of the ingestions.
+    async fn alter_timestamp_intervals(
+        &mut self,
+        intervals: Vec<(GlobalId, Duration)>,
+    ) -> Result<(), StorageError>;
+
     /// Acquire an immutable reference to the export state, should it exist.
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError>;
 
@@ -621,6 +634,9 @@ pub struct StorageControllerState<T: Timestamp + Lattice + Codec64 + TimestampMa
     /// and its contents are entirely driven by `StorageResponse::StatisticsUpdates`'s.
     source_statistics:
         Arc<std::sync::Mutex<HashMap<GlobalId, HashMap<usize, SourceStatisticsUpdate>>>>,
+    /// Histograms of the frontier lag of sinks with latency tracking enabled, keyed by
+    /// sink and bucket. Maintained as sink write frontiers advance and periodically written.
+    sink_frontier_lags: Arc<std::sync::Mutex<HashMap<GlobalId, BTreeMap<u64, u64>>>>,
 }
 
 /// A storage controller for a storage instance.
@@ -645,6 +661,8 @@ pub enum StorageError {
     SourceIdReused(GlobalId),
     /// The source identifier is not present.
     IdentifierMissing(GlobalId),
+    /// The source identifier does not identify an ingestion.
+    IdentifierNotIngestion(GlobalId),
     /// The update contained in the appended batch was at a timestamp equal or beyond the batch's upper
     UpdateBeyondUpper(GlobalId),
     /// The read was at a timestamp before the collection's since
@@ -664,6 +682,7 @@ impl Error for StorageError {
         match self {
             Self::SourceIdReused(_) => None,
             Self::IdentifierMissing(_) => None,
+            Self::IdentifierNotIngestion(_) => None,
             Self::UpdateBeyondUpper(_) => None,
             Self::ReadBeforeSince(_) => None,
             Self::InvalidUppers(_) => None,
@@ -683,6 +702,9 @@ impl fmt::Display for StorageError {
                 "source identifier was re-created after having been dropped: {id}"
             ),
             Self::IdentifierMissing(id) => write!(f, "collection identifier is not present: {id}"),
+            Self::IdentifierNotIngestion(id) => {
+                write!(f, "collection identifier is not an ingestion: {id}")
+            }
             Self::UpdateBeyondUpper(id) => {
                 write!(
                     f,
@@ -766,6 +788,7 @@ impl<T: Timestamp + Lattice + Codec64 + From<EpochMillis> + TimestampManipulatio
             now,
             envd_epoch,
             source_statistics: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            sink_frontier_lags: Arc::new(std::sync::Mutex::new(HashMap::new())),
         }
     }
 }
@@ -773,7 +796,13 @@ impl<T: Timestamp + Lattice + Codec64 + From<EpochMillis> + TimestampManipulatio
 #[async_trait(?Send)]
 impl<T> StorageController for Controller<T>
 where
-    T: Timestamp + Lattice + TotalOrder + Codec64 + From<EpochMillis> + TimestampManipulation,
+    T: Timestamp
+        + Lattice
+        + TotalOrder
+        + Codec64
+        + From<EpochMillis>
+        + Into<EpochMillis>
+        + TimestampManipulation,
     StorageCommand<T>: RustType<ProtoStorageCommand>,
     StorageResponse<T>: RustType<ProtoStorageResponse>,
     MetadataExportFetcher: MetadataExport<T>,
@@ -1066,6 +1095,36 @@ where
                             // dropped, so that the internal task will stop.
                             self.state.introspection_tokens.insert(id, scraper_token);
                         }
+                        IntrospectionType::StorageSinkFrontierLagHistogram => {
+                            // Set the collection to empty.
+                            self.reconcile_managed_collection(id, vec![]).await;
+
+                            let scraper_token = statistics::spawn_sink_frontier_lag_scraper(
+                                id.clone(),
+                                // These do a shallow copy.
+                                self.state.collection_manager.clone(),
+                                Arc::clone(&self.state.sink_frontier_lags),
+                            );
+
+                            // Make sure this is dropped when the controller is
+                            // dropped, so that the internal task will stop.
+                            self.state.introspection_tokens.insert(id, scraper_token);
+                        }
+                        IntrospectionType::StorageKafkaConsumerStatistics => {
+                            // Set the collection to empty.
+                            self.reconcile_managed_collection(id, vec![]).await;
+
+                            let scraper_token = statistics::spawn_kafka_consumer_statistics_scraper(
+                                id.clone(),
+                                // These do a shallow copy.
+                                self.state.collection_manager.clone(),
+                                Arc::clone(&self.state.source_statistics),
+                            );
+
+                            // Make sure this is dropped when the controller is
+                            // dropped, so that the internal task will stop.
+                            self.state.introspection_tokens.insert(id, scraper_token);
+                        }
                         IntrospectionType::SourceStatusHistory
                         | IntrospectionType::SinkStatusHistory => {
                             // nothing to do: these collections are append only
@@ -1089,6 +1148,38 @@ where
         Ok(())
     }
 
+    async fn alter_timestamp_intervals(
+        &mut self,
+        intervals: Vec<(GlobalId, Duration)>,
+    ) -> Result<(), StorageError> {
+        // Validate first, to avoid corrupting state.
+        for (id, _) in intervals.iter() {
+            match &self.collection(*id)?.description.data_source {
+                DataSource::Ingestion(_) => {}
+                DataSource::Introspection(_) | DataSource::Other => {
+                    return Err(StorageError::IdentifierNotIngestion(*id));
+                }
+            }
+        }
+
+        for (id, interval) in intervals {
+            if let DataSource::Ingestion(ingestion) =
+                &mut self.collection_mut(id)?.description.data_source
+            {
+                ingestion.desc.timestamp_interval = interval;
+            }
+
+            let client = self
+                .hosts
+                .client(id)
+                .ok_or(StorageError::IdentifierMissing(id))?;
+            client.send(StorageCommand::UpdateTimestampIntervals(vec![(
+                id, interval,
+            )]));
+        }
+        Ok(())
+    }
+
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError> {
         self.state
             .exports
@@ -1203,9 +1294,11 @@ where
                 description: StorageSinkDesc {
                     from: from_id,
                     from_desc: description.sink.from_desc,
+                    from_projection: description.sink.from_projection,
                     connection: description.sink.connection,
                     envelope: description.sink.envelope,
                     as_of,
+                    track_latency: description.sink.track_latency,
                     status_id,
                     from_storage_metadata,
                 },
@@ -1259,6 +1352,11 @@ where
             self.update_write_frontiers(&[(id, Antichain::new())]);
             let status_id = self.state.introspection_ids[&IntrospectionType::SinkStatusHistory];
             self.state.pending_host_deprovisions.insert((id, status_id));
+            self.state
+                .sink_frontier_lags
+                .lock()
+                .expect("poisoned")
+                .remove(&id);
         }
     }
 
@@ -1363,11 +1461,31 @@ where
             }
         }
 
+        let now = (self.state.now)();
+        let sink_frontier_lags = Arc::clone(&self.state.sink_frontier_lags);
+
         // Exports come first so we can update the collections below based on any new export write frontiers
         for (id, new_upper) in exports {
             let export = self
                 .export_mut(*id)
                 .expect("Export previously validated to exist");
+            if export.description.sink.track_latency
+                && PartialOrder::less_than(&export.write_frontier, new_upper)
+            {
+                // All updates before `new_upper` have been written by the sink. The time elapsed
+                // since the latest of their timestamps is the frontier lag of the sink. This only
+                // approximates the end-to-end latency of updates, as the frontier can also advance
+                // without any updates, and timestamps are not always minted on ingestion.
+                if let Some(ts) = new_upper.as_option().and_then(|ts| ts.step_back()) {
+                    let lag_ms = now.saturating_sub(ts.into());
+                    let mut sink_frontier_lags = sink_frontier_lags.lock().expect("poisoned");
+                    *sink_frontier_lags
+                        .entry(*id)
+                        .or_default()
+                        .entry(lag_ms.max(1).next_power_of_two())
+                        .or_default() += 1;
+                }
+            }
             export.write_frontier.join_assign(new_upper);
             collections.entry(export.from()).or_insert(None);
         }
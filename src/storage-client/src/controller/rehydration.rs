@@ -328,6 +328,15 @@ where
                 }
             }
             StorageCommand::AllowCompaction(_frontiers) => {}
+            StorageCommand::UpdateTimestampIntervals(intervals) => {
+                // Rehydrated ingestions must start out with the most recent
+                // timestamp interval.
+                for (id, interval) in intervals {
+                    if let Some(ingestion) = self.sources.get_mut(id) {
+                        ingestion.description.desc.timestamp_interval = *interval;
+                    }
+                }
+            }
         }
     }
 
@@ -10,7 +10,7 @@
 //! A tokio task (and support machinery) for producing storage statistics.
 
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -31,22 +31,135 @@ pub(super) fn spawn_statistics_scraper(
     collection_mgmt: CollectionManager,
     shared_stats: Arc<Mutex<HashMap<GlobalId, HashMap<usize, SourceStatisticsUpdate>>>>,
 ) -> Box<dyn Any + Send + Sync> {
+    spawn_scraper(
+        "statistics_scraper",
+        statistics_collection_id,
+        collection_mgmt,
+        move |row_buf, rows| {
+            let shared_stats = shared_stats.lock().expect("poisoned");
+
+            for (src_id, sources) in shared_stats.iter() {
+                for (worker_id, stats) in sources.iter() {
+                    let mut packer = row_buf.packer();
+
+                    packer.push(Datum::from(src_id.to_string().as_str()));
+                    packer.push(Datum::from(u64::cast_from(*worker_id)));
+                    packer.push(Datum::from(stats.snapshot_committed));
+                    packer.push(Datum::from(stats.messages_received));
+                    packer.push(Datum::from(stats.updates_staged));
+                    packer.push(Datum::from(stats.updates_committed));
+                    packer.push(Datum::from(stats.bytes_received));
+                    packer.push(Datum::from(stats.messages_quarantined));
+                    packer.push(Datum::from(stats.last_quarantine_error.as_deref()));
+
+                    rows.push(row_buf.clone());
+                }
+            }
+        },
+    )
+}
+
+/// Spawns a task that continually (at an interval) writes the statistics of the consumers of Kafka
+/// sources, which are part of the storaged statistics consolidated in shared memory in the
+/// controller.
+pub(super) fn spawn_kafka_consumer_statistics_scraper(
+    statistics_collection_id: GlobalId,
+    collection_mgmt: CollectionManager,
+    shared_stats: Arc<Mutex<HashMap<GlobalId, HashMap<usize, SourceStatisticsUpdate>>>>,
+) -> Box<dyn Any + Send + Sync> {
+    spawn_scraper(
+        "kafka_consumer_statistics_scraper",
+        statistics_collection_id,
+        collection_mgmt,
+        move |row_buf, rows| {
+            let shared_stats = shared_stats.lock().expect("poisoned");
+
+            for (src_id, sources) in shared_stats.iter() {
+                for (worker_id, stats) in sources.iter() {
+                    let consumer = match &stats.kafka_consumer {
+                        Some(consumer) => consumer,
+                        None => continue,
+                    };
+                    for broker in &consumer.brokers {
+                        let mut packer = row_buf.packer();
+
+                        packer.push(Datum::from(src_id.to_string().as_str()));
+                        packer.push(Datum::from(u64::cast_from(*worker_id)));
+                        packer.push(Datum::from(broker.broker.as_str()));
+                        packer.push(Datum::from(broker.state.as_str()));
+                        packer.push(Datum::from(broker.rx_errors));
+                        packer.push(Datum::from(broker.tx_errors));
+                        packer.push(Datum::from(broker.throttle_time_avg_ms));
+                        packer.push(Datum::from(consumer.rebalances));
+
+                        rows.push(row_buf.clone());
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Spawns a task that continually (at an interval) writes the frontier lag histograms of
+/// sinks that are maintained in shared memory in the controller.
+pub(super) fn spawn_sink_frontier_lag_scraper(
+    lag_collection_id: GlobalId,
+    collection_mgmt: CollectionManager,
+    shared_latencies: Arc<Mutex<HashMap<GlobalId, BTreeMap<u64, u64>>>>,
+) -> Box<dyn Any + Send + Sync> {
+    spawn_scraper(
+        "sink_frontier_lag_scraper",
+        lag_collection_id,
+        collection_mgmt,
+        move |row_buf, rows| {
+            let shared_latencies = shared_latencies.lock().expect("poisoned");
+
+            for (sink_id, histogram) in shared_latencies.iter() {
+                for (lag_ms, count) in histogram.iter() {
+                    let mut packer = row_buf.packer();
+
+                    packer.push(Datum::from(sink_id.to_string().as_str()));
+                    packer.push(Datum::from(*lag_ms));
+                    packer.push(Datum::from(*count));
+
+                    rows.push(row_buf.clone());
+                }
+            }
+        },
+    )
+}
+
+/// Spawns a task that continually (at an interval) replaces the contents of the collection
+/// with the rows produced by `collect_rows`.
+fn spawn_scraper<F>(
+    name: &'static str,
+    collection_id: GlobalId,
+    collection_mgmt: CollectionManager,
+    mut collect_rows: F,
+) -> Box<dyn Any + Send + Sync>
+where
+    F: FnMut(&mut Row, &mut Vec<Row>) + Send + 'static,
+{
     // TODO(guswynn): Should this be configurable? Maybe via LaunchDarkly?
     const STATISTICS_INTERVAL: Duration = Duration::from_secs(30);
 
     let (shutdown_tx, mut shutdown_rx) = oneshot::channel::<()>();
 
-    mz_ore::task::spawn(|| "statistics_scraper", async move {
+    mz_ore::task::spawn(|| name, async move {
         // Keep track of what we think is the contents of the output
         // collection, so that we can emit the required retractions/updates
         // when we learn about new metrics.
         //
-        // We assume that `shared_stats` is kept up-to-date by the controller.
+        // We assume that the shared state read by `collect_rows` is kept
+        // up-to-date by the controller.
         let mut current_metrics = ChangeBatch::new();
 
         let mut interval = tokio::time::interval(STATISTICS_INTERVAL);
         interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
+        let mut row_buf = Row::default();
+        let mut rows = Vec::new();
+
         loop {
             tokio::select! {
                 _msg = &mut shutdown_rx => {
@@ -54,7 +167,6 @@ pub(super) fn spawn_statistics_scraper(
                 }
 
                 _ = interval.tick() => {
-                    let mut row_buf = Row::default();
                     let mut correction = current_metrics
                         .iter()
                         .cloned()
@@ -66,39 +178,22 @@ pub(super) fn spawn_statistics_scraper(
                     // be fine!
                     //
                     // TODO: consider using a RwLock instead of a mutex.
-                    {
-                        let shared_stats = shared_stats.lock().expect("poisoned");
-
-                        for (src_id, sources) in shared_stats.iter() {
-                            for (worker_id, stats) in sources.iter() {
-                                let mut packer = row_buf.packer();
-
-                                packer.push(Datum::from(src_id.to_string().as_str()));
-                                packer.push(Datum::from(u64::cast_from(*worker_id)));
-                                packer.push(Datum::from(stats.snapshot_committed));
-                                packer.push(Datum::from(stats.messages_received));
-                                packer.push(Datum::from(stats.updates_staged));
-                                packer.push(Datum::from(stats.updates_committed));
-                                packer.push(Datum::from(stats.bytes_received));
-
-                                correction.push((row_buf.clone(), 1));
-                            }
-                        }
-                    }
+                    collect_rows(&mut row_buf, &mut rows);
+                    correction.extend(rows.drain(..).map(|row| (row, 1)));
 
                     // Update our view of the output collection and write updates
                     // out to the collection.
                     if !correction.is_empty() {
                         current_metrics.extend(correction.iter().cloned());
                         collection_mgmt
-                            .append_to_collection(statistics_collection_id, correction)
+                            .append_to_collection(collection_id, correction)
                             .await;
                     }
                 }
             }
         }
 
-        tracing::info!("shutting down statistics sender task");
+        tracing::info!("shutting down {} task", name);
     });
 
     Box::new(shutdown_tx)
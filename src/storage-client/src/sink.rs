@@ -10,12 +10,15 @@
 use std::collections::BTreeMap;
 use std::time::Duration;
 
-use anyhow::{anyhow, Context};
+use anyhow::{anyhow, bail, Context};
 use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, ResourceSpecifier, TopicReplication};
+use rdkafka::producer::{BaseRecord, Producer, ThreadedProducer};
 use rdkafka::ClientContext;
 
 use mz_kafka_util::client::MzClientContext;
 use mz_ore::collections::CollectionExt;
+use mz_ore::task;
+use mz_repr::GlobalId;
 
 use crate::types::connections::ConnectionContext;
 use crate::types::sinks::{
@@ -33,6 +36,11 @@ pub async fn build_sink_connection(
 ) -> Result<StorageSinkConnection, anyhow::Error> {
     match builder {
         StorageSinkConnectionBuilder::Kafka(k) => build_kafka(k, connection_context).await,
+        // Elasticsearch sinks write to an index that the user manages, so
+        // there is nothing to set up ahead of time.
+        StorageSinkConnectionBuilder::Elasticsearch(e) => {
+            Ok(StorageSinkConnection::Elasticsearch(e))
+        }
     }
 }
 
@@ -42,6 +50,7 @@ async fn ensure_kafka_topic<C>(
     mut partition_count: i32,
     mut replication_factor: i32,
     retention: KafkaSinkConnectionRetention,
+    cleanup_policy: Option<&str>,
 ) -> Result<(), anyhow::Error>
 where
     C: ClientContext,
@@ -142,6 +151,9 @@ where
     if let Some(ref retention_bytes) = retention_bytes_str {
         kafka_topic = kafka_topic.set("retention.bytes", retention_bytes);
     }
+    if let Some(cleanup_policy) = cleanup_policy {
+        kafka_topic = kafka_topic.set("cleanup.policy", cleanup_policy);
+    }
 
     mz_kafka_util::admin::ensure_topic(
         client,
@@ -154,6 +166,71 @@ where
     Ok(())
 }
 
+/// Returns the key of the records of the sink with ID `sink_id` in its
+/// progress topic.
+pub fn progress_key(sink_id: GlobalId) -> String {
+    format!("mz-sink-{sink_id}")
+}
+
+/// Removes the progress records of a dropped sink from its progress topic.
+///
+/// Progress topics are shared by all sinks of a connection, so rather than
+/// deleting the topic, this writes a tombstone for the sink's key, which lets
+/// Kafka compact the sink's records away.
+pub async fn drop_kafka_sink_progress(
+    connection: &KafkaSinkConnection,
+    sink_id: GlobalId,
+    connection_context: &ConnectionContext,
+) -> Result<(), anyhow::Error> {
+    let producer: ThreadedProducer<_> = connection
+        .connection
+        .create_with_context(connection_context, MzClientContext, &BTreeMap::new())
+        .await
+        .context("creating producer failed")?;
+    let key = progress_key(sink_id);
+    producer
+        .send(BaseRecord::<_, ()>::to(&connection.progress.topic).key(&key))
+        .map_err(|(e, _)| e)
+        .with_context(|| {
+            format!(
+                "error writing tombstone to progress topic {}",
+                connection.progress.topic
+            )
+        })?;
+    task::spawn_blocking(
+        || format!("drop_kafka_sink_progress:{sink_id}"),
+        move || producer.flush(Duration::from_secs(30)),
+    )
+    .await??;
+    Ok(())
+}
+
+/// Returns an error if `topic` does not exist.
+async fn check_kafka_topic_exists<C>(
+    client: &AdminClient<C>,
+    topic: &str,
+) -> Result<(), anyhow::Error>
+where
+    C: ClientContext,
+{
+    // Fetch the metadata of all topics rather than just `topic`, as requesting
+    // the metadata of a missing topic creates it on brokers that have
+    // `auto.create.topics.enable` set.
+    let metadata = client
+        .inner()
+        .fetch_metadata(None, Duration::from_secs(5))
+        .with_context(|| {
+            format!(
+                "error fetching metadata when checking topic {} for sink",
+                topic
+            )
+        })?;
+    if !metadata.topics().iter().any(|t| t.name() == topic) {
+        bail!("topic {} does not exist and CREATE TOPIC is false", topic);
+    }
+    Ok(())
+}
+
 /// Publish value and optional key schemas for a given topic.
 ///
 /// TODO(benesch): do we need to delete the Kafka topic if publishing the
@@ -201,15 +278,20 @@ async fn build_kafka(
         .create_with_context(&connection_context, MzClientContext, &BTreeMap::new())
         .await
         .context("creating admin client failed")?;
-    ensure_kafka_topic(
-        &client,
-        &builder.topic_name,
-        builder.partition_count,
-        builder.replication_factor,
-        builder.retention,
-    )
-    .await
-    .context("error registering kafka topic for sink")?;
+    if builder.create_topic {
+        ensure_kafka_topic(
+            &client,
+            &builder.topic_name,
+            builder.partition_count,
+            builder.replication_factor,
+            builder.retention,
+            builder.cleanup_policy.as_deref(),
+        )
+        .await
+        .context("error registering kafka topic for sink")?;
+    } else {
+        check_kafka_topic_exists(&client, &builder.topic_name).await?;
+    }
 
     let published_schema_info = match builder.format {
         KafkaSinkFormat::Avro {
@@ -247,6 +329,9 @@ async fn build_kafka(
                 1,
                 builder.replication_factor,
                 KafkaSinkConnectionRetention::default(),
+                // Only the latest progress record of each sink is ever read,
+                // and dropped sinks write tombstones for their records.
+                Some("compact"),
             )
             .await
             .context("error registering kafka consistency topic for sink")?;
@@ -265,5 +350,7 @@ async fn build_kafka(
         published_schema_info,
         progress,
         fuel: builder.fuel,
+        partitioning: builder.partitioning,
+        sort_by_key: builder.sort_by_key,
     }))
 }
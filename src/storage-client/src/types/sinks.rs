@@ -18,6 +18,8 @@ use serde::{Deserialize, Serialize};
 use timely::progress::frontier::Antichain;
 use timely::PartialOrder;
 
+use mz_expr::MirScalarExpr;
+use mz_interchange::envelopes::{DbzEnvelope, DbzSource};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::{GlobalId, RelationDesc};
 
@@ -34,9 +36,14 @@ include!(concat!(
 pub struct StorageSinkDesc<S: StorageSinkDescFillState, T = mz_repr::Timestamp> {
     pub from: GlobalId,
     pub from_desc: RelationDesc,
+    /// The columns of `from` the sink emits, in order. `None` if the sink
+    /// emits all columns.
+    pub from_projection: Option<Vec<usize>>,
     pub connection: StorageSinkConnection,
     pub envelope: Option<SinkEnvelope>,
     pub as_of: SinkAsOf<T>,
+    /// Whether to record the end-to-end latency of updates written by the sink.
+    pub track_latency: bool,
     pub status_id: Option<<S as StorageSinkDescFillState>::StatusId>,
     pub from_storage_metadata: <S as StorageSinkDescFillState>::StorageMetadata,
 }
@@ -68,9 +75,11 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
         (
             any::<GlobalId>(),
             any::<RelationDesc>(),
+            any::<Option<Vec<usize>>>(),
             any::<StorageSinkConnection>(),
             any::<Option<SinkEnvelope>>(),
             any::<SinkAsOf<mz_repr::Timestamp>>(),
+            any::<bool>(),
             any::<Option<ShardId>>(),
             any::<CollectionMetadata>(),
         )
@@ -78,18 +87,22 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
                 |(
                     from,
                     from_desc,
+                    from_projection,
                     connection,
                     envelope,
                     as_of,
+                    track_latency,
                     status_id,
                     from_storage_metadata,
                 )| {
                     StorageSinkDesc {
                         from,
                         from_desc,
+                        from_projection,
                         connection,
                         envelope,
                         as_of,
+                        track_latency,
                         status_id,
                         from_storage_metadata,
                     }
@@ -99,14 +112,28 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
     }
 }
 
+impl RustType<ProtoColumnProjection> for Vec<usize> {
+    fn into_proto(&self) -> ProtoColumnProjection {
+        ProtoColumnProjection {
+            columns: self.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoColumnProjection) -> Result<Self, TryFromProtoError> {
+        proto.columns.into_rust()
+    }
+}
+
 impl RustType<ProtoStorageSinkDesc> for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
     fn into_proto(&self) -> ProtoStorageSinkDesc {
         ProtoStorageSinkDesc {
             connection: Some(self.connection.into_proto()),
             from: Some(self.from.into_proto()),
             from_desc: Some(self.from_desc.into_proto()),
+            from_projection: self.from_projection.into_proto(),
             envelope: self.envelope.into_proto(),
             as_of: Some(self.as_of.into_proto()),
+            track_latency: self.track_latency,
             status_id: self.status_id.into_proto(),
             from_storage_metadata: Some(self.from_storage_metadata.into_proto()),
         }
@@ -118,6 +145,7 @@ impl RustType<ProtoStorageSinkDesc> for StorageSinkDesc<MetadataFilled, mz_repr:
             from_desc: proto
                 .from_desc
                 .into_rust_if_some("ProtoStorageSinkDesc::from_desc")?,
+            from_projection: proto.from_projection.into_rust()?,
             connection: proto
                 .connection
                 .into_rust_if_some("ProtoStorageSinkDesc::connection")?,
@@ -125,6 +153,7 @@ impl RustType<ProtoStorageSinkDesc> for StorageSinkDesc<MetadataFilled, mz_repr:
             as_of: proto
                 .as_of
                 .into_rust_if_some("ProtoStorageSinkDesc::as_of")?,
+            track_latency: proto.track_latency,
             status_id: proto.status_id.into_rust()?,
             from_storage_metadata: proto
                 .from_storage_metadata
@@ -133,18 +162,34 @@ impl RustType<ProtoStorageSinkDesc> for StorageSinkDesc<MetadataFilled, mz_repr:
     }
 }
 
-#[derive(Arbitrary, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum SinkEnvelope {
     Debezium,
+    /// A Debezium envelope that additionally carries the `source`, `op` and
+    /// `ts_ms` fields of the envelope that Debezium itself emits.
+    DebeziumFull(DbzSourceInfo),
     Upsert,
 }
 
+impl SinkEnvelope {
+    /// Returns the flavor of Debezium envelope that the sink wraps its values
+    /// in, if any.
+    pub fn dbz_envelope(&self) -> Option<DbzEnvelope> {
+        match self {
+            SinkEnvelope::Debezium => Some(DbzEnvelope::Plain),
+            SinkEnvelope::DebeziumFull(_) => Some(DbzEnvelope::Full),
+            SinkEnvelope::Upsert => None,
+        }
+    }
+}
+
 impl RustType<ProtoSinkEnvelope> for SinkEnvelope {
     fn into_proto(&self) -> ProtoSinkEnvelope {
         use proto_sink_envelope::Kind;
         ProtoSinkEnvelope {
             kind: Some(match self {
                 SinkEnvelope::Debezium => Kind::Debezium(()),
+                SinkEnvelope::DebeziumFull(source) => Kind::DebeziumFull(source.into_proto()),
                 SinkEnvelope::Upsert => Kind::Upsert(()),
             }),
         }
@@ -157,11 +202,60 @@ impl RustType<ProtoSinkEnvelope> for SinkEnvelope {
             .ok_or_else(|| TryFromProtoError::missing_field("ProtoSinkEnvelope::kind"))?;
         Ok(match kind {
             Kind::Debezium(()) => SinkEnvelope::Debezium,
+            Kind::DebeziumFull(source) => SinkEnvelope::DebeziumFull(source.into_rust()?),
             Kind::Upsert(()) => SinkEnvelope::Upsert,
         })
     }
 }
 
+/// Describes the origin of the updates of a sink with a full Debezium envelope,
+/// as reported in the `source` field of each of its records.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DbzSourceInfo {
+    /// The version of Materialize that planned the sink.
+    pub version: String,
+    /// The fully qualified name of the sink.
+    pub name: String,
+    /// The database, schema and name of the sinked relation.
+    pub database: String,
+    pub schema: String,
+    pub table: String,
+}
+
+impl DbzSourceInfo {
+    pub fn as_dbz_source(&self) -> DbzSource {
+        DbzSource {
+            version: &self.version,
+            name: &self.name,
+            db: &self.database,
+            schema: &self.schema,
+            table: &self.table,
+        }
+    }
+}
+
+impl RustType<ProtoDbzSourceInfo> for DbzSourceInfo {
+    fn into_proto(&self) -> ProtoDbzSourceInfo {
+        ProtoDbzSourceInfo {
+            version: self.version.clone(),
+            name: self.name.clone(),
+            database: self.database.clone(),
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoDbzSourceInfo) -> Result<Self, TryFromProtoError> {
+        Ok(DbzSourceInfo {
+            version: proto.version,
+            name: proto.name,
+            database: proto.database,
+            schema: proto.schema,
+            table: proto.table,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SinkAsOf<T = mz_repr::Timestamp> {
     pub frontier: Antichain<T>,
@@ -220,6 +314,7 @@ impl RustType<ProtoSinkAsOf> for SinkAsOf<mz_repr::Timestamp> {
 #[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub enum StorageSinkConnection {
     Kafka(KafkaSinkConnection),
+    Elasticsearch(ElasticsearchSinkConnection),
 }
 
 impl StorageSinkConnection {
@@ -228,6 +323,7 @@ impl StorageSinkConnection {
         use StorageSinkConnection::*;
         match self {
             Kafka(KafkaSinkConnection { connection_id, .. }) => Some(*connection_id),
+            Elasticsearch(_) => None,
         }
     }
 
@@ -235,6 +331,7 @@ impl StorageSinkConnection {
     pub fn name(&self) -> &'static str {
         match self {
             StorageSinkConnection::Kafka(_) => "kafka",
+            StorageSinkConnection::Elasticsearch(_) => "elasticsearch",
         }
     }
 }
@@ -245,6 +342,9 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
         ProtoStorageSinkConnection {
             kind: Some(match self {
                 StorageSinkConnection::Kafka(kafka) => Kind::Kafka(kafka.into_proto()),
+                StorageSinkConnection::Elasticsearch(elasticsearch) => {
+                    Kind::Elasticsearch(elasticsearch.into_proto())
+                }
             }),
         }
     }
@@ -256,6 +356,43 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
             .ok_or_else(|| TryFromProtoError::missing_field("ProtoStorageSinkConnection::kind"))?;
         Ok(match kind {
             Kind::Kafka(kafka) => StorageSinkConnection::Kafka(kafka.into_rust()?),
+            Kind::Elasticsearch(elasticsearch) => {
+                StorageSinkConnection::Elasticsearch(elasticsearch.into_rust()?)
+            }
+        })
+    }
+}
+
+/// How a Kafka sink assigns records to partitions, if not with the producer's
+/// partitioner.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum KafkaSinkPartitioning {
+    /// Assign records to the partitions of the topic in turn.
+    RoundRobin,
+    /// Assign each record to the partition given by evaluating the expression
+    /// on its key, modulo the number of partitions of the topic.
+    Expr(MirScalarExpr),
+}
+
+impl RustType<ProtoKafkaSinkPartitioning> for KafkaSinkPartitioning {
+    fn into_proto(&self) -> ProtoKafkaSinkPartitioning {
+        use proto_kafka_sink_partitioning::Kind;
+        ProtoKafkaSinkPartitioning {
+            kind: Some(match self {
+                KafkaSinkPartitioning::RoundRobin => Kind::RoundRobin(()),
+                KafkaSinkPartitioning::Expr(expr) => Kind::Expr(expr.into_proto()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoKafkaSinkPartitioning) -> Result<Self, TryFromProtoError> {
+        use proto_kafka_sink_partitioning::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoKafkaSinkPartitioning::kind"))?;
+        Ok(match kind {
+            Kind::RoundRobin(()) => KafkaSinkPartitioning::RoundRobin,
+            Kind::Expr(expr) => KafkaSinkPartitioning::Expr(expr.into_rust()?),
         })
     }
 }
@@ -290,6 +427,12 @@ pub struct KafkaSinkConnection {
     // Maximum number of records the sink will attempt to send each time it is
     // invoked
     pub fuel: usize,
+    /// How the sink assigns records to partitions. `None` if the producer's
+    /// partitioner assigns them.
+    pub partitioning: Option<KafkaSinkPartitioning>,
+    /// Whether the records of each timestamp are produced sorted by key, with
+    /// retractions before insertions.
+    pub sort_by_key: bool,
 }
 
 proptest::prop_compose! {
@@ -303,6 +446,8 @@ proptest::prop_compose! {
         published_schema_info in any::<Option<PublishedSchemaInfo>>(),
         progress in any::<KafkaSinkProgressConnection>(),
         fuel in any::<usize>(),
+        partitioning in any::<Option<KafkaSinkPartitioning>>(),
+        sort_by_key in any::<bool>(),
     ) -> KafkaSinkConnection {
         KafkaSinkConnection {
             connection,
@@ -314,6 +459,8 @@ proptest::prop_compose! {
             published_schema_info,
             progress,
             fuel,
+            partitioning,
+            sort_by_key,
         }
     }
 }
@@ -373,6 +520,8 @@ impl RustType<ProtoKafkaSinkConnection> for KafkaSinkConnection {
             published_schema_info: self.published_schema_info.into_proto(),
             progress: Some(self.progress.into_proto()),
             fuel: self.fuel.into_proto(),
+            partitioning: self.partitioning.into_proto(),
+            sort_by_key: self.sort_by_key,
         }
     }
 
@@ -395,6 +544,50 @@ impl RustType<ProtoKafkaSinkConnection> for KafkaSinkConnection {
                 .progress
                 .into_rust_if_some("ProtoKafkaSinkConnection::progress")?,
             fuel: proto.fuel.into_rust()?,
+            partitioning: proto.partitioning.into_rust()?,
+            sort_by_key: proto.sort_by_key,
+        })
+    }
+}
+
+/// A sink that maintains the rows of a relation as the documents of an
+/// Elasticsearch or OpenSearch index.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ElasticsearchSinkConnection {
+    /// The base URL of the cluster, e.g. `http://localhost:9200`.
+    pub url: String,
+    /// The name of the index that the sink writes to.
+    pub index: String,
+    /// The key of the sink. The encoded key of a row is the `_id` of its
+    /// document.
+    pub key_desc_and_indices: (RelationDesc, Vec<usize>),
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    pub value_desc: RelationDesc,
+}
+
+impl RustType<ProtoElasticsearchSinkConnection> for ElasticsearchSinkConnection {
+    fn into_proto(&self) -> ProtoElasticsearchSinkConnection {
+        ProtoElasticsearchSinkConnection {
+            url: self.url.clone(),
+            index: self.index.clone(),
+            key_desc_and_indices: Some(self.key_desc_and_indices.into_proto()),
+            relation_key_indices: self.relation_key_indices.into_proto(),
+            value_desc: Some(self.value_desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoElasticsearchSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(ElasticsearchSinkConnection {
+            url: proto.url,
+            index: proto.index,
+            key_desc_and_indices: proto
+                .key_desc_and_indices
+                .into_rust_if_some("ProtoElasticsearchSinkConnection::key_desc_and_indices")?,
+            relation_key_indices: proto.relation_key_indices.into_rust()?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoElasticsearchSinkConnection::value_desc")?,
         })
     }
 }
@@ -425,6 +618,7 @@ impl RustType<ProtoPublishedSchemaInfo> for PublishedSchemaInfo {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum StorageSinkConnectionBuilder {
     Kafka(KafkaSinkConnectionBuilder),
+    Elasticsearch(ElasticsearchSinkConnection),
 }
 
 impl StorageSinkConnectionBuilder {
@@ -433,6 +627,7 @@ impl StorageSinkConnectionBuilder {
         use StorageSinkConnectionBuilder::*;
         match self {
             Kafka(KafkaSinkConnectionBuilder { connection_id, .. }) => Some(*connection_id),
+            Elasticsearch(_) => None,
         }
     }
 
@@ -441,6 +636,7 @@ impl StorageSinkConnectionBuilder {
         use StorageSinkConnectionBuilder::*;
         match self {
             Kafka(_) => "kafka",
+            Elasticsearch(_) => "elasticsearch",
         }
     }
 }
@@ -466,6 +662,12 @@ pub struct KafkaSinkConnectionBuilder {
     pub replication_factor: i32,
     pub fuel: usize,
     pub retention: KafkaSinkConnectionRetention,
+    /// The `cleanup.policy` of the topic, if it is created by the sink.
+    pub cleanup_policy: Option<String>,
+    /// Whether to create the topic if it does not exist, rather than failing.
+    pub create_topic: bool,
+    pub partitioning: Option<KafkaSinkPartitioning>,
+    pub sort_by_key: bool,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
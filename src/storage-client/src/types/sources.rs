@@ -1562,6 +1562,16 @@ impl SourceDesc {
                 connection: GenericSourceConnection::LoadGenerator(_),
                 ..
             } => false,
+            // Postgres tables are retracted when they are refreshed
+            SourceDesc {
+                connection: GenericSourceConnection::PostgresTable(_),
+                ..
+            } => false,
+            // Upstream views can produce retractions
+            SourceDesc {
+                connection: GenericSourceConnection::Materialize(_),
+                ..
+            } => false,
             // Other sources the `None` envelope are append-only.
             SourceDesc {
                 envelope: SourceEnvelope::None(_),
@@ -1575,6 +1585,7 @@ impl SourceDesc {
                     GenericSourceConnection::S3(_)
                     | GenericSourceConnection::Kafka(_)
                     | GenericSourceConnection::Kinesis(_)
+                    | GenericSourceConnection::Http(_)
                     | GenericSourceConnection::TestScript(_),
                 ..
             } => false,
@@ -1587,6 +1598,9 @@ impl SourceDesc {
             GenericSourceConnection::Kafka(_)
             | GenericSourceConnection::Kinesis(_)
             | GenericSourceConnection::S3(_)
+            | GenericSourceConnection::PostgresTable(_)
+            | GenericSourceConnection::Materialize(_)
+            | GenericSourceConnection::Http(_)
             | GenericSourceConnection::TestScript(_) => 0,
             GenericSourceConnection::LoadGenerator(connection) => {
                 connection.load_generator.views().len()
@@ -1614,7 +1628,10 @@ pub enum GenericSourceConnection {
     Kinesis(KinesisSourceConnection),
     S3(S3SourceConnection),
     Postgres(PostgresSourceConnection),
+    PostgresTable(PostgresTableSourceConnection),
+    Materialize(MaterializeSourceConnection),
     LoadGenerator(LoadGeneratorSourceConnection),
+    Http(HttpSourceConnection),
     TestScript(TestScriptSourceConnection),
 }
 
@@ -1625,8 +1642,12 @@ impl GenericSourceConnection {
             Kafka(KafkaSourceConnection { connection_id, .. })
             | Kinesis(KinesisSourceConnection { connection_id, .. })
             | S3(S3SourceConnection { connection_id, .. })
-            | Postgres(PostgresSourceConnection { connection_id, .. }) => Some(*connection_id),
-            LoadGenerator(_) | TestScript(_) => None,
+            | Postgres(PostgresSourceConnection { connection_id, .. })
+            | PostgresTable(PostgresTableSourceConnection { connection_id, .. })
+            | Materialize(MaterializeSourceConnection { connection_id, .. }) => {
+                Some(*connection_id)
+            }
+            LoadGenerator(_) | Http(_) | TestScript(_) => None,
         }
     }
 }
@@ -1642,9 +1663,16 @@ impl RustType<ProtoSourceConnection> for GenericSourceConnection {
                 GenericSourceConnection::Postgres(postgres) => {
                     Kind::Postgres(postgres.into_proto())
                 }
+                GenericSourceConnection::PostgresTable(postgres_table) => {
+                    Kind::PostgresTable(postgres_table.into_proto())
+                }
+                GenericSourceConnection::Materialize(materialize) => {
+                    Kind::Materialize(materialize.into_proto())
+                }
                 GenericSourceConnection::LoadGenerator(loadgen) => {
                     Kind::Loadgen(loadgen.into_proto())
                 }
+                GenericSourceConnection::Http(http) => Kind::Http(http.into_proto()),
                 GenericSourceConnection::TestScript(testscript) => {
                     Kind::Testscript(testscript.into_proto())
                 }
@@ -1662,7 +1690,14 @@ impl RustType<ProtoSourceConnection> for GenericSourceConnection {
             Kind::Kinesis(kinesis) => GenericSourceConnection::Kinesis(kinesis.into_rust()?),
             Kind::S3(s3) => GenericSourceConnection::S3(s3.into_rust()?),
             Kind::Postgres(postgres) => GenericSourceConnection::Postgres(postgres.into_rust()?),
+            Kind::PostgresTable(postgres_table) => {
+                GenericSourceConnection::PostgresTable(postgres_table.into_rust()?)
+            }
+            Kind::Materialize(materialize) => {
+                GenericSourceConnection::Materialize(materialize.into_rust()?)
+            }
             Kind::Loadgen(loadgen) => GenericSourceConnection::LoadGenerator(loadgen.into_rust()?),
+            Kind::Http(http) => GenericSourceConnection::Http(http.into_rust()?),
             Kind::Testscript(testscript) => {
                 GenericSourceConnection::TestScript(testscript.into_rust()?)
             }
@@ -1732,7 +1767,10 @@ impl GenericSourceConnection {
             Self::Kinesis(_) => vec![],
             Self::S3(_) => vec![],
             Self::Postgres(_) => vec![],
+            Self::PostgresTable(_) => vec![],
+            Self::Materialize(_) => vec![],
             Self::LoadGenerator(_) => vec![],
+            Self::Http(_) => vec![],
             Self::TestScript(_) => vec![],
         }
     }
@@ -1769,7 +1807,10 @@ impl GenericSourceConnection {
             GenericSourceConnection::Kinesis(_)
             | GenericSourceConnection::S3(_)
             | GenericSourceConnection::Postgres(_)
+            | GenericSourceConnection::PostgresTable(_)
+            | GenericSourceConnection::Materialize(_)
             | GenericSourceConnection::LoadGenerator(_)
+            | GenericSourceConnection::Http(_)
             | GenericSourceConnection::TestScript(_) => Vec::new(),
         }
     }
@@ -1781,7 +1822,10 @@ impl GenericSourceConnection {
             GenericSourceConnection::Kinesis(c) => c.name(),
             GenericSourceConnection::S3(c) => c.name(),
             GenericSourceConnection::Postgres(c) => c.name(),
+            GenericSourceConnection::PostgresTable(c) => c.name(),
+            GenericSourceConnection::Materialize(c) => c.name(),
             GenericSourceConnection::LoadGenerator(c) => c.name(),
+            GenericSourceConnection::Http(c) => c.name(),
             GenericSourceConnection::TestScript(c) => c.name(),
         }
     }
@@ -1799,7 +1843,10 @@ impl GenericSourceConnection {
             }
             GenericSourceConnection::S3(_) => None,
             GenericSourceConnection::Postgres(_) => None,
+            GenericSourceConnection::PostgresTable(_) => None,
+            GenericSourceConnection::Materialize(_) => None,
             GenericSourceConnection::LoadGenerator(_) => None,
+            GenericSourceConnection::Http(_) => None,
             GenericSourceConnection::TestScript(_) => None,
         }
     }
@@ -1954,6 +2001,146 @@ impl RustType<ProtoPostgresSourceConnection> for PostgresSourceConnection {
     }
 }
 
+/// A source that snapshots a single Postgres table, once or periodically,
+/// rather than replicating its changes.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresTableSourceConnection {
+    pub connection_id: GlobalId,
+    pub connection: PostgresConnection,
+    /// The table to snapshot.
+    pub table: mz_postgres_util::desc::PostgresTableDesc,
+    /// The cast expressions to convert the string encoded columns of the
+    /// table to their target types.
+    pub casts: Vec<MirScalarExpr>,
+    /// The time to wait between snapshots, if the table is refreshed rather
+    /// than snapshotted only once.
+    pub refresh_interval: Option<Duration>,
+}
+
+impl Arbitrary for PostgresTableSourceConnection {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<PostgresConnection>(),
+            any::<GlobalId>(),
+            any::<mz_postgres_util::desc::PostgresTableDesc>(),
+            proptest::collection::vec(any::<MirScalarExpr>(), 1..4),
+            any::<Option<Duration>>(),
+        )
+            .prop_map(
+                |(connection, connection_id, table, casts, refresh_interval)| Self {
+                    connection,
+                    connection_id,
+                    table,
+                    casts,
+                    refresh_interval,
+                },
+            )
+            .boxed()
+    }
+}
+
+impl SourceConnection for PostgresTableSourceConnection {
+    fn name(&self) -> &'static str {
+        "postgres-table"
+    }
+}
+
+impl RustType<ProtoPostgresTableSourceConnection> for PostgresTableSourceConnection {
+    fn into_proto(&self) -> ProtoPostgresTableSourceConnection {
+        ProtoPostgresTableSourceConnection {
+            connection_id: Some(self.connection_id.into_proto()),
+            connection: Some(self.connection.into_proto()),
+            table: Some(self.table.into_proto()),
+            casts: self.casts.iter().map(|cast| cast.into_proto()).collect(),
+            refresh_interval: self.refresh_interval.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresTableSourceConnection) -> Result<Self, TryFromProtoError> {
+        Ok(PostgresTableSourceConnection {
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoPostgresTableSourceConnection::connection_id")?,
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoPostgresTableSourceConnection::connection")?,
+            table: proto
+                .table
+                .into_rust_if_some("ProtoPostgresTableSourceConnection::table")?,
+            casts: proto.casts.into_rust()?,
+            refresh_interval: proto.refresh_interval.into_rust()?,
+        })
+    }
+}
+
+/// A source that subscribes to a relation of another Materialize instance.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MaterializeSourceConnection {
+    pub connection_id: GlobalId,
+    pub connection: PostgresConnection,
+    /// The upstream relation to subscribe to.
+    pub view: mz_postgres_util::desc::PostgresTableDesc,
+    /// The cast expressions to convert the string encoded columns of the
+    /// relation to their target types.
+    pub casts: Vec<MirScalarExpr>,
+}
+
+impl Arbitrary for MaterializeSourceConnection {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<PostgresConnection>(),
+            any::<GlobalId>(),
+            any::<mz_postgres_util::desc::PostgresTableDesc>(),
+            proptest::collection::vec(any::<MirScalarExpr>(), 1..4),
+        )
+            .prop_map(|(connection, connection_id, view, casts)| Self {
+                connection,
+                connection_id,
+                view,
+                casts,
+            })
+            .boxed()
+    }
+}
+
+impl SourceConnection for MaterializeSourceConnection {
+    fn name(&self) -> &'static str {
+        "materialize"
+    }
+}
+
+impl RustType<ProtoMaterializeSourceConnection> for MaterializeSourceConnection {
+    fn into_proto(&self) -> ProtoMaterializeSourceConnection {
+        ProtoMaterializeSourceConnection {
+            connection_id: Some(self.connection_id.into_proto()),
+            connection: Some(self.connection.into_proto()),
+            view: Some(self.view.into_proto()),
+            casts: self.casts.iter().map(|cast| cast.into_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: ProtoMaterializeSourceConnection) -> Result<Self, TryFromProtoError> {
+        Ok(MaterializeSourceConnection {
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoMaterializeSourceConnection::connection_id")?,
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoMaterializeSourceConnection::connection")?,
+            view: proto
+                .view
+                .into_rust_if_some("ProtoMaterializeSourceConnection::view")?,
+            casts: proto.casts.into_rust()?,
+        })
+    }
+}
+
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PostgresSourcePublicationDetails {
     pub tables: Vec<mz_postgres_util::desc::PostgresTableDesc>,
@@ -2273,6 +2460,44 @@ impl RustType<ProtoLoadGeneratorSourceConnection> for LoadGeneratorSourceConnect
     }
 }
 
+/// A source that periodically polls an HTTP endpoint.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HttpSourceConnection {
+    /// The URL to poll.
+    pub url: String,
+    /// The time to wait between polls.
+    pub poll_interval: Duration,
+    /// The top-level field of each record that identifies it, if the source
+    /// emits upsert snapshots rather than appending every record it sees.
+    pub key_field: Option<String>,
+}
+
+impl SourceConnection for HttpSourceConnection {
+    fn name(&self) -> &'static str {
+        "http"
+    }
+}
+
+impl RustType<ProtoHttpSourceConnection> for HttpSourceConnection {
+    fn into_proto(&self) -> ProtoHttpSourceConnection {
+        ProtoHttpSourceConnection {
+            url: self.url.clone(),
+            poll_interval: Some(self.poll_interval.into_proto()),
+            key_field: self.key_field.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoHttpSourceConnection) -> Result<Self, TryFromProtoError> {
+        Ok(HttpSourceConnection {
+            url: proto.url,
+            poll_interval: proto
+                .poll_interval
+                .into_rust_if_some("ProtoHttpSourceConnection::poll_interval")?,
+            key_field: proto.key_field,
+        })
+    }
+}
+
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct TestScriptSourceConnection {
     pub desc_json: String,
@@ -300,24 +300,35 @@ impl DataEncoding {
 #[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct AvroEncoding {
     pub schema: String,
+    /// The schema that records were written with, if they are not decoded with
+    /// the schemas in a schema registry and `schema` only selects some of its
+    /// fields.
+    pub writer_schema: Option<String>,
     pub csr_connection: Option<CsrConnection>,
     pub confluent_wire_format: bool,
+    /// Whether to skip records whose writer schema can not be resolved
+    /// against `schema`, rather than producing decode errors for them.
+    pub quarantine_unresolvable_schemas: bool,
 }
 
 impl RustType<ProtoAvroEncoding> for AvroEncoding {
     fn into_proto(&self) -> ProtoAvroEncoding {
         ProtoAvroEncoding {
             schema: self.schema.clone(),
+            writer_schema: self.writer_schema.clone(),
             csr_connection: self.csr_connection.into_proto(),
             confluent_wire_format: self.confluent_wire_format,
+            quarantine_unresolvable_schemas: self.quarantine_unresolvable_schemas,
         }
     }
 
     fn from_proto(proto: ProtoAvroEncoding) -> Result<Self, TryFromProtoError> {
         Ok(AvroEncoding {
             schema: proto.schema,
+            writer_schema: proto.writer_schema,
             csr_connection: proto.csr_connection.into_rust()?,
             confluent_wire_format: proto.confluent_wire_format,
+            quarantine_unresolvable_schemas: proto.quarantine_unresolvable_schemas,
         })
     }
 }
@@ -9,7 +9,7 @@
 
 use tokio::runtime::Handle as TokioHandle;
 
-use mz_interchange::avro::Decoder;
+use mz_interchange::avro::{Decoder, SchemaResolutionError};
 use mz_repr::Row;
 use mz_storage_client::types::connections::CsrClient;
 use mz_storage_client::types::errors::DecodeErrorKind;
@@ -19,19 +19,35 @@ pub struct AvroDecoderState {
     tokio_handle: TokioHandle,
     decoder: Decoder<CsrClient>,
     events_success: i64,
+    /// Whether to quarantine records whose schema can not be resolved, rather
+    /// than returning errors for them.
+    quarantine_unresolvable_schemas: bool,
+    /// The error of the last record that was quarantined, if it has not been
+    /// taken yet.
+    quarantined: Option<String>,
 }
 
 impl AvroDecoderState {
     pub fn new(
         value_schema: &str,
+        writer_schema: Option<&str>,
         ccsr_client: Option<CsrClient>,
         debug_name: String,
         confluent_wire_format: bool,
+        quarantine_unresolvable_schemas: bool,
     ) -> Result<Self, anyhow::Error> {
         Ok(AvroDecoderState {
             tokio_handle: TokioHandle::current(),
-            decoder: Decoder::new(value_schema, ccsr_client, debug_name, confluent_wire_format)?,
+            decoder: Decoder::new(
+                value_schema,
+                writer_schema,
+                ccsr_client,
+                debug_name,
+                confluent_wire_format,
+            )?,
             events_success: 0,
+            quarantine_unresolvable_schemas,
+            quarantined: None,
         })
     }
 
@@ -41,10 +57,25 @@ impl AvroDecoderState {
                 self.events_success += 1;
                 Ok(Some(row))
             }
+            Err(err)
+                if self.quarantine_unresolvable_schemas
+                    && err.downcast_ref::<SchemaResolutionError>().is_some() =>
+            {
+                // Skip the rest of the record, which can't be decoded.
+                *bytes = &[];
+                self.quarantined = Some(format!("{:#}", err));
+                Ok(None)
+            }
             Err(err) => Err(DecodeErrorKind::Text(format!(
                 "avro deserialization error: {:#}",
                 err
             ))),
         }
     }
+
+    /// Returns the error of the last record that was quarantined since the
+    /// last call, if any.
+    pub fn take_quarantined(&mut self) -> Option<String> {
+        self.quarantined.take()
+    }
 }
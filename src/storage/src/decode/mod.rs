@@ -47,6 +47,7 @@ use self::avro::AvroDecoderState;
 use self::csv::CsvDecoderState;
 use self::metrics::DecodeMetrics;
 use self::protobuf::ProtobufDecoderState;
+use crate::source::statistics::SourceStatistics;
 use crate::source::types::{DecodeResult, SourceOutput};
 
 mod avro;
@@ -62,11 +63,13 @@ mod protobuf;
 pub fn render_decode_cdcv2<G: Scope<Timestamp = Timestamp>>(
     stream: &Stream<G, SourceOutput<Option<Vec<u8>>, Option<Vec<u8>>, ()>>,
     schema: &str,
+    writer_schema: Option<&str>,
     registry: Option<CsrClient>,
     confluent_wire_format: bool,
 ) -> (Collection<G, Row, Diff>, Box<dyn Any + Send + Sync>) {
     // We will have already checked validity of the schema by now, so this can't fail.
-    let mut resolver = ConfluentAvroResolver::new(schema, registry, confluent_wire_format).unwrap();
+    let mut resolver =
+        ConfluentAvroResolver::new(schema, writer_schema, registry, confluent_wire_format).unwrap();
     let channel = Rc::new(RefCell::new(VecDeque::new()));
     let activator: Rc<RefCell<Option<SyncActivator>>> = Rc::new(RefCell::new(None));
     let mut vector = Vec::new();
@@ -235,6 +238,15 @@ impl DataDecoder {
         }
     }
 
+    /// Returns the error of the last record that the decoder quarantined since
+    /// the last call, if any.
+    pub fn take_quarantined(&mut self) -> Option<String> {
+        match &mut self.inner {
+            DataDecoderInner::Avro(avro) => avro.take_quarantined(),
+            _ => None,
+        }
+    }
+
     pub fn log_errors(&self, n: usize) {
         self.metrics.count_errors(&self.inner, n);
     }
@@ -257,8 +269,10 @@ fn get_decoder(
     match encoding.inner {
         DataEncodingInner::Avro(AvroEncoding {
             schema,
+            writer_schema,
             csr_connection,
             confluent_wire_format,
+            quarantine_unresolvable_schemas,
         }) => {
             let csr_client = match csr_connection {
                 None => None,
@@ -270,9 +284,11 @@ fn get_decoder(
             };
             let state = avro::AvroDecoderState::new(
                 &schema,
+                writer_schema.as_deref(),
                 csr_client,
                 debug_name.to_string(),
                 confluent_wire_format,
+                quarantine_unresolvable_schemas,
             )
             .expect("Failed to create avro decoder, even though we validated ccsr client creation in purification.");
             DataDecoder {
@@ -358,6 +374,7 @@ pub fn render_decode_delimited<G>(
     debug_name: &str,
     metadata_items: Vec<IncludedColumnSource>,
     metrics: DecodeMetrics,
+    source_statistics: SourceStatistics,
     connection_context: &ConnectionContext,
 ) -> (Stream<G, DecodeResult>, Option<Box<dyn Any + Send + Sync>>)
 where
@@ -424,6 +441,18 @@ where
                             })
                         });
 
+                    // Quarantined messages are skipped entirely, as emitting
+                    // just their key or value would make them look like
+                    // messages without one.
+                    let key_quarantined = key_decoder
+                        .as_mut()
+                        .and_then(|decoder| decoder.take_quarantined());
+                    let value_quarantined = value_decoder.take_quarantined();
+                    if let Some(error) = key_quarantined.or(value_quarantined) {
+                        source_statistics.inc_messages_quarantined(error);
+                        continue;
+                    }
+
                     if matches!(&key, Some(Err(_))) || matches!(&value, Some(Err(_))) {
                         n_errors += 1;
                     } else if matches!(&value, Some(Ok(_))) {
@@ -17,10 +17,11 @@ use std::sync::Arc;
 
 use differential_dataflow::operators::arrange::arrangement::ArrangeByKey;
 use differential_dataflow::{AsCollection, Collection, Hashable};
+use timely::dataflow::operators::Map;
 use timely::dataflow::Scope;
 use tokio::sync::Mutex;
 
-use mz_interchange::envelopes::{combine_at_timestamp, dbz_format, upsert_format};
+use mz_interchange::envelopes::{combine_at_timestamp, dbz_format, dbz_full_format, upsert_format};
 use mz_ore::now::NowFn;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::{PersistLocation, ShardId};
@@ -68,8 +69,15 @@ pub(crate) fn render_sink<G: Scope<Timestamp = Timestamp>>(
     );
     needed_tokens.push(source_token);
 
-    let ok_collection =
-        apply_sink_envelope(sink_id, sink, &sink_render, ok_collection.as_collection());
+    let mut ok_collection = ok_collection.as_collection();
+    if let Some(projection) = sink.from_projection.clone() {
+        ok_collection = ok_collection.map(move |row| {
+            let datums = row.unpack();
+            Row::pack(projection.iter().map(|&idx| datums[idx]))
+        });
+    }
+
+    let ok_collection = apply_sink_envelope(sink_id, sink, &sink_render, ok_collection);
 
     let healthchecker_args = HealthcheckerArgs {
         persist_clients: Arc::clone(&storage_state.persist_clients),
@@ -157,11 +165,12 @@ where
 
     // Apply the envelope.
     // * "Debezium" consolidates the stream, sorts it by time, and produces DiffPairs from it.
-    //   It then renders those as Avro.
+    //   It then renders those as Avro. "Full" Debezium envelopes additionally
+    //   describe the origin, kind and time of each update.
     // * Upsert" does the same, except at the last step, it renders the diff pair in upsert format.
     //   (As part of doing so, it asserts that there are not multiple conflicting values at the same timestamp)
-    let collection = match sink.envelope {
-        Some(SinkEnvelope::Debezium) => {
+    let collection = match &sink.envelope {
+        Some(envelope @ (SinkEnvelope::Debezium | SinkEnvelope::DebeziumFull(_))) => {
             let combined = combine_at_timestamp(keyed.arrange_by_key().stream);
 
             // if there is no user-specified key, remove the synthetic
@@ -173,18 +182,43 @@ where
                 combined.map(|(_key, value)| (None, value))
             };
 
+            let source = match envelope {
+                SinkEnvelope::DebeziumFull(source) => Some(Rc::new(source.clone())),
+                _ => None,
+            };
+            // The updates of the initial snapshot of a sink are all emitted at
+            // its as-of.
+            let snapshot_ts = if sink.as_of.strict {
+                None
+            } else {
+                sink.as_of.frontier.as_option().copied()
+            };
+
             // This has to be an `Rc<RefCell<...>>` because the inner closure (passed to `Iterator::map`) references it, and it might outlive the outer closure.
             let row_buf = Rc::new(RefCell::new(Row::default()));
-            let collection = combined.flat_map(move |(mut k, v)| {
-                let max_idx = v.len() - 1;
-                let row_buf = Rc::clone(&row_buf);
-                v.into_iter().enumerate().map(move |(idx, dp)| {
-                    let k = if idx == max_idx { k.take() } else { k.clone() };
-                    let mut row_buf = row_buf.borrow_mut();
-                    dbz_format(&mut row_buf.packer(), dp);
-                    (k, Some(row_buf.clone()))
+            let collection = combined
+                .inner
+                .flat_map(move |((mut k, v), t, diff)| {
+                    let max_idx = v.len() - 1;
+                    let row_buf = Rc::clone(&row_buf);
+                    let source = source.clone();
+                    v.into_iter().enumerate().map(move |(idx, dp)| {
+                        let k = if idx == max_idx { k.take() } else { k.clone() };
+                        let mut row_buf = row_buf.borrow_mut();
+                        match &source {
+                            None => dbz_format(&mut row_buf.packer(), dp),
+                            Some(source) => dbz_full_format(
+                                &mut row_buf.packer(),
+                                dp,
+                                source.as_dbz_source(),
+                                i64::try_from(t).unwrap_or(i64::MAX),
+                                snapshot_ts == Some(t),
+                            ),
+                        }
+                        ((k, Some(row_buf.clone())), t, diff)
+                    })
                 })
-            });
+                .as_collection();
             collection
         }
         Some(SinkEnvelope::Upsert) => {
@@ -246,5 +280,6 @@ where
 {
     match connection {
         StorageSinkConnection::Kafka(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Elasticsearch(connection) => Box::new(connection.clone()),
     }
 }
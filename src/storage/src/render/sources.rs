@@ -21,6 +21,7 @@ use timely::dataflow::operators::{Exchange, Map, OkErr, ToStream};
 use timely::dataflow::{Scope, Stream};
 use timely::progress::Antichain;
 use tokio::runtime::Handle as TokioHandle;
+use tokio::sync::watch;
 
 use mz_repr::{Datum, Diff, GlobalId, Row, RowPacker, Timestamp};
 use mz_storage_client::controller::CollectionMetadata;
@@ -31,7 +32,10 @@ use mz_timely_util::operator::{CollectionExt, StreamExt};
 
 use crate::decode::{render_decode, render_decode_cdcv2, render_decode_delimited};
 use crate::source::types::{DecodeResult, SourceOutput};
-use crate::source::{self, DelimitedValueSourceConnection, RawSourceCreationConfig};
+use crate::source::{
+    self, DelimitedValueSourceConnection, RawSourceCreationConfig, ResumableHttpConnection,
+    ResumableMaterializeConnection, ResumablePostgresTableConnection,
+};
 
 /// A type-level enum that holds one of two types of sources depending on their message type
 ///
@@ -85,11 +89,21 @@ where
 
     let connection = description.desc.connection.clone();
     let source_name = format!("{}-{}", connection.name(), id);
+    let (timestamp_interval_tx, timestamp_interval_rx) =
+        watch::channel(description.desc.timestamp_interval);
+    storage_state
+        .source_timestamp_intervals
+        .insert(id, timestamp_interval_tx);
+    let source_statistics = storage_state
+        .source_statistics
+        .get(&id)
+        .expect("statistics initialized")
+        .clone();
     let base_source_config = RawSourceCreationConfig {
         name: source_name,
         id,
         num_outputs: description.desc.num_outputs(),
-        timestamp_interval: description.desc.timestamp_interval.clone(),
+        timestamp_interval: timestamp_interval_rx,
         worker_id: scope.index(),
         worker_count: scope.peers(),
         encoding: description.desc.encoding.clone(),
@@ -99,11 +113,7 @@ where
         resume_upper: resume_upper.clone(),
         storage_metadata: description.ingestion_metadata.clone(),
         persist_clients: Arc::clone(&storage_state.persist_clients),
-        source_statistics: storage_state
-            .source_statistics
-            .get(&id)
-            .expect("statistics initialized")
-            .clone(),
+        source_statistics: source_statistics.clone(),
     };
 
     // TODO(petrosagg): put the description as-is in the RawSourceCreationConfig instead of cloning
@@ -157,6 +167,43 @@ where
             let oks = oks.into_iter().map(SourceType::Row).collect();
             ((oks, err), cap)
         }
+        GenericSourceConnection::PostgresTable(connection) => {
+            // The source reads back its previous output when it restarts
+            let connection = ResumablePostgresTableConnection {
+                connection,
+                persist_clients: Arc::clone(&base_source_config.persist_clients),
+                storage_metadata: base_source_config.storage_metadata.clone(),
+                resume_upper: base_source_config.resume_upper.clone(),
+            };
+            let ((oks, err), cap) = source::create_raw_source(
+                scope,
+                base_source_config,
+                connection,
+                storage_state.connection_context.clone(),
+                resumption_calculator,
+            );
+            let oks = oks.into_iter().map(SourceType::Row).collect();
+            ((oks, err), cap)
+        }
+        GenericSourceConnection::Materialize(connection) => {
+            // The source reads back its previous output if it cannot resume
+            // its subscription
+            let connection = ResumableMaterializeConnection {
+                connection,
+                persist_clients: Arc::clone(&base_source_config.persist_clients),
+                storage_metadata: base_source_config.storage_metadata.clone(),
+                resume_upper: base_source_config.resume_upper.clone(),
+            };
+            let ((oks, err), cap) = source::create_raw_source(
+                scope,
+                base_source_config,
+                connection,
+                storage_state.connection_context.clone(),
+                resumption_calculator,
+            );
+            let oks = oks.into_iter().map(SourceType::Row).collect();
+            ((oks, err), cap)
+        }
         GenericSourceConnection::LoadGenerator(connection) => {
             let ((oks, err), cap) = source::create_raw_source(
                 scope,
@@ -168,6 +215,24 @@ where
             let oks = oks.into_iter().map(SourceType::Row).collect();
             ((oks, err), cap)
         }
+        GenericSourceConnection::Http(connection) => {
+            // Keyed sources read back their previous keys after a restart
+            let connection = ResumableHttpConnection {
+                connection,
+                persist_clients: Arc::clone(&base_source_config.persist_clients),
+                storage_metadata: base_source_config.storage_metadata.clone(),
+                resume_upper: base_source_config.resume_upper.clone(),
+            };
+            let ((oks, err), cap) = source::create_raw_source(
+                scope,
+                base_source_config,
+                connection,
+                storage_state.connection_context.clone(),
+                resumption_calculator,
+            );
+            let oks = oks.into_iter().map(SourceType::Delimited).collect();
+            ((oks, err), cap)
+        }
         GenericSourceConnection::TestScript(connection) => {
             let ((oks, err), cap) = source::create_raw_source(
                 scope,
@@ -260,8 +325,10 @@ where
         if let SourceEnvelope::CdcV2 = &envelope {
             let AvroEncoding {
                 schema,
+                writer_schema,
                 csr_connection,
                 confluent_wire_format,
+                quarantine_unresolvable_schemas: _,
             } = match value_encoding.inner {
                 DataEncodingInner::Avro(enc) => enc,
                 _ => unreachable!("Attempted to create non-Avro CDCv2 source"),
@@ -283,8 +350,13 @@ where
             };
             // TODO(petrosagg): this should move to the envelope section below and
             // made to work with a stream of Rows instead of decoding Avro directly
-            let (oks, token) =
-                render_decode_cdcv2(&ok_source, &schema, csr_client, confluent_wire_format);
+            let (oks, token) = render_decode_cdcv2(
+                &ok_source,
+                &schema,
+                writer_schema.as_deref(),
+                csr_client,
+                confluent_wire_format,
+            );
             needed_tokens.push(Rc::new(token));
             (oks, None)
         } else {
@@ -299,6 +371,7 @@ where
                     dataflow_debug_name,
                     metadata_columns,
                     storage_state.decode_metrics.clone(),
+                    source_statistics,
                     &storage_state.connection_context,
                 ),
                 SourceType::ByteStream(source) => render_decode(
@@ -10,6 +10,7 @@
 use std::any::Any;
 use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
 use std::rc::Rc;
 
 use differential_dataflow::hashable::Hashable;
@@ -35,6 +36,10 @@ use mz_timely_util::operator::StreamExt;
 
 use crate::source::types::DecodeResult;
 
+/// The capacity below which the upsert state is never shrunk, so that small
+/// states are not reallocated over and over again.
+const MIN_SHRINK_CAPACITY: usize = 1024;
+
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 struct UpsertSourceData {
     /// The actual value
@@ -458,10 +463,13 @@ where
                         output,
                     )
                 }
-                // Discard entries, capabilities for complete times.
+                // Discard entries, capabilities for complete times. This
+                // includes the deletions at those times, which have been
+                // applied to `current_values`.
                 for time in removed_times {
                     pending_values.remove(&time);
                 }
+                shrink_current_values(current_values);
             }
         },
     );
@@ -678,6 +686,23 @@ fn process_pending_values_batch(
     }
 }
 
+/// Releases the memory of deleted keys in the upsert state.
+///
+/// The upsert state keeps no tombstones: deleting a key removes it from
+/// `current_values`, and a pending deletion in `pending_values` is discarded
+/// as soon as the input frontier passes its time. The map, however, keeps the
+/// memory of the deleted key's slot until it is shrunk. Without shrinking, the state of a
+/// source whose keys churn keeps the memory of its largest size forever, so we
+/// shrink the map once at most a quarter of its capacity is in use. Shrinking to
+/// twice the number of keys leaves room for the map to grow again without
+/// immediately reallocating.
+fn shrink_current_values<K: Eq + Hash, V>(current_values: &mut HashMap<K, V>) {
+    let capacity = current_values.capacity();
+    if capacity > MIN_SHRINK_CAPACITY && current_values.len() < capacity / 4 {
+        current_values.shrink_to(std::cmp::max(current_values.len() * 2, MIN_SHRINK_CAPACITY));
+    }
+}
+
 fn build_datum_vec_for_evaluation<'row>(
     dv: &'row mut DatumVec,
     upsert_style: &UpsertStyle,
@@ -756,6 +781,35 @@ fn rehydrate(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_shrink_current_values() {
+        let mut current_values = HashMap::new();
+        for i in 0..100_000 {
+            current_values.insert(i, i);
+        }
+        let peak_capacity = current_values.capacity();
+
+        // A map that is mostly in use is not shrunk.
+        current_values.retain(|k, _| *k < 50_000);
+        shrink_current_values(&mut current_values);
+        assert_eq!(current_values.capacity(), peak_capacity);
+
+        // A map that is mostly empty is shrunk, but keeps its values.
+        current_values.retain(|k, _| *k < 10_000);
+        shrink_current_values(&mut current_values);
+        assert!(current_values.capacity() < peak_capacity);
+        assert!(current_values.capacity() >= 20_000);
+        assert_eq!(current_values.len(), 10_000);
+        assert_eq!(current_values.get(&9_999), Some(&9_999));
+
+        // Small maps are never shrunk.
+        current_values.clear();
+        current_values.shrink_to(MIN_SHRINK_CAPACITY);
+        let small_capacity = current_values.capacity();
+        shrink_current_values(&mut current_values);
+        assert_eq!(current_values.capacity(), small_capacity);
+    }
+
     #[test]
     fn test_rehydrate_thin_first() {
         let mut packer = Row::default();
@@ -0,0 +1,524 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that maintains the rows of a relation as the documents of an
+//! Elasticsearch or OpenSearch index.
+//!
+//! Every row is a document whose `_id` is the row's key. Once the frontier of
+//! the input has passed a timestamp, the sink writes the updates of that
+//! timestamp with the bulk API: upserts index the document, replacing any
+//! previous version of it, and deletions delete it. Failed requests, and
+//! actions that the cluster rejects, are retried with backoff until they
+//! succeed, so every update is written at least once. Writing an update again
+//! is harmless, because indexing and deleting by `_id` are idempotent.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context};
+use differential_dataflow::{Collection, Hashable};
+use futures::StreamExt;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::Scope;
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::{debug, warn};
+
+use mz_interchange::encode::column_names_and_types;
+use mz_interchange::json::encode_datums_as_json;
+use mz_ore::cast::CastFrom;
+use mz_ore::retry::Retry;
+use mz_repr::{ColumnName, ColumnType, Diff, GlobalId, Row, Timestamp};
+use mz_storage_client::types::errors::DataflowError;
+use mz_storage_client::types::sinks::{
+    ElasticsearchSinkConnection, MetadataFilled, SinkAsOf, StorageSinkDesc,
+};
+use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
+
+use crate::render::sinks::{HealthcheckerArgs, SinkRender};
+use crate::sink::{Healthchecker, SinkStatus};
+use crate::storage_state::StorageState;
+
+/// The maximum number of actions in a single bulk request.
+const MAX_BULK_ACTIONS: usize = 1000;
+
+/// The time after which a bulk request that has not completed is retried.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+// The same clamp as the Kafka sink's: long enough to reduce load on a cluster
+// that is struggling, short enough to resume quickly once it has recovered.
+const BACKOFF_CLAMP: Duration = Duration::from_secs(30);
+
+impl<G> SinkRender<G> for ElasticsearchSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        Some(&self.key_desc_and_indices.1)
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        // Errors in the sinked relation do not reach the index, as for Kafka
+        // sinks.
+        _err_collection: Collection<G, DataflowError, Diff>,
+        healthchecker_args: HealthcheckerArgs,
+    ) -> Option<Rc<dyn Any>>
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        // As for Kafka sinks, a single worker writes to the index, and all
+        // other workers report an empty write frontier.
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let token = elasticsearch(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            sink.as_of.clone(),
+            Rc::clone(&shared_frontier),
+            healthchecker_args,
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        Some(token)
+    }
+}
+
+/// A single action of a bulk request.
+#[derive(Debug)]
+enum BulkAction {
+    /// Index the JSON encoded `document` with the given `_id`.
+    Index { id: String, document: Vec<u8> },
+    /// Delete the document with the given `_id`, if it exists.
+    Delete { id: String },
+}
+
+/// The parts of the response to a bulk request that the sink inspects.
+#[derive(Debug, Deserialize)]
+struct BulkResponse {
+    /// Whether any of the actions failed.
+    errors: bool,
+    /// The results of the actions, each keyed by the name of its action.
+    items: Vec<HashMap<String, BulkItemResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkItemResult {
+    status: u16,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+struct ElasticsearchSinkState {
+    name: String,
+    client: reqwest::Client,
+    bulk_url: String,
+    index: String,
+    key_columns: Vec<(ColumnName, ColumnType)>,
+    value_columns: Vec<(ColumnName, ColumnType)>,
+    healthchecker: Option<Healthchecker>,
+}
+
+impl ElasticsearchSinkState {
+    fn new(name: String, connection: ElasticsearchSinkConnection) -> Self {
+        let ElasticsearchSinkConnection {
+            url,
+            index,
+            key_desc_and_indices: (key_desc, _key_indices),
+            relation_key_indices: _,
+            value_desc,
+        } = connection;
+        ElasticsearchSinkState {
+            name,
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .expect("must build Client"),
+            bulk_url: format!("{}/_bulk", url.trim_end_matches('/')),
+            index,
+            key_columns: column_names_and_types(key_desc),
+            value_columns: column_names_and_types(value_desc),
+            healthchecker: None,
+        }
+    }
+
+    async fn update_status(&mut self, status: SinkStatus) {
+        if let Some(hc) = &mut self.healthchecker {
+            hc.update_status(status).await;
+        }
+    }
+
+    /// Returns the `_id` of the document of the row with the given key: the
+    /// value of the key if it has a single column, or else the JSON encoding
+    /// of the key.
+    fn document_id(&self, key: &Row) -> String {
+        let key = match encode_datums_as_json(key.iter(), &self.key_columns) {
+            serde_json::Value::Object(fields) if fields.len() == 1 => fields
+                .into_iter()
+                .map(|(_name, value)| value)
+                .next()
+                .expect("known to have one field"),
+            key => key,
+        };
+        match key {
+            serde_json::Value::String(id) => id,
+            key => key.to_string(),
+        }
+    }
+
+    fn action(&self, key: Row, value: Option<Row>) -> BulkAction {
+        let id = self.document_id(&key);
+        match value {
+            Some(value) => BulkAction::Index {
+                id,
+                document: encode_datums_as_json(value.iter(), &self.value_columns)
+                    .to_string()
+                    .into_bytes(),
+            },
+            None => BulkAction::Delete { id },
+        }
+    }
+
+    /// Encodes `actions` as the newline-delimited JSON body of a bulk request.
+    fn bulk_body(&self, actions: &[BulkAction]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for action in actions {
+            let (op, id, document) = match action {
+                BulkAction::Index { id, document } => ("index", id, Some(document)),
+                BulkAction::Delete { id } => ("delete", id, None),
+            };
+            let metadata = json!({ op: { "_index": &self.index, "_id": id } });
+            serde_json::to_writer(&mut body, &metadata).expect("writing to a vec cannot fail");
+            body.push(b'\n');
+            if let Some(document) = document {
+                body.extend_from_slice(document);
+                body.push(b'\n');
+            }
+        }
+        body
+    }
+
+    /// Sends a single bulk request, and checks that the cluster has applied
+    /// all of its actions.
+    async fn try_send_bulk(&self, body: Vec<u8>) -> Result<(), anyhow::Error> {
+        let response = self
+            .client
+            .post(&self.bulk_url)
+            .header(CONTENT_TYPE, "application/x-ndjson")
+            .body(body)
+            .send()
+            .await
+            .context("sending bulk request")?;
+        let status = response.status();
+        let bytes = response.bytes().await.context("reading bulk response")?;
+        self.check_bulk_response(status, &bytes)
+    }
+
+    /// Checks that the response to a bulk request reports that the cluster
+    /// has applied all of its actions.
+    fn check_bulk_response(&self, status: StatusCode, bytes: &[u8]) -> Result<(), anyhow::Error> {
+        if !status.is_success() {
+            bail!(
+                "bulk request failed with status {status}: {}",
+                String::from_utf8_lossy(bytes)
+            );
+        }
+        let response: BulkResponse =
+            serde_json::from_slice(bytes).context("decoding bulk response")?;
+        if !response.errors {
+            return Ok(());
+        }
+        for item in response.items {
+            for (op, result) in item {
+                match result.status {
+                    200..=299 => (),
+                    // Deleting a document that does not exist is not an
+                    // error, e.g. when retrying a request that had already
+                    // been applied.
+                    404 if op == "delete" => (),
+                    status => {
+                        let error = result.error.unwrap_or(serde_json::Value::Null);
+                        return Err(anyhow!(
+                            "{op} action on index {} failed with status {status}: {error}",
+                            self.index
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the actions of a timestamp, retrying each bulk request with
+    /// backoff until it succeeds. The sink is reported as stalled while
+    /// requests fail.
+    async fn write(&mut self, time: Timestamp, actions: Vec<BulkAction>) {
+        debug!(
+            "{}: writing {} actions at {}",
+            self.name,
+            actions.len(),
+            time
+        );
+        for chunk in actions.chunks(MAX_BULK_ACTIONS) {
+            let body = self.bulk_body(chunk);
+            let tries = Retry::default()
+                .max_tries(usize::MAX)
+                .clamp_backoff(BACKOFF_CLAMP)
+                .into_retry_stream();
+            tokio::pin!(tries);
+            loop {
+                tries.next().await.expect("infinite stream");
+                match self.try_send_bulk(body.clone()).await {
+                    Ok(()) => break,
+                    Err(e) => {
+                        warn!("{}: error writing to Elasticsearch: {e:#}", self.name);
+                        self.update_status(SinkStatus::Stalled(format!("{e:#}")))
+                            .await;
+                    }
+                }
+            }
+            self.update_status(SinkStatus::Running).await;
+        }
+    }
+}
+
+/// Writes a stream of upserts to an Elasticsearch index.
+///
+/// This operator exchanges all updates to a single worker by hashing on the
+/// given sink `id`. Updates are written once the input frontier has passed
+/// their time, in ascending timestamp order, and the sink's `write_frontier`
+/// advances once they have been written. Updates that are not beyond the given
+/// [`SinkAsOf`] are discarded.
+fn elasticsearch<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    id: GlobalId,
+    connection: ElasticsearchSinkConnection,
+    as_of: SinkAsOf,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    healthchecker_args: HealthcheckerArgs,
+) -> Rc<dyn Any>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let scope = collection.inner.scope();
+    let name = format!("elasticsearch-{}", id);
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), scope.clone());
+
+    let hashed_id = id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_input(&collection.inner, Exchange::new(move |_| hashed_id));
+
+    let button = builder.build(move |_capabilities| async move {
+        if !is_active_worker {
+            return;
+        }
+
+        let mut s = ElasticsearchSinkState::new(name, connection);
+        if let Some(status_shard_id) = healthchecker_args.status_shard_id {
+            let hc = Healthchecker::new(
+                id,
+                &healthchecker_args.persist_clients,
+                healthchecker_args.persist_location.clone(),
+                status_shard_id,
+                healthchecker_args.now_fn.clone(),
+            )
+            .await
+            .expect("error initializing healthchecker");
+            s.healthchecker = Some(hc);
+        }
+        s.update_status(SinkStatus::Running).await;
+
+        let mut pending: BTreeMap<Timestamp, Vec<BulkAction>> = BTreeMap::new();
+        let mut vector = Vec::new();
+        while let Some(event) = input.next().await {
+            match event {
+                Event::Data(_, rows) => {
+                    rows.swap(&mut vector);
+                    for ((key, value), time, diff) in vector.drain(..) {
+                        let should_emit = if as_of.strict {
+                            as_of.frontier.less_than(&time)
+                        } else {
+                            as_of.frontier.less_equal(&time)
+                        };
+                        if !should_emit || diff == 0 {
+                            continue;
+                        }
+                        let key = key.expect("Elasticsearch sinks have a key");
+                        pending.entry(time).or_default().push(s.action(key, value));
+                    }
+                }
+                Event::Progress(frontier) => {
+                    while let Some(time) = pending.keys().next().copied() {
+                        if frontier.less_equal(&time) {
+                            break;
+                        }
+                        let actions = pending.remove(&time).expect("known to exist");
+                        s.write(time, actions).await;
+                    }
+                    *write_frontier.borrow_mut() = frontier;
+                }
+            }
+        }
+    });
+
+    Rc::new(button.press_on_drop())
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::{Datum, RelationDesc, ScalarType};
+
+    use super::*;
+
+    fn sink_state(key_desc: RelationDesc) -> ElasticsearchSinkState {
+        let value_desc = RelationDesc::empty()
+            .with_column("id", ScalarType::Int32.nullable(false))
+            .with_column("name", ScalarType::String.nullable(true));
+        ElasticsearchSinkState::new(
+            "elasticsearch-u1".into(),
+            ElasticsearchSinkConnection {
+                url: "http://localhost:9200/".into(),
+                index: "things".into(),
+                key_desc_and_indices: (key_desc, vec![0]),
+                relation_key_indices: None,
+                value_desc,
+            },
+        )
+    }
+
+    #[test]
+    fn test_document_id() {
+        let s =
+            sink_state(RelationDesc::empty().with_column("id", ScalarType::Int32.nullable(false)));
+        assert_eq!(s.document_id(&Row::pack_slice(&[Datum::Int32(1)])), "1");
+
+        let s =
+            sink_state(RelationDesc::empty().with_column("id", ScalarType::String.nullable(false)));
+        assert_eq!(s.document_id(&Row::pack_slice(&[Datum::String("a")])), "a");
+
+        let s = sink_state(
+            RelationDesc::empty()
+                .with_column("a", ScalarType::Int32.nullable(false))
+                .with_column("b", ScalarType::String.nullable(false)),
+        );
+        assert_eq!(
+            s.document_id(&Row::pack_slice(&[Datum::Int32(1), Datum::String("x")])),
+            r#"{"a":1,"b":"x"}"#
+        );
+    }
+
+    #[test]
+    fn test_bulk_body() {
+        let s =
+            sink_state(RelationDesc::empty().with_column("id", ScalarType::Int32.nullable(false)));
+        assert_eq!(s.bulk_url, "http://localhost:9200/_bulk");
+        let actions = vec![
+            s.action(
+                Row::pack_slice(&[Datum::Int32(1)]),
+                Some(Row::pack_slice(&[Datum::Int32(1), Datum::String("one")])),
+            ),
+            s.action(Row::pack_slice(&[Datum::Int32(2)]), None),
+            s.action(
+                Row::pack_slice(&[Datum::Int32(3)]),
+                Some(Row::pack_slice(&[Datum::Int32(3), Datum::Null])),
+            ),
+        ];
+        let body = String::from_utf8(s.bulk_body(&actions)).unwrap();
+        assert_eq!(
+            body,
+            concat!(
+                r#"{"index":{"_index":"things","_id":"1"}}"#,
+                "\n",
+                r#"{"id":1,"name":"one"}"#,
+                "\n",
+                r#"{"delete":{"_index":"things","_id":"2"}}"#,
+                "\n",
+                r#"{"index":{"_index":"things","_id":"3"}}"#,
+                "\n",
+                r#"{"id":3,"name":null}"#,
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_bulk_response() {
+        let s =
+            sink_state(RelationDesc::empty().with_column("id", ScalarType::Int32.nullable(false)));
+
+        let ok = br#"{"errors": false, "items": [{"index": {"status": 201}}]}"#;
+        assert!(s.check_bulk_response(StatusCode::OK, ok).is_ok());
+
+        // Deleting a document that does not exist is not an error.
+        let missing_delete = br#"{"errors": true, "items": [
+            {"index": {"status": 200}},
+            {"delete": {"status": 404, "result": "not_found"}}
+        ]}"#;
+        assert!(s
+            .check_bulk_response(StatusCode::OK, missing_delete)
+            .is_ok());
+
+        // A single failed action fails the request, so that it is retried.
+        let partial_failure = br#"{"errors": true, "items": [
+            {"index": {"status": 201}},
+            {"index": {"status": 429, "error": {"type": "es_rejected_execution_exception"}}}
+        ]}"#;
+        let error = s
+            .check_bulk_response(StatusCode::OK, partial_failure)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("index action on index things failed with status 429"));
+        assert!(error.contains("es_rejected_execution_exception"));
+
+        // Only deletions may be missing.
+        let missing_index = br#"{"errors": true, "items": [{"index": {"status": 404}}]}"#;
+        assert!(s
+            .check_bulk_response(StatusCode::OK, missing_index)
+            .is_err());
+
+        let error = s
+            .check_bulk_response(StatusCode::SERVICE_UNAVAILABLE, b"overloaded")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "bulk request failed with status 503 Service Unavailable: overloaded"
+        );
+    }
+}
@@ -22,13 +22,14 @@ use differential_dataflow::{Collection, Hashable};
 use futures::{StreamExt, TryFutureExt};
 use itertools::Itertools;
 use maplit::btreemap;
-use prometheus::core::AtomicU64;
+use prometheus::core::{AtomicI64, AtomicU64};
 use rdkafka::client::ClientContext;
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::error::{KafkaError, KafkaResult, RDKafkaErrorCode};
 use rdkafka::message::{Header, Message, OwnedHeaders, OwnedMessage, ToBytes};
 use rdkafka::producer::Producer;
 use rdkafka::producer::{BaseRecord, DeliveryResult, ProducerContext, ThreadedProducer};
+use rdkafka::statistics::Statistics;
 use rdkafka::{Offset, TopicPartitionList};
 use serde::{Deserialize, Serialize};
 use timely::dataflow::channels::pact::Exchange;
@@ -43,21 +44,27 @@ use tokio::runtime::Handle as TokioHandle;
 use tokio::sync::{mpsc, Mutex};
 use tracing::{debug, error, info, warn};
 
+use mz_expr::MirScalarExpr;
 use mz_interchange::avro::{AvroEncoder, AvroSchemaGenerator};
 use mz_interchange::encode::Encode;
 use mz_interchange::json::JsonEncoder;
 use mz_kafka_util::client::{BrokerRewritingClientContext, MzClientContext};
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
-use mz_ore::metrics::{CounterVecExt, DeleteOnDropCounter, DeleteOnDropGauge, GaugeVecExt};
+use mz_ore::iter::IteratorExt;
+use mz_ore::metrics::{
+    CounterVecExt, DeleteOnDropCounter, DeleteOnDropGauge, DeleteOnDropHistogram, GaugeVecExt,
+    HistogramVecExt, IntGaugeVec,
+};
+use mz_ore::now::NowFn;
 use mz_ore::retry::{Retry, RetryResult};
 use mz_ore::{halt, task};
-use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_repr::{Datum, Diff, GlobalId, Row, RowArena, Timestamp};
 use mz_storage_client::types::connections::ConnectionContext;
 use mz_storage_client::types::errors::DataflowError;
 use mz_storage_client::types::sinks::{
-    KafkaSinkConnection, MetadataFilled, PublishedSchemaInfo, SinkAsOf, SinkEnvelope,
-    StorageSinkDesc,
+    KafkaSinkConnection, KafkaSinkPartitioning, MetadataFilled, PublishedSchemaInfo, SinkAsOf,
+    SinkEnvelope, StorageSinkDesc,
 };
 use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
 
@@ -124,9 +131,10 @@ where
             sinked_collection,
             sink_id,
             self.clone(),
-            sink.envelope,
+            sink.envelope.clone(),
             sink.as_of.clone(),
             Rc::clone(&shared_frontier),
+            sink.track_latency,
             &storage_state.sink_metrics.kafka,
             &storage_state.connection_context,
             healthchecker_args,
@@ -146,6 +154,18 @@ pub struct SinkMetrics {
     message_send_errors_counter: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
     message_delivery_errors_counter: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
     rows_queued: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    producer_queued_messages: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    producer_queued_bytes: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    producer_batch_size_avg: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    producer_batch_count_avg: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    /// Only present if latency tracking is enabled for the sink.
+    frontier_lag: Option<DeleteOnDropHistogram<'static, Vec<String>>>,
+    /// The offset after the last delivered message of each partition of the
+    /// topic, created as partitions receive their first message.
+    partition_offset_produced:
+        Mutex<HashMap<i32, DeleteOnDropGauge<'static, AtomicI64, Vec<String>>>>,
+    partition_offset_produced_base: IntGaugeVec,
+    partition_labels: Vec<String>,
 }
 
 impl SinkMetrics {
@@ -154,6 +174,7 @@ impl SinkMetrics {
         topic_name: &str,
         sink_id: &str,
         worker_id: &str,
+        track_latency: bool,
     ) -> SinkMetrics {
         let labels = vec![
             topic_name.to_string(),
@@ -170,9 +191,46 @@ impl SinkMetrics {
             message_delivery_errors_counter: base
                 .message_delivery_errors_counter
                 .get_delete_on_drop_counter(labels.clone()),
-            rows_queued: base.rows_queued.get_delete_on_drop_gauge(labels),
+            rows_queued: base.rows_queued.get_delete_on_drop_gauge(labels.clone()),
+            producer_queued_messages: base
+                .producer_queued_messages
+                .get_delete_on_drop_gauge(labels.clone()),
+            producer_queued_bytes: base
+                .producer_queued_bytes
+                .get_delete_on_drop_gauge(labels.clone()),
+            producer_batch_size_avg: base
+                .producer_batch_size_avg
+                .get_delete_on_drop_gauge(labels.clone()),
+            producer_batch_count_avg: base
+                .producer_batch_count_avg
+                .get_delete_on_drop_gauge(labels.clone()),
+            frontier_lag: track_latency
+                .then(|| base.frontier_lag.get_delete_on_drop_histogram(labels)),
+            partition_offset_produced: Mutex::new(HashMap::new()),
+            partition_offset_produced_base: base.partition_offset_produced.clone(),
+            partition_labels: vec![topic_name.to_string(), sink_id.to_string()],
         }
     }
+
+    /// Records the delivery of a message to the given partition and offset.
+    ///
+    /// Must not be called from an async context.
+    fn record_delivery(&self, partition: i32, offset: i64) {
+        self.partition_offset_produced
+            .blocking_lock()
+            .entry(partition)
+            .or_insert_with_key(|partition| {
+                self.partition_offset_produced_base
+                    .get_delete_on_drop_gauge(
+                        self.partition_labels
+                            .iter()
+                            .cloned()
+                            .chain_one(partition.to_string())
+                            .collect(),
+                    )
+            })
+            .set(offset + 1);
+    }
 }
 
 #[derive(Clone)]
@@ -214,6 +272,7 @@ impl KafkaSinkSendRetryManager {
 }
 
 pub struct SinkProducerContext {
+    topic: String,
     metrics: Arc<SinkMetrics>,
     status_tx: mpsc::Sender<SinkStatus>,
     retry_manager: Arc<Mutex<KafkaSinkSendRetryManager>>,
@@ -230,13 +289,33 @@ impl ClientContext for SinkProducerContext {
         let _ = self.status_tx.try_send(status);
         MzClientContext.error(error, reason)
     }
+    fn stats(&self, statistics: Statistics) {
+        self.metrics
+            .producer_queued_messages
+            .set(statistics.msg_cnt);
+        self.metrics.producer_queued_bytes.set(statistics.msg_size);
+        if let Some(topic) = statistics.topics.get(&self.topic) {
+            self.metrics
+                .producer_batch_size_avg
+                .set(u64::try_from(topic.batchsize.avg).unwrap_or(0));
+            self.metrics
+                .producer_batch_count_avg
+                .set(u64::try_from(topic.batchcnt.avg).unwrap_or(0));
+        }
+    }
 }
 impl ProducerContext for SinkProducerContext {
     type DeliveryOpaque = ();
 
     fn delivery(&self, result: &DeliveryResult, _: Self::DeliveryOpaque) {
         match result {
-            Ok(_) => self.retry_manager.blocking_lock().record_success(),
+            Ok(msg) => {
+                // The progress topic is written by the same producer.
+                if msg.topic() == self.topic {
+                    self.metrics.record_delivery(msg.partition(), msg.offset());
+                }
+                self.retry_manager.blocking_lock().record_success()
+            }
             Err((e, msg)) => {
                 self.metrics.message_delivery_errors_counter.inc();
                 // TODO: figure out a good way to back these retries off.  Should be okay without
@@ -390,6 +469,42 @@ struct KafkaSinkState {
     /// ensures that we don't write updates more than once, ensuring
     /// exactly-once guarantees.
     write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+
+    /// Used to measure the frontier lag of committed progress records.
+    now: NowFn,
+
+    /// Assigns partitions to records, if the sink does not leave that to the
+    /// producer's partitioner. Set once the partition count of the topic is
+    /// known.
+    partitioner: Option<SinkPartitioner>,
+}
+
+/// Assigns the records of a sink to the partitions of its topic.
+struct SinkPartitioner {
+    partition_count: i32,
+    /// The partition of the next record without a partition hint.
+    next_partition: i32,
+}
+
+impl SinkPartitioner {
+    /// Returns the partition for a record with the given partition hint.
+    ///
+    /// Records with a hint, computed from the sink's `PARTITION BY`
+    /// expression, are sent to the hint modulo the partition count. Records
+    /// without one are distributed round-robin.
+    fn partition(&mut self, hint: Option<i64>) -> i32 {
+        match hint {
+            Some(hint) => {
+                let partition = hint.rem_euclid(i64::from(self.partition_count));
+                i32::try_from(partition).expect("partition count fits in i32")
+            }
+            None => {
+                let partition = self.next_partition;
+                self.next_partition = (partition + 1) % self.partition_count;
+                partition
+            }
+        }
+    }
 }
 
 struct SinkConsumerContext {
@@ -419,12 +534,15 @@ impl KafkaSinkState {
         metrics: &KafkaBaseMetrics,
         connection_context: &ConnectionContext,
         gate_ts: Rc<Cell<Option<Timestamp>>>,
+        track_latency: bool,
+        now: NowFn,
     ) -> Self {
         let metrics = Arc::new(SinkMetrics::new(
             metrics,
             &connection.topic,
             &sink_id.to_string(),
             &worker_id,
+            track_latency,
         ));
 
         let retry_manager = Arc::new(Mutex::new(KafkaSinkSendRetryManager::new()));
@@ -432,6 +550,7 @@ impl KafkaSinkState {
         let (status_tx, mut status_rx) = mpsc::channel(16);
 
         let producer_context = SinkProducerContext {
+            topic: connection.topic.clone(),
             metrics: Arc::clone(&metrics),
             status_tx: status_tx.clone(),
             retry_manager: Arc::clone(&retry_manager),
@@ -450,37 +569,48 @@ impl KafkaSinkState {
             });
         }
 
+        let mut producer_options = btreemap! {
+            // Ensure that messages are sinked in order and without
+            // duplicates. Note that this only applies to a single
+            // instance of a producer - in the case of restarts, all
+            // bets are off and full exactly once support is required.
+            "enable.idempotence" => "true".into(),
+            // Increase limits for the Kafka producer's internal
+            // buffering of messages Currently we don't have a great
+            // backpressure mechanism to tell indexes or views to slow
+            // down, so the only thing we can do with a message that we
+            // can't immediately send is to put it in a buffer and
+            // there's no point having buffers within the dataflow layer
+            // and Kafka If the sink starts falling behind and the
+            // buffers start consuming too much memory the best thing to
+            // do is to drop the sink Sets the buffer size to be 16 GB
+            // (note that this setting is in KB)
+            "queue.buffering.max.kbytes" => format!("{}", 16 << 20),
+            // Set the max messages buffered by the producer at any time
+            // to 10MM which is the maximum allowed value.
+            "queue.buffering.max.messages" => format!("{}", 10_000_000),
+            // Make the Kafka producer wait at least 10 ms before
+            // sending out MessageSets TODO(rkhaitan): experiment with
+            // different settings for this value to see if it makes a
+            // big difference.
+            "queue.buffering.max.ms" => format!("{}", 10),
+            "transactional.id" => format!("mz-producer-{sink_id}-{worker_id}"),
+            // Report producer statistics, which are exported as
+            // per-sink metrics, once a second.
+            "statistics.interval.ms" => format!("{}", 1000),
+        };
+        // `queue.buffering.max.ms` is an alias of `linger.ms`, so respect the
+        // value the user configured, if any. Extra options would otherwise
+        // override it.
+        if connection.connection.options.contains_key("linger.ms") {
+            producer_options.remove("queue.buffering.max.ms");
+        }
+
         let producer = TokioHandle::current()
             .block_on(connection.connection.create_with_context(
                 connection_context,
                 producer_context,
-                &btreemap! {
-                    // Ensure that messages are sinked in order and without
-                    // duplicates. Note that this only applies to a single
-                    // instance of a producer - in the case of restarts, all
-                    // bets are off and full exactly once support is required.
-                    "enable.idempotence" => "true".into(),
-                    // Increase limits for the Kafka producer's internal
-                    // buffering of messages Currently we don't have a great
-                    // backpressure mechanism to tell indexes or views to slow
-                    // down, so the only thing we can do with a message that we
-                    // can't immediately send is to put it in a buffer and
-                    // there's no point having buffers within the dataflow layer
-                    // and Kafka If the sink starts falling behind and the
-                    // buffers start consuming too much memory the best thing to
-                    // do is to drop the sink Sets the buffer size to be 16 GB
-                    // (note that this setting is in KB)
-                    "queue.buffering.max.kbytes" => format!("{}", 16 << 20),
-                    // Set the max messages buffered by the producer at any time
-                    // to 10MM which is the maximum allowed value.
-                    "queue.buffering.max.messages" => format!("{}", 10_000_000),
-                    // Make the Kafka producer wait at least 10 ms before
-                    // sending out MessageSets TODO(rkhaitan): experiment with
-                    // different settings for this value to see if it makes a
-                    // big difference.
-                    "queue.buffering.max.ms" => format!("{}", 10),
-                    "transactional.id" => format!("mz-producer-{sink_id}-{worker_id}"),
-                },
+                &producer_options,
             ))
             .expect("creating Kafka producer for sink failed");
         let producer = KafkaTxProducer {
@@ -512,12 +642,14 @@ impl KafkaSinkState {
             ready_rows: VecDeque::new(),
             retry_manager,
             progress_topic: connection.progress.topic,
-            progress_key: format!("mz-sink-{sink_id}"),
+            progress_key: mz_storage_client::sink::progress_key(sink_id),
             progress_client: Some(Arc::new(progress_client)),
             healthchecker,
             gate_ts,
             latest_progress_ts: Timestamp::minimum(),
             write_frontier,
+            now,
+            partitioner: None,
         }
     }
 
@@ -581,12 +713,36 @@ impl KafkaSinkState {
                     Some(p) => transformed_msg.payload(p),
                     None => transformed_msg,
                 };
+                // Retried records must stay in the partition they were
+                // assigned to originally.
+                if self.partitioner.is_some() && msg.partition() >= 0 {
+                    transformed_msg = transformed_msg.partition(msg.partition());
+                }
                 self.send(transformed_msg).await;
             }
             self.flush_inner().await;
         }
     }
 
+    /// Returns the number of partitions of the sink's topic.
+    async fn fetch_partition_count(&self) -> Result<i32, anyhow::Error> {
+        let client = Arc::clone(&self.producer.inner);
+        let topic = self.topic.clone();
+        task::spawn_blocking(
+            || format!("get_partitions:{}", self.name),
+            move || {
+                let partitions = mz_kafka_util::client::get_partitions(
+                    client.client(),
+                    &topic,
+                    Duration::from_secs(10),
+                )?;
+                i32::try_from(partitions.len())
+                    .with_context(|| format!("topic {topic} has too many partitions"))
+            },
+        )
+        .await?
+    }
+
     async fn flush_inner(&self) {
         Retry::default()
             .max_tries(usize::MAX)
@@ -604,20 +760,17 @@ impl KafkaSinkState {
         fn get_next_message<C>(
             consumer: &BaseConsumer<C>,
             timeout: Duration,
-        ) -> Result<Option<(Vec<u8>, Vec<u8>, i64)>, anyhow::Error>
+        ) -> Result<Option<(Vec<u8>, Option<Vec<u8>>, i64)>, anyhow::Error>
         where
             C: ConsumerContext,
         {
             if let Some(result) = consumer.poll(timeout) {
                 match result {
-                    Ok(message) => match message.payload() {
-                        Some(p) => Ok(Some((
-                            message.key().unwrap_or(&[]).to_vec(),
-                            p.to_vec(),
-                            message.offset(),
-                        ))),
-                        None => bail!("unexpected null payload"),
-                    },
+                    Ok(message) => Ok(Some((
+                        message.key().unwrap_or(&[]).to_vec(),
+                        message.payload().map(|p| p.to_vec()),
+                        message.offset(),
+                    ))),
                     Err(KafkaError::PartitionEOF(_)) => Ok(None),
                     Err(err) => bail!("Failed to process message {}", err),
                 }
@@ -700,11 +853,20 @@ impl KafkaSinkState {
                 debug_assert!(offset >= latest_offset.unwrap_or(0));
                 latest_offset = Some(offset);
 
-                let timestamp_opt = if &key == progress_key_bytes {
-                    let progress: ProgressRecord = serde_json::from_slice(&message)?;
-                    Some(progress.timestamp)
-                } else {
-                    None
+                if &key != progress_key_bytes {
+                    continue;
+                }
+                let timestamp_opt = match message {
+                    Some(message) => {
+                        let progress: ProgressRecord = serde_json::from_slice(&message)?;
+                        Some(progress.timestamp)
+                    }
+                    // A tombstone, written when a sink with the same ID was
+                    // dropped. Its records do not apply to this sink.
+                    None => {
+                        latest_ts = None;
+                        None
+                    }
                 };
 
                 if let Some(ts) = timestamp_opt {
@@ -862,6 +1024,17 @@ impl KafkaSinkState {
                 )
                 .await;
 
+                // All updates at or before `min_frontier` are now visible to
+                // consumers of the sink. The difference between the current
+                // time and `min_frontier` is the frontier lag of the sink,
+                // which approximates the end-to-end latency of the last of
+                // them if their timestamps were minted when they were
+                // ingested.
+                if let Some(lag) = &self.metrics.frontier_lag {
+                    let elapsed = (self.now)().saturating_sub(min_frontier.into());
+                    lag.observe(Duration::from_millis(elapsed).as_secs_f64());
+                }
+
                 progress_emitted = true;
                 self.latest_progress_ts = min_frontier;
             }
@@ -910,6 +1083,11 @@ impl KafkaSinkState {
 struct EncodedRow {
     key: Option<Vec<u8>>,
     value: Option<Vec<u8>>,
+    /// The value of the sink's `PARTITION BY` expression for this row, if any.
+    partition_hint: Option<i64>,
+    /// Whether the row retracts data, i.e. is an upsert tombstone or a
+    /// Debezium record without an `after` value.
+    retraction: bool,
     count: usize,
 }
 
@@ -921,6 +1099,7 @@ fn kafka<G>(
     envelope: Option<SinkEnvelope>,
     as_of: SinkAsOf,
     write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    track_latency: bool,
     metrics: &KafkaBaseMetrics,
     connection_context: &ConnectionContext,
     healthchecker_args: HealthcheckerArgs,
@@ -939,6 +1118,14 @@ where
         .as_ref()
         .map(|(desc, _indices)| desc.clone());
     let value_desc = connection.value_desc.clone();
+    let partition_expr = match &connection.partitioning {
+        Some(KafkaSinkPartitioning::Expr(expr)) => Some(expr.clone()),
+        Some(KafkaSinkPartitioning::RoundRobin) | None => None,
+    };
+    let dbz = envelope
+        .as_ref()
+        .and_then(SinkEnvelope::dbz_envelope)
+        .is_some();
 
     let encoded_stream = match connection.published_schema_info {
         Some(PublishedSchemaInfo {
@@ -950,7 +1137,7 @@ where
                 None,
                 key_desc,
                 value_desc,
-                matches!(envelope, Some(SinkEnvelope::Debezium)),
+                envelope.as_ref().and_then(SinkEnvelope::dbz_envelope),
             )
             .expect("avro schema validated");
             let encoder = AvroEncoder::new(schema_generator, key_schema_id, value_schema_id);
@@ -959,6 +1146,8 @@ where
                 as_of.clone(),
                 Rc::clone(&shared_gate_ts),
                 encoder,
+                partition_expr,
+                dbz,
                 connection.fuel,
                 name.clone(),
             )
@@ -967,13 +1156,15 @@ where
             let encoder = JsonEncoder::new(
                 key_desc,
                 value_desc,
-                matches!(envelope, Some(SinkEnvelope::Debezium)),
+                envelope.as_ref().and_then(SinkEnvelope::dbz_envelope),
             );
             encode_stream(
                 stream,
                 as_of.clone(),
                 Rc::clone(&shared_gate_ts),
                 encoder,
+                partition_expr,
+                dbz,
                 connection.fuel,
                 name.clone(),
             )
@@ -988,6 +1179,7 @@ where
         as_of,
         shared_gate_ts,
         write_frontier,
+        track_latency,
         metrics,
         connection_context,
         healthchecker_args,
@@ -999,20 +1191,32 @@ where
 /// This operator exchanges all updates to a single worker by hashing on the given sink `id`.
 ///
 /// Updates are only sent to Kafka once the input frontier has passed their `time`. Updates are
-/// sent in ascending timestamp order. The order of updates at the same timestamp will not be changed.
+/// sent in ascending timestamp order. The order of updates at the same timestamp will not be changed,
+/// unless the connection asks for them to be sorted by key, with retractions before insertions.
 /// However, it is important to keep in mind that this operator exchanges updates so if the input
 /// stream is sharded updates will likely arrive at this operator in some non-deterministic order.
 ///
 /// Updates that are not beyond the given [`SinkAsOf`] and/or the `gate_ts` in
 /// [`KafkaSinkConnection`] will be discarded without producing them.
+///
+/// If `track_latency` is set, the frontier lag of every progress record the
+/// sink commits is recorded in the sink's metrics.
 pub fn produce_to_kafka<G>(
-    stream: Stream<G, ((Option<Vec<u8>>, Option<Vec<u8>>), Timestamp, Diff)>,
+    stream: Stream<
+        G,
+        (
+            (Option<Vec<u8>>, Option<Vec<u8>>, Option<i64>, bool),
+            Timestamp,
+            Diff,
+        ),
+    >,
     id: GlobalId,
     name: String,
     connection: KafkaSinkConnection,
     as_of: SinkAsOf,
     shared_gate_ts: Rc<Cell<Option<Timestamp>>>,
     write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    track_latency: bool,
     metrics: &KafkaBaseMetrics,
     connection_context: &ConnectionContext,
     healthchecker_args: HealthcheckerArgs,
@@ -1023,6 +1227,8 @@ where
     let scope = stream.scope();
     let mut builder = AsyncOperatorBuilder::new(name.clone(), scope.clone());
 
+    let assign_partitions = connection.partitioning.is_some();
+    let sort_by_key = connection.sort_by_key;
     let mut s = KafkaSinkState::new(
         connection,
         name,
@@ -1032,6 +1238,8 @@ where
         metrics,
         connection_context,
         Rc::clone(&shared_gate_ts),
+        track_latency,
+        healthchecker_args.now_fn.clone(),
     );
 
     let mut vector = Vec::new();
@@ -1095,6 +1303,15 @@ where
             s.maybe_update_progress(&gate);
         }
 
+        if assign_partitions {
+            let partition_count = s.fetch_partition_count().await;
+            let partition_count = s.halt_on_err(partition_count).await;
+            s.partitioner = Some(SinkPartitioner {
+                partition_count,
+                next_partition: 0,
+            });
+        }
+
         s.update_status(SinkStatus::Running).await;
 
         while let Some(event) = input.next().await {
@@ -1103,7 +1320,7 @@ where
                     // Queue all pending rows waiting to be sent to kafka
                     assert!(is_active_worker);
                     rows.swap(&mut vector);
-                    for ((key, value), time, diff) in vector.drain(..) {
+                    for ((key, value, partition_hint, retraction), time, diff) in vector.drain(..) {
                         let should_emit = if as_of.strict {
                             as_of.frontier.less_than(&time)
                         } else {
@@ -1125,7 +1342,13 @@ where
                             usize::try_from(diff).expect("can't sink negative multiplicities");
 
                         let rows = s.pending_rows.entry(time).or_default();
-                        rows.push(EncodedRow { key, value, count });
+                        rows.push(EncodedRow {
+                            key,
+                            value,
+                            partition_hint,
+                            retraction,
+                            count,
+                        });
                         s.metrics.rows_queued.inc();
                     }
                 }
@@ -1139,7 +1362,19 @@ where
                         .collect();
                     closed_ts.sort_unstable();
                     closed_ts.into_iter().for_each(|ts| {
-                        let rows = s.pending_rows.remove(&ts).unwrap();
+                        let mut rows = s.pending_rows.remove(&ts).unwrap();
+                        if sort_by_key {
+                            // Order the rows by key, with retractions first, and
+                            // break ties by value so that the order does not
+                            // depend on the order in which the rows arrived.
+                            rows.sort_unstable_by(|a, b| {
+                                (&a.key, !a.retraction, &a.value).cmp(&(
+                                    &b.key,
+                                    !b.retraction,
+                                    &b.value,
+                                ))
+                            });
+                        }
                         s.ready_rows.push_back((ts, rows));
                     });
 
@@ -1169,6 +1404,11 @@ where
                                 Some(r) => record.key(r),
                                 None => record,
                             };
+                            let record = match s.partitioner.as_mut() {
+                                Some(partitioner) => record
+                                    .partition(partitioner.partition(encoded_row.partition_hint)),
+                                None => record,
+                            };
 
                             let ts_bytes = ts.to_string().into_bytes();
                             let record = record.headers(OwnedHeaders::new().insert(Header {
@@ -1264,6 +1504,12 @@ where
 ///
 /// Input [`Row`] updates must me compatible with the given implementor of [`Encode`].
 ///
+/// If a `partition_expr` is given, it is evaluated on the key of every update, and its result is
+/// emitted alongside the encoded update as a partition hint. A `NULL` result yields hint 0.
+///
+/// Every encoded update is also emitted with a flag that reports whether it retracts data, i.e.
+/// whether it has no value, or, if `dbz` is set, whether its Debezium `after` field is `NULL`.
+///
 /// Updates that are not beyond the given [`SinkAsOf`] and/or the `gate_ts` will be discarded
 /// without encoding them.
 ///
@@ -1279,9 +1525,18 @@ fn encode_stream<G>(
     as_of: SinkAsOf,
     shared_gate_ts: Rc<Cell<Option<Timestamp>>>,
     encoder: impl Encode + 'static,
+    partition_expr: Option<MirScalarExpr>,
+    dbz: bool,
     fuel: usize,
     name_prefix: String,
-) -> Stream<G, ((Option<Vec<u8>>, Option<Vec<u8>>), Timestamp, Diff)>
+) -> Stream<
+    G,
+    (
+        (Option<Vec<u8>>, Option<Vec<u8>>, Option<i64>, bool),
+        Timestamp,
+        Diff,
+    ),
+>
 where
     G: Scope<Timestamp = Timestamp>,
 {
@@ -1305,7 +1560,11 @@ where
     >,
                                  output: &mut OutputHandle<
         _,
-        ((Option<Vec<u8>>, Option<Vec<u8>>), Timestamp, Diff),
+        (
+            (Option<Vec<u8>>, Option<Vec<u8>>, Option<i64>, bool),
+            Timestamp,
+            Diff,
+        ),
         _,
     >| {
         let mut fuel_remaining = fuel;
@@ -1347,9 +1606,28 @@ where
             records
                 .drain(..num_records_to_drain)
                 .for_each(|((key, value), time, diff)| {
+                    let partition_hint = partition_expr.as_ref().map(|expr| {
+                        let key = key.as_ref().expect("PARTITION BY requires a key");
+                        let temp_storage = RowArena::new();
+                        let datums = key.unpack();
+                        match expr.eval(&datums, &temp_storage) {
+                            Ok(Datum::Int64(hint)) => hint,
+                            Ok(Datum::Null) => 0,
+                            Ok(datum) => {
+                                panic!("PARTITION BY expression evaluated to {datum:?}")
+                            }
+                            Err(e) => {
+                                halt!("{}: failed to evaluate PARTITION BY: {}", name_prefix, e)
+                            }
+                        }
+                    });
+                    let retraction = match &value {
+                        None => true,
+                        Some(value) => dbz && value.iter().nth(1) == Some(Datum::Null),
+                    };
                     let key = key.map(|key| encoder.encode_key_unchecked(key));
                     let value = value.map(|value| encoder.encode_value_unchecked(value));
-                    session.give(((key, value), time, diff));
+                    session.give(((key, value, partition_hint, retraction), time, diff));
                 });
 
             fuel_remaining -= num_records_to_drain;
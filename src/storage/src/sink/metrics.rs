@@ -11,7 +11,7 @@
 
 use mz_ore::{
     metric,
-    metrics::{IntCounterVec, MetricsRegistry, UIntGaugeVec},
+    metrics::{HistogramVec, IntCounterVec, IntGaugeVec, MetricsRegistry, UIntGaugeVec},
 };
 
 /// Metrics reported by each kafka sink.
@@ -21,6 +21,12 @@ pub struct KafkaBaseMetrics {
     pub(crate) message_send_errors_counter: IntCounterVec,
     pub(crate) message_delivery_errors_counter: IntCounterVec,
     pub(crate) rows_queued: UIntGaugeVec,
+    pub(crate) producer_queued_messages: UIntGaugeVec,
+    pub(crate) producer_queued_bytes: UIntGaugeVec,
+    pub(crate) producer_batch_size_avg: UIntGaugeVec,
+    pub(crate) producer_batch_count_avg: UIntGaugeVec,
+    pub(crate) frontier_lag: HistogramVec,
+    pub(crate) partition_offset_produced: IntGaugeVec,
 }
 
 impl KafkaBaseMetrics {
@@ -46,6 +52,36 @@ impl KafkaBaseMetrics {
                 help: "The current number of rows queued by the Kafka sink operator (note that one row can generate multiple Kafka messages)",
                 var_labels: ["topic", "sink_id", "worker_id"],
             )),
+            producer_queued_messages: registry.register(metric!(
+                name: "mz_kafka_sink_producer_queued_messages",
+                help: "The current number of messages in the Kafka producer's queues, as reported by librdkafka",
+                var_labels: ["topic", "sink_id", "worker_id"],
+            )),
+            producer_queued_bytes: registry.register(metric!(
+                name: "mz_kafka_sink_producer_queued_bytes",
+                help: "The current size of the messages in the Kafka producer's queues, as reported by librdkafka",
+                var_labels: ["topic", "sink_id", "worker_id"],
+            )),
+            producer_batch_size_avg: registry.register(metric!(
+                name: "mz_kafka_sink_producer_batch_size_avg_bytes",
+                help: "The average size of the batches the Kafka producer sent to the topic, as reported by librdkafka",
+                var_labels: ["topic", "sink_id", "worker_id"],
+            )),
+            producer_batch_count_avg: registry.register(metric!(
+                name: "mz_kafka_sink_producer_batch_messages_avg",
+                help: "The average number of messages in the batches the Kafka producer sent to the topic, as reported by librdkafka",
+                var_labels: ["topic", "sink_id", "worker_id"],
+            )),
+            frontier_lag: registry.register(metric!(
+                name: "mz_kafka_sink_frontier_lag_seconds",
+                help: "The time between the commit of a progress record and the timestamp it covers, for sinks with latency tracking enabled",
+                var_labels: ["topic", "sink_id", "worker_id"],
+            )),
+            partition_offset_produced: registry.register(metric!(
+                name: "mz_kafka_sink_partition_offset_produced",
+                help: "The offset after the last message the Kafka sink delivered to the partition. This corresponds to the log end offset of the partition, if the sink is its only producer",
+                var_labels: ["topic", "sink_id", "partition_id"],
+            )),
         }
     }
 }
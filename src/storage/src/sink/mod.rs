@@ -9,6 +9,7 @@
 
 //! Moving data to external systems
 
+mod elasticsearch;
 mod healthcheck;
 mod kafka;
 mod metrics;
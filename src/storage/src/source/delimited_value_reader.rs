@@ -137,6 +137,9 @@ where
             NextMessage::Ready(SourceMessageType::SourceStatus(update)) => {
                 NextMessage::Ready(SourceMessageType::SourceStatus(update))
             }
+            NextMessage::Ready(SourceMessageType::KafkaConsumerStatistics(statistics)) => {
+                NextMessage::Ready(SourceMessageType::KafkaConsumerStatistics(statistics))
+            }
             NextMessage::Pending => NextMessage::Pending,
             NextMessage::TransientDelay => NextMessage::TransientDelay,
             NextMessage::Finished => NextMessage::Finished,
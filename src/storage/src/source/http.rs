@@ -0,0 +1,483 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A source that periodically polls an HTTP endpoint.
+//!
+//! Each poll fetches the endpoint and splits the response body into records:
+//! a JSON array produces one record per element, and any other body produces
+//! one record per non-empty line. Without a key field, every record of every
+//! response is emitted. With a key field, the source emits upsert snapshots:
+//! only records that are new or have changed since the previous response are
+//! emitted, along with tombstones for the keys that the response no longer
+//! contains. After a restart, the keys emitted before are read back from the
+//! output collection of the source, so that the first response deletes the
+//! ones that it no longer contains.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context};
+use timely::progress::Antichain;
+use timely::scheduling::SyncActivator;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use mz_expr::PartitionId;
+use mz_persist_client::cache::PersistClientCache;
+use mz_repr::{Datum, GlobalId, Timestamp};
+use mz_storage_client::controller::CollectionMetadata;
+use mz_storage_client::types::connections::ConnectionContext;
+use mz_storage_client::types::sources::encoding::SourceDataEncoding;
+use mz_storage_client::types::sources::{HttpSourceConnection, MzOffset, SourceConnection};
+
+use crate::source::commit::LogCommitter;
+use crate::source::metrics::SourceBaseMetrics;
+use crate::source::postgres_table::recover_contents;
+use crate::source::source_reader_pipeline::HealthStatus;
+use crate::source::types::SourceConnectionBuilder;
+use crate::source::{SourceMessage, SourceMessageType, SourceReader};
+
+/// The time after which a poll that has not completed fails.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+type Record = (Option<Vec<u8>>, Option<Vec<u8>>);
+
+/// An [`HttpSourceConnection`] along with the means to read back the keys that
+/// the source emitted before it restarted.
+#[derive(Clone)]
+pub struct ResumableHttpConnection {
+    pub connection: HttpSourceConnection,
+    pub persist_clients: Arc<Mutex<PersistClientCache>>,
+    pub storage_metadata: CollectionMetadata,
+    pub resume_upper: Antichain<Timestamp>,
+}
+
+impl SourceConnection for ResumableHttpConnection {
+    fn name(&self) -> &'static str {
+        self.connection.name()
+    }
+}
+
+pub struct HttpSourceReader {
+    source_name: String,
+    source_id: GlobalId,
+    client: reqwest::Client,
+    url: String,
+    poll_interval: Duration,
+    key_field: Option<String>,
+    persist_clients: Arc<Mutex<PersistClientCache>>,
+    storage_metadata: CollectionMetadata,
+    resume_upper: Antichain<Timestamp>,
+    offset: MzOffset,
+    /// The time at which the endpoint was last polled.
+    last_poll: Option<Instant>,
+    /// Whether the source reported that it is stalled since its last
+    /// successful poll.
+    stalled: bool,
+    /// The records of the last response, by key. Only maintained when the
+    /// source emits upsert snapshots, and `None` until the first successful
+    /// poll.
+    snapshot: Option<BTreeMap<Vec<u8>, Vec<u8>>>,
+    /// The records of the last response that remain to be emitted.
+    pending: VecDeque<Record>,
+    // HTTP sources support single-threaded ingestion only, so only one of the
+    // `HttpSourceReader`s will actually produce data.
+    active_read_worker: bool,
+    // The non-active reader has to report back that it is not consuming from
+    // the one [`PartitionId:None`] partition before it can finish.
+    reported_unconsumed_partitions: bool,
+}
+
+impl SourceConnectionBuilder for ResumableHttpConnection {
+    type Reader = HttpSourceReader;
+    type OffsetCommitter = LogCommitter;
+
+    fn into_reader(
+        self,
+        source_name: String,
+        source_id: GlobalId,
+        worker_id: usize,
+        worker_count: usize,
+        _consumer_activator: SyncActivator,
+        restored_offsets: Vec<(PartitionId, Option<MzOffset>)>,
+        _encoding: SourceDataEncoding,
+        _metrics: SourceBaseMetrics,
+        _connection_context: ConnectionContext,
+    ) -> Result<(Self::Reader, Self::OffsetCommitter), anyhow::Error> {
+        let active_read_worker =
+            crate::source::responsible_for(&source_id, worker_id, worker_count, &PartitionId::None);
+
+        let offset = restored_offsets
+            .into_iter()
+            .find_map(|(pid, offset)| {
+                if pid == PartitionId::None {
+                    offset
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Ok((
+            HttpSourceReader {
+                source_name,
+                source_id,
+                client: reqwest::Client::builder()
+                    .timeout(REQUEST_TIMEOUT)
+                    .build()
+                    .expect("must build Client"),
+                url: self.connection.url,
+                poll_interval: self.connection.poll_interval,
+                key_field: self.connection.key_field,
+                persist_clients: self.persist_clients,
+                storage_metadata: self.storage_metadata,
+                resume_upper: self.resume_upper,
+                offset,
+                last_poll: None,
+                stalled: false,
+                snapshot: None,
+                pending: VecDeque::new(),
+                active_read_worker,
+                reported_unconsumed_partitions: false,
+            },
+            LogCommitter {
+                source_id,
+                worker_id,
+                worker_count,
+            },
+        ))
+    }
+}
+
+impl HttpSourceReader {
+    /// Fetches the endpoint and returns the records that need to be emitted,
+    /// along with a description of the records of the response that were
+    /// skipped because they have no key, if any.
+    async fn poll(&mut self) -> Result<(Vec<Record>, Option<String>), anyhow::Error> {
+        let response = self
+            .client
+            .get(&self.url)
+            .send()
+            .await?
+            .error_for_status()?;
+        let json = match &self.key_field {
+            // Snapshots are always keyed by a field of a JSON record.
+            Some(_) => true,
+            None => response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(is_json_content_type)
+                .unwrap_or(false),
+        };
+        let body = response.bytes().await?;
+        let records = split_records(&body, json)?;
+
+        let key_field = match &self.key_field {
+            None => {
+                let records = records
+                    .into_iter()
+                    .map(|value| (None, Some(value)))
+                    .collect();
+                return Ok((records, None));
+            }
+            Some(key_field) => key_field,
+        };
+
+        let record_count = records.len();
+        let mut snapshot = BTreeMap::new();
+        let mut skipped = vec![];
+        for value in records {
+            match extract_key(&value, key_field) {
+                Ok(key) => {
+                    snapshot.insert(key, value);
+                }
+                Err(e) => skipped.push(e),
+            }
+        }
+        let mut records = diff_snapshots(self.snapshot.as_ref(), &snapshot);
+        // The first response after a restart also deletes the keys emitted
+        // before the restart that it no longer contains.
+        if self.snapshot.is_none() {
+            for key in self.recover_keys().await? {
+                if !snapshot.contains_key(&key) {
+                    records.push((Some(key), None));
+                }
+            }
+        }
+        self.snapshot = Some(snapshot);
+
+        let skipped = skipped.first().map(|e| {
+            format!(
+                "skipped {} of the {} records of the response: {:#}",
+                skipped.len(),
+                record_count,
+                e
+            )
+        });
+        Ok((records, skipped))
+    }
+
+    /// Reads back the keys that the source emitted before it restarted.
+    ///
+    /// The key of an upsert source is the first column of its output, and the
+    /// key formats of HTTP sources with a key field decode it as is.
+    async fn recover_keys(&self) -> Result<BTreeSet<Vec<u8>>, anyhow::Error> {
+        let contents = recover_contents(
+            &self.persist_clients,
+            &self.storage_metadata,
+            &self.resume_upper,
+            &format!("http source recovery {}", self.source_id),
+        )
+        .await?;
+        let keys = contents
+            .keys()
+            .filter_map(|row| match row.iter().next() {
+                Some(Datum::String(key)) => Some(key.as_bytes().to_vec()),
+                Some(Datum::Bytes(key)) => Some(key.to_vec()),
+                _ => None,
+            })
+            .collect();
+        Ok(keys)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SourceReader for HttpSourceReader {
+    type Key = Option<Vec<u8>>;
+    type Value = Option<Vec<u8>>;
+    type Time = MzOffset;
+    type Diff = ();
+
+    async fn next(
+        &mut self,
+        _timestamp_granularity: Duration,
+    ) -> Option<SourceMessageType<Self::Key, Self::Value, Self::Diff>> {
+        if !self.active_read_worker {
+            if !self.reported_unconsumed_partitions {
+                self.reported_unconsumed_partitions = true;
+                return Some(SourceMessageType::DropPartitionCapabilities(vec![
+                    PartitionId::None,
+                ]));
+            }
+            return None;
+        }
+
+        loop {
+            if let Some((key, value)) = self.pending.pop_front() {
+                let message = Ok(SourceMessage {
+                    output: 0,
+                    upstream_time_millis: None,
+                    key,
+                    value,
+                    headers: None,
+                });
+                let ts = (PartitionId::None, self.offset);
+                // All records of a response share an offset, which is
+                // finalized by the last of them.
+                if self.pending.is_empty() {
+                    self.offset += 1;
+                    return Some(SourceMessageType::Finalized(message, ts, ()));
+                } else {
+                    return Some(SourceMessageType::InProgress(message, ts, ()));
+                }
+            }
+
+            if let Some(last_poll) = self.last_poll {
+                let elapsed = last_poll.elapsed();
+                if elapsed < self.poll_interval {
+                    tokio::time::sleep(self.poll_interval - elapsed).await;
+                }
+            }
+            self.last_poll = Some(Instant::now());
+
+            let status = match self.poll().await {
+                Ok((records, skipped)) => {
+                    self.pending.extend(records);
+                    match skipped {
+                        Some(skipped) => {
+                            warn!(
+                                "error polling {} for HTTP source {}: {}",
+                                self.url, self.source_name, skipped
+                            );
+                            Some(HealthStatus::StalledWithError(skipped))
+                        }
+                        None if self.stalled => Some(HealthStatus::Running),
+                        None => None,
+                    }
+                }
+                // Failed polls are retried at the next interval.
+                Err(e) => {
+                    warn!(
+                        "error polling {} for HTTP source {}: {:#}",
+                        self.url, self.source_name, e
+                    );
+                    Some(HealthStatus::StalledWithError(format!("{:#}", e)))
+                }
+            };
+            if let Some(status) = status {
+                self.stalled = matches!(status, HealthStatus::StalledWithError(_));
+                return Some(SourceMessageType::status(status));
+            }
+        }
+    }
+}
+
+/// Reports whether a `Content-Type` header value describes JSON.
+fn is_json_content_type(content_type: &str) -> bool {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+    mime == "application/json" || mime.ends_with("+json")
+}
+
+/// Splits a response body into records.
+///
+/// A JSON body that is an array produces one record per element, and any other
+/// JSON body produces a single record. A non-JSON body produces one record per
+/// non-empty line.
+fn split_records(body: &[u8], json: bool) -> Result<Vec<Vec<u8>>, anyhow::Error> {
+    if json {
+        let value: serde_json::Value =
+            serde_json::from_slice(body).context("parsing response as JSON")?;
+        let records = match value {
+            serde_json::Value::Array(elements) => elements
+                .iter()
+                .map(serde_json::to_vec)
+                .collect::<Result<_, _>>()?,
+            value => vec![serde_json::to_vec(&value)?],
+        };
+        Ok(records)
+    } else {
+        Ok(body
+            .split(|b| *b == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_vec())
+            .collect())
+    }
+}
+
+/// Extracts the key of a JSON record from its top-level `key_field`.
+///
+/// String keys are returned as is, and all other keys as their JSON
+/// representation.
+fn extract_key(record: &[u8], key_field: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let value: serde_json::Value = serde_json::from_slice(record)?;
+    let object = match value {
+        serde_json::Value::Object(object) => object,
+        _ => bail!("record is not a JSON object"),
+    };
+    match object.get(key_field) {
+        Some(serde_json::Value::String(key)) => Ok(key.as_bytes().to_vec()),
+        Some(serde_json::Value::Null) | None => {
+            Err(anyhow!("record is missing key field {}", key_field))
+        }
+        Some(key) => Ok(serde_json::to_vec(key)?),
+    }
+}
+
+/// Computes the upsert records that turn the `previous` snapshot into the
+/// `current` one.
+///
+/// Without a previous snapshot all records are emitted, but no tombstones. The
+/// keys emitted before the source restarted are deleted by the caller.
+fn diff_snapshots(
+    previous: Option<&BTreeMap<Vec<u8>, Vec<u8>>>,
+    current: &BTreeMap<Vec<u8>, Vec<u8>>,
+) -> Vec<Record> {
+    let mut records = vec![];
+    if let Some(previous) = previous {
+        for key in previous.keys() {
+            if !current.contains_key(key) {
+                records.push((Some(key.clone()), None));
+            }
+        }
+    }
+    for (key, value) in current {
+        if previous.and_then(|previous| previous.get(key)) != Some(value) {
+            records.push((Some(key.clone()), Some(value.clone())));
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_records() {
+        assert_eq!(
+            split_records(br#"[{"a": 1}, {"a": 2}]"#, true).unwrap(),
+            vec![br#"{"a":1}"#.to_vec(), br#"{"a":2}"#.to_vec()]
+        );
+        assert_eq!(
+            split_records(br#"{"a": 1}"#, true).unwrap(),
+            vec![br#"{"a":1}"#.to_vec()]
+        );
+        assert!(split_records(b"not json", true).is_err());
+        assert_eq!(
+            split_records(b"a,1\r\n\nb,2\n", false).unwrap(),
+            vec![b"a,1".to_vec(), b"b,2".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_extract_key() {
+        assert_eq!(
+            extract_key(br#"{"id": "a", "v": 1}"#, "id").unwrap(),
+            b"a".to_vec()
+        );
+        assert_eq!(
+            extract_key(br#"{"id": 1, "v": 1}"#, "id").unwrap(),
+            b"1".to_vec()
+        );
+        assert!(extract_key(br#"{"v": 1}"#, "id").is_err());
+        assert!(extract_key(br#"{"id": null}"#, "id").is_err());
+        assert!(extract_key(b"[1]", "id").is_err());
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let snapshot = |records: &[(&str, &str)]| -> BTreeMap<Vec<u8>, Vec<u8>> {
+            records
+                .iter()
+                .map(|(k, v)| (k.as_bytes().to_vec(), v.as_bytes().to_vec()))
+                .collect()
+        };
+        let record = |k: &str, v: Option<&str>| {
+            (
+                Some(k.as_bytes().to_vec()),
+                v.map(|v| v.as_bytes().to_vec()),
+            )
+        };
+
+        let first = snapshot(&[("a", "1"), ("b", "2")]);
+        assert_eq!(
+            diff_snapshots(None, &first),
+            vec![record("a", Some("1")), record("b", Some("2"))]
+        );
+
+        let second = snapshot(&[("b", "3"), ("c", "4")]);
+        assert_eq!(
+            diff_snapshots(Some(&first), &second),
+            vec![
+                record("a", None),
+                record("b", Some("3")),
+                record("c", Some("4"))
+            ]
+        );
+        assert_eq!(diff_snapshots(Some(&second), &second), vec![]);
+    }
+}
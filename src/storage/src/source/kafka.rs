@@ -8,10 +8,10 @@
 // by the Apache License, Version 2.0.
 
 use anyhow::Context;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use maplit::btreemap;
 use rdkafka::consumer::base_consumer::PartitionQueue;
@@ -30,6 +30,7 @@ use mz_expr::PartitionId;
 use mz_kafka_util::client::{BrokerRewritingClientContext, MzClientContext};
 use mz_ore::thread::{JoinHandleExt, UnparkOnDropHandle};
 use mz_repr::{adt::jsonb::Jsonb, GlobalId};
+use mz_storage_client::client::{KafkaBrokerStatistics, KafkaConsumerStatistics};
 use mz_storage_client::types::connections::{ConnectionContext, StringOrSecret};
 use mz_storage_client::types::sources::encoding::SourceDataEncoding;
 use mz_storage_client::types::sources::{KafkaSourceConnection, MzOffset};
@@ -43,10 +44,14 @@ use crate::source::{
     NextMessage, SourceMessage, SourceMessageType, SourceReader, SourceReaderError,
 };
 
-use self::metrics::KafkaPartitionMetrics;
+use self::metrics::{KafkaConsumerMetrics, KafkaPartitionMetrics};
 
 mod metrics;
 
+/// The minimum time between two fetches of the watermarks of a partition that
+/// delivers messages the source has already read.
+const OFFSET_REGRESSION_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
 /// Contains all information necessary to ingest data from Kafka
 pub struct KafkaSourceReader {
     /// Name of the topic on which this source is backed on
@@ -67,6 +72,18 @@ pub struct KafkaSourceReader {
     /// reader. An offset of -1 indicates that no prior message has been read
     /// for the given partition.
     last_offsets: HashMap<i32, i64>,
+    /// The low and high watermark of each partition, as last reported by the
+    /// statistics callback.
+    watermarks: HashMap<i32, (i64, i64)>,
+    /// The last time that the watermarks of each partition were fetched by
+    /// `check_offset_regression`.
+    watermark_fetches: HashMap<i32, Instant>,
+    /// The partitions whose offsets moved backwards, which the source no
+    /// longer ingests.
+    regressed_partitions: HashSet<i32>,
+    /// The error that the source reports while it does not ingest
+    /// `regressed_partitions`.
+    regression_error: Option<String>,
     /// The offset to start reading from for each partition.
     start_offsets: HashMap<i32, i64>,
     /// Channel to receive Kafka statistics JSON blobs from the stats callback.
@@ -79,6 +96,11 @@ pub struct KafkaSourceReader {
     _metadata_thread_handle: UnparkOnDropHandle<()>,
     /// A handle to the partition specific metrics
     partition_metrics: KafkaPartitionMetrics,
+    /// A handle to the metrics of the consumer
+    consumer_metrics: KafkaConsumerMetrics,
+    /// The statistics of the consumer that have not been reported to the
+    /// source statistics yet.
+    pending_consumer_statistics: Option<KafkaConsumerStatistics>,
     /// Whether or not to unpack and allocate headers and pass them through in the `SourceMessage`
     include_headers: bool,
     /// The latest status detected by the metadata refresh thread.
@@ -147,6 +169,10 @@ impl SourceConnectionBuilder for KafkaSourceConnection {
                     "topic.metadata.refresh.interval.ms" => "30000".into(), // 30s
                     // TODO: document the rationale for this.
                     "fetch.message.max.bytes" => "134217728".into(),
+                    // How often librdkafka reports statistics, which track
+                    // the high watermarks of partitions and the state of the
+                    // connections to brokers.
+                    "statistics.interval.ms" => "1000".into(),
                     // Consumer group ID. librdkafka requires this, and we use
                     // offset committing to provide a way for users to monitor
                     // ingest progress, though we do not rely on the committed
@@ -264,11 +290,22 @@ impl SourceConnectionBuilder for KafkaSourceConnection {
                 worker_id,
                 worker_count,
                 last_offsets: HashMap::new(),
+                watermarks: HashMap::new(),
+                watermark_fetches: HashMap::new(),
+                regressed_partitions: HashSet::new(),
+                regression_error: None,
                 start_offsets,
                 stats_rx,
                 partition_info,
                 include_headers: self.include_headers.is_some(),
                 _metadata_thread_handle: metadata_thread_handle,
+                consumer_metrics: KafkaConsumerMetrics::new(
+                    &metrics,
+                    topic.clone(),
+                    source_id,
+                    worker_id,
+                ),
+                pending_consumer_statistics: None,
                 partition_metrics: KafkaPartitionMetrics::new(
                     metrics,
                     partition_ids,
@@ -395,6 +432,21 @@ impl SourceReader for KafkaSourceReader {
                 next_message = NextMessage::Ready(SourceMessageType::status(status))
             }
         }
+        if let NextMessage::Pending = next_message {
+            // Partitions whose offsets moved backwards stay stalled until the
+            // source is recreated.
+            if let Some(error) = &self.regression_error {
+                next_message = NextMessage::Ready(SourceMessageType::status(
+                    HealthStatus::StalledWithError(error.clone()),
+                ));
+            }
+        }
+        if let NextMessage::Pending = next_message {
+            if let Some(statistics) = self.pending_consumer_statistics.take() {
+                next_message =
+                    NextMessage::Ready(SourceMessageType::KafkaConsumerStatistics(statistics));
+            }
+        }
 
         next_message
     }
@@ -578,10 +630,43 @@ impl KafkaSourceReader {
                             for (id, partition) in &topic.partitions {
                                 self.partition_metrics
                                     .set_offset_max(*id, partition.hi_offset);
+                                self.update_watermarks(
+                                    *id,
+                                    partition.lo_offset,
+                                    partition.hi_offset,
+                                );
                             }
                         }
                         None => error!("No stats found for topic: {}", &self.topic_name),
                     }
+
+                    let mut brokers: Vec<_> = statistics
+                        .brokers
+                        .values()
+                        // librdkafka reports an internal broker that does not
+                        // correspond to a broker of the cluster.
+                        .filter(|broker| broker.source != "internal")
+                        .map(|broker| KafkaBrokerStatistics {
+                            broker: broker.name.clone(),
+                            state: broker.state.clone(),
+                            rx_errors: broker.rxerrs,
+                            tx_errors: broker.txerrs,
+                            throttle_time_avg_ms: broker
+                                .throttle
+                                .as_ref()
+                                .map_or(0, |throttle| u64::try_from(throttle.avg).unwrap_or(0)),
+                        })
+                        .collect();
+                    brokers.sort_by(|a, b| a.broker.cmp(&b.broker));
+                    let consumer_statistics = KafkaConsumerStatistics {
+                        rebalances: statistics
+                            .cgrp
+                            .as_ref()
+                            .map_or(0, |cgrp| u64::try_from(cgrp.rebalance_cnt).unwrap_or(0)),
+                        brokers,
+                    };
+                    self.consumer_metrics.update(&consumer_statistics);
+                    self.pending_consumer_statistics = Some(consumer_statistics);
                 }
                 Err(e) => {
                     error!("failed decoding librdkafka statistics JSON: {}", e);
@@ -590,6 +675,115 @@ impl KafkaSourceReader {
         }
     }
 
+    /// Records the watermarks of partition `pid` reported by the statistics
+    /// callback, and stops ingesting the partition if either of them moved
+    /// backwards since the last report.
+    ///
+    /// Watermarks only move backwards if the topic was deleted and recreated,
+    /// or its partition truncated. This detects a recreation even if the new
+    /// topic has already grown past the offsets that the source has read, as
+    /// long as the statistics callback observed the partition in between.
+    fn update_watermarks(&mut self, pid: i32, lo_offset: i64, hi_offset: i64) {
+        // Watermarks of -1 are not yet known, and the statistics include
+        // partitions that this worker does not read.
+        if lo_offset < 0 || hi_offset < 0 || !self.last_offsets.contains_key(&pid) {
+            return;
+        }
+        let prev = self.watermarks.insert(pid, (lo_offset, hi_offset));
+        let (prev_lo_offset, prev_hi_offset) = match prev {
+            Some(prev) if lo_offset < prev.0 || hi_offset < prev.1 => prev,
+            _ => return,
+        };
+        if self.regressed_partitions.contains(&pid) {
+            return;
+        }
+        let detail = format!(
+            "the low and high watermarks moved from {} and {} to {} and {}",
+            prev_lo_offset, prev_hi_offset, lo_offset, hi_offset
+        );
+        self.stall_partition(pid, &detail);
+    }
+
+    /// Checks whether the offsets of partition `pid` moved backwards, i.e.
+    /// whether its current high watermark is at or below `last_offset`, the
+    /// last offset that the source has read from it. If so, stops ingesting
+    /// the partition and returns the error to report.
+    ///
+    /// This runs whenever the partition delivers a message that the source
+    /// has already read, which is expected after a seek. The watermarks
+    /// reported by the statistics callback may predate the regression, so the
+    /// current ones are fetched, but at most once per
+    /// [`OFFSET_REGRESSION_CHECK_INTERVAL`], since the fetch blocks the
+    /// reader.
+    fn check_offset_regression(&mut self, pid: i32, last_offset: i64) -> Option<String> {
+        if self.regressed_partitions.contains(&pid) {
+            // Assigning partitions resumes paused ones, so this runs again
+            // whenever a regressed partition delivers a message.
+            self.pause_partition(pid);
+            return self.regression_error.clone();
+        }
+
+        let now = Instant::now();
+        match self.watermark_fetches.get(&pid) {
+            Some(fetched) if now.duration_since(*fetched) < OFFSET_REGRESSION_CHECK_INTERVAL => {
+                return None;
+            }
+            _ => {
+                self.watermark_fetches.insert(pid, now);
+            }
+        }
+        let hi_offset =
+            match self
+                .consumer
+                .fetch_watermarks(&self.topic_name, pid, Duration::from_secs(1))
+            {
+                Ok((_lo_offset, hi_offset)) => hi_offset,
+                Err(e) => {
+                    warn!(
+                        "failed to fetch watermarks of topic {} partition {} for source {}: {}",
+                        self.topic_name, pid, self.source_name, e
+                    );
+                    return None;
+                }
+            };
+        if hi_offset > last_offset {
+            return None;
+        }
+        let detail = format!(
+            "the high watermark is {}, but the source has already read offset {}",
+            hi_offset, last_offset
+        );
+        self.stall_partition(pid, &detail);
+        self.regression_error.clone()
+    }
+
+    /// Stops ingesting partition `pid`, whose offsets moved backwards as
+    /// described by `detail`, and reports the source as stalled until it is
+    /// recreated.
+    fn stall_partition(&mut self, pid: i32, detail: &str) {
+        let message = format!(
+            "offsets of topic {} partition {} moved backwards: {}; the topic may have been \
+            deleted and recreated. Drop and recreate the source to ingest the new topic",
+            self.topic_name, pid, detail
+        );
+        error!("source {}: {}", self.source_name, message);
+        self.regressed_partitions.insert(pid);
+        self.regression_error = Some(message);
+        self.pause_partition(pid);
+    }
+
+    /// Pauses the consumption of partition `pid`.
+    fn pause_partition(&self, pid: i32) {
+        let mut partition_list = TopicPartitionList::new();
+        partition_list.add_partition(&self.topic_name, pid);
+        if let Err(e) = self.consumer.pause(&partition_list) {
+            error!(
+                "failed to pause topic {} partition {} for source {}: {}",
+                self.topic_name, pid, self.source_name, e
+            );
+        }
+    }
+
     /// Polls from the next partition queue and returns the message, if any.
     ///
     /// We maintain the list of partition queues in a queue, and add queues that we polled from to
@@ -672,6 +866,13 @@ impl KafkaSourceReader {
                 time.1.offset,
                 last_offset + 1,
             );
+            // Receiving old messages is expected after a seek, but not if the
+            // partition no longer has the messages we've read.
+            if let Some(error) = self.check_offset_regression(partition, last_offset) {
+                return NextMessage::Ready(SourceMessageType::status(
+                    HealthStatus::StalledWithError(error),
+                ));
+            }
             // Seek to the *next* offset that we have not yet processed
             self.fast_forward_consumer(partition, last_offset + 1);
             // We explicitly should not consume the message as we have already processed it
@@ -680,6 +881,8 @@ impl KafkaSourceReader {
             NextMessage::TransientDelay
         } else {
             *last_offset_ref = offset_as_i64;
+            self.partition_metrics
+                .set_offset_consumed(partition, offset_as_i64 + 1);
             NextMessage::Ready(SourceMessageType::Finalized(message, time, ()))
         }
     }
@@ -9,18 +9,21 @@
 
 use std::collections::HashMap;
 
-use prometheus::core::AtomicI64;
+use prometheus::core::{AtomicI64, AtomicU64};
 use tracing::debug;
 
 use mz_ore::iter::IteratorExt;
 use mz_ore::metrics::{DeleteOnDropGauge, GaugeVecExt};
 use mz_repr::GlobalId;
+use mz_storage_client::client::KafkaConsumerStatistics;
+
+use crate::source::metrics::{KafkaMetrics, SourceBaseMetrics};
 
-use crate::source::metrics::SourceBaseMetrics;
 pub(super) struct KafkaPartitionMetrics {
     labels: Vec<String>,
     base_metrics: SourceBaseMetrics,
     partition_offset_map: HashMap<i32, DeleteOnDropGauge<'static, AtomicI64, Vec<String>>>,
+    partition_offset_consumed_map: HashMap<i32, DeleteOnDropGauge<'static, AtomicI64, Vec<String>>>,
 }
 
 impl KafkaPartitionMetrics {
@@ -41,6 +44,7 @@ impl KafkaPartitionMetrics {
                         .get_delete_on_drop_gauge(labels.to_vec()),
                 )
             })),
+            partition_offset_consumed_map: HashMap::new(),
             labels: vec![topic.clone(), source_id.to_string()],
             base_metrics,
         }
@@ -73,4 +77,110 @@ impl KafkaPartitionMetrics {
             })
             .set(offset);
     }
+
+    /// Records the offset of the next message to consume from a partition.
+    pub fn set_offset_consumed(&mut self, id: i32, offset: i64) {
+        self.partition_offset_consumed_map
+            .entry(id)
+            .or_insert_with_key(|id| {
+                self.base_metrics
+                    .partition_specific
+                    .partition_offset_consumed
+                    .get_delete_on_drop_gauge(
+                        self.labels
+                            .iter()
+                            .cloned()
+                            .chain_one(format!("{}", id))
+                            .collect(),
+                    )
+            })
+            .set(offset);
+    }
+}
+
+/// Metrics of the consumer of a Kafka source, as reported by the librdkafka
+/// statistics callback.
+pub(super) struct KafkaConsumerMetrics {
+    labels: Vec<String>,
+    base_metrics: KafkaMetrics,
+    rebalances: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    brokers: HashMap<String, KafkaBrokerMetrics>,
+}
+
+/// Metrics of a broker that the consumer of a Kafka source is connected to.
+struct KafkaBrokerMetrics {
+    up: DeleteOnDropGauge<'static, AtomicI64, Vec<String>>,
+    rx_errors: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    tx_errors: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    throttle_time_avg_ms: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+}
+
+impl KafkaConsumerMetrics {
+    pub fn new(
+        base_metrics: &SourceBaseMetrics,
+        topic: String,
+        source_id: GlobalId,
+        worker_id: usize,
+    ) -> Self {
+        let labels = vec![topic, source_id.to_string(), worker_id.to_string()];
+        let base_metrics = base_metrics.kafka.clone();
+        Self {
+            rebalances: base_metrics
+                .consumer_rebalances
+                .get_delete_on_drop_gauge(labels.clone()),
+            brokers: HashMap::new(),
+            labels,
+            base_metrics,
+        }
+    }
+
+    /// Updates the metrics to the statistics that the consumer reported last.
+    pub fn update(&mut self, statistics: &KafkaConsumerStatistics) {
+        self.rebalances.set(statistics.rebalances);
+
+        // Forget the brokers that the consumer is no longer connected to.
+        self.brokers.retain(|name, _| {
+            statistics
+                .brokers
+                .iter()
+                .any(|broker| &broker.broker == name)
+        });
+        for broker in &statistics.brokers {
+            let metrics = self
+                .brokers
+                .entry(broker.broker.clone())
+                .or_insert_with_key(|name| {
+                    let labels: Vec<_> = self
+                        .labels
+                        .iter()
+                        .cloned()
+                        .chain_one(name.clone())
+                        .collect();
+                    KafkaBrokerMetrics {
+                        up: self
+                            .base_metrics
+                            .broker_up
+                            .get_delete_on_drop_gauge(labels.clone()),
+                        rx_errors: self
+                            .base_metrics
+                            .broker_rx_errors
+                            .get_delete_on_drop_gauge(labels.clone()),
+                        tx_errors: self
+                            .base_metrics
+                            .broker_tx_errors
+                            .get_delete_on_drop_gauge(labels.clone()),
+                        throttle_time_avg_ms: self
+                            .base_metrics
+                            .broker_throttle_time_avg_ms
+                            .get_delete_on_drop_gauge(labels),
+                    }
+                });
+            metrics.up.set(if broker.state == "UP" { 1 } else { 0 });
+            metrics.rx_errors.set(broker.rx_errors);
+            metrics.tx_errors.set(broker.tx_errors);
+            metrics
+                .throttle_time_avg_ms
+                .set(broker.throttle_time_avg_ms);
+        }
+    }
 }
@@ -0,0 +1,408 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A source that subscribes to a relation of another Materialize instance.
+//!
+//! The source runs `SUBSCRIBE ... WITH (PROGRESS)` against the upstream
+//! instance over pgwire, and emits the updates of each upstream timestamp
+//! once the subscription reports that the timestamp is complete. Upstream
+//! timestamps are used as the offsets of the source, so that after a restart
+//! the source resumes the subscription just beyond the last timestamp that it
+//! emitted, rather than emitting another snapshot.
+//!
+//! If the upstream relation has been compacted beyond that timestamp, the
+//! source reads back the contents it emitted from its output collection, and
+//! subscribes again with a snapshot, whose difference to those contents it
+//! emits.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail};
+use futures::StreamExt;
+use timely::progress::Antichain;
+use timely::scheduling::SyncActivator;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use mz_expr::{MirScalarExpr, PartitionId};
+use mz_persist_client::cache::PersistClientCache;
+use mz_postgres_util::desc::PostgresTableDesc;
+use mz_repr::{Datum, DatumVec, Diff, GlobalId, Row, Timestamp};
+use mz_storage_client::controller::CollectionMetadata;
+use mz_storage_client::types::connections::{ConnectionContext, PostgresConnection};
+use mz_storage_client::types::sources::encoding::SourceDataEncoding;
+use mz_storage_client::types::sources::{MaterializeSourceConnection, MzOffset, SourceConnection};
+
+use crate::source::commit::LogCommitter;
+use crate::source::metrics::SourceBaseMetrics;
+use crate::source::postgres::cast_row;
+use crate::source::postgres_table::{diff_contents, recover_contents};
+use crate::source::source_reader_pipeline::HealthStatus;
+use crate::source::types::{HealthStatusUpdate, SourceConnectionBuilder};
+use crate::source::{SourceMessage, SourceMessageType, SourceReader};
+
+/// The time to wait before resubscribing after the subscription failed.
+const RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The number of columns that `SUBSCRIBE ... WITH (PROGRESS)` emits before the
+/// columns of the relation: `mz_timestamp`, `mz_progressed` and `mz_diff`.
+const SUBSCRIBE_METADATA_COLUMNS: usize = 3;
+
+/// The client of a running subscription along with its output.
+type Subscription = (
+    tokio_postgres::Client,
+    Pin<Box<tokio_postgres::CopyOutStream>>,
+);
+
+/// A [`MaterializeSourceConnection`] along with the means to read back the
+/// contents that the source emitted before it restarted.
+#[derive(Clone)]
+pub struct ResumableMaterializeConnection {
+    pub connection: MaterializeSourceConnection,
+    pub persist_clients: Arc<Mutex<PersistClientCache>>,
+    pub storage_metadata: CollectionMetadata,
+    pub resume_upper: Antichain<Timestamp>,
+}
+
+impl SourceConnection for ResumableMaterializeConnection {
+    fn name(&self) -> &'static str {
+        self.connection.name()
+    }
+}
+
+pub struct MaterializeSourceReader {
+    source_name: String,
+    source_id: GlobalId,
+    connection: PostgresConnection,
+    connection_context: ConnectionContext,
+    view: PostgresTableDesc,
+    casts: Vec<MirScalarExpr>,
+    persist_clients: Arc<Mutex<PersistClientCache>>,
+    storage_metadata: CollectionMetadata,
+    resume_upper: Antichain<Timestamp>,
+    /// The upstream timestamp from which updates have not been emitted yet.
+    offset: MzOffset,
+    /// The offset at which the source restarted.
+    resumed_offset: MzOffset,
+    /// The client and the output of the running subscription, if any.
+    subscription: Option<Subscription>,
+    /// The time at which the last subscription failed.
+    last_failure: Option<Instant>,
+    /// Whether the last subscription failed, and the source reported that it
+    /// is stalled.
+    stalled: bool,
+    /// Whether the source can only recover from the last failure by
+    /// restarting.
+    needs_restart: bool,
+    /// The contents that the source emitted before it restarted, if it
+    /// subscribed with a snapshot that they have to be diffed against.
+    previous_contents: Option<BTreeMap<Row, Diff>>,
+    /// The updates of the upstream timestamp that is not yet known to be
+    /// complete.
+    buffer: Vec<(Row, Diff)>,
+    /// The upstream timestamp of the updates in `buffer`.
+    buffer_offset: Option<MzOffset>,
+    /// The updates of a complete upstream timestamp that remain to be emitted.
+    pending: VecDeque<(Row, Diff)>,
+    /// The upstream timestamp of the updates in `pending`.
+    pending_offset: MzOffset,
+    // Scratch space to use while evaluating casts
+    datum_vec: DatumVec,
+    text_row: Row,
+    // Materialize sources support single-threaded ingestion only, so only
+    // one of the `MaterializeSourceReader`s will actually produce data.
+    active_read_worker: bool,
+    // The non-active reader has to report back that it is not consuming from
+    // the one [`PartitionId:None`] partition before it can finish.
+    reported_unconsumed_partitions: bool,
+}
+
+impl SourceConnectionBuilder for ResumableMaterializeConnection {
+    type Reader = MaterializeSourceReader;
+    type OffsetCommitter = LogCommitter;
+
+    fn into_reader(
+        self,
+        source_name: String,
+        source_id: GlobalId,
+        worker_id: usize,
+        worker_count: usize,
+        _consumer_activator: SyncActivator,
+        restored_offsets: Vec<(PartitionId, Option<MzOffset>)>,
+        _encoding: SourceDataEncoding,
+        _metrics: SourceBaseMetrics,
+        connection_context: ConnectionContext,
+    ) -> Result<(Self::Reader, Self::OffsetCommitter), anyhow::Error> {
+        let active_read_worker =
+            crate::source::responsible_for(&source_id, worker_id, worker_count, &PartitionId::None);
+
+        let offset = restored_offsets
+            .into_iter()
+            .find_map(|(pid, offset)| {
+                if pid == PartitionId::None {
+                    offset
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Ok((
+            MaterializeSourceReader {
+                source_name,
+                source_id,
+                connection: self.connection.connection,
+                connection_context,
+                view: self.connection.view,
+                casts: self.connection.casts,
+                persist_clients: self.persist_clients,
+                storage_metadata: self.storage_metadata,
+                resume_upper: self.resume_upper,
+                offset,
+                resumed_offset: offset,
+                subscription: None,
+                last_failure: None,
+                stalled: false,
+                needs_restart: false,
+                previous_contents: None,
+                buffer: vec![],
+                buffer_offset: None,
+                pending: VecDeque::new(),
+                pending_offset: MzOffset::default(),
+                datum_vec: DatumVec::new(),
+                text_row: Row::default(),
+                active_read_worker,
+                reported_unconsumed_partitions: false,
+            },
+            LogCommitter {
+                source_id,
+                worker_id,
+                worker_count,
+            },
+        ))
+    }
+}
+
+impl MaterializeSourceReader {
+    /// Connects to the upstream instance and starts a subscription that emits
+    /// the updates from `self.offset` on.
+    async fn subscribe(&mut self) -> Result<Subscription, anyhow::Error> {
+        let config = self
+            .connection
+            .config(&*self.connection_context.secrets_reader)
+            .await?;
+        let client = config.connect("materialize_source_subscribe").await?;
+
+        // A source that emitted updates only needs the updates since, and not
+        // another snapshot, unless the upstream relation no longer retains
+        // them.
+        if self.offset > MzOffset::default() && self.previous_contents.is_none() {
+            let query = format!(
+                "COPY (SUBSCRIBE {:?}.{:?} WITH (PROGRESS, SNAPSHOT = false) AS OF {}) TO STDOUT",
+                self.view.namespace,
+                self.view.name,
+                self.offset.offset - 1
+            );
+            match client.copy_out_simple(&query).await {
+                Ok(stream) => return Ok((client, Box::pin(stream))),
+                Err(e) if !is_invalid_as_of(&e) => return Err(e.into()),
+                Err(e) => {
+                    // Only the contents emitted before the restart can be read
+                    // back, and the updates emitted since are not retained.
+                    if self.offset != self.resumed_offset {
+                        self.needs_restart = true;
+                        return Err(e.into());
+                    }
+                    warn!(
+                        "cannot resume subscription to view {:?}.{:?} for source {}, \
+                         subscribing with a snapshot: {:#}",
+                        self.view.namespace, self.view.name, self.source_name, e
+                    );
+                    self.previous_contents = Some(
+                        recover_contents(
+                            &self.persist_clients,
+                            &self.storage_metadata,
+                            &self.resume_upper,
+                            &format!("materialize source recovery {}", self.source_id),
+                        )
+                        .await?,
+                    );
+                }
+            }
+        }
+
+        let query = format!(
+            "COPY (SUBSCRIBE {:?}.{:?} WITH (PROGRESS)) TO STDOUT",
+            self.view.namespace, self.view.name
+        );
+        let stream = client.copy_out_simple(&query).await?;
+        Ok((client, Box::pin(stream)))
+    }
+
+    /// Reads the next row of the subscription, and moves the updates of the
+    /// buffered timestamp to `pending` if the row shows that it is complete.
+    async fn read_update(&mut self) -> Result<(), anyhow::Error> {
+        if self.subscription.is_none() {
+            if let Some(last_failure) = self.last_failure {
+                let elapsed = last_failure.elapsed();
+                if elapsed < RETRY_INTERVAL {
+                    tokio::time::sleep(RETRY_INTERVAL - elapsed).await;
+                }
+            }
+            self.subscription = Some(self.subscribe().await?);
+        }
+        let (_, stream) = self.subscription.as_mut().expect("subscribed above");
+        let b = match stream.next().await.transpose()? {
+            Some(b) => b,
+            None => bail!("subscription ended unexpectedly"),
+        };
+
+        let parser = mz_pgcopy::CopyTextFormatParser::new(b.as_ref(), "\t", "\\N");
+        let mut raw_values = parser.iter_raw(SUBSCRIBE_METADATA_COLUMNS + self.view.columns.len());
+        let mut metadata = Vec::with_capacity(SUBSCRIBE_METADATA_COLUMNS);
+        for _ in 0..SUBSCRIBE_METADATA_COLUMNS {
+            let value = raw_values
+                .next()
+                .ok_or_else(|| anyhow!("subscription row is missing metadata columns"))??;
+            metadata.push(value.map(std::str::from_utf8).transpose()?);
+        }
+        let (timestamp, progressed, diff) = match metadata[..] {
+            [Some(timestamp), Some(progressed), diff] => (timestamp, progressed, diff),
+            _ => bail!("subscription row has null metadata"),
+        };
+        let timestamp = MzOffset::from(timestamp.parse::<u64>()?);
+
+        // All updates at times before a progressed timestamp have been
+        // received, and updates are received in timestamp order.
+        let complete = match self.buffer_offset {
+            Some(buffer_offset) => buffer_offset < timestamp,
+            None => false,
+        };
+        if complete {
+            self.pending_offset = self.buffer_offset.take().expect("known to be some");
+            match self.previous_contents.take() {
+                // The first complete timestamp of a subscription with a
+                // snapshot holds the entire contents of the relation.
+                Some(previous) => {
+                    let mut current = BTreeMap::new();
+                    for (row, diff) in self.buffer.drain(..) {
+                        *current.entry(row).or_insert(0) += diff;
+                    }
+                    current.retain(|_, diff| *diff != 0);
+                    self.pending.extend(diff_contents(&previous, &current));
+                }
+                None => self.pending.extend(self.buffer.drain(..)),
+            }
+        }
+
+        if progressed == "t" {
+            return Ok(());
+        }
+        let diff: Diff = match diff {
+            Some(diff) => diff.parse()?,
+            None => bail!("subscription update has a null mz_diff"),
+        };
+
+        let mut packer = self.text_row.packer();
+        while let Some(raw_value) = raw_values.next() {
+            match raw_value? {
+                Some(value) => packer.push(Datum::String(std::str::from_utf8(value)?)),
+                None => packer.push(Datum::Null),
+            }
+        }
+        let mut datums = self.datum_vec.borrow();
+        datums.extend(self.text_row.iter());
+        let row = cast_row(&self.casts, &datums)?;
+
+        self.buffer.push((row, diff));
+        self.buffer_offset = Some(timestamp);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SourceReader for MaterializeSourceReader {
+    type Key = ();
+    type Value = Row;
+    type Time = MzOffset;
+    type Diff = Diff;
+
+    async fn next(
+        &mut self,
+        _timestamp_granularity: Duration,
+    ) -> Option<SourceMessageType<Self::Key, Self::Value, Self::Diff>> {
+        if !self.active_read_worker {
+            if !self.reported_unconsumed_partitions {
+                self.reported_unconsumed_partitions = true;
+                return Some(SourceMessageType::DropPartitionCapabilities(vec![
+                    PartitionId::None,
+                ]));
+            }
+            return None;
+        }
+
+        loop {
+            if let Some((row, diff)) = self.pending.pop_front() {
+                let message = Ok(SourceMessage {
+                    output: 0,
+                    upstream_time_millis: None,
+                    key: (),
+                    value: row,
+                    headers: None,
+                });
+                let ts = (PartitionId::None, self.pending_offset);
+                // All updates of an upstream timestamp share an offset, which
+                // is finalized by the last of them.
+                if self.pending.is_empty() {
+                    self.offset = self.pending_offset + 1;
+                    return Some(SourceMessageType::Finalized(message, ts, diff));
+                } else {
+                    return Some(SourceMessageType::InProgress(message, ts, diff));
+                }
+            }
+
+            match self.read_update().await {
+                Ok(()) => {
+                    if self.stalled {
+                        self.stalled = false;
+                        return Some(SourceMessageType::status(HealthStatus::Running));
+                    }
+                }
+                Err(e) => {
+                    // The updates of the buffered timestamp are received again
+                    // when the source resubscribes.
+                    warn!(
+                        "error subscribing to view {:?}.{:?} for source {}: {:#}",
+                        self.view.namespace, self.view.name, self.source_name, e
+                    );
+                    self.subscription = None;
+                    self.buffer.clear();
+                    self.buffer_offset = None;
+                    self.last_failure = Some(Instant::now());
+                    self.stalled = true;
+                    return Some(SourceMessageType::SourceStatus(HealthStatusUpdate {
+                        update: HealthStatus::StalledWithError(format!("{:#}", e)),
+                        should_halt: self.needs_restart,
+                    }));
+                }
+            }
+        }
+    }
+}
+
+/// Reports whether the upstream instance refused a subscription because its
+/// `AS OF` is no longer valid for the upstream relation.
+fn is_invalid_as_of(e: &tokio_postgres::Error) -> bool {
+    e.as_db_error().map_or(false, |e| {
+        e.message().contains("is not valid for all inputs")
+    })
+}
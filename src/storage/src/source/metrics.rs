@@ -80,6 +80,48 @@ impl KinesisMetrics {
     }
 }
 
+/// The base metrics set for the kafka module.
+#[derive(Clone, Debug)]
+pub(crate) struct KafkaMetrics {
+    pub(crate) broker_up: IntGaugeVec,
+    pub(crate) broker_rx_errors: UIntGaugeVec,
+    pub(crate) broker_tx_errors: UIntGaugeVec,
+    pub(crate) broker_throttle_time_avg_ms: UIntGaugeVec,
+    pub(crate) consumer_rebalances: UIntGaugeVec,
+}
+
+impl KafkaMetrics {
+    fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            broker_up: registry.register(metric!(
+                name: "mz_kafka_broker_up",
+                help: "Whether the connection of the source's consumer to the broker is up",
+                var_labels: ["topic", "source_id", "worker_id", "broker"],
+            )),
+            broker_rx_errors: registry.register(metric!(
+                name: "mz_kafka_broker_rx_errors",
+                help: "The number of errors the source's consumer encountered receiving from the broker",
+                var_labels: ["topic", "source_id", "worker_id", "broker"],
+            )),
+            broker_tx_errors: registry.register(metric!(
+                name: "mz_kafka_broker_tx_errors",
+                help: "The number of errors the source's consumer encountered sending to the broker",
+                var_labels: ["topic", "source_id", "worker_id", "broker"],
+            )),
+            broker_throttle_time_avg_ms: registry.register(metric!(
+                name: "mz_kafka_broker_throttle_time_avg_ms",
+                help: "The average time the broker throttled the source's consumer over the last statistics interval",
+                var_labels: ["topic", "source_id", "worker_id", "broker"],
+            )),
+            consumer_rebalances: registry.register(metric!(
+                name: "mz_kafka_consumer_rebalances",
+                help: "The number of consumer group rebalances of the source's consumer",
+                var_labels: ["topic", "source_id", "worker_id"],
+            )),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct SourceSpecificMetrics {
     pub(super) capability: UIntGaugeVec,
@@ -156,6 +198,7 @@ pub(super) struct PartitionSpecificMetrics {
     pub(super) closed_ts: UIntGaugeVec,
     pub(super) messages_ingested: GenericCounterVec<AtomicI64>,
     pub(super) partition_offset_max: IntGaugeVec,
+    pub(super) partition_offset_consumed: IntGaugeVec,
     pub(super) source_resume_upper: UIntGaugeVec,
 }
 
@@ -188,6 +231,11 @@ impl PartitionSpecificMetrics {
                 help: "High watermark offset on broker for partition",
                 var_labels: ["topic", "source_id", "partition_id"],
             )),
+            partition_offset_consumed: registry.register(metric!(
+                name: "mz_kafka_partition_offset_consumed",
+                help: "The offset of the next message the source will consume from the partition. The difference to mz_kafka_partition_offset_max is the consumer lag of the source",
+                var_labels: ["topic", "source_id", "partition_id"],
+            )),
             source_resume_upper: registry.register(metric!(
                 name: "mz_source_resume_upper",
                 help: "The offset-domain upper that is used for initializing this partition",
@@ -266,6 +314,7 @@ pub struct SourceBaseMetrics {
 
     pub(crate) s3: S3Metrics,
     pub(crate) kinesis: KinesisMetrics,
+    pub(crate) kafka: KafkaMetrics,
 
     pub(crate) bytes_read: IntCounter,
 
@@ -283,6 +332,7 @@ impl SourceBaseMetrics {
 
             s3: S3Metrics::register_with(registry),
             kinesis: KinesisMetrics::register_with(registry),
+            kafka: KafkaMetrics::register_with(registry),
 
             bytes_read: registry.register(metric!(
                 name: "mz_bytes_read_total",
@@ -39,10 +39,13 @@ mod commit;
 mod delimited_value_reader;
 pub mod generator;
 pub mod healthcheck;
+mod http;
 mod kafka;
 mod kinesis;
+mod materialize;
 pub mod metrics;
 mod postgres;
+mod postgres_table;
 mod reclock;
 mod resumption;
 mod s3;
@@ -55,9 +58,12 @@ pub mod types;
 
 pub use delimited_value_reader::DelimitedValueSourceConnection;
 pub use generator::LoadGeneratorSourceReader;
+pub use http::{HttpSourceReader, ResumableHttpConnection};
 pub use kafka::KafkaSourceReader;
 pub use kinesis::KinesisSourceReader;
+pub use materialize::{MaterializeSourceReader, ResumableMaterializeConnection};
 pub use postgres::PostgresSourceReader;
+pub use postgres_table::{PostgresTableSourceReader, ResumablePostgresTableConnection};
 pub use s3::S3SourceReader;
 pub use source_reader_pipeline::create_raw_source;
 pub use source_reader_pipeline::RawSourceCreationConfig;
@@ -733,7 +733,7 @@ impl PostgresTaskInfo {
                 let mut datums = datum_vec.borrow();
                 datums.extend(text_row.iter());
 
-                let row = try_definite!(PostgresTaskInfo::cast_row(&info.casts, &datums));
+                let row = try_definite!(cast_row(&info.casts, &datums));
 
                 self.row_sender
                     .insert(info.output_index, row, self.lsn)
@@ -782,18 +782,6 @@ impl PostgresTaskInfo {
         Ok(())
     }
 
-    /// Casts a text row into the target types
-    fn cast_row(table_cast: &[MirScalarExpr], datums: &[Datum<'_>]) -> Result<Row, anyhow::Error> {
-        let arena = mz_repr::RowArena::new();
-        let mut row = Row::default();
-        let mut packer = row.packer();
-        for column_cast in table_cast {
-            let datum = column_cast.eval(datums, &arena)?;
-            packer.push(datum);
-        }
-        Ok(row)
-    }
-
     async fn produce_replication(&mut self) -> Result<(), ReplicationError> {
         use ReplicationError::*;
 
@@ -958,7 +946,7 @@ impl PostgresTaskInfo {
                             new_tuple,
                             &mut *datums
                         ));
-                        let row = try_definite!(PostgresTaskInfo::cast_row(&info.casts, &datums));
+                        let row = try_definite!(cast_row(&info.casts, &datums));
                         inserts.push((info.output_index, row));
                     }
                     Update(update) if self.source_tables.contains_key(&update.rel_id()) => {
@@ -981,8 +969,7 @@ impl PostgresTaskInfo {
                             old_tuple,
                             &mut *old_datums
                         ));
-                        let old_row =
-                            try_definite!(PostgresTaskInfo::cast_row(&info.casts, &old_datums));
+                        let old_row = try_definite!(cast_row(&info.casts, &old_datums));
                         deletes.push((info.output_index, old_row));
                         drop(old_datums);
 
@@ -1003,8 +990,7 @@ impl PostgresTaskInfo {
                             new_tuple,
                             &mut *new_datums
                         ));
-                        let new_row =
-                            try_definite!(PostgresTaskInfo::cast_row(&info.casts, &new_datums));
+                        let new_row = try_definite!(cast_row(&info.casts, &new_datums));
                         inserts.push((info.output_index, new_row));
                     }
                     Delete(delete) if self.source_tables.contains_key(&delete.rel_id()) => {
@@ -1027,7 +1013,7 @@ impl PostgresTaskInfo {
                             old_tuple,
                             &mut *datums
                         ));
-                        let row = try_definite!(PostgresTaskInfo::cast_row(&info.casts, &datums));
+                        let row = try_definite!(cast_row(&info.casts, &datums));
                         deletes.push((info.output_index, row));
                     }
                     Commit(commit) => {
@@ -1170,3 +1156,18 @@ impl PostgresTaskInfo {
         Ok(())
     }
 }
+
+/// Casts a text row into the target types
+pub(super) fn cast_row(
+    table_cast: &[MirScalarExpr],
+    datums: &[Datum<'_>],
+) -> Result<Row, anyhow::Error> {
+    let arena = mz_repr::RowArena::new();
+    let mut row = Row::default();
+    let mut packer = row.packer();
+    for column_cast in table_cast {
+        let datum = column_cast.eval(datums, &arena)?;
+        packer.push(datum);
+    }
+    Ok(row)
+}
@@ -0,0 +1,409 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A source that snapshots a single Postgres table.
+//!
+//! Rather than replicating the changes to a table, the source copies the
+//! entire table, either once or on an interval, and emits the differences
+//! between each copy and the previous one. This supports tables in databases
+//! that cannot provide logical replication, at the cost of keeping a copy of
+//! the table in memory.
+//!
+//! After a restart, the source reads back the contents it previously emitted
+//! from its output collection, so that the next copy of the table only emits
+//! the changes since.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use futures::StreamExt;
+use timely::progress::Antichain;
+use timely::scheduling::SyncActivator;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use mz_expr::{MirScalarExpr, PartitionId};
+use mz_persist_client::cache::PersistClientCache;
+use mz_postgres_util::desc::PostgresTableDesc;
+use mz_repr::{Datum, DatumVec, Diff, GlobalId, Row, Timestamp};
+use mz_storage_client::controller::CollectionMetadata;
+use mz_storage_client::types::connections::{ConnectionContext, PostgresConnection};
+use mz_storage_client::types::sources::encoding::SourceDataEncoding;
+use mz_storage_client::types::sources::{
+    MzOffset, PostgresTableSourceConnection, SourceConnection, SourceData,
+};
+
+use crate::source::commit::LogCommitter;
+use crate::source::metrics::SourceBaseMetrics;
+use crate::source::postgres::cast_row;
+use crate::source::source_reader_pipeline::HealthStatus;
+use crate::source::types::SourceConnectionBuilder;
+use crate::source::{SourceMessage, SourceMessageType, SourceReader};
+
+/// The time to wait before retrying a failed snapshot of a table that is not
+/// periodically refreshed.
+const RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A [`PostgresTableSourceConnection`] along with the means to read back the
+/// contents that the source emitted before it restarted.
+#[derive(Clone)]
+pub struct ResumablePostgresTableConnection {
+    pub connection: PostgresTableSourceConnection,
+    pub persist_clients: Arc<Mutex<PersistClientCache>>,
+    pub storage_metadata: CollectionMetadata,
+    pub resume_upper: Antichain<Timestamp>,
+}
+
+impl SourceConnection for ResumablePostgresTableConnection {
+    fn name(&self) -> &'static str {
+        self.connection.name()
+    }
+}
+
+pub struct PostgresTableSourceReader {
+    source_name: String,
+    source_id: GlobalId,
+    connection: PostgresConnection,
+    connection_context: ConnectionContext,
+    table: PostgresTableDesc,
+    casts: Vec<MirScalarExpr>,
+    refresh_interval: Option<Duration>,
+    persist_clients: Arc<Mutex<PersistClientCache>>,
+    storage_metadata: CollectionMetadata,
+    resume_upper: Antichain<Timestamp>,
+    offset: MzOffset,
+    /// The time at which the table was last snapshotted.
+    last_attempt: Option<Instant>,
+    /// Whether the table is not refreshed and has already been snapshotted.
+    finished: bool,
+    /// Whether the source reported that it is stalled since its last
+    /// successful snapshot.
+    stalled: bool,
+    /// The contents of the table as of the last snapshot. `None` until the
+    /// contents emitted before a restart have been read back.
+    contents: Option<BTreeMap<Row, Diff>>,
+    /// The updates of the last snapshot that remain to be emitted.
+    pending: VecDeque<(Row, Diff)>,
+    // Postgres table sources support single-threaded ingestion only, so only
+    // one of the `PostgresTableSourceReader`s will actually produce data.
+    active_read_worker: bool,
+    // The non-active reader has to report back that it is not consuming from
+    // the one [`PartitionId:None`] partition before it can finish.
+    reported_unconsumed_partitions: bool,
+}
+
+impl SourceConnectionBuilder for ResumablePostgresTableConnection {
+    type Reader = PostgresTableSourceReader;
+    type OffsetCommitter = LogCommitter;
+
+    fn into_reader(
+        self,
+        source_name: String,
+        source_id: GlobalId,
+        worker_id: usize,
+        worker_count: usize,
+        _consumer_activator: SyncActivator,
+        restored_offsets: Vec<(PartitionId, Option<MzOffset>)>,
+        _encoding: SourceDataEncoding,
+        _metrics: SourceBaseMetrics,
+        connection_context: ConnectionContext,
+    ) -> Result<(Self::Reader, Self::OffsetCommitter), anyhow::Error> {
+        let active_read_worker =
+            crate::source::responsible_for(&source_id, worker_id, worker_count, &PartitionId::None);
+
+        let offset = restored_offsets
+            .into_iter()
+            .find_map(|(pid, offset)| {
+                if pid == PartitionId::None {
+                    offset
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        // Each snapshot that produced updates advanced the offset, so the
+        // source has not emitted anything if the offset is still at its start.
+        let resumed = offset > MzOffset::default();
+        let finished =
+            self.resume_upper.is_empty() || (resumed && self.connection.refresh_interval.is_none());
+        let contents = if resumed { None } else { Some(BTreeMap::new()) };
+
+        Ok((
+            PostgresTableSourceReader {
+                source_name,
+                source_id,
+                connection: self.connection.connection,
+                connection_context,
+                table: self.connection.table,
+                casts: self.connection.casts,
+                refresh_interval: self.connection.refresh_interval,
+                persist_clients: self.persist_clients,
+                storage_metadata: self.storage_metadata,
+                resume_upper: self.resume_upper,
+                offset,
+                last_attempt: None,
+                finished,
+                stalled: false,
+                contents,
+                pending: VecDeque::new(),
+                active_read_worker,
+                reported_unconsumed_partitions: false,
+            },
+            LogCommitter {
+                source_id,
+                worker_id,
+                worker_count,
+            },
+        ))
+    }
+}
+
+impl PostgresTableSourceReader {
+    /// Snapshots the table and returns the updates that need to be emitted.
+    async fn refresh(&mut self) -> Result<Vec<(Row, Diff)>, anyhow::Error> {
+        let previous = match self.contents.take() {
+            Some(previous) => previous,
+            None => self.recover_contents().await?,
+        };
+        let current = match self.snapshot().await {
+            Ok(current) => current,
+            Err(e) => {
+                self.contents = Some(previous);
+                return Err(e);
+            }
+        };
+        let updates = diff_contents(&previous, &current);
+        self.contents = Some(current);
+        Ok(updates)
+    }
+
+    /// Reads back the contents that the source emitted before it restarted.
+    async fn recover_contents(&self) -> Result<BTreeMap<Row, Diff>, anyhow::Error> {
+        recover_contents(
+            &self.persist_clients,
+            &self.storage_metadata,
+            &self.resume_upper,
+            &format!("postgres table recovery {}", self.source_id),
+        )
+        .await
+    }
+
+    /// Copies the table and returns its contents.
+    async fn snapshot(&self) -> Result<BTreeMap<Row, Diff>, anyhow::Error> {
+        let config = self
+            .connection
+            .config(&*self.connection_context.secrets_reader)
+            .await?;
+        let client = config.connect("postgres_table_snapshot").await?;
+        let reader = client
+            .copy_out_simple(
+                format!(
+                    "COPY {:?}.{:?} TO STDOUT (FORMAT TEXT, DELIMITER '\t')",
+                    self.table.namespace, self.table.name
+                )
+                .as_str(),
+            )
+            .await?;
+        tokio::pin!(reader);
+
+        let mut contents = BTreeMap::new();
+        // Scratch space to use while evaluating casts
+        let mut datum_vec = DatumVec::new();
+        let mut text_row = Row::default();
+        while let Some(b) = reader.next().await.transpose()? {
+            let mut packer = text_row.packer();
+            let parser = mz_pgcopy::CopyTextFormatParser::new(b.as_ref(), "\t", "\\N");
+            let mut raw_values = parser.iter_raw(self.table.columns.len());
+            while let Some(raw_value) = raw_values.next() {
+                match raw_value? {
+                    Some(value) => packer.push(Datum::String(std::str::from_utf8(value)?)),
+                    None => packer.push(Datum::Null),
+                }
+            }
+
+            let mut datums = datum_vec.borrow();
+            datums.extend(text_row.iter());
+            let row = cast_row(&self.casts, &datums)?;
+            *contents.entry(row).or_insert(0) += 1;
+        }
+        Ok(contents)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SourceReader for PostgresTableSourceReader {
+    type Key = ();
+    type Value = Row;
+    type Time = MzOffset;
+    type Diff = Diff;
+
+    async fn next(
+        &mut self,
+        _timestamp_granularity: Duration,
+    ) -> Option<SourceMessageType<Self::Key, Self::Value, Self::Diff>> {
+        if !self.active_read_worker {
+            if !self.reported_unconsumed_partitions {
+                self.reported_unconsumed_partitions = true;
+                return Some(SourceMessageType::DropPartitionCapabilities(vec![
+                    PartitionId::None,
+                ]));
+            }
+            return None;
+        }
+
+        loop {
+            if let Some((row, diff)) = self.pending.pop_front() {
+                let message = Ok(SourceMessage {
+                    output: 0,
+                    upstream_time_millis: None,
+                    key: (),
+                    value: row,
+                    headers: None,
+                });
+                let ts = (PartitionId::None, self.offset);
+                // All updates of a snapshot share an offset, which is
+                // finalized by the last of them.
+                if self.pending.is_empty() {
+                    self.offset += 1;
+                    return Some(SourceMessageType::Finalized(message, ts, diff));
+                } else {
+                    return Some(SourceMessageType::InProgress(message, ts, diff));
+                }
+            }
+
+            if self.finished {
+                future::pending::<()>().await;
+            }
+
+            if let Some(last_attempt) = self.last_attempt {
+                let interval = self.refresh_interval.unwrap_or(RETRY_INTERVAL);
+                let elapsed = last_attempt.elapsed();
+                if elapsed < interval {
+                    tokio::time::sleep(interval - elapsed).await;
+                }
+            }
+            self.last_attempt = Some(Instant::now());
+
+            match self.refresh().await {
+                Ok(updates) => {
+                    self.pending.extend(updates);
+                    self.finished = self.refresh_interval.is_none();
+                    if self.stalled {
+                        self.stalled = false;
+                        return Some(SourceMessageType::status(HealthStatus::Running));
+                    }
+                }
+                // Failed snapshots are retried at the next interval.
+                Err(e) => {
+                    warn!(
+                        "error snapshotting table {:?}.{:?} for source {}: {:#}",
+                        self.table.namespace, self.table.name, self.source_name, e
+                    );
+                    self.stalled = true;
+                    return Some(SourceMessageType::status(HealthStatus::StalledWithError(
+                        format!("error snapshotting table: {:#}", e),
+                    )));
+                }
+            }
+        }
+    }
+}
+
+/// Reads back the rows that a source emitted before it restarted at
+/// `resume_upper`. Errors that the source emitted are ignored.
+pub(crate) async fn recover_contents(
+    persist_clients: &Mutex<PersistClientCache>,
+    storage_metadata: &CollectionMetadata,
+    resume_upper: &Antichain<Timestamp>,
+    purpose: &str,
+) -> Result<BTreeMap<Row, Diff>, anyhow::Error> {
+    let as_of = match resume_upper.as_option() {
+        Some(&upper) if upper > Timestamp::MIN => upper.saturating_sub(1),
+        _ => return Ok(BTreeMap::new()),
+    };
+
+    let persist_client = persist_clients
+        .lock()
+        .await
+        .open(storage_metadata.persist_location.clone())
+        .await?;
+    let mut read_handle = persist_client
+        .open_leased_reader::<SourceData, (), Timestamp, Diff>(storage_metadata.data_shard, purpose)
+        .await?;
+    let updates = read_handle
+        .snapshot_and_fetch(Antichain::from_elem(as_of))
+        .await
+        .map_err(|since| anyhow!("cannot read contents as of {}: {:?}", as_of, since))?;
+    read_handle.expire().await;
+
+    let mut contents = BTreeMap::new();
+    for ((data, _), _, diff) in updates {
+        if let Ok(SourceData(Ok(row))) = data {
+            *contents.entry(row).or_insert(0) += diff;
+        }
+    }
+    contents.retain(|_, diff| *diff != 0);
+    Ok(contents)
+}
+
+/// Computes the updates that turn the `previous` contents of a table into the
+/// `current` ones.
+pub(crate) fn diff_contents(
+    previous: &BTreeMap<Row, Diff>,
+    current: &BTreeMap<Row, Diff>,
+) -> Vec<(Row, Diff)> {
+    let mut updates = vec![];
+    for (row, diff) in previous {
+        let new_diff = current.get(row).copied().unwrap_or(0);
+        if new_diff != *diff {
+            updates.push((row.clone(), new_diff - diff));
+        }
+    }
+    for (row, diff) in current {
+        if !previous.contains_key(row) {
+            updates.push((row.clone(), *diff));
+        }
+    }
+    updates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_contents() {
+        let row = |i: i64| Row::pack_slice(&[Datum::Int64(i)]);
+        let contents = |rows: &[(i64, Diff)]| -> BTreeMap<Row, Diff> {
+            rows.iter().map(|(i, diff)| (row(*i), *diff)).collect()
+        };
+
+        let empty = contents(&[]);
+        let first = contents(&[(1, 1), (2, 2)]);
+        assert_eq!(
+            diff_contents(&empty, &first),
+            vec![(row(1), 1), (row(2), 2)]
+        );
+
+        let second = contents(&[(2, 1), (3, 1)]);
+        assert_eq!(
+            diff_contents(&first, &second),
+            vec![(row(1), -1), (row(2), -1), (row(3), 1)]
+        );
+        assert_eq!(diff_contents(&second, &second), vec![]);
+        assert_eq!(
+            diff_contents(&second, &empty),
+            vec![(row(2), -1), (row(3), -1)]
+        );
+    }
+}
@@ -45,7 +45,7 @@ use timely::dataflow::operators::{Broadcast, CapabilitySet, Partition};
 use timely::dataflow::{Scope, Stream};
 use timely::progress::{Antichain, Timestamp as _};
 use timely::PartialOrder;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 use tokio::time::MissedTickBehavior;
 use tokio_stream::StreamExt;
 use tracing::{info, trace, warn};
@@ -94,8 +94,8 @@ pub struct RawSourceCreationConfig {
     /// The total count of workers
     pub worker_count: usize,
     /// Granularity with which timestamps should be closed (and capabilities
-    /// downgraded).
-    pub timestamp_interval: Duration,
+    /// downgraded). The interval can change while the source is running.
+    pub timestamp_interval: watch::Receiver<Duration>,
     /// Data encoding
     pub encoding: SourceDataEncoding,
     /// The function to return a now time.
@@ -287,7 +287,7 @@ where
         num_outputs: _,
         worker_id,
         worker_count,
-        timestamp_interval,
+        mut timestamp_interval,
         encoding: _,
         storage_metadata: _,
         resume_upper: _,
@@ -314,14 +314,17 @@ where
             batch_upper: batch_upper.clone(),
         });
 
-        let source_stream = source_reader.into_stream(timestamp_interval).fuse();
+        // Source readers only learn about the timestamp interval the source
+        // was started with.
+        let initial_timestamp_interval = *timestamp_interval.borrow_and_update();
+        let source_stream = source_reader.into_stream(initial_timestamp_interval).fuse();
 
         tokio::pin!(source_stream);
 
         // Emit batches more frequently than we mint new timestamps. We're
         // hoping that most of the batches that we emit will end up making
         // it into the freshly minted bindings when remap_operator ticks.
-        let mut emission_interval = tokio::time::interval(timestamp_interval / 5);
+        let mut emission_interval = tokio::time::interval(initial_timestamp_interval / 5);
         emission_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
         let mut untimestamped_messages = HashMap::<_, Vec<_>>::new();
@@ -394,6 +397,9 @@ where
                                 SourceMessageType::SourceStatus(update) => {
                                     status_update = Some(update);
                                 }
+                                SourceMessageType::KafkaConsumerStatistics(statistics) => {
+                                    source_statistics.set_kafka_consumer_statistics(statistics);
+                                }
                             }
                         }
                         None => {
@@ -445,6 +451,12 @@ where
                         }
                     );
                 }
+                // N.B. This branch is cancel-safe because `changed` is.
+                Ok(()) = timestamp_interval.changed() => {
+                    let timestamp_interval = *timestamp_interval.borrow_and_update();
+                    emission_interval = tokio::time::interval(timestamp_interval / 5);
+                    emission_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                }
             }
         }
     }))
@@ -962,19 +974,16 @@ where
 
 struct RemapClock {
     now: NowFn,
-    update_interval_ms: u64,
+    update_interval: watch::Receiver<Duration>,
     upper: Antichain<Timestamp>,
     sleep: Pin<Box<tokio::time::Sleep>>,
 }
 
 impl RemapClock {
-    fn new(now: NowFn, update_interval: Duration) -> Self {
+    fn new(now: NowFn, update_interval: watch::Receiver<Duration>) -> Self {
         Self {
             now,
-            update_interval_ms: update_interval
-                .as_millis()
-                .try_into()
-                .expect("huge duration"),
+            update_interval,
             upper: Antichain::from_elem(timely::progress::Timestamp::minimum()),
             sleep: Box::pin(tokio::time::sleep_until(tokio::time::Instant::now())),
         }
@@ -988,9 +997,17 @@ impl futures::Stream for RemapClock {
         loop {
             futures::ready!(self.sleep.as_mut().poll(cx));
             let now = (self.now)();
-            let mut new_ts = now - now % self.update_interval_ms;
-            if (now % self.update_interval_ms) != 0 {
-                new_ts += self.update_interval_ms;
+            // The interval can change at any time, but `upper` makes sure we
+            // never mint a timestamp that is not beyond the previous one.
+            let update_interval_ms: u64 = self
+                .update_interval
+                .borrow()
+                .as_millis()
+                .try_into()
+                .expect("huge duration");
+            let mut new_ts = now - now % update_interval_ms;
+            if (now % update_interval_ms) != 0 {
+                new_ts += update_interval_ms;
             }
             let new_ts: Timestamp = new_ts.try_into().expect("must fit");
 
@@ -1030,7 +1047,7 @@ where
         num_outputs: _,
         worker_id,
         worker_count,
-        timestamp_interval,
+        mut timestamp_interval,
         encoding: _,
         storage_metadata,
         resume_upper,
@@ -1093,7 +1110,7 @@ where
         )
         .await
         .unwrap_or_else(|e| panic!("Failed to create remap handle for source {}: {:#}", name, e));
-        let clock = RemapClock::new(now.clone(), timestamp_interval);
+        let clock = RemapClock::new(now.clone(), timestamp_interval.clone());
         let (mut timestamper, mut initial_batch) = ReclockOperator::new(remap_handle, clock).await;
 
         let mut follower = ReclockFollower::new(as_of);
@@ -1132,7 +1149,7 @@ where
         // The last frontier we compacted the remap shard to, starting at [0].
         let mut last_compaction_since = Antichain::from_elem(Timestamp::default());
 
-        let mut ticker = tokio::time::interval(timestamp_interval);
+        let mut ticker = tokio::time::interval(*timestamp_interval.borrow_and_update());
         ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
         let mut input_frontier = Antichain::from_elem(Timestamp::default());
@@ -1209,6 +1226,11 @@ where
                         last_compaction_since = new_compaction_since;
                     }
                 }
+                // Mint new bindings at the new interval from now on.
+                Ok(()) = timestamp_interval.changed() => {
+                    ticker = tokio::time::interval(*timestamp_interval.borrow_and_update());
+                    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+                }
                 Some(event) = input.next() => match event {
                     Event::Data(_cap, data) => {
                         data.swap(&mut buffer);
@@ -19,7 +19,7 @@ use mz_ore::metric;
 use mz_ore::metrics::{CounterVecExt, DeleteOnDropCounter, DeleteOnDropGauge, GaugeVecExt};
 use mz_ore::metrics::{IntCounterVec, MetricsRegistry, UIntGaugeVec};
 use mz_repr::GlobalId;
-use mz_storage_client::client::SourceStatisticsUpdate;
+use mz_storage_client::client::{KafkaConsumerStatistics, SourceStatisticsUpdate};
 use prometheus::core::AtomicU64;
 
 use crate::source::metrics::SourceBaseMetrics;
@@ -31,6 +31,7 @@ pub(crate) struct SourceStatisticsMetricsDefinitions {
     pub(crate) updates_staged: IntCounterVec,
     pub(crate) updates_committed: IntCounterVec,
     pub(crate) bytes_received: IntCounterVec,
+    pub(crate) messages_quarantined: IntCounterVec,
 }
 
 impl SourceStatisticsMetricsDefinitions {
@@ -61,6 +62,11 @@ impl SourceStatisticsMetricsDefinitions {
                 help: "The number of bytes worth of messages the worker has received from upstream. The way the bytes are counted is source-specific.",
                 var_labels: ["source_id", "worker_id", "parent_source_id"],
             )),
+            messages_quarantined: registry.register(metric!(
+                name: "mz_messages_quarantined",
+                help: "The number of messages the worker has skipped because their schema could not be resolved.",
+                var_labels: ["source_id", "worker_id", "parent_source_id"],
+            )),
         }
     }
 }
@@ -72,6 +78,7 @@ pub struct SourceStatisticsMetrics {
     pub(crate) updates_staged: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
     pub(crate) updates_committed: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
     pub(crate) bytes_received: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    pub(crate) messages_quarantined: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
 }
 
 impl SourceStatisticsMetrics {
@@ -128,6 +135,14 @@ impl SourceStatisticsMetrics {
                     worker_id.to_string(),
                     parent_source_id.to_string(),
                 ]),
+            messages_quarantined: metrics
+                .source_statistics
+                .messages_quarantined
+                .get_delete_on_drop_counter(vec![
+                    id.to_string(),
+                    worker_id.to_string(),
+                    parent_source_id.to_string(),
+                ]),
         }
     }
 }
@@ -179,6 +194,9 @@ impl SourceStatistics {
                     updates_staged: 0,
                     updates_committed: 0,
                     bytes_received: 0,
+                    messages_quarantined: 0,
+                    last_quarantine_error: None,
+                    kafka_consumer: None,
                 },
                 SourceStatisticsMetrics::new(id, worker_id, metrics, parent_source_id, shard_id),
             ))),
@@ -234,4 +252,19 @@ impl SourceStatistics {
         cur.1.bytes_received = cur.1.bytes_received + value;
         cur.2.bytes_received.inc_by(value);
     }
+
+    /// Increment the `messages_quarantined` stat, and record `error` as the
+    /// error of the most recently quarantined message.
+    pub fn inc_messages_quarantined(&self, error: String) {
+        let mut cur = self.stats.borrow_mut();
+        cur.1.messages_quarantined = cur.1.messages_quarantined + 1;
+        cur.1.last_quarantine_error = Some(error);
+        cur.2.messages_quarantined.inc();
+    }
+
+    /// Set the statistics of the consumer of a Kafka source.
+    pub fn set_kafka_consumer_statistics(&self, statistics: KafkaConsumerStatistics) {
+        let mut cur = self.stats.borrow_mut();
+        cur.1.kafka_consumer = Some(statistics);
+    }
 }
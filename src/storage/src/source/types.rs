@@ -29,6 +29,7 @@ use timely::Data;
 use mz_expr::PartitionId;
 use mz_ore::metrics::{CounterVecExt, DeleteOnDropCounter, DeleteOnDropGauge, GaugeVecExt};
 use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_storage_client::client::KafkaConsumerStatistics;
 use mz_storage_client::types::connections::ConnectionContext;
 use mz_storage_client::types::errors::{DecodeError, SourceErrorDetails};
 use mz_storage_client::types::sources::encoding::SourceDataEncoding;
@@ -200,6 +201,9 @@ pub enum SourceMessageType<Key, Value, Diff> {
     /// We need these to compute a "global" source upper, when determining
     /// completeness of a timestamp.
     DropPartitionCapabilities(Vec<PartitionId>),
+    /// The statistics that the consumer of a Kafka source reported last, for
+    /// `mz_internal.mz_kafka_consumer_statistics`.
+    KafkaConsumerStatistics(KafkaConsumerStatistics),
 }
 
 impl<Key, Value, Diff> SourceMessageType<Key, Value, Diff> {
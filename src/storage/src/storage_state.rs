@@ -103,6 +103,9 @@ pub struct StorageState {
     /// Stats objects shared with operators to allow them to update the metrics
     /// we report in `StatisticsUpdates` responses.
     pub source_statistics: HashMap<GlobalId, SourceStatistics>,
+    /// Senders of the current timestamp interval of each ingestion, which
+    /// allow changing the interval of running ingestion dataflows.
+    pub source_timestamp_intervals: HashMap<GlobalId, watch::Sender<Duration>>,
 }
 
 /// This maintains an additional read hold on the source data for a sink, alongside
@@ -373,12 +376,26 @@ impl<'w, A: Allocate> Worker<'w, A> {
                         self.storage_state.source_uppers.remove(&id);
                         self.storage_state.reported_frontiers.remove(&id);
                         self.storage_state.source_tokens.remove(&id);
+                        self.storage_state.source_timestamp_intervals.remove(&id);
                         self.storage_state.sink_tokens.remove(&id);
                         self.storage_state.sink_handles.remove(&id);
                         self.storage_state.dropped_ids.push(id);
                     }
                 }
             }
+            StorageCommand::UpdateTimestampIntervals(intervals) => {
+                for (id, interval) in intervals {
+                    if let Some(ingestion) = self.storage_state.ingestions.get_mut(&id) {
+                        ingestion.desc.timestamp_interval = interval;
+                    }
+                    if let Some(sender) = self.storage_state.source_timestamp_intervals.get(&id) {
+                        tracing::info!("updating timestamp interval of {id} to {interval:?}");
+                        // The ingestion dataflow may have shut down already,
+                        // in which case there is nobody left to inform.
+                        let _ = sender.send(interval);
+                    }
+                }
+            }
         }
     }
 
@@ -495,16 +512,25 @@ impl<'w, A: Allocate> Worker<'w, A> {
         // ingestions no longer exist.
         let mut stale_ingestions = self.storage_state.ingestions.keys().collect::<HashSet<_>>();
         let mut stale_exports = self.storage_state.exports.keys().collect::<HashSet<_>>();
+        let mut timestamp_intervals = Vec::new();
         for command in &mut commands {
             match command {
                 StorageCommand::CreateSources(ingestions) => {
                     ingestions.retain_mut(|ingestion| {
                         if let Some(existing) = self.storage_state.ingestions.get(&ingestion.id) {
                             stale_ingestions.remove(&ingestion.id);
+                            // The timestamp interval can be changed at runtime,
+                            // so we might have missed an update to it.
+                            let mut existing = existing.clone();
+                            let timestamp_interval = ingestion.description.desc.timestamp_interval;
+                            if existing.desc.timestamp_interval != timestamp_interval {
+                                existing.desc.timestamp_interval = timestamp_interval;
+                                timestamp_intervals.push((ingestion.id, timestamp_interval));
+                            }
                             // If we've been asked to create an ingestion that is
                             // already installed, the descriptions must match
                             // exactly.
-                            if *existing != ingestion.description {
+                            if existing != ingestion.description {
                                 halt!(
                                     "new ingestion with ID {} does not match existing ingestion:\n{:?}\nvs\n{:?}",
                                     ingestion.id,
@@ -538,10 +564,16 @@ impl<'w, A: Allocate> Worker<'w, A> {
                 }
                 StorageCommand::InitializationComplete
                 | StorageCommand::UpdateConfiguration(_)
-                | StorageCommand::AllowCompaction(_) => (),
+                | StorageCommand::AllowCompaction(_)
+                | StorageCommand::UpdateTimestampIntervals(_) => (),
             }
         }
 
+        // Apply any timestamp interval changes to the ingestions we keep.
+        commands.push(StorageCommand::UpdateTimestampIntervals(
+            timestamp_intervals,
+        ));
+
         // Synthesize a drop command to remove stale ingestions and exports
         commands.push(StorageCommand::AllowCompaction(
             stale_ingestions
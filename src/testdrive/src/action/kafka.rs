@@ -9,12 +9,14 @@
 
 mod add_partitions;
 mod create_topic;
+mod delete_topic;
 mod ingest;
 mod verify_commit;
 mod verify_data;
 
 pub use add_partitions::run_add_partitions;
 pub use create_topic::run_create_topic;
+pub use delete_topic::run_delete_topic;
 pub use ingest::run_ingest;
 pub use verify_commit::run_verify_commit;
 pub use verify_data::run_verify_data;
@@ -0,0 +1,74 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::cmp;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use rdkafka::producer::Producer;
+
+use mz_ore::collections::CollectionExt;
+use mz_ore::retry::Retry;
+use mz_ore::str::StrExt;
+
+use crate::action::{ControlFlow, State};
+use crate::parser::BuiltinCommand;
+
+pub async fn run_delete_topic(
+    mut cmd: BuiltinCommand,
+    state: &mut State,
+) -> Result<ControlFlow, anyhow::Error> {
+    let topic_prefix = format!("testdrive-{}", cmd.args.string("topic")?);
+    cmd.args.done()?;
+
+    let topic_name = format!("{}-{}", topic_prefix, state.seed);
+    println!("Deleting Kafka topic {}", topic_name);
+
+    if !state.kafka_topics.contains_key(&topic_name) {
+        bail!(
+            "topic {} not created by kafka-create-topic",
+            topic_name.quoted(),
+        )
+    }
+
+    let res = state
+        .kafka_admin
+        .delete_topics(&[topic_name.as_str()], &state.kafka_admin_opts)
+        .await
+        .context("deleting topic")?;
+    if res.len() != 1 {
+        bail!(
+            "kafka topic deletion returned {} results, but exactly one result was expected",
+            res.len()
+        );
+    }
+    if let Err((_topic_name, e)) = res.into_element() {
+        return Err(e.into());
+    }
+
+    // Topic deletion is asynchronous, see `run_create_topic`. Wait until the
+    // topic's metadata is gone, so that a subsequent `kafka-create-topic`
+    // recreates it.
+    Retry::default()
+        .max_duration(state.default_timeout)
+        .retry_async_canceling(|_| async {
+            let metadata = state.kafka_producer.client().fetch_metadata(
+                None,
+                Some(cmp::max(state.default_timeout, Duration::from_secs(1))),
+            )?;
+            if metadata.topics().iter().any(|t| t.name() == topic_name) {
+                bail!("topic {} still exists", topic_name.quoted());
+            }
+            Ok(())
+        })
+        .await?;
+
+    state.kafka_topics.remove(&topic_name);
+    Ok(ControlFlow::Continue)
+}
@@ -40,7 +40,7 @@ enum Format {
         message: String,
         confluent_wire_format: bool,
         schema_id_subject: Option<String>,
-        schema_message_id: u8,
+        schema_message_id: Option<u8>,
     },
     Bytes {
         terminator: Option<u8>,
@@ -59,7 +59,7 @@ enum Transcoder {
         message: MessageDescriptor,
         confluent_wire_format: bool,
         schema_id: i32,
-        schema_message_id: u8,
+        schema_message_id: Option<u8>,
     },
     Bytes {
         terminator: Option<u8>,
@@ -67,6 +67,22 @@ enum Transcoder {
 }
 
 impl Transcoder {
+    /// Encodes the indexes of a message as the Confluent wire format does: as
+    /// an array of zigzag varints preceded by its length, except that the
+    /// first message of a schema is encoded as a single 0 byte.
+    fn encode_message_indexes(message: &MessageDescriptor, out: &mut Vec<u8>) {
+        let indexes = mz_interchange::protobuf::message_indexes(message);
+        if indexes == [0] {
+            out.push(0);
+            return;
+        }
+        let zigzag = |n: i32| u64::from(((n << 1) ^ (n >> 31)) as u32);
+        prost::encoding::encode_varint(zigzag(i32::try_from(indexes.len()).unwrap()), out);
+        for index in indexes {
+            prost::encoding::encode_varint(zigzag(index), out);
+        }
+    }
+
     fn decode_json<R, T>(row: R) -> Result<Option<T>, anyhow::Error>
     where
         R: Read,
@@ -119,19 +135,22 @@ impl Transcoder {
                 schema_message_id,
             } => {
                 if let Some(val) = Self::decode_json::<_, serde_json::Value>(row)? {
-                    let message = DynamicMessage::deserialize(message.clone(), val)
+                    let descriptor = message;
+                    let message = DynamicMessage::deserialize(descriptor.clone(), val)
                         .context("parsing protobuf JSON")?;
                     let mut out = vec![];
                     if *confluent_wire_format {
-                        // See: https://github.com/MaterializeInc/materialize/issues/9250
                         // The first byte is a magic byte (0) that indicates the Confluent
                         // serialization format version, and the next four bytes are a
                         // 32-bit schema ID, which we default to something fun.
-                        // And, as we only support single-message proto files for now,
-                        // we also set the following message id to 0.
                         out.write_u8(0).unwrap();
                         out.write_i32::<NetworkEndian>(*schema_id).unwrap();
-                        out.write_u8(*schema_message_id).unwrap();
+                        // The schema ID is followed by the indexes of the message within
+                        // the schema, unless the test overrides them with a raw byte.
+                        match schema_message_id {
+                            Some(schema_message_id) => out.write_u8(*schema_message_id).unwrap(),
+                            None => Self::encode_message_indexes(descriptor, &mut out),
+                        }
                     }
                     message.encode(&mut out)?;
                     Ok(Some(out))
@@ -189,7 +208,7 @@ pub async fn run_ingest(
                 // false
                 confluent_wire_format: cmd.args.opt_bool("confluent-wire-format")?.unwrap_or(false),
                 schema_id_subject: cmd.args.opt_string("schema-id-subject"),
-                schema_message_id: cmd.args.opt_parse::<u8>("schema-message-id")?.unwrap_or(0),
+                schema_message_id: cmd.args.opt_parse::<u8>("schema-message-id")?,
             }
         }
         "bytes" => Format::Bytes { terminator: None },
@@ -211,10 +230,7 @@ pub async fn run_ingest(
                 message,
                 confluent_wire_format: cmd.args.opt_bool("confluent-wire-format")?.unwrap_or(false),
                 schema_id_subject: cmd.args.opt_string("key-schema-id-subject"),
-                schema_message_id: cmd
-                    .args
-                    .opt_parse::<u8>("key-schema-message-id")?
-                    .unwrap_or(0),
+                schema_message_id: cmd.args.opt_parse::<u8>("key-schema-message-id")?,
             })
         }
         Some("bytes") => Some(Format::Bytes {
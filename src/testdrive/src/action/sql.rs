@@ -70,6 +70,7 @@ pub async fn run_sql(mut cmd: SqlCommand, state: &mut State) -> Result<ControlFl
         | CreateRole(_)
         | AlterObjectRename(_)
         | AlterIndex(_)
+        | AlterSchema(_)
         | Discard(_)
         | DropDatabase(_)
         | DropObjects(_)
@@ -80,8 +80,8 @@ use crate::{TransformArgs, TransformError};
 use itertools::Itertools;
 use mz_expr::visit::{Visit, VisitChildren};
 use mz_expr::{
-    func, AggregateFunc, Id, JoinInputMapper, MirRelationExpr, MirScalarExpr, VariadicFunc,
-    RECURSION_LIMIT,
+    func, AggregateFunc, BinaryFunc, Id, JoinInputMapper, MirRelationExpr, MirScalarExpr,
+    VariadicFunc, RECURSION_LIMIT,
 };
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_repr::{ColumnType, Datum, ScalarType};
@@ -202,7 +202,6 @@ impl PredicatePushdown {
                             let mut pred_not_translated = Vec::new();
 
                             for mut predicate in predicates.drain(..) {
-                                use mz_expr::BinaryFunc;
                                 use mz_expr::UnaryFunc;
                                 if let MirScalarExpr::CallBinary {
                                     func: BinaryFunc::Eq,
@@ -779,6 +778,35 @@ impl PredicatePushdown {
         input_arity: usize,
         all_errors: bool,
     ) -> Result<(Vec<MirScalarExpr>, Vec<MirScalarExpr>), TransformError> {
+        // The expressions of the map that can be inlined, in terms of the columns
+        // of the input. Expressions may refer to the columns of earlier
+        // expressions of the map, for example after memoization has split a
+        // chain of jsonb accessors like `(#0 -> 'a') ->> 'b'` into one
+        // expression per accessor, so we first inline those columns. This
+        // cannot blow up the size of the expressions, as `can_inline` only
+        // accepts expressions in which each call has at most one argument that
+        // is not a leaf.
+        let mut inlined: Vec<Option<MirScalarExpr>> = Vec::with_capacity(scalars.len());
+        for scalar in scalars {
+            let mut scalar = scalar.clone();
+            let mut inlinable = true;
+            scalar.visit_mut_post(&mut |e| {
+                if let MirScalarExpr::Column(c) = e {
+                    if *c >= input_arity {
+                        match &inlined[*c - input_arity] {
+                            Some(expr) => *e = expr.clone(),
+                            None => inlinable = false,
+                        }
+                    }
+                }
+            })?;
+            if inlinable && PredicatePushdown::can_inline(&scalar, input_arity) {
+                inlined.push(Some(scalar));
+            } else {
+                inlined.push(None);
+            }
+        }
+
         let mut pushdown = Vec::new();
         let mut retained = Vec::new();
         for mut predicate in predicates.drain(..) {
@@ -787,19 +815,18 @@ impl PredicatePushdown {
             // expression that can be inlined.
             // We also will not push down literal errors, unless all predicates are.
             if (!predicate.is_literal_err() || all_errors)
-                && predicate.support().iter().all(|c| {
-                    *c < input_arity
-                        || PredicatePushdown::can_inline(&scalars[*c - input_arity], input_arity)
-                })
+                && predicate
+                    .support()
+                    .iter()
+                    .all(|c| *c < input_arity || inlined[*c - input_arity].is_some())
             {
                 predicate.visit_mut_post(&mut |e| {
                     if let MirScalarExpr::Column(c) = e {
-                        // NB: this inlining would be invalid if can_inline did not
-                        // verify that scalars[*c - input_arity] referenced only
-                        // expressions from the input and not any newly-constructed
-                        // columns from the Map.
+                        // NB: this inlining would be invalid if the inlined
+                        // expressions referenced any newly-constructed columns
+                        // from the Map, rather than only columns of the input.
                         if *c >= input_arity {
-                            *e = scalars[*c - input_arity].clone()
+                            *e = inlined[*c - input_arity].clone().expect("checked above")
                         }
                     }
                 })?;
@@ -842,6 +869,9 @@ impl PredicatePushdown {
     /// `(isnull(expr1) && isnull(expr2)) || (expr1 = expr2)`, or
     /// `(decompose_is_null(expr1) && decompose_is_null(expr2)) || (expr1 = expr2)`,
     /// extract `expr1` and `expr2`.
+    ///
+    /// The equality may also be guarded by `!isnull(expr1) && !isnull(expr2)`,
+    /// which is how `expr1 IS NOT DISTINCT FROM expr2` is planned.
     fn extract_equal_or_both_null(
         s: &mut MirScalarExpr,
         column_types: &[ColumnType],
@@ -868,23 +898,47 @@ impl PredicatePushdown {
         column_types: &[ColumnType],
     ) -> Option<(MirScalarExpr, MirScalarExpr)> {
         use mz_expr::BinaryFunc;
-        if let MirScalarExpr::CallBinary {
-            func: BinaryFunc::Eq,
-            expr1: eq_lhs,
-            expr2: eq_rhs,
-        } = &or_arg2
-        {
-            let isnull1 = eq_lhs.clone().call_is_null();
-            let isnull2 = eq_rhs.clone().call_is_null();
-            let both_null = MirScalarExpr::CallVariadic {
-                func: VariadicFunc::And,
-                exprs: vec![isnull1, isnull2],
-            };
-
-            if Self::extract_reduced_conjunction_terms(both_null, column_types)
-                == Self::extract_reduced_conjunction_terms(or_arg1.clone(), column_types)
+        let terms = Self::extract_reduced_conjunction_terms(or_arg2.clone(), column_types);
+        for (i, term) in terms.iter().enumerate() {
+            if let MirScalarExpr::CallBinary {
+                func: BinaryFunc::Eq,
+                expr1: eq_lhs,
+                expr2: eq_rhs,
+            } = term
             {
-                return Some(((**eq_lhs).clone(), (**eq_rhs).clone()));
+                // Any other terms must be exactly the guards that neither side
+                // of the equality is null.
+                let guards: Vec<_> = terms
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, t)| t.clone())
+                    .collect();
+                if !guards.is_empty() {
+                    let not_null = MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::And,
+                        exprs: vec![
+                            eq_lhs.clone().call_is_null().not(),
+                            eq_rhs.clone().call_is_null().not(),
+                        ],
+                    };
+                    if guards != Self::extract_reduced_conjunction_terms(not_null, column_types) {
+                        continue;
+                    }
+                }
+
+                let isnull1 = eq_lhs.clone().call_is_null();
+                let isnull2 = eq_rhs.clone().call_is_null();
+                let both_null = MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::And,
+                    exprs: vec![isnull1, isnull2],
+                };
+
+                if Self::extract_reduced_conjunction_terms(both_null, column_types)
+                    == Self::extract_reduced_conjunction_terms(or_arg1.clone(), column_types)
+                {
+                    return Some(((**eq_lhs).clone(), (**eq_rhs).clone()));
+                }
             }
         }
         None
@@ -915,6 +969,17 @@ impl PredicatePushdown {
         Self::is_safe_leaf(s, input_arity)
             || match s {
                 MirScalarExpr::CallUnary { func: _, expr } => Self::can_inline(expr, input_arity),
+                // Jsonb accessors are cheap and pure, so we inline chains of
+                // them, like `(payload -> 'a') ->> 'b'`, so that filters on
+                // the fields of jsonb values can be pushed towards the source.
+                MirScalarExpr::CallBinary {
+                    func:
+                        BinaryFunc::JsonbGetInt64 { .. }
+                        | BinaryFunc::JsonbGetString { .. }
+                        | BinaryFunc::JsonbGetPath { .. },
+                    expr1,
+                    expr2,
+                } => Self::can_inline(expr1, input_arity) && Self::is_safe_leaf(expr2, input_arity),
                 MirScalarExpr::CallBinary {
                     func: _,
                     expr1,